@@ -34,6 +34,7 @@ mv --verbose --force \
     "target/${TARGET_WINDOWS}/release/zargo.exe" \
     "target/${TARGET_WINDOWS}/release/zvm.exe" \
     "target/${TARGET_WINDOWS}/release/znc.exe" \
+    "target/${TARGET_WINDOWS}/release/zinc-lsp.exe" \
     "target/${TARGET_WINDOWS}/release/schnorr.exe" \
     "zinc-${VERSION_ZINC}-windows"
 zip --verbose -r \
@@ -62,6 +63,7 @@ mv --verbose --force \
     "target/${TARGET_LINUX}/release/zargo" \
     "target/${TARGET_LINUX}/release/zvm" \
     "target/${TARGET_LINUX}/release/znc" \
+    "target/${TARGET_LINUX}/release/zinc-lsp" \
     "target/${TARGET_LINUX}/release/schnorr" \
     "zinc-${VERSION_ZINC}-linux"
 tar --verbose \
@@ -105,6 +107,7 @@ mv --verbose --force \
     "target/${TARGET_MACOS}/release/zargo" \
     "target/${TARGET_MACOS}/release/zvm" \
     "target/${TARGET_MACOS}/release/znc" \
+    "target/${TARGET_MACOS}/release/zinc-lsp" \
     "target/${TARGET_MACOS}/release/schnorr" \
     "zinc-${VERSION_ZINC}-macos"
 zip --verbose -r \
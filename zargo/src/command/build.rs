@@ -16,6 +16,8 @@ use crate::directory::source::Directory as SourceDirectory;
 use crate::directory::source::Error as SourceDirectoryError;
 use crate::executable::compiler::Compiler;
 use crate::executable::compiler::Error as CompilerError;
+use crate::lockfile::Error as LockfileError;
+use crate::lockfile::Lockfile;
 use crate::manifest::Error as ManifestError;
 use crate::manifest::Manifest;
 
@@ -56,12 +58,30 @@ pub struct Command {
         default_value = "./data/public-data.json"
     )]
     public_data: PathBuf,
+
+    #[structopt(
+        long = "message-format",
+        help = "The compiler diagnostics format, either `human` or `json`",
+        default_value = "human",
+        possible_values = &["human", "json"]
+    )]
+    message_format: String,
+
+    #[structopt(
+        long = "emit",
+        help = "Dumps the compiler output next to the circuit binary file, comma-separated `tokens`, `ast`, `ir` or `asm`"
+    )]
+    emit: Option<String>,
 }
 
 #[derive(Debug, Fail)]
 pub enum Error {
     #[fail(display = "manifest file {}", _0)]
     ManifestFile(ManifestError),
+    #[fail(display = "`{}` is a library, only circuits can be built", _0)]
+    LibraryNotBuildable(String),
+    #[fail(display = "lockfile {}", _0)]
+    Lockfile(LockfileError),
     #[fail(display = "build directory {}", _0)]
     BuildDirectory(BuildDirectoryError),
     #[fail(display = "data directory {}", _0)]
@@ -74,13 +94,22 @@ pub enum Error {
 
 impl Command {
     pub fn execute(self) -> Result<(), Error> {
-        let _manifest = Manifest::try_from(&self.manifest_path).map_err(Error::ManifestFile)?;
+        let manifest = Manifest::try_from(&self.manifest_path).map_err(Error::ManifestFile)?;
+        if manifest.is_library() {
+            return Err(Error::LibraryNotBuildable(
+                manifest.project().name.to_owned(),
+            ));
+        }
 
         let mut circuit_path = self.manifest_path.clone();
         if circuit_path.is_file() {
             circuit_path.pop();
         }
 
+        let lockfile = Lockfile::resolve(&circuit_path, &manifest).map_err(Error::Lockfile)?;
+        lockfile.write_to(&circuit_path).map_err(Error::Lockfile)?;
+        let dependencies = lockfile.compiler_dependencies(&circuit_path);
+
         let source_file_paths =
             SourceDirectory::files(&circuit_path).map_err(Error::SourceDirectory)?;
 
@@ -93,6 +122,10 @@ impl Command {
             &self.public_data,
             &self.circuit,
             &source_file_paths,
+            &dependencies,
+            &manifest.lints,
+            Some(self.message_format.as_str()),
+            self.emit.as_deref(),
         )
         .map_err(Error::Compiler)?;
 
@@ -0,0 +1,103 @@
+//!
+//! The `doc` command.
+//!
+
+use std::convert::TryFrom;
+use std::path::PathBuf;
+
+use failure::Fail;
+use structopt::StructOpt;
+
+use crate::directory::source::Directory as SourceDirectory;
+use crate::directory::source::Error as SourceDirectoryError;
+use crate::executable::compiler::Compiler;
+use crate::executable::compiler::Error as CompilerError;
+use crate::lockfile::Error as LockfileError;
+use crate::lockfile::Lockfile;
+use crate::manifest::Error as ManifestError;
+use crate::manifest::Manifest;
+
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Generates the project documentation")]
+pub struct Command {
+    #[structopt(
+        short = "v",
+        parse(from_occurrences),
+        help = "Shows verbose logs, use multiple times for more verbosity"
+    )]
+    verbosity: usize,
+
+    #[structopt(
+        long = "manifest-path",
+        help = "Path to Zargo.toml",
+        default_value = "./Zargo.toml"
+    )]
+    manifest_path: PathBuf,
+
+    #[structopt(
+        short = "o",
+        long = "output",
+        help = "Path to the documentation directory",
+        default_value = "./build/doc"
+    )]
+    output: PathBuf,
+
+    #[structopt(
+        long = "format",
+        help = "The documentation format, either `html` or `markdown`",
+        default_value = "html",
+        possible_values = &["html", "markdown"]
+    )]
+    format: String,
+
+    #[structopt(
+        long = "message-format",
+        help = "The compiler diagnostics format, either `human` or `json`",
+        default_value = "human",
+        possible_values = &["human", "json"]
+    )]
+    message_format: String,
+}
+
+#[derive(Debug, Fail)]
+pub enum Error {
+    #[fail(display = "manifest file {}", _0)]
+    ManifestFile(ManifestError),
+    #[fail(display = "lockfile {}", _0)]
+    Lockfile(LockfileError),
+    #[fail(display = "source directory {}", _0)]
+    SourceDirectory(SourceDirectoryError),
+    #[fail(display = "compiler {}", _0)]
+    Compiler(CompilerError),
+}
+
+impl Command {
+    pub fn execute(self) -> Result<(), Error> {
+        let manifest = Manifest::try_from(&self.manifest_path).map_err(Error::ManifestFile)?;
+
+        let mut project_path = self.manifest_path.clone();
+        if project_path.is_file() {
+            project_path.pop();
+        }
+
+        let lockfile = Lockfile::resolve(&project_path, &manifest).map_err(Error::Lockfile)?;
+        lockfile.write_to(&project_path).map_err(Error::Lockfile)?;
+        let dependencies = lockfile.compiler_dependencies(&project_path);
+
+        let source_file_paths =
+            SourceDirectory::files(&project_path).map_err(Error::SourceDirectory)?;
+
+        Compiler::doc(
+            self.verbosity,
+            manifest.project().name.as_str(),
+            &self.output,
+            self.format.as_str(),
+            &source_file_paths,
+            &dependencies,
+            Some(self.message_format.as_str()),
+        )
+        .map_err(Error::Compiler)?;
+
+        Ok(())
+    }
+}
@@ -6,8 +6,11 @@ use failure::Fail;
 
 use crate::command::build::Error as BuildCommandError;
 use crate::command::clean::Error as CleanCommandError;
+use crate::command::doc::Error as DocCommandError;
+use crate::command::fmt::Error as FmtCommandError;
 use crate::command::init::Error as InitCommandError;
 use crate::command::new::Error as NewCommandError;
+use crate::command::profile::Error as ProfileCommandError;
 use crate::command::proof_check::Error as ProofCheckCommandError;
 use crate::command::prove::Error as ProveCommandError;
 use crate::command::run::Error as RunCommandError;
@@ -25,8 +28,14 @@ pub enum Error {
     #[fail(display = "{}", _0)]
     Clean(CleanCommandError),
     #[fail(display = "{}", _0)]
+    Fmt(FmtCommandError),
+    #[fail(display = "{}", _0)]
+    Doc(DocCommandError),
+    #[fail(display = "{}", _0)]
     Run(RunCommandError),
     #[fail(display = "{}", _0)]
+    Profile(ProfileCommandError),
+    #[fail(display = "{}", _0)]
     Setup(SetupCommandError),
     #[fail(display = "{}", _0)]
     Prove(ProveCommandError),
@@ -60,12 +69,30 @@ impl From<CleanCommandError> for Error {
     }
 }
 
+impl From<FmtCommandError> for Error {
+    fn from(inner: FmtCommandError) -> Self {
+        Self::Fmt(inner)
+    }
+}
+
+impl From<DocCommandError> for Error {
+    fn from(inner: DocCommandError) -> Self {
+        Self::Doc(inner)
+    }
+}
+
 impl From<RunCommandError> for Error {
     fn from(inner: RunCommandError) -> Self {
         Self::Run(inner)
     }
 }
 
+impl From<ProfileCommandError> for Error {
+    fn from(inner: ProfileCommandError) -> Self {
+        Self::Profile(inner)
+    }
+}
+
 impl From<SetupCommandError> for Error {
     fn from(inner: SetupCommandError) -> Self {
         Self::Setup(inner)
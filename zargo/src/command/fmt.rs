@@ -0,0 +1,69 @@
+//!
+//! The `fmt` command.
+//!
+
+use std::convert::TryFrom;
+use std::path::PathBuf;
+
+use failure::Fail;
+use structopt::StructOpt;
+
+use crate::directory::source::Directory as SourceDirectory;
+use crate::directory::source::Error as SourceDirectoryError;
+use crate::executable::compiler::Compiler;
+use crate::executable::compiler::Error as CompilerError;
+use crate::manifest::Error as ManifestError;
+use crate::manifest::Manifest;
+
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Formats the project source files")]
+pub struct Command {
+    #[structopt(
+        short = "v",
+        parse(from_occurrences),
+        help = "Shows verbose logs, use multiple times for more verbosity"
+    )]
+    verbosity: usize,
+
+    #[structopt(
+        long = "manifest-path",
+        help = "Path to Zargo.toml",
+        default_value = "./Zargo.toml"
+    )]
+    manifest_path: PathBuf,
+
+    #[structopt(
+        long = "check",
+        help = "Checks the files without writing them, and fails if any of them is not formatted"
+    )]
+    check: bool,
+}
+
+#[derive(Debug, Fail)]
+pub enum Error {
+    #[fail(display = "manifest file {}", _0)]
+    ManifestFile(ManifestError),
+    #[fail(display = "source directory {}", _0)]
+    SourceDirectory(SourceDirectoryError),
+    #[fail(display = "compiler {}", _0)]
+    Compiler(CompilerError),
+}
+
+impl Command {
+    pub fn execute(self) -> Result<(), Error> {
+        let _manifest = Manifest::try_from(&self.manifest_path).map_err(Error::ManifestFile)?;
+
+        let mut project_path = self.manifest_path;
+        if project_path.is_file() {
+            project_path.pop();
+        }
+
+        let source_file_paths =
+            SourceDirectory::files(&project_path).map_err(Error::SourceDirectory)?;
+
+        Compiler::format(self.verbosity, &source_file_paths, self.check)
+            .map_err(Error::Compiler)?;
+
+        Ok(())
+    }
+}
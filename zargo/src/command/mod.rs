@@ -4,9 +4,12 @@
 
 pub mod build;
 pub mod clean;
+pub mod doc;
 pub mod error;
+pub mod fmt;
 pub mod init;
 pub mod new;
+pub mod profile;
 pub mod proof_check;
 pub mod prove;
 pub mod run;
@@ -17,9 +20,12 @@ use structopt::StructOpt;
 
 use self::build::Command as BuildCommand;
 use self::clean::Command as CleanCommand;
+use self::doc::Command as DocCommand;
 use self::error::Error;
+use self::fmt::Command as FmtCommand;
 use self::init::Command as InitCommand;
 use self::new::Command as NewCommand;
+use self::profile::Command as ProfileCommand;
 use self::proof_check::Command as ProofCheckCommand;
 use self::prove::Command as ProveCommand;
 use self::run::Command as RunCommand;
@@ -32,7 +38,10 @@ pub enum Command {
     Init(InitCommand),
     Build(BuildCommand),
     Clean(CleanCommand),
+    Fmt(FmtCommand),
+    Doc(DocCommand),
     Run(RunCommand),
+    Profile(ProfileCommand),
     Setup(SetupCommand),
     Prove(ProveCommand),
     Verify(VerifyCommand),
@@ -46,7 +55,10 @@ impl Command {
             Self::Init(command) => command.execute()?,
             Self::Build(command) => command.execute()?,
             Self::Clean(command) => command.execute()?,
+            Self::Fmt(command) => command.execute()?,
+            Self::Doc(command) => command.execute()?,
             Self::Run(command) => command.execute()?,
+            Self::Profile(command) => command.execute()?,
             Self::Setup(command) => command.execute()?,
             Self::Prove(command) => command.execute()?,
             Self::Verify(command) => command.execute()?,
@@ -10,6 +10,8 @@ use std::path::PathBuf;
 use failure::Fail;
 use structopt::StructOpt;
 
+use crate::directory::source::lib::Error as LibFileError;
+use crate::directory::source::lib::Lib as LibFile;
 use crate::directory::source::main::Error as MainFileError;
 use crate::directory::source::main::Main as MainFile;
 use crate::directory::source::Directory as SourceDirectory;
@@ -33,6 +35,9 @@ pub struct Command {
     )]
     name: Option<String>,
 
+    #[structopt(long = "lib", help = "Creates a library instead of a circuit")]
+    is_library: bool,
+
     #[structopt(parse(from_os_str))]
     path: PathBuf,
 }
@@ -54,6 +59,8 @@ pub enum Error {
     SourceDirectory(SourceDirectoryError),
     #[fail(display = "main file {}", _0)]
     MainFile(MainFileError),
+    #[fail(display = "lib file {}", _0)]
+    LibFile(LibFileError),
 }
 
 impl Command {
@@ -75,20 +82,34 @@ impl Command {
             Error::CreatingRootDirectory(self.path.as_os_str().to_owned(), error)
         })?;
 
-        Manifest::new(&circuit_name)
-            .write_to(&self.path)
-            .map_err(Error::ManifestFile)?;
+        let manifest = if self.is_library {
+            Manifest::new_library(&circuit_name)
+        } else {
+            Manifest::new(&circuit_name)
+        };
+        manifest.write_to(&self.path).map_err(Error::ManifestFile)?;
 
         SourceDirectory::create(&self.path).map_err(Error::SourceDirectory)?;
 
-        if !MainFile::exists_at(&self.path) {
+        if self.is_library {
+            if !LibFile::exists_at(&self.path) {
+                LibFile::new(&circuit_name)
+                    .write_to(&self.path)
+                    .map_err(Error::LibFile)?;
+            }
+        } else if !MainFile::exists_at(&self.path) {
             MainFile::new(&circuit_name)
                 .write_to(&self.path)
                 .map_err(Error::MainFile)?;
         }
 
         log::info!(
-            "     Created circuit `{}` at {}",
+            "     Created {} `{}` at {}",
+            if self.is_library {
+                "library"
+            } else {
+                "circuit"
+            },
             circuit_name,
             self.path.to_string_lossy()
         );
@@ -0,0 +1,141 @@
+//!
+//! The `profile` command.
+//!
+
+use std::convert::TryFrom;
+use std::path::PathBuf;
+
+use failure::Fail;
+use structopt::StructOpt;
+
+use crate::directory::build::Directory as BuildDirectory;
+use crate::directory::build::Error as BuildDirectoryError;
+use crate::directory::data::Directory as DataDirectory;
+use crate::directory::data::Error as DataDirectoryError;
+use crate::directory::source::Directory as SourceDirectory;
+use crate::directory::source::Error as SourceDirectoryError;
+use crate::executable::compiler::Compiler;
+use crate::executable::compiler::Error as CompilerError;
+use crate::executable::virtual_machine::Error as VirtualMachineError;
+use crate::executable::virtual_machine::VirtualMachine;
+use crate::lockfile::Error as LockfileError;
+use crate::lockfile::Lockfile;
+use crate::manifest::Error as ManifestError;
+use crate::manifest::Manifest;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    about = "Runs a circuit and prints the constraints per function, source line and library call"
+)]
+pub struct Command {
+    #[structopt(
+        short = "v",
+        parse(from_occurrences),
+        help = "Shows verbose logs, use multiple times for more verbosity"
+    )]
+    verbosity: usize,
+
+    #[structopt(
+        long = "manifest-path",
+        help = "Path to Zargo.toml",
+        default_value = "./Zargo.toml"
+    )]
+    manifest_path: PathBuf,
+
+    #[structopt(
+        long = "circuit",
+        help = "Path to the circuit binary file",
+        default_value = "./build/default.znb"
+    )]
+    circuit: PathBuf,
+
+    #[structopt(
+        long = "witness",
+        help = "Path to the witness JSON file",
+        default_value = "./data/witness.json"
+    )]
+    witness: PathBuf,
+
+    #[structopt(
+        long = "public-data",
+        help = "Path to the public data JSON file to write",
+        default_value = "./data/public-data.json"
+    )]
+    public_data: PathBuf,
+
+    #[structopt(
+        long = "folded",
+        help = "Path to the folded stacks file to write, which can be rendered as a flamegraph"
+    )]
+    folded: Option<PathBuf>,
+}
+
+#[derive(Debug, Fail)]
+pub enum Error {
+    #[fail(display = "manifest file {}", _0)]
+    ManifestFile(ManifestError),
+    #[fail(display = "`{}` is a library, only circuits can be built", _0)]
+    LibraryNotBuildable(String),
+    #[fail(display = "lockfile {}", _0)]
+    Lockfile(LockfileError),
+    #[fail(display = "source directory {}", _0)]
+    SourceDirectory(SourceDirectoryError),
+    #[fail(display = "build directory {}", _0)]
+    BuildDirectory(BuildDirectoryError),
+    #[fail(display = "data directory {}", _0)]
+    DataDirectory(DataDirectoryError),
+    #[fail(display = "compiler {}", _0)]
+    Compiler(CompilerError),
+    #[fail(display = "virtual machine {}", _0)]
+    VirtualMachine(VirtualMachineError),
+}
+
+impl Command {
+    pub fn execute(self) -> Result<(), Error> {
+        let manifest = Manifest::try_from(&self.manifest_path).map_err(Error::ManifestFile)?;
+        if manifest.is_library() {
+            return Err(Error::LibraryNotBuildable(
+                manifest.project().name.to_owned(),
+            ));
+        }
+
+        let mut circuit_path = self.manifest_path.clone();
+        if circuit_path.is_file() {
+            circuit_path.pop();
+        }
+
+        let lockfile = Lockfile::resolve(&circuit_path, &manifest).map_err(Error::Lockfile)?;
+        lockfile.write_to(&circuit_path).map_err(Error::Lockfile)?;
+        let dependencies = lockfile.compiler_dependencies(&circuit_path);
+
+        let source_file_paths =
+            SourceDirectory::files(&circuit_path).map_err(Error::SourceDirectory)?;
+
+        BuildDirectory::create(&circuit_path).map_err(Error::BuildDirectory)?;
+        DataDirectory::create(&circuit_path).map_err(Error::DataDirectory)?;
+
+        Compiler::build(
+            self.verbosity,
+            &self.witness,
+            &self.public_data,
+            &self.circuit,
+            &source_file_paths,
+            &dependencies,
+            &manifest.lints,
+            None,
+            None,
+        )
+        .map_err(Error::Compiler)?;
+
+        VirtualMachine::profile(
+            self.verbosity,
+            &self.circuit,
+            &self.witness,
+            &self.public_data,
+            self.folded.as_ref(),
+        )
+        .map_err(Error::VirtualMachine)?;
+
+        Ok(())
+    }
+}
@@ -18,6 +18,8 @@ use crate::executable::compiler::Compiler;
 use crate::executable::compiler::Error as CompilerError;
 use crate::executable::virtual_machine::Error as VirtualMachineError;
 use crate::executable::virtual_machine::VirtualMachine;
+use crate::lockfile::Error as LockfileError;
+use crate::lockfile::Lockfile;
 use crate::manifest::Error as ManifestError;
 use crate::manifest::Manifest;
 
@@ -64,6 +66,10 @@ pub struct Command {
 pub enum Error {
     #[fail(display = "manifest file {}", _0)]
     ManifestFile(ManifestError),
+    #[fail(display = "`{}` is a library, only circuits can be built", _0)]
+    LibraryNotBuildable(String),
+    #[fail(display = "lockfile {}", _0)]
+    Lockfile(LockfileError),
     #[fail(display = "source directory {}", _0)]
     SourceDirectory(SourceDirectoryError),
     #[fail(display = "build directory {}", _0)]
@@ -78,13 +84,22 @@ pub enum Error {
 
 impl Command {
     pub fn execute(self) -> Result<(), Error> {
-        let _manifest = Manifest::try_from(&self.manifest_path).map_err(Error::ManifestFile)?;
+        let manifest = Manifest::try_from(&self.manifest_path).map_err(Error::ManifestFile)?;
+        if manifest.is_library() {
+            return Err(Error::LibraryNotBuildable(
+                manifest.project().name.to_owned(),
+            ));
+        }
 
         let mut circuit_path = self.manifest_path.clone();
         if circuit_path.is_file() {
             circuit_path.pop();
         }
 
+        let lockfile = Lockfile::resolve(&circuit_path, &manifest).map_err(Error::Lockfile)?;
+        lockfile.write_to(&circuit_path).map_err(Error::Lockfile)?;
+        let dependencies = lockfile.compiler_dependencies(&circuit_path);
+
         let source_file_paths =
             SourceDirectory::files(&circuit_path).map_err(Error::SourceDirectory)?;
 
@@ -97,6 +112,10 @@ impl Command {
             &self.public_data,
             &self.circuit,
             &source_file_paths,
+            &dependencies,
+            &manifest.lints,
+            None,
+            None,
         )
         .map_err(Error::Compiler)?;
 
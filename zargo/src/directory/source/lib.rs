@@ -0,0 +1,83 @@
+//!
+//! The library `lib.zn` file.
+//!
+
+use std::fs::File;
+use std::io;
+use std::io::Write;
+use std::path::PathBuf;
+
+use failure::Fail;
+use serde_derive::Deserialize;
+
+#[derive(Deserialize)]
+pub struct Lib {
+    pub library_name: String,
+}
+
+#[derive(Debug, Fail)]
+pub enum Error {
+    #[fail(display = "creating: {}", _0)]
+    Creating(io::Error),
+    #[fail(display = "writing: {}", _0)]
+    Writing(io::Error),
+}
+
+pub static FILE_NAME_DEFAULT: &str = "lib.zn";
+
+impl Lib {
+    pub fn new(library_name: &str) -> Self {
+        Self {
+            library_name: library_name.to_owned(),
+        }
+    }
+
+    ///
+    /// Returns the `lib.zn` path of the library project at `path`.
+    ///
+    pub fn path(path: &PathBuf) -> PathBuf {
+        let mut path = path.to_owned();
+        path.push(PathBuf::from(super::DIRECTORY_NAME_DEFAULT));
+        path.push(PathBuf::from(FILE_NAME_DEFAULT));
+        path
+    }
+
+    pub fn exists_at(path: &PathBuf) -> bool {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            if !path.ends_with(super::DIRECTORY_NAME_DEFAULT) {
+                path.push(PathBuf::from(super::DIRECTORY_NAME_DEFAULT));
+            }
+            path.push(PathBuf::from(FILE_NAME_DEFAULT));
+        }
+        path.exists()
+    }
+
+    pub fn write_to(self, path: &PathBuf) -> Result<(), Error> {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            if !path.ends_with(super::DIRECTORY_NAME_DEFAULT) {
+                path.push(PathBuf::from(super::DIRECTORY_NAME_DEFAULT));
+            }
+            path.push(PathBuf::from(FILE_NAME_DEFAULT));
+        }
+
+        let mut file = File::create(&path).map_err(Error::Creating)?;
+        file.write_all(self.template().as_bytes())
+            .map_err(Error::Writing)
+    }
+
+    fn template(&self) -> String {
+        format!(
+            r#"//!
+//! The '{}' library module.
+//!
+
+pub fn add(a: u8, b: u8) -> u8 {{
+    a + b
+}}
+"#,
+            self.library_name
+        )
+    }
+}
@@ -2,6 +2,7 @@
 //! The circuit `source` directory.
 //!
 
+pub mod lib;
 pub mod main;
 
 use std::ffi::OsString;
@@ -49,9 +50,18 @@ impl Directory {
     pub fn files(path: &PathBuf) -> Result<Vec<PathBuf>, Error> {
         let mut path = path.to_owned();
         path.push(PathBuf::from(DIRECTORY_NAME_DEFAULT));
-        let directory = fs::read_dir(&path).map_err(Error::Reading)?;
 
         let mut file_paths = Vec::new();
+        Self::collect_files(&path, &mut file_paths)?;
+        Ok(file_paths)
+    }
+
+    ///
+    /// Collects the source files, descending into the nested module directories.
+    ///
+    fn collect_files(path: &PathBuf, file_paths: &mut Vec<PathBuf>) -> Result<(), Error> {
+        let directory = fs::read_dir(path).map_err(Error::Reading)?;
+
         for file_entry in directory.into_iter() {
             let file_entry = file_entry.map_err(Error::GettingFileEntry)?;
             let file_path = file_entry.path();
@@ -59,6 +69,10 @@ impl Directory {
             let file_type = file_entry
                 .file_type()
                 .map_err(|error| Error::GettingFileType(file_path.as_os_str().to_owned(), error))?;
+            if file_type.is_dir() {
+                Self::collect_files(&file_path, file_paths)?;
+                continue;
+            }
             if !file_type.is_file() {
                 return Err(Error::InvalidFileType(
                     file_path.as_os_str().to_owned(),
@@ -79,6 +93,6 @@ impl Directory {
             file_paths.push(file_path);
         }
 
-        Ok(file_paths)
+        Ok(())
     }
 }
@@ -2,6 +2,8 @@
 //! The compiler executable.
 //!
 
+use std::collections::BTreeMap;
+use std::ffi::OsString;
 use std::io;
 use std::path::PathBuf;
 use std::process;
@@ -9,6 +11,8 @@ use std::process::ExitStatus;
 
 use failure::Fail;
 
+use crate::manifest::LintLevel;
+
 pub struct Compiler {}
 
 static BINARY_NAME_DEFAULT: &str = "znc";
@@ -24,14 +28,36 @@ pub enum Error {
 }
 
 impl Compiler {
+    #[allow(clippy::too_many_arguments)]
     pub fn build(
         verbosity: usize,
         witness_path: &PathBuf,
         public_data_path: &PathBuf,
         circuit_path: &PathBuf,
         source_file_paths: &[PathBuf],
+        dependencies: &[(String, PathBuf)],
+        lints: &BTreeMap<String, LintLevel>,
+        message_format: Option<&str>,
+        emit: Option<&str>,
     ) -> Result<(), Error> {
-        let mut child = process::Command::new(BINARY_NAME_DEFAULT)
+        let mut command = process::Command::new(BINARY_NAME_DEFAULT);
+        for (specification, path) in dependencies.iter() {
+            let mut argument = OsString::from(specification);
+            argument.push("=");
+            argument.push(path);
+            command.arg("--dependency").arg(argument);
+        }
+        for (name, level) in lints.iter() {
+            command.arg(format!("--{}", level)).arg(name);
+        }
+        if let Some(message_format) = message_format {
+            command.arg("--message-format").arg(message_format);
+        }
+        if let Some(emit) = emit {
+            command.arg("--emit").arg(emit);
+        }
+
+        let mut child = command
             .args(vec!["-v"; verbosity])
             .arg("--witness")
             .arg(witness_path)
@@ -51,4 +77,78 @@ impl Compiler {
 
         Ok(())
     }
+
+    ///
+    /// Formats the source files in place, or only checks them if `check` is set.
+    ///
+    pub fn format(
+        verbosity: usize,
+        source_file_paths: &[PathBuf],
+        check: bool,
+    ) -> Result<(), Error> {
+        let mut command = process::Command::new(BINARY_NAME_DEFAULT);
+        command.arg("fmt");
+        if check {
+            command.arg("--check");
+        }
+
+        let mut child = command
+            .args(vec!["-v"; verbosity])
+            .args(source_file_paths)
+            .spawn()
+            .map_err(Error::Spawning)?;
+
+        let status = child.wait().map_err(Error::Waiting)?;
+
+        if !status.success() {
+            return Err(Error::Failure(status));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Generates the documentation of the package called `name` in the `format`.
+    ///
+    pub fn doc(
+        verbosity: usize,
+        name: &str,
+        output_path: &PathBuf,
+        format: &str,
+        source_file_paths: &[PathBuf],
+        dependencies: &[(String, PathBuf)],
+        message_format: Option<&str>,
+    ) -> Result<(), Error> {
+        let mut command = process::Command::new(BINARY_NAME_DEFAULT);
+        command.arg("doc");
+        for (specification, path) in dependencies.iter() {
+            let mut argument = OsString::from(specification);
+            argument.push("=");
+            argument.push(path);
+            command.arg("--dependency").arg(argument);
+        }
+        if let Some(message_format) = message_format {
+            command.arg("--message-format").arg(message_format);
+        }
+
+        let mut child = command
+            .args(vec!["-v"; verbosity])
+            .arg("--name")
+            .arg(name)
+            .arg("--format")
+            .arg(format)
+            .arg("--output")
+            .arg(output_path)
+            .args(source_file_paths)
+            .spawn()
+            .map_err(Error::Spawning)?;
+
+        let status = child.wait().map_err(Error::Waiting)?;
+
+        if !status.success() {
+            return Err(Error::Failure(status));
+        }
+
+        Ok(())
+    }
 }
@@ -57,6 +57,38 @@ impl VirtualMachine {
         Ok(())
     }
 
+    pub fn profile(
+        verbosity: usize,
+        circuit_path: &PathBuf,
+        witness_path: &PathBuf,
+        public_data_path: &PathBuf,
+        folded_path: Option<&PathBuf>,
+    ) -> Result<(), Error> {
+        let mut command = process::Command::new(BINARY_NAME_DEFAULT);
+        command
+            .args(vec!["-v"; verbosity])
+            .arg("profile")
+            .arg("--circuit")
+            .arg(circuit_path)
+            .arg("--input")
+            .arg(witness_path)
+            .arg("--output")
+            .arg(public_data_path);
+        if let Some(folded_path) = folded_path {
+            command.arg("--folded").arg(folded_path);
+        }
+
+        let mut process = command.spawn().map_err(Error::Spawning)?;
+
+        let status = process.wait().map_err(Error::Waiting)?;
+
+        if !status.success() {
+            return Err(Error::Failure(status));
+        }
+
+        Ok(())
+    }
+
     pub fn setup(
         verbosity: usize,
         circuit_path: &PathBuf,
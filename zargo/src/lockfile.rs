@@ -0,0 +1,229 @@
+//!
+//! The Zargo lockfile.
+//!
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io;
+use std::io::Write;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+
+use failure::Fail;
+use serde_derive::Deserialize;
+use serde_derive::Serialize;
+
+use crate::directory::source::lib::Lib as LibFile;
+use crate::manifest::Error as ManifestError;
+use crate::manifest::Manifest;
+
+pub static FILE_NAME_DEFAULT: &str = "Zargo.lock";
+
+static HEADER: &str = "# This file is generated by Zargo and must not be edited manually.\n\n";
+
+///
+/// The resolved dependency graph, where the first package is the project itself and the
+/// package paths are relative to the project directory.
+///
+#[derive(Serialize, Deserialize)]
+pub struct Lockfile {
+    pub package: Vec<Package>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Package {
+    pub name: String,
+    pub version: String,
+    pub path: PathBuf,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dependencies: Vec<String>,
+}
+
+#[derive(Debug, Fail)]
+pub enum Error {
+    #[fail(display = "dependency `{}` manifest file {}", _0, _1)]
+    ManifestFile(String, ManifestError),
+    #[fail(display = "dependency `{}` at {:?} is not a library", _0, _1)]
+    NotLibrary(String, PathBuf),
+    #[fail(
+        display = "dependency `{}` at {:?} is named `{}` in its manifest",
+        _0, _1, _2
+    )]
+    NameMismatch(String, PathBuf, String),
+    #[fail(display = "dependency `{}` is found at both {:?} and {:?}", _0, _1, _2)]
+    PathConflict(String, PathBuf, PathBuf),
+    #[fail(display = "dependency `{}` depends on itself: {}", _0, _1)]
+    Cycle(String, String),
+    #[fail(display = "`{}` serializing: {}", _0, _1)]
+    Serializing(&'static str, toml::ser::Error),
+    #[fail(display = "`{}` creating: {}", _0, _1)]
+    Creating(&'static str, io::Error),
+    #[fail(display = "`{}` writing: {}", _0, _1)]
+    Writing(&'static str, io::Error),
+}
+
+impl Lockfile {
+    ///
+    /// Resolves the path dependencies of the project at `project_path` transitively.
+    ///
+    pub fn resolve(project_path: &PathBuf, manifest: &Manifest) -> Result<Self, Error> {
+        let root = Package::new(manifest, PathBuf::from("."));
+
+        let mut packages = BTreeMap::new();
+        let mut stack = vec![root.name.clone()];
+        Self::resolve_dependencies(
+            project_path,
+            &PathBuf::new(),
+            manifest,
+            &mut packages,
+            &mut stack,
+        )?;
+
+        let mut package = Vec::with_capacity(packages.len() + 1);
+        package.push(root);
+        package.extend(packages.into_values());
+        Ok(Self { package })
+    }
+
+    ///
+    /// Returns the `--dependency` arguments of the compiler, where the project dependencies
+    /// go as `<name>=<path>` and the dependencies of the dependencies go as
+    /// `<dependent>/<name>=<path>`.
+    ///
+    pub fn compiler_dependencies(&self, project_path: &Path) -> Vec<(String, PathBuf)> {
+        let paths: BTreeMap<&str, PathBuf> = self
+            .package
+            .iter()
+            .skip(1)
+            .map(|package| {
+                (
+                    package.name.as_str(),
+                    LibFile::path(&project_path.join(&package.path)),
+                )
+            })
+            .collect();
+
+        let mut dependencies = Vec::new();
+        for (index, package) in self.package.iter().enumerate() {
+            for name in package.dependencies.iter() {
+                let specification = if index == 0 {
+                    name.to_owned()
+                } else {
+                    format!("{}/{}", package.name, name)
+                };
+                if let Some(path) = paths.get(name.as_str()) {
+                    dependencies.push((specification, path.to_owned()));
+                }
+            }
+        }
+        dependencies
+    }
+
+    pub fn write_to(&self, path: &PathBuf) -> Result<(), Error> {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            path.push(PathBuf::from(FILE_NAME_DEFAULT));
+        }
+
+        let data =
+            toml::to_string(self).map_err(|error| Error::Serializing(FILE_NAME_DEFAULT, error))?;
+
+        let mut file =
+            File::create(&path).map_err(|error| Error::Creating(FILE_NAME_DEFAULT, error))?;
+        file.write_all(HEADER.as_bytes())
+            .and_then(|()| file.write_all(data.as_bytes()))
+            .map_err(|error| Error::Writing(FILE_NAME_DEFAULT, error))
+    }
+
+    ///
+    /// Visits the dependencies of the package at `package_path` depth-first.
+    ///
+    /// The `stack` contains the packages being visited, which is used to detect cycles.
+    ///
+    fn resolve_dependencies(
+        project_path: &PathBuf,
+        package_path: &Path,
+        manifest: &Manifest,
+        packages: &mut BTreeMap<String, Package>,
+        stack: &mut Vec<String>,
+    ) -> Result<(), Error> {
+        for (name, dependency) in manifest.dependencies.iter() {
+            let path = Self::normalize(package_path.join(&dependency.path));
+
+            if stack.iter().any(|visited| visited == name) {
+                let mut cycle = stack.to_owned();
+                cycle.push(name.to_owned());
+                return Err(Error::Cycle(name.to_owned(), cycle.join(" -> ")));
+            }
+            if let Some(package) = packages.get(name) {
+                if package.path != path {
+                    return Err(Error::PathConflict(
+                        name.to_owned(),
+                        package.path.to_owned(),
+                        path,
+                    ));
+                }
+                continue;
+            }
+
+            let dependency_manifest = Manifest::try_from(&project_path.join(&path))
+                .map_err(|error| Error::ManifestFile(name.to_owned(), error))?;
+            if !dependency_manifest.is_library() {
+                return Err(Error::NotLibrary(name.to_owned(), path));
+            }
+            if &dependency_manifest.project().name != name {
+                return Err(Error::NameMismatch(
+                    name.to_owned(),
+                    path,
+                    dependency_manifest.project().name.to_owned(),
+                ));
+            }
+
+            stack.push(name.to_owned());
+            Self::resolve_dependencies(project_path, &path, &dependency_manifest, packages, stack)?;
+            stack.pop();
+
+            packages.insert(
+                name.to_owned(),
+                Package::new(&dependency_manifest, path.to_owned()),
+            );
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Removes the `.` and `..` components of the path lexically, so the same package
+    /// referenced via different relative paths is recognized.
+    ///
+    fn normalize(path: PathBuf) -> PathBuf {
+        let mut normalized = PathBuf::new();
+        for component in path.components() {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => match normalized.components().next_back() {
+                    Some(Component::Normal(_)) => {
+                        normalized.pop();
+                    }
+                    Some(Component::RootDir) => {}
+                    _ => normalized.push(component),
+                },
+                component => normalized.push(component),
+            }
+        }
+        normalized
+    }
+}
+
+impl Package {
+    pub fn new(manifest: &Manifest, path: PathBuf) -> Self {
+        Self {
+            name: manifest.project().name.to_owned(),
+            version: manifest.project().version.to_owned(),
+            path,
+            dependencies: manifest.dependencies.keys().cloned().collect(),
+        }
+    }
+}
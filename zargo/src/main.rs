@@ -5,6 +5,7 @@
 mod command;
 mod directory;
 mod executable;
+mod lockfile;
 mod manifest;
 
 use std::process;
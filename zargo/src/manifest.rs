@@ -2,7 +2,9 @@
 //! The Zargo manifest.
 //!
 
+use std::collections::BTreeMap;
 use std::convert::TryFrom;
+use std::fmt;
 use std::fs::File;
 use std::io;
 use std::io::Read;
@@ -14,17 +16,40 @@ use serde_derive::Deserialize;
 
 pub static FILE_NAME_DEFAULT: &str = "Zargo.toml";
 
+static PANIC_PROJECT_SECTION_VALIDATED: &str = "The project section is validated on reading";
+
 #[derive(Deserialize)]
 pub struct Manifest {
-    pub circuit: Circuit,
+    pub circuit: Option<Project>,
+    pub library: Option<Project>,
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, Dependency>,
+    #[serde(default)]
+    pub lints: BTreeMap<String, LintLevel>,
 }
 
 #[derive(Deserialize)]
-pub struct Circuit {
+pub struct Project {
     pub name: String,
     pub version: String,
 }
 
+#[derive(Deserialize)]
+pub struct Dependency {
+    pub path: PathBuf,
+}
+
+///
+/// The lint level, which is passed to the compiler as the `--allow`, `--warn`, or `--deny` flag.
+///
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
 #[derive(Debug, Fail)]
 pub enum Error {
     #[fail(display = "`{}` opening: {}", _0, _1)]
@@ -39,18 +64,43 @@ pub enum Error {
     Creating(&'static str, io::Error),
     #[fail(display = "`{}` writing: {}", _0, _1)]
     Writing(&'static str, io::Error),
+    #[fail(
+        display = "`{}` must contain exactly one of the `[circuit]` and `[library]` sections",
+        _0
+    )]
+    ProjectSectionInvalid(&'static str),
 }
 
 impl Manifest {
     pub fn new(circuit_name: &str) -> Self {
         Self {
-            circuit: Circuit {
-                name: circuit_name.to_owned(),
-                version: "0.1.0".to_owned(),
-            },
+            circuit: Some(Project::new(circuit_name)),
+            library: None,
+            dependencies: BTreeMap::new(),
+            lints: BTreeMap::new(),
         }
     }
 
+    pub fn new_library(library_name: &str) -> Self {
+        Self {
+            circuit: None,
+            library: Some(Project::new(library_name)),
+            dependencies: BTreeMap::new(),
+            lints: BTreeMap::new(),
+        }
+    }
+
+    pub fn project(&self) -> &Project {
+        self.circuit
+            .as_ref()
+            .or_else(|| self.library.as_ref())
+            .expect(PANIC_PROJECT_SECTION_VALIDATED)
+    }
+
+    pub fn is_library(&self) -> bool {
+        self.library.is_some()
+    }
+
     pub fn exists_at(path: &PathBuf) -> bool {
         let mut path = path.to_owned();
         if path.is_dir() {
@@ -73,15 +123,42 @@ impl Manifest {
 
     fn template(&self) -> String {
         format!(
-            r#"[circuit]
+            r#"[{}]
 name = "{}"
-version = "0.1.0"
+version = "{}"
+
+[dependencies]
 "#,
-            self.circuit.name
+            if self.is_library() {
+                "library"
+            } else {
+                "circuit"
+            },
+            self.project().name,
+            self.project().version,
         )
     }
 }
 
+impl Project {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_owned(),
+            version: "0.1.0".to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for LintLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Allow => write!(f, "allow"),
+            Self::Warn => write!(f, "warn"),
+            Self::Deny => write!(f, "deny"),
+        }
+    }
+}
+
 impl TryFrom<&PathBuf> for Manifest {
     type Error = Error;
 
@@ -102,6 +179,12 @@ impl TryFrom<&PathBuf> for Manifest {
         file.read_to_string(&mut buffer)
             .map_err(|error| Error::Reading(FILE_NAME_DEFAULT, error))?;
 
-        Ok(toml::from_str(&buffer).map_err(|error| Error::Parsing(FILE_NAME_DEFAULT, error))?)
+        let manifest: Self =
+            toml::from_str(&buffer).map_err(|error| Error::Parsing(FILE_NAME_DEFAULT, error))?;
+        if manifest.circuit.is_some() == manifest.library.is_some() {
+            return Err(Error::ProjectSectionInvalid(FILE_NAME_DEFAULT));
+        }
+
+        Ok(manifest)
     }
 }
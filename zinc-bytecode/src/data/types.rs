@@ -1,12 +1,16 @@
 pub use crate::scalar::{IntegerType, ScalarType};
+use num_bigint::BigInt;
 use serde_derive::{Deserialize, Serialize};
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum DataType {
     Unit,
     Scalar(ScalarType),
-    // Enum is always a field
-    Enum,
+    // Enum is a tag followed by a union of the variant payloads
+    Enum {
+        bitlength: usize,
+        variants: Vec<EnumVariant>,
+    },
     Struct(Vec<(String, DataType)>),
     Tuple(Vec<DataType>),
     Array(Box<DataType>, usize),
@@ -17,10 +21,39 @@ impl DataType {
         match self {
             DataType::Unit => 0,
             DataType::Scalar(_) => 1,
-            DataType::Enum => 1,
+            DataType::Enum { variants, .. } => {
+                1 + variants
+                    .iter()
+                    .map(EnumVariant::size)
+                    .max()
+                    .unwrap_or_default()
+            }
             DataType::Struct(fields) => fields.iter().map(|(_, f)| f.size()).sum(),
             DataType::Tuple(fields) => fields.iter().map(|f| f.size()).sum(),
             DataType::Array(element_type, array_size) => element_type.size() * *array_size,
         }
     }
 }
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct EnumVariant {
+    pub name: String,
+    pub value: BigInt,
+    pub fields: Vec<(String, DataType)>,
+}
+
+impl EnumVariant {
+    pub fn size(&self) -> usize {
+        self.fields.iter().map(|(_, f)| f.size()).sum()
+    }
+
+    /// Tuple-like variant fields are named after their indexes.
+    pub fn is_tuple(&self) -> bool {
+        !self.fields.is_empty()
+            && self
+                .fields
+                .iter()
+                .enumerate()
+                .all(|(index, (name, _))| name == &index.to_string())
+    }
+}
@@ -3,7 +3,7 @@ use num_traits::Num;
 use serde_derive::{Deserialize, Serialize};
 use serde_json as json;
 
-use crate::data::types::{DataType, IntegerType, ScalarType};
+use crate::data::types::{DataType, EnumVariant, IntegerType, ScalarType};
 use failure::Fail;
 use std::collections::HashSet;
 use std::fmt;
@@ -35,6 +35,14 @@ pub struct StructField {
     pub value: Value,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnumValue {
+    pub variant: String,
+    pub tag: ScalarValue,
+    pub fields: Vec<StructField>,
+    pub padding: usize,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ScalarValue {
     Field(BigInt),
@@ -64,13 +72,41 @@ pub enum Value {
     Scalar(ScalarValue),
     Struct(Vec<StructField>),
     Array(Vec<Value>),
+    Enum(EnumValue),
 }
 
 impl Value {
     pub fn default_from_type(data_type: &DataType) -> Self {
         match data_type {
             DataType::Unit => Value::Unit,
-            DataType::Enum => Value::Scalar(ScalarValue::Field(0.into())),
+            DataType::Enum {
+                bitlength,
+                variants,
+            } => {
+                let size = data_type.size();
+                match variants.first() {
+                    Some(variant) => Value::Enum(EnumValue {
+                        variant: variant.name.clone(),
+                        tag: ScalarValue::Integer(
+                            variant.value.clone(),
+                            IntegerType {
+                                is_signed: false,
+                                bitlength: *bitlength,
+                            },
+                        ),
+                        fields: variant
+                            .fields
+                            .iter()
+                            .map(|(name, data_type)| StructField {
+                                field: name.clone(),
+                                value: Value::default_from_type(data_type),
+                            })
+                            .collect(),
+                        padding: size - 1 - variant.size(),
+                    }),
+                    None => Value::Unit,
+                }
+            }
             DataType::Scalar(scalar_type) => match scalar_type {
                 ScalarType::Field => Value::Scalar(ScalarValue::Field(0.into())),
                 ScalarType::Boolean => Value::Scalar(ScalarValue::Bool(false)),
@@ -116,13 +152,26 @@ impl Value {
                     value.to_flat_values_recursive(flat_array);
                 }
             }
+            Value::Enum(EnumValue {
+                tag,
+                fields,
+                padding,
+                ..
+            }) => {
+                flat_array.push(tag.to_bigint());
+                for StructField { value, .. } in fields.iter() {
+                    value.to_flat_values_recursive(flat_array);
+                }
+                for _ in 0..*padding {
+                    flat_array.push(BigInt::from(0));
+                }
+            }
         }
     }
 
     /// Creates value from flat array and data type.
     pub fn from_flat_values(data_type: &DataType, flat_values: &[BigInt]) -> Option<Self> {
-        let mut value = Self::default_from_type(data_type);
-        let consumed = value.fill_from_flat_values(flat_values)?;
+        let (value, consumed) = Self::from_flat_values_recursive(data_type, flat_values)?;
         if consumed == flat_values.len() {
             Some(value)
         } else {
@@ -130,39 +179,96 @@ impl Value {
         }
     }
 
-    /// Fills values from slice, returns number of used values or None if there is not enough.
-    fn fill_from_flat_values(&mut self, flat_values: &[BigInt]) -> Option<usize> {
-        match self {
-            Value::Unit => Some(0),
-            Value::Scalar(scalar) => {
-                match scalar {
-                    ScalarValue::Field(value) | ScalarValue::Integer(value, _) => {
-                        *value = flat_values.first()?.clone();
-                    }
-                    ScalarValue::Bool(value) => {
-                        *value = flat_values.first()? != &BigInt::from(0);
-                    }
+    /// Reads value from slice, returns it with number of used values or None if there is not enough.
+    fn from_flat_values_recursive(
+        data_type: &DataType,
+        flat_values: &[BigInt],
+    ) -> Option<(Self, usize)> {
+        match data_type {
+            DataType::Unit => Some((Value::Unit, 0)),
+            DataType::Scalar(scalar_type) => {
+                let value = flat_values.first()?.clone();
+                let scalar = match scalar_type {
+                    ScalarType::Field => ScalarValue::Field(value),
+                    ScalarType::Boolean => ScalarValue::Bool(value != BigInt::from(0)),
+                    ScalarType::Integer(int_type) => ScalarValue::Integer(value, *int_type),
+                };
+                Some((Value::Scalar(scalar), 1))
+            }
+            DataType::Enum {
+                bitlength,
+                variants,
+            } => {
+                let size = data_type.size();
+                if flat_values.len() < size {
+                    return None;
                 }
-                Some(1)
+
+                let tag = flat_values.first()?;
+                let variant = variants.iter().find(|variant| &variant.value == tag)?;
+                let (fields, offset) =
+                    Self::fields_from_flat_values(&variant.fields, &flat_values[1..])?;
+
+                let value = Value::Enum(EnumValue {
+                    variant: variant.name.clone(),
+                    tag: ScalarValue::Integer(
+                        tag.clone(),
+                        IntegerType {
+                            is_signed: false,
+                            bitlength: *bitlength,
+                        },
+                    ),
+                    fields,
+                    padding: size - 1 - offset,
+                });
+                Some((value, size))
             }
-            Value::Struct(fields) => {
+            DataType::Struct(field_types) => {
+                let (fields, offset) = Self::fields_from_flat_values(field_types, flat_values)?;
+                Some((Value::Struct(fields), offset))
+            }
+            DataType::Tuple(types) => {
+                let mut values = Vec::with_capacity(types.len());
                 let mut offset = 0;
-                for StructField { value, .. } in fields.iter_mut() {
+                for data_type in types.iter() {
                     let slice = &flat_values[offset..];
-                    offset += value.fill_from_flat_values(slice)?;
+                    let (value, consumed) = Self::from_flat_values_recursive(data_type, slice)?;
+                    values.push(value);
+                    offset += consumed;
                 }
-                Some(offset)
+                Some((Value::Array(values), offset))
             }
-            Value::Array(values) => {
+            DataType::Array(data_type, len) => {
+                let mut values = Vec::with_capacity(*len);
                 let mut offset = 0;
-                for value in values.iter_mut() {
+                for _ in 0..*len {
                     let slice = &flat_values[offset..];
-                    offset += value.fill_from_flat_values(slice)?;
+                    let (value, consumed) = Self::from_flat_values_recursive(data_type, slice)?;
+                    values.push(value);
+                    offset += consumed;
                 }
-                Some(offset)
+                Some((Value::Array(values), offset))
             }
         }
     }
+
+    fn fields_from_flat_values(
+        field_types: &[(String, DataType)],
+        flat_values: &[BigInt],
+    ) -> Option<(Vec<StructField>, usize)> {
+        let mut fields = Vec::with_capacity(field_types.len());
+        let mut offset = 0;
+        for (name, data_type) in field_types.iter() {
+            let slice = &flat_values[offset..];
+            let (value, consumed) = Self::from_flat_values_recursive(data_type, slice)?;
+            fields.push(StructField {
+                field: name.clone(),
+                value,
+            });
+            offset += consumed;
+        }
+        Some((fields, offset))
+    }
 }
 
 // Pretty json de/serialization
@@ -195,6 +301,31 @@ impl Value {
                 json::Value::Object(object)
             }
             Value::Array(values) => json::Value::Array(values.iter().map(Self::to_json).collect()),
+            Value::Enum(EnumValue {
+                variant, fields, ..
+            }) => {
+                if fields.is_empty() {
+                    return json::Value::String(variant.clone());
+                }
+
+                let is_tuple = fields
+                    .iter()
+                    .enumerate()
+                    .all(|(index, field)| field.field == index.to_string());
+                let payload = if is_tuple {
+                    json::Value::Array(fields.iter().map(|field| field.value.to_json()).collect())
+                } else {
+                    let mut object = json::Map::<String, serde_json::Value>::new();
+                    for field in fields.iter() {
+                        object.insert(field.field.clone(), field.value.to_json());
+                    }
+                    json::Value::Object(object)
+                };
+
+                let mut object = json::Map::<String, serde_json::Value>::new();
+                object.insert(variant.clone(), payload);
+                json::Value::Object(object)
+            }
         }
     }
 
@@ -202,7 +333,10 @@ impl Value {
         match dtype {
             DataType::Unit => Self::unit_from_json(value),
             DataType::Scalar(t) => Self::scalar_from_json(value, t),
-            DataType::Enum => Self::field_from_json(value),
+            DataType::Enum {
+                bitlength,
+                variants,
+            } => Self::enum_from_json(value, *bitlength, variants, dtype.size()),
             DataType::Struct(fields) => Self::struct_from_json(value, fields),
             DataType::Tuple(dtype) => Self::tuple_from_json(value, dtype),
             DataType::Array(dtype, size) => Self::array_from_json(value, dtype, *size),
@@ -278,6 +412,13 @@ impl Value {
         value: &json::Value,
         field_types: &[(String, DataType)],
     ) -> Result<Self, JsonValueError> {
+        Self::fields_from_json(value, field_types).map(Value::Struct)
+    }
+
+    fn fields_from_json(
+        value: &json::Value,
+        field_types: &[(String, DataType)],
+    ) -> Result<Vec<StructField>, JsonValueError> {
         let object = value
             .as_object()
             .ok_or_else(|| JsonValueErrorType::type_error("structure", value))?;
@@ -305,7 +446,76 @@ impl Value {
             }
         }
 
-        Ok(Value::Struct(field_values))
+        Ok(field_values)
+    }
+
+    fn enum_from_json(
+        value: &json::Value,
+        bitlength: usize,
+        variants: &[EnumVariant],
+        size: usize,
+    ) -> Result<Self, JsonValueError> {
+        let (name, payload) = match value {
+            json::Value::String(name) => (name, None),
+            json::Value::Object(object) if object.len() == 1 => {
+                let (name, payload) = object.iter().next().expect("object has one entry");
+                (name, Some(payload))
+            }
+            value => {
+                return Err(JsonValueErrorType::type_error(
+                    "enum (variant name or single-entry object)",
+                    value,
+                )
+                .into())
+            }
+        };
+
+        let variant = variants
+            .iter()
+            .find(|variant| &variant.name == name)
+            .ok_or_else(|| JsonValueErrorType::UnknownVariant(name.clone()))?;
+
+        let fields = match payload {
+            None if variant.fields.is_empty() => Vec::new(),
+            None => return Err(JsonValueErrorType::MissingVariantPayload(name.clone()).into()),
+            Some(_) if variant.fields.is_empty() => {
+                return Err(JsonValueErrorType::UnexpectedVariantPayload(name.clone()).into())
+            }
+            Some(payload) if variant.is_tuple() => {
+                let types: Vec<DataType> = variant
+                    .fields
+                    .iter()
+                    .map(|(_, dtype)| dtype.clone())
+                    .collect();
+                let values = match Self::tuple_from_json(payload, &types).in_struct(name)? {
+                    Value::Array(values) => values,
+                    _ => unreachable!("tuples are parsed as arrays"),
+                };
+                variant
+                    .fields
+                    .iter()
+                    .zip(values)
+                    .map(|((field, _), value)| StructField {
+                        field: field.clone(),
+                        value,
+                    })
+                    .collect()
+            }
+            Some(payload) => Self::fields_from_json(payload, &variant.fields).in_struct(name)?,
+        };
+
+        Ok(Value::Enum(EnumValue {
+            variant: name.clone(),
+            tag: ScalarValue::Integer(
+                variant.value.clone(),
+                IntegerType {
+                    is_signed: false,
+                    bitlength,
+                },
+            ),
+            fields,
+            padding: size - 1 - variant.size(),
+        }))
     }
 
     fn tuple_from_json(value: &json::Value, types: &[DataType]) -> Result<Self, JsonValueError> {
@@ -433,6 +643,15 @@ pub enum JsonValueErrorType {
         expected, actual
     )]
     UnexpectedSize { expected: usize, actual: usize },
+
+    #[fail(display = "unknown enum variant \"{}\"", _0)]
+    UnknownVariant(String),
+
+    #[fail(display = "payload for enum variant \"{}\" is missing", _0)]
+    MissingVariantPayload(String),
+
+    #[fail(display = "enum variant \"{}\" has no payload", _0)]
+    UnexpectedVariantPayload(String),
 }
 
 impl JsonValueErrorType {
@@ -0,0 +1,47 @@
+//!
+//! The compiler message format.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+///
+/// The format the compiler diagnostics are printed in.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MessageFormat {
+    /// The colored Rust-style output with the source code context.
+    Human,
+    /// A JSON object per diagnostic on a separate line.
+    Json,
+}
+
+impl Default for MessageFormat {
+    fn default() -> Self {
+        Self::Human
+    }
+}
+
+impl FromStr for MessageFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            value => Err(format!(
+                "unknown message format `{}`, expected `human` or `json`",
+                value
+            )),
+        }
+    }
+}
+
+impl fmt::Display for MessageFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Human => write!(f, "human"),
+            Self::Json => write!(f, "json"),
+        }
+    }
+}
@@ -0,0 +1,352 @@
+//!
+//! The compiler diagnostic.
+//!
+
+mod tests;
+
+pub mod message_format;
+
+use std::fmt;
+
+use colored::Colorize;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use crate::lexical::token::location::Location;
+
+use self::message_format::MessageFormat;
+
+///
+/// The diagnostic severity.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+///
+/// The compiler error or warning, which can be printed either for humans or for tools.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    /// The diagnostic severity.
+    pub severity: Severity,
+    /// The stable diagnostic identifier, e.g. `semantic::scope::item_undeclared` or `unused_mut`.
+    /// Is empty for the errors, which are not produced by the compiler passes.
+    pub code: String,
+    /// The diagnostic message.
+    pub message: String,
+    /// The diagnostic location. Is not set for the diagnostics which are not related to a place
+    /// in the source code.
+    pub location: Option<Location>,
+    /// The end of the source code range, if the diagnostic spans several lines.
+    pub end: Option<Location>,
+    /// The location of the related item, e.g. of the previous declaration.
+    pub reference: Option<Location>,
+    /// The suggestions how to fix the problem.
+    pub help: Vec<String>,
+    /// The additional information, e.g. the lint level.
+    pub notes: Vec<String>,
+}
+
+impl Diagnostic {
+    ///
+    /// Creates an error without a source code location.
+    ///
+    pub fn error_message(message: &str, help: Option<&str>) -> Self {
+        Self::new(Severity::Error, message, None, None, None, help)
+    }
+
+    ///
+    /// Creates an error pointing to a location.
+    ///
+    pub fn error_line(message: &str, location: Location, help: Option<&str>) -> Self {
+        Self::new(Severity::Error, message, Some(location), None, None, help)
+    }
+
+    ///
+    /// Creates an error pointing to a location and the related item location.
+    ///
+    pub fn error_line_with_reference(
+        message: &str,
+        location: Location,
+        reference: Option<Location>,
+        help: Option<&str>,
+    ) -> Self {
+        Self::new(
+            Severity::Error,
+            message,
+            Some(location),
+            None,
+            reference,
+            help,
+        )
+    }
+
+    ///
+    /// Creates an error spanning the source code from `start` to `end`.
+    ///
+    pub fn error_range(message: &str, start: Location, end: Location, help: Option<&str>) -> Self {
+        Self::new(Severity::Error, message, Some(start), Some(end), None, help)
+    }
+
+    ///
+    /// Creates a warning pointing to a location and optionally the related item location.
+    ///
+    pub fn warning_line(
+        message: &str,
+        location: Location,
+        reference: Option<Location>,
+        help: Option<&str>,
+    ) -> Self {
+        Self::new(
+            Severity::Warning,
+            message,
+            Some(location),
+            None,
+            reference,
+            help,
+        )
+    }
+
+    pub fn with_code(mut self, code: String) -> Self {
+        self.code = code;
+        self
+    }
+
+    pub fn with_note(mut self, note: String) -> Self {
+        self.notes.push(note);
+        self
+    }
+
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    ///
+    /// Renders the diagnostic in the `format`, where `context` are the lines of the source file
+    /// the diagnostic is located in.
+    ///
+    pub fn render(self, context: &[&str], format: MessageFormat) -> String {
+        match format {
+            MessageFormat::Human => self.format(context),
+            MessageFormat::Json => self.to_json(context).to_string(),
+        }
+    }
+
+    ///
+    /// Renders the diagnostics of a single file one after another in the `format`.
+    ///
+    pub fn render_all(
+        diagnostics: Vec<Self>,
+        context: &[&str],
+        format: MessageFormat,
+    ) -> Vec<String> {
+        diagnostics
+            .into_iter()
+            .map(|diagnostic| diagnostic.render(context, format))
+            .collect()
+    }
+
+    ///
+    /// Formats the diagnostic in the Rust style with the source code lines it points to.
+    ///
+    pub fn format(&self, context: &[&str]) -> String {
+        let label = match self.severity {
+            Severity::Error => "error".bright_red(),
+            Severity::Warning => "warning".bright_yellow(),
+        };
+
+        let mut strings = Vec::with_capacity(12);
+        strings.push(String::new());
+        strings.push(format!("{}: {}", label, self.message.bright_white()));
+
+        match self.reference {
+            Some(reference) if Some(reference.file_index) == self.file_index() => {
+                strings.extend(self.format_context(context, reference, reference));
+            }
+            Some(reference) => {
+                // the reference is located in another file, whose lines are not available here
+                strings.push(format!(" {} {}", "-->".bright_cyan(), reference));
+            }
+            None => {}
+        }
+
+        if let Some(location) = self.location {
+            strings.push(format!(" {} {}", "-->".bright_cyan(), location));
+            strings.extend(self.format_context(context, location, self.end.unwrap_or(location)));
+        }
+
+        for help in self.help.iter() {
+            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
+        }
+        for note in self.notes.iter() {
+            strings.push(format!("{}: {}", "note".bright_white(), note.bright_blue()));
+        }
+        strings.push(String::new());
+        strings.join("\n")
+    }
+
+    ///
+    /// Converts the diagnostic into a JSON object, which is stable across the compiler versions.
+    ///
+    /// The span end column is exclusive and covers the token at the diagnostic location.
+    /// The fields related to the source code are `null` for the diagnostics without a location,
+    /// e.g. the input/output errors, which have no `code` as well.
+    ///
+    pub fn to_json(&self, context: &[&str]) -> JsonValue {
+        let span = self.span(context).map(|(start, end)| {
+            json!({
+                "start": Self::location_to_json(start),
+                "end": Self::location_to_json(end),
+            })
+        });
+
+        json!({
+            "severity": self.severity.to_string(),
+            "code": if self.code.is_empty() {
+                None
+            } else {
+                Some(self.code.as_str())
+            },
+            "message": self.message,
+            "file": self.location.and_then(Self::file_path),
+            "line": self.location.map(|location| location.line),
+            "column": self.location.map(|location| location.column),
+            "span": span,
+            "hints": self.help,
+            "notes": self.notes,
+            "related": self
+                .reference
+                .map(|reference| {
+                    let mut related = Self::location_to_json(reference);
+                    related["file"] = json!(Self::file_path(reference));
+                    vec![related]
+                })
+                .unwrap_or_default(),
+        })
+    }
+
+    ///
+    /// Returns the source code range the diagnostic points to, where the end column is
+    /// exclusive. If the diagnostic points to a single location, the range covers the token
+    /// at that location.
+    ///
+    pub fn span(&self, context: &[&str]) -> Option<(Location, Location)> {
+        self.location.map(|start| {
+            let end = match self.end {
+                Some(end) => end.shifted_right(1),
+                None => start.shifted_right(Self::token_length(context, start)),
+            };
+            (start, end)
+        })
+    }
+
+    fn new(
+        severity: Severity,
+        message: &str,
+        location: Option<Location>,
+        end: Option<Location>,
+        reference: Option<Location>,
+        help: Option<&str>,
+    ) -> Self {
+        Self {
+            severity,
+            code: String::new(),
+            message: message.to_owned(),
+            location,
+            end,
+            reference,
+            help: help.map(|help| vec![help.to_owned()]).unwrap_or_default(),
+            notes: vec![],
+        }
+    }
+
+    ///
+    /// Formats the source code lines from `start` to `end` with the caret under the `end` column.
+    ///
+    fn format_context(&self, context: &[&str], start: Location, end: Location) -> Vec<String> {
+        let line_number_length = end.line.to_string().len();
+
+        let mut strings = Vec::with_capacity(3 + end.line - start.line);
+        strings.push(format!(
+            "{}{}",
+            " ".repeat(line_number_length + 1),
+            "|".bright_cyan()
+        ));
+        for line_number in start.line..=end.line {
+            if let Some(line) = context.get(line_number - 1) {
+                strings.push(format!(
+                    "{}{}",
+                    (line_number.to_string() + " | ").bright_cyan(),
+                    line
+                ));
+            }
+        }
+        let (underline, caret) = match self.severity {
+            Severity::Error => ("_".repeat(end.column - 1).bright_red(), "^".bright_red()),
+            Severity::Warning => (
+                "_".repeat(end.column - 1).bright_yellow(),
+                "^".bright_yellow(),
+            ),
+        };
+        strings.push(format!(
+            "{}{} {}{}",
+            " ".repeat(line_number_length + 1),
+            "|".bright_cyan(),
+            underline,
+            caret
+        ));
+        strings
+    }
+
+    fn file_index(&self) -> Option<Option<usize>> {
+        self.location.map(|location| location.file_index)
+    }
+
+    fn location_to_json(location: Location) -> JsonValue {
+        json!({
+            "line": location.line,
+            "column": location.column,
+        })
+    }
+
+    fn file_path(location: Location) -> Option<String> {
+        location
+            .file_path()
+            .map(|path| path.to_string_lossy().to_string())
+    }
+
+    ///
+    /// Returns the length of the word starting at the location, or 1 if the location points to
+    /// a symbol or is out of the source code.
+    ///
+    fn token_length(context: &[&str], location: Location) -> usize {
+        let length = context
+            .get(location.line.wrapping_sub(1))
+            .map(|line| {
+                line.chars()
+                    .skip(location.column.saturating_sub(1))
+                    .take_while(|character| character.is_ascii_alphanumeric() || *character == '_')
+                    .count()
+            })
+            .unwrap_or_default();
+
+        if length == 0 {
+            1
+        } else {
+            length
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warning => write!(f, "warning"),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//!
+//! The diagnostic tests.
+//!
+
+#![cfg(test)]
+
+use serde_json::json;
+
+use crate::diagnostic::message_format::MessageFormat;
+use crate::diagnostic::Diagnostic;
+use crate::diagnostic::Severity;
+use crate::error::Error;
+use crate::lexical::token::lexeme::symbol::Symbol;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::location::Location;
+use crate::semantic::error::Error as SemanticError;
+use crate::semantic::scope::error::Error as ScopeError;
+use crate::syntax::error::Error as SyntaxError;
+use crate::warning::lint::Level;
+use crate::warning::Warning;
+
+#[test]
+fn ok_error_code() {
+    let error = Error::Semantic(SemanticError::Scope(
+        Location::new(3, 5),
+        ScopeError::ItemUndeclared {
+            name: "value".to_owned(),
+        },
+    ));
+
+    assert_eq!(error.code(), "semantic::scope::item_undeclared");
+}
+
+#[test]
+fn ok_error_code_struct_variant() {
+    let error = Error::Syntax(SyntaxError::ExpectedExpressionOrOperand {
+        location: Location::new(1, 22),
+        found: Lexeme::Symbol(Symbol::BracketCurlyRight),
+    });
+
+    assert_eq!(error.code(), "syntax::expected_expression_or_operand");
+}
+
+#[test]
+fn ok_json() {
+    let context = vec!["fn main() -> u8 {", "    value", "}"];
+    let error = Error::Semantic(SemanticError::Scope(
+        Location::new(2, 5),
+        ScopeError::ItemUndeclared {
+            name: "value".to_owned(),
+        },
+    ));
+
+    let expected = json!({
+        "severity": "error",
+        "code": "semantic::scope::item_undeclared",
+        "message": "cannot find item `value` in this scope",
+        "file": null,
+        "line": 2,
+        "column": 5,
+        "span": {
+            "start": { "line": 2, "column": 5 },
+            "end": { "line": 2, "column": 10 },
+        },
+        "hints": [],
+        "notes": [],
+        "related": [],
+    });
+
+    assert_eq!(error.diagnostic().to_json(context.as_slice()), expected);
+}
+
+#[test]
+fn ok_json_warning() {
+    let context = vec![
+        "fn main(witness: u8) -> u8 {",
+        "    let mut value = witness;",
+    ];
+    let warning = Warning::UnusedMut {
+        location: Location::new(2, 13),
+        name: "value".to_owned(),
+    };
+
+    let result = warning.diagnostic(Level::Warn).to_json(context.as_slice());
+
+    assert_eq!(result["severity"], json!("warning"));
+    assert_eq!(result["code"], json!("unused_mut"));
+    assert_eq!(
+        result["hints"],
+        json!(["consider removing the `mut` keyword"])
+    );
+    assert_eq!(
+        result["notes"],
+        json!(["lint `unused_mut` is set to `warn`"])
+    );
+}
+
+#[test]
+fn ok_denied_warning_is_error() {
+    let warning = Warning::UnusedMut {
+        location: Location::new(2, 13),
+        name: "value".to_owned(),
+    };
+
+    assert_eq!(warning.diagnostic(Level::Deny).severity, Severity::Error);
+}
+
+#[test]
+fn ok_render_json_single_line() {
+    let diagnostic = Diagnostic::error_message("something went wrong", Some("try again"));
+
+    let result = diagnostic.render(&[], MessageFormat::Json);
+
+    assert!(!result.contains('\n'));
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(result.as_str())
+            .expect(crate::semantic::tests::PANIC_TEST_DATA)["hints"],
+        json!(["try again"])
+    );
+}
+
+#[test]
+fn ok_message_format_from_str() {
+    assert_eq!("human".parse(), Ok(MessageFormat::Human));
+    assert_eq!("json".parse(), Ok(MessageFormat::Json));
+    assert_eq!(
+        "xml".parse::<MessageFormat>(),
+        Err("unknown message format `xml`, expected `human` or `json`".to_owned())
+    );
+}
@@ -0,0 +1,59 @@
+//!
+//! The documentation format.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+///
+/// The format the documentation pages are written in.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    /// The static HTML pages, which can be opened in a browser.
+    Html,
+    /// The Markdown pages, which can be put into a book or a repository.
+    Markdown,
+}
+
+impl Format {
+    ///
+    /// The extension of the page files.
+    ///
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Html => "html",
+            Self::Markdown => "md",
+        }
+    }
+}
+
+impl Default for Format {
+    fn default() -> Self {
+        Self::Html
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "html" => Ok(Self::Html),
+            "markdown" => Ok(Self::Markdown),
+            value => Err(format!(
+                "unknown documentation format `{}`, expected `html` or `markdown`",
+                value
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Html => write!(f, "html"),
+            Self::Markdown => write!(f, "markdown"),
+        }
+    }
+}
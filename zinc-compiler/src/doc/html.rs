@@ -0,0 +1,125 @@
+//!
+//! The HTML documentation renderer.
+//!
+
+use crate::doc::format::Format;
+use crate::doc::Item;
+use crate::doc::Kind;
+use crate::doc::Module;
+
+static STYLE: &str = "body { max-width: 60em; margin: 2em auto; padding: 0 1em; font-family: sans-serif; line-height: 1.5; } \
+pre { background: #f5f5f5; padding: 0.5em 1em; overflow-x: auto; } \
+h3, h4 { font-family: monospace; } \
+.members { margin-left: 2em; }";
+
+///
+/// Renders the module page of the package called `package`.
+///
+pub fn module(package: &str, module: &Module) -> String {
+    let title = if module.path.is_empty() {
+        format!("Package {}", package)
+    } else {
+        format!("Module {}::{}", package, module.path.join("::"))
+    };
+
+    let mut page = String::new();
+    page.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+    page.push_str(&format!("<title>{}</title>\n", escape(title.as_str())));
+    page.push_str(&format!("<style>{}</style>\n", STYLE));
+    page.push_str("</head>\n<body>\n");
+    if !module.path.is_empty() {
+        page.push_str(&format!(
+            "<nav><a href=\"{}\">{}</a></nav>\n",
+            Module::file_name(&[], Format::Html),
+            escape(package)
+        ));
+    }
+    page.push_str(&format!("<h1>{}</h1>\n", escape(title.as_str())));
+    if let Some(ref doc) = module.doc {
+        self::doc(&mut page, doc);
+    }
+
+    for kind in Kind::SECTIONS.iter() {
+        let items = module
+            .items
+            .iter()
+            .filter(|item| item.kind == *kind)
+            .collect::<Vec<&Item>>();
+        if items.is_empty() {
+            continue;
+        }
+
+        page.push_str(&format!("<h2>{}</h2>\n", kind.title()));
+        if let Kind::Module = kind {
+            page.push_str("<ul>\n");
+            for item in items.into_iter() {
+                let mut path = module.path.to_owned();
+                path.push(item.name.to_owned());
+                page.push_str(&format!(
+                    "<li><a href=\"{}\"><code>{}</code></a>",
+                    Module::file_name(path.as_slice(), Format::Html),
+                    escape(item.name.as_str())
+                ));
+                if let Some(summary) = Item::summary(item.doc.as_ref()) {
+                    page.push_str(&format!(": {}", escape(summary.as_str())));
+                }
+                page.push_str("</li>\n");
+            }
+            page.push_str("</ul>\n");
+            continue;
+        }
+
+        for item in items.into_iter() {
+            self::item(&mut page, item, "h3");
+            if !item.members.is_empty() {
+                page.push_str("<div class=\"members\">\n");
+                for member in item.members.iter() {
+                    self::item(&mut page, member, "h4");
+                }
+                page.push_str("</div>\n");
+            }
+        }
+    }
+
+    page.push_str("</body>\n</html>\n");
+    page
+}
+
+fn item(page: &mut String, item: &Item, heading: &str) {
+    page.push_str(&format!(
+        "<{0} id=\"{1}\">{1}</{0}>\n<pre><code>{2}</code></pre>\n",
+        heading,
+        escape(item.name.as_str()),
+        escape(item.signature.as_str())
+    ));
+    if let Some(ref doc) = item.doc {
+        self::doc(page, doc);
+    }
+}
+
+///
+/// Writes the doc comment paragraphs, which are separated with empty lines.
+///
+fn doc(page: &mut String, doc: &str) {
+    let mut paragraph = Vec::new();
+    for line in doc.lines().chain(std::iter::once("")) {
+        if line.trim().is_empty() {
+            if !paragraph.is_empty() {
+                page.push_str(&format!(
+                    "<p>{}</p>\n",
+                    escape(paragraph.join("\n").as_str())
+                ));
+                paragraph.clear();
+            }
+        } else {
+            paragraph.push(line);
+        }
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,77 @@
+//!
+//! The Markdown documentation renderer.
+//!
+
+use crate::doc::format::Format;
+use crate::doc::Item;
+use crate::doc::Kind;
+use crate::doc::Module;
+
+///
+/// Renders the module page of the package called `package`.
+///
+pub fn module(package: &str, module: &Module) -> String {
+    let mut page = String::new();
+
+    if module.path.is_empty() {
+        page.push_str(&format!("# Package `{}`\n", package));
+    } else {
+        page.push_str(&format!(
+            "# Module `{}::{}`\n",
+            package,
+            module.path.join("::")
+        ));
+    }
+    if let Some(ref doc) = module.doc {
+        page.push_str(&format!("\n{}\n", doc));
+    }
+
+    for kind in Kind::SECTIONS.iter() {
+        let items = module
+            .items
+            .iter()
+            .filter(|item| item.kind == *kind)
+            .collect::<Vec<&Item>>();
+        if items.is_empty() {
+            continue;
+        }
+
+        page.push_str(&format!("\n## {}\n", kind.title()));
+        if let Kind::Module = kind {
+            page.push('\n');
+            for item in items.into_iter() {
+                let mut path = module.path.to_owned();
+                path.push(item.name.to_owned());
+                page.push_str(&format!(
+                    "- [`{}`]({})",
+                    item.name,
+                    Module::file_name(path.as_slice(), Format::Markdown)
+                ));
+                if let Some(summary) = Item::summary(item.doc.as_ref()) {
+                    page.push_str(&format!(": {}", summary));
+                }
+                page.push('\n');
+            }
+            continue;
+        }
+
+        for item in items.into_iter() {
+            self::item(&mut page, item, "###");
+            for member in item.members.iter() {
+                self::item(&mut page, member, "####");
+            }
+        }
+    }
+
+    page
+}
+
+fn item(page: &mut String, item: &Item, heading: &str) {
+    page.push_str(&format!(
+        "\n{} `{}`\n\n```rust\n{}\n```\n",
+        heading, item.name, item.signature
+    ));
+    if let Some(ref doc) = item.doc {
+        page.push_str(&format!("\n{}\n", doc));
+    }
+}
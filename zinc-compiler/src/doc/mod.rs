@@ -0,0 +1,398 @@
+//!
+//! The documentation generator.
+//!
+
+mod tests;
+
+pub mod format;
+pub mod html;
+pub mod markdown;
+
+use crate::lexical::token::lexeme::literal::integer::Integer as IntegerLiteral;
+use crate::semantic::symbol::Table as SymbolTable;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::statement::local_impl::Statement as ImplementationLocalStatement;
+use crate::syntax::tree::statement::local_mod::Statement as ModuleLocalStatement;
+use crate::syntax::tree::statement::local_trait::Statement as TraitLocalStatement;
+use crate::syntax::tree::statement::r#enum::Statement as EnumStatement;
+use crate::syntax::tree::Tree;
+
+use self::format::Format;
+
+///
+/// The documentation of a package, which consists of a page per module.
+///
+#[derive(Debug, Default)]
+pub struct Documentation {
+    /// The package name, which is the title of the root module page.
+    pub name: String,
+    /// The module pages, in the order they have been added.
+    pub modules: Vec<Module>,
+}
+
+///
+/// The module documentation page.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Module {
+    /// The module path, e.g. `["utils", "math"]`, which is empty for the root module.
+    pub path: Vec<String>,
+    /// The `//!` doc comment of the module.
+    pub doc: Option<String>,
+    /// The documented items, in the order of their declaration.
+    pub items: Vec<Item>,
+}
+
+///
+/// The documented item.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item {
+    /// The item kind, which defines the page section the item belongs to.
+    pub kind: Kind,
+    /// The item name.
+    pub name: String,
+    /// The item signature, where the types are resolved by the semantic analyzer.
+    pub signature: String,
+    /// The `///` doc comment of the item.
+    pub doc: Option<String>,
+    /// The methods and constants of a type, or the methods of a trait.
+    pub members: Vec<Item>,
+}
+
+///
+/// The documented item kind.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kind {
+    Module,
+    Constant,
+    Type,
+    Structure,
+    Enumeration,
+    Trait,
+    Function,
+}
+
+impl Documentation {
+    ///
+    /// Creates the documentation of the package called `name`.
+    ///
+    pub fn new(name: String) -> Self {
+        Self {
+            name,
+            modules: Vec::new(),
+        }
+    }
+
+    ///
+    /// Adds the page of the module, whose syntax tree is `tree`.
+    ///
+    /// The `table` is the symbol table filled while analyzing the module, which the item
+    /// signatures are taken from. The items, which have not been analyzed, are described with
+    /// their names only.
+    ///
+    pub fn add(&mut self, path: Vec<String>, tree: Tree, table: &SymbolTable) {
+        self.modules.push(Module::new(path, tree, table));
+    }
+
+    ///
+    /// Renders the pages in the `format`.
+    ///
+    /// Returns the page file names and contents, where the root module page is the `index` one.
+    /// The submodules, which are not documented with `///` comments at their `mod`
+    /// statements, are described with their own `//!` comments.
+    ///
+    pub fn render(&self, format: Format) -> Vec<(String, String)> {
+        self.modules
+            .iter()
+            .map(|module| {
+                let mut module = module.to_owned();
+                for item in module.items.iter_mut() {
+                    if item.kind != Kind::Module || item.doc.is_some() {
+                        continue;
+                    }
+                    let mut path = module.path.to_owned();
+                    path.push(item.name.to_owned());
+                    item.doc = self
+                        .modules
+                        .iter()
+                        .find(|submodule| submodule.path == path)
+                        .and_then(|submodule| submodule.doc.to_owned());
+                }
+
+                let page = match format {
+                    Format::Html => self::html::module(self.name.as_str(), &module),
+                    Format::Markdown => self::markdown::module(self.name.as_str(), &module),
+                };
+                (Module::file_name(module.path.as_slice(), format), page)
+            })
+            .collect()
+    }
+}
+
+impl Module {
+    ///
+    /// Collects the items declared in the module tree.
+    ///
+    /// The methods and constants of the type implementations are attached to the types
+    /// declared in the same module.
+    ///
+    pub fn new(path: Vec<String>, tree: Tree, table: &SymbolTable) -> Self {
+        let mut items = Vec::with_capacity(tree.statements.len());
+        let mut implementations = Vec::new();
+
+        for statement in tree.statements.into_iter() {
+            let item = match statement {
+                ModuleLocalStatement::Mod(statement) => Item::new(
+                    Kind::Module,
+                    &statement.identifier,
+                    statement.is_public,
+                    statement.doc,
+                    table,
+                    "mod",
+                ),
+                ModuleLocalStatement::Const(statement) => Item::new(
+                    Kind::Constant,
+                    &statement.identifier,
+                    statement.is_public,
+                    statement.doc,
+                    table,
+                    "const",
+                ),
+                ModuleLocalStatement::Type(statement) => Item::new(
+                    Kind::Type,
+                    &statement.identifier,
+                    statement.is_public,
+                    statement.doc,
+                    table,
+                    "type",
+                ),
+                ModuleLocalStatement::Struct(statement) => Item::new(
+                    Kind::Structure,
+                    &statement.identifier,
+                    statement.is_public,
+                    statement.doc,
+                    table,
+                    "struct",
+                ),
+                ModuleLocalStatement::Enum(statement) => Item::enumeration(statement),
+                ModuleLocalStatement::Trait(statement) => {
+                    let mut item = Item::new(
+                        Kind::Trait,
+                        &statement.identifier,
+                        statement.is_public,
+                        statement.doc,
+                        table,
+                        "trait",
+                    );
+                    for statement in statement.statements.into_iter() {
+                        if let TraitLocalStatement::Fn(statement) = statement {
+                            item.members.push(Item::new(
+                                Kind::Function,
+                                &statement.identifier,
+                                false,
+                                statement.doc,
+                                table,
+                                "fn",
+                            ));
+                        }
+                    }
+                    item
+                }
+                ModuleLocalStatement::Fn(statement) => Item::new(
+                    Kind::Function,
+                    &statement.identifier,
+                    statement.is_public,
+                    statement.doc,
+                    table,
+                    "fn",
+                ),
+                ModuleLocalStatement::Impl(statement) => {
+                    implementations.push(statement);
+                    continue;
+                }
+                ModuleLocalStatement::Use(_) => continue,
+                ModuleLocalStatement::Empty(_) => continue,
+            };
+            items.push(item);
+        }
+
+        for implementation in implementations.into_iter() {
+            let owner = items.iter_mut().find(|item| {
+                (item.kind == Kind::Structure || item.kind == Kind::Enumeration)
+                    && item.name == implementation.identifier.name
+            });
+            let owner = match owner {
+                Some(owner) => owner,
+                None => continue,
+            };
+
+            for statement in implementation.statements.into_iter() {
+                let member = match statement {
+                    ImplementationLocalStatement::Const(statement) => Item::new(
+                        Kind::Constant,
+                        &statement.identifier,
+                        false,
+                        statement.doc,
+                        table,
+                        "const",
+                    ),
+                    ImplementationLocalStatement::Fn(statement) => Item::new(
+                        Kind::Function,
+                        &statement.identifier,
+                        false,
+                        statement.doc,
+                        table,
+                        "fn",
+                    ),
+                    ImplementationLocalStatement::Empty(_) => continue,
+                };
+                owner.members.push(member);
+            }
+        }
+
+        Self {
+            path,
+            doc: tree.doc,
+            items,
+        }
+    }
+
+    ///
+    /// Returns the page file name of the module at `path`, e.g. `utils.math.html`.
+    ///
+    pub fn file_name(path: &[String], format: Format) -> String {
+        if path.is_empty() {
+            format!("index.{}", format.extension())
+        } else {
+            format!("{}.{}", path.join("."), format.extension())
+        }
+    }
+}
+
+impl Item {
+    ///
+    /// Describes the item declared with `identifier`.
+    ///
+    /// The signature is taken from the declaration symbol. If the item has not been analyzed,
+    /// e.g. an unused generic function, the `keyword` and the name are used instead.
+    ///
+    pub fn new(
+        kind: Kind,
+        identifier: &Identifier,
+        is_public: bool,
+        doc: Option<String>,
+        table: &SymbolTable,
+        keyword: &str,
+    ) -> Self {
+        let signature = table
+            .symbol_at(identifier.location)
+            .filter(|symbol| symbol.location == identifier.location)
+            .map(|symbol| symbol.description.to_owned())
+            .unwrap_or_else(|| format!("{} {}", keyword, identifier.name));
+
+        Self {
+            kind,
+            name: identifier.name.to_owned(),
+            signature: Self::visibility(signature, is_public),
+            doc,
+            members: Vec::new(),
+        }
+    }
+
+    ///
+    /// Describes the enumeration with its variants and their values, which are not kept by the
+    /// semantic analyzer type.
+    ///
+    pub fn enumeration(statement: EnumStatement) -> Self {
+        let variants = statement
+            .variants
+            .iter()
+            .map(|variant| match variant.literal {
+                Some(ref literal) => format!(
+                    "{} = {}",
+                    variant.identifier.name,
+                    Self::integer(&literal.inner)
+                ),
+                None => variant.identifier.name.to_owned(),
+            })
+            .collect::<Vec<String>>()
+            .join(", ");
+        let signature = format!("enum {} {{ {} }}", statement.identifier.name, variants);
+
+        Self {
+            kind: Kind::Enumeration,
+            name: statement.identifier.name,
+            signature: Self::visibility(signature, statement.is_public),
+            doc: statement.doc,
+            members: Vec::new(),
+        }
+    }
+
+    ///
+    /// Returns the first paragraph of the doc comment, which is shown in the item lists.
+    ///
+    pub fn summary(doc: Option<&String>) -> Option<String> {
+        doc.and_then(|doc| {
+            let summary = doc
+                .lines()
+                .map(str::trim)
+                .take_while(|line| !line.is_empty())
+                .collect::<Vec<&str>>()
+                .join(" ");
+            if summary.is_empty() {
+                None
+            } else {
+                Some(summary)
+            }
+        })
+    }
+
+    fn visibility(signature: String, is_public: bool) -> String {
+        if is_public {
+            format!("pub {}", signature)
+        } else {
+            signature
+        }
+    }
+
+    fn integer(literal: &IntegerLiteral) -> String {
+        match literal {
+            IntegerLiteral::Binary { inner } => format!("0b{}", inner),
+            IntegerLiteral::Octal { inner } => format!("0o{}", inner),
+            IntegerLiteral::Decimal { inner } => inner.to_owned(),
+            IntegerLiteral::Hexadecimal { inner } => format!("0x{}", inner),
+        }
+    }
+}
+
+impl Kind {
+    ///
+    /// The item kinds in the order of the page sections.
+    ///
+    pub const SECTIONS: [Kind; 7] = [
+        Kind::Module,
+        Kind::Constant,
+        Kind::Type,
+        Kind::Structure,
+        Kind::Enumeration,
+        Kind::Trait,
+        Kind::Function,
+    ];
+
+    ///
+    /// The page section title.
+    ///
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Module => "Modules",
+            Self::Constant => "Constants",
+            Self::Type => "Types",
+            Self::Structure => "Structures",
+            Self::Enumeration => "Enumerations",
+            Self::Trait => "Traits",
+            Self::Function => "Functions",
+        }
+    }
+}
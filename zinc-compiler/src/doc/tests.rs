@@ -0,0 +1,160 @@
+//!
+//! The documentation generator tests.
+//!
+
+#![cfg(test)]
+
+use crate::doc::format::Format;
+use crate::doc::Documentation;
+use crate::doc::Kind;
+use crate::doc::Module;
+use crate::syntax::parser::Parser;
+
+static INPUT: &str = r#"
+//! The circuit.
+//!
+//! Sums the points.
+
+/// The point limit.
+const LIMIT: u8 = 100;
+
+/// The alias.
+type Coordinate = u8;
+
+/// The point.
+/// Has two coordinates.
+struct Point {
+    x: Coordinate,
+    y: Coordinate,
+}
+
+impl Point {
+    /// Creates a point.
+    fn new(x: u8, y: u8) -> Point {
+        Point { x: x, y: y }
+    }
+}
+
+// Not a doc comment.
+enum Direction {
+    Up = 1,
+    Down = 0x10,
+}
+
+//// Not a doc comment either.
+fn main(witness: u8) -> u8 {
+    /// Documents nothing.
+    let point = Point::new(witness, LIMIT);
+    point.x + point.y + Direction::Up as u8
+}
+"#;
+
+fn module(input: &str) -> Module {
+    let table = crate::semantic::tests::compile_entry_symbols(input)
+        .expect(crate::semantic::tests::PANIC_TEST_DATA);
+    let tree = Parser::default()
+        .parse(input, None)
+        .expect(crate::semantic::tests::PANIC_TEST_DATA);
+
+    Module::new(vec![], tree, &table)
+}
+
+#[test]
+fn ok_doc_comments() {
+    let module = module(INPUT);
+
+    assert_eq!(
+        module.doc,
+        Some("The circuit.\n\nSums the points.".to_owned())
+    );
+
+    let docs = module
+        .items
+        .iter()
+        .map(|item| item.doc.as_deref())
+        .collect::<Vec<Option<&str>>>();
+    assert_eq!(
+        docs,
+        vec![
+            Some("The point limit."),
+            Some("The alias."),
+            Some("The point.\nHas two coordinates."),
+            None,
+            None,
+        ]
+    );
+    assert_eq!(
+        module.items[2].members[0].doc.as_deref(),
+        Some("Creates a point.")
+    );
+}
+
+#[test]
+fn ok_signatures() {
+    let module = module(INPUT);
+
+    let signatures = module
+        .items
+        .iter()
+        .map(|item| (item.kind, item.signature.as_str()))
+        .collect::<Vec<(Kind, &str)>>();
+    assert_eq!(
+        signatures,
+        vec![
+            (Kind::Constant, "const LIMIT: u8"),
+            (Kind::Type, "type Coordinate = u8"),
+            (Kind::Structure, "struct Point { x: u8, y: u8 }"),
+            (Kind::Enumeration, "enum Direction { Up = 1, Down = 0x10 }"),
+            (Kind::Function, "fn main(witness: u8) -> u8"),
+        ]
+    );
+    assert_eq!(
+        module.items[2].members[0].signature,
+        "fn new(x: u8, y: u8) -> struct Point"
+    );
+}
+
+#[test]
+fn ok_markdown() {
+    let mut documentation = Documentation::new("points".to_owned());
+    documentation.modules.push(module(INPUT));
+
+    let pages = documentation.render(Format::Markdown);
+
+    assert_eq!(pages.len(), 1);
+    let (name, page) = &pages[0];
+    assert_eq!(name, "index.md");
+    assert!(page.starts_with("# Package `points`\n\nThe circuit.\n\nSums the points.\n"));
+    assert!(page.contains(
+        "\n## Constants\n\n### `LIMIT`\n\n```rust\nconst LIMIT: u8\n```\n\nThe point limit.\n"
+    ));
+    assert!(page.contains("\n#### `new`\n\n```rust\nfn new(x: u8, y: u8) -> struct Point\n```\n"));
+}
+
+#[test]
+fn ok_html() {
+    let input = r#"
+/// Compares `a < b`.
+pub fn less(a: u8, b: u8) -> bool {
+    a < b
+}
+
+fn main() {}
+"#;
+
+    let mut documentation = Documentation::new("compare".to_owned());
+    documentation.modules.push(Module {
+        path: vec!["util".to_owned()],
+        ..module(input)
+    });
+
+    let pages = documentation.render(Format::Html);
+
+    let (name, page) = &pages[0];
+    assert_eq!(name, "util.html");
+    assert!(page.contains("<title>Module compare::util</title>"));
+    assert!(page.contains("<nav><a href=\"index.html\">compare</a></nav>"));
+    assert!(page.contains(
+        "<pre><code>pub fn less(a: u8, b: u8) -&gt; bool</code></pre>\n<p>Compares `a &lt; b`.</p>"
+    ));
+}
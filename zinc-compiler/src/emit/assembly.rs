@@ -0,0 +1,69 @@
+//!
+//! The bytecode assembly listing.
+//!
+
+use std::collections::HashMap;
+
+use zinc_bytecode::dispatch_instruction;
+use zinc_bytecode::Instruction;
+use zinc_bytecode::InstructionInfo;
+
+///
+/// Builds the assembly listing of the `instructions`, where each instruction is written with its
+/// address and source code location.
+///
+/// The markers are not listed themselves. The file and function markers start a new section,
+/// and each line marker is followed by the source code line, which is read with `source` by
+/// the file name. If a file cannot be read, only the line numbers are written.
+///
+pub fn listing<S>(instructions: &[Instruction], mut source: S) -> String
+where
+    S: FnMut(&str) -> Option<String>,
+{
+    let mut files = HashMap::<String, Option<Vec<String>>>::new();
+    let mut file = String::new();
+    let mut line = 0;
+    let mut column = 0;
+
+    let mut listing = String::new();
+    for (address, instruction) in instructions.iter().enumerate() {
+        match instruction {
+            Instruction::FileMarker(marker) => {
+                if marker.file != file {
+                    file = marker.file.to_owned();
+                    listing.push_str(&format!("\n; file {}\n", file));
+                }
+            }
+            Instruction::FunctionMarker(marker) => {
+                listing.push_str(&format!("\n{}:\n", marker.function));
+            }
+            Instruction::LineMarker(marker) => {
+                line = marker.line;
+                let code = files
+                    .entry(file.to_owned())
+                    .or_insert_with(|| {
+                        source(file.as_str()).map(|code| code.lines().map(str::to_owned).collect())
+                    })
+                    .as_ref()
+                    .and_then(|lines| lines.get(line.wrapping_sub(1)));
+                match code {
+                    Some(code) => listing.push_str(&format!("; {:>4} | {}\n", line, code.trim())),
+                    None => listing.push_str(&format!("; {:>4} |\n", line)),
+                }
+            }
+            Instruction::ColumnMarker(marker) => column = marker.column,
+            instruction => {
+                let assembly = dispatch_instruction!(instruction => instruction.to_assembly());
+                if line == 0 {
+                    listing.push_str(&format!("{:05}    {}\n", address, assembly));
+                } else {
+                    listing.push_str(&format!(
+                        "{:05}    {:<40} ; {}:{}\n",
+                        address, assembly, line, column
+                    ));
+                }
+            }
+        }
+    }
+    listing
+}
@@ -0,0 +1,127 @@
+//!
+//! The compiler output dumps.
+//!
+
+mod tests;
+
+pub mod assembly;
+
+use std::cell::RefCell;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+use crate::generator::Tree as IntermediateTree;
+use crate::lexical::token::Token;
+use crate::syntax::tree::Tree as SyntaxTree;
+
+thread_local! {
+    /// The intermediate trees of the compiled files, which are only recorded if it has been
+    /// requested with `Emit::start_intermediate`.
+    static INTERMEDIATE: RefCell<Option<Vec<String>>> = RefCell::new(None);
+}
+
+///
+/// The compiler output, which can be dumped in addition to the bytecode.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Emit {
+    /// The lexer tokens of each source file.
+    Tokens,
+    /// The syntax tree of each source file.
+    Ast,
+    /// The intermediate tree, which the bytecode is generated from.
+    Ir,
+    /// The bytecode assembly listing annotated with the source code.
+    Asm,
+}
+
+impl Emit {
+    ///
+    /// The extension of the dump file, which is written next to the bytecode file.
+    ///
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Tokens => "tokens",
+            Self::Ast => "ast",
+            Self::Ir => "ir",
+            Self::Asm => "asm",
+        }
+    }
+
+    ///
+    /// Dumps the tokens of the source file at `path`, one per line with its location.
+    ///
+    pub fn tokens(path: &Path, tokens: &[Token]) -> String {
+        let mut dump = Self::header(path);
+        for token in tokens.iter() {
+            dump.push_str(&format!(
+                "{}:{} {}\n",
+                token.location.line, token.location.column, token.lexeme
+            ));
+        }
+        dump
+    }
+
+    ///
+    /// Dumps the syntax tree of the source file at `path`.
+    ///
+    pub fn syntax_tree(path: &Path, tree: &SyntaxTree) -> String {
+        format!("{}{:#?}\n", Self::header(path), tree)
+    }
+
+    ///
+    /// Starts recording the intermediate trees of the compiled files.
+    ///
+    pub fn start_intermediate() {
+        INTERMEDIATE.with(|trees| trees.replace(Some(Vec::new())));
+    }
+
+    ///
+    /// Stops recording the intermediate trees and returns their dump in the compilation order.
+    ///
+    pub fn take_intermediate() -> String {
+        INTERMEDIATE
+            .with(|trees| trees.replace(None))
+            .unwrap_or_default()
+            .join("\n")
+    }
+
+    ///
+    /// Records the intermediate tree of the file at `path`, if the recording has been started.
+    ///
+    pub fn record_intermediate(path: &Path, tree: &IntermediateTree) {
+        INTERMEDIATE.with(|trees| {
+            if let Some(ref mut trees) = *trees.borrow_mut() {
+                trees.push(format!("{}{:#?}\n", Self::header(path), tree));
+            }
+        });
+    }
+
+    fn header(path: &Path) -> String {
+        format!("// {}\n", path.to_string_lossy())
+    }
+}
+
+impl FromStr for Emit {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "tokens" => Ok(Self::Tokens),
+            "ast" => Ok(Self::Ast),
+            "ir" => Ok(Self::Ir),
+            "asm" => Ok(Self::Asm),
+            value => Err(format!(
+                "unknown output `{}`, expected `tokens`, `ast`, `ir` or `asm`",
+                value
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Emit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.extension())
+    }
+}
@@ -0,0 +1,104 @@
+//!
+//! The compiler output dump tests.
+//!
+
+#![cfg(test)]
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use crate::emit::assembly;
+use crate::emit::Emit;
+use crate::file::File;
+use crate::generator::bytecode::Bytecode;
+use crate::generator::Tree as IntermediateTree;
+use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
+use crate::syntax::parser::Parser;
+
+static INPUT: &str = r#"
+fn main(witness: u8) -> u8 {
+    let value = witness * 2;
+    value + 1
+}
+"#;
+
+#[test]
+fn ok_from_str() {
+    let emits = "tokens,ast,ir,asm"
+        .split(',')
+        .map(Emit::from_str)
+        .collect::<Result<Vec<Emit>, String>>();
+
+    assert_eq!(
+        emits,
+        Ok(vec![Emit::Tokens, Emit::Ast, Emit::Ir, Emit::Asm])
+    );
+}
+
+#[test]
+fn error_from_str() {
+    assert_eq!(
+        Emit::from_str("llvm"),
+        Err("unknown output `llvm`, expected `tokens`, `ast`, `ir` or `asm`".to_owned())
+    );
+}
+
+#[test]
+fn ok_tokens() {
+    let file = File {
+        path: PathBuf::from("emit_tokens.zn"),
+        code: "let value = 42;".to_owned(),
+    };
+
+    let tokens = file
+        .tokens()
+        .expect(crate::semantic::tests::PANIC_TEST_DATA);
+
+    assert_eq!(
+        Emit::tokens(&file.path, tokens.as_slice()),
+        "// emit_tokens.zn\n1:1 let\n1:5 value\n1:11 =\n1:13 42\n1:15 ;\n"
+    );
+}
+
+#[test]
+fn ok_intermediate() {
+    let path = PathBuf::from("emit_intermediate.zn");
+
+    Emit::record_intermediate(&path, &IntermediateTree::new());
+    assert_eq!(Emit::take_intermediate(), "");
+
+    Emit::start_intermediate();
+    Emit::record_intermediate(&path, &IntermediateTree::new());
+    assert_eq!(
+        Emit::take_intermediate(),
+        "// emit_intermediate.zn\nTree {\n    statements: [],\n}\n"
+    );
+}
+
+#[test]
+fn ok_assembly() {
+    let bytecode = Rc::new(RefCell::new(Bytecode::new()));
+    bytecode.borrow_mut().start_new_file("main.zn");
+    EntryAnalyzer::default()
+        .compile(
+            Parser::default()
+                .parse(INPUT, None)
+                .expect(crate::semantic::tests::PANIC_TEST_DATA),
+            HashMap::new(),
+        )
+        .expect(crate::semantic::tests::PANIC_TEST_DATA)
+        .write_all_to_bytecode(bytecode.clone());
+
+    let listing = assembly::listing(bytecode.borrow().instructions(), |file| match file {
+        "main.zn" => Some(INPUT.to_owned()),
+        _ => None,
+    });
+
+    assert!(listing.starts_with("00000    call 2 1\n00001    exit\n\n; file main.zn\n\nmain:\n"));
+    assert!(listing.contains(";    3 | let value = witness * 2;\n"));
+    assert!(listing.contains(";    4 | value + 1\n"));
+    assert!(!listing.contains("marker:"));
+}
@@ -2,16 +2,17 @@
 //! The Zinc compiler error.
 //!
 
-use colored::Colorize;
-
+use crate::diagnostic::message_format::MessageFormat;
+use crate::diagnostic::Diagnostic;
 use crate::file::error::Error as FileError;
+use crate::file::graph::error::Error as GraphError;
 use crate::lexical::error::Error as LexicalError;
-use crate::lexical::token::location::Location;
 use crate::semantic::casting::error::Error as CastingError;
 use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::integer::error::Error as IntegerConstantError;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::place::error::Error as PlaceError;
+use crate::semantic::element::r#type::enumeration::error::Error as EnumerationTypeError;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::builtin::error::Error as BuiltInFunctionTypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionTypeError;
@@ -29,22 +30,108 @@ use crate::syntax::error::Error as SyntaxError;
 #[derive(Debug, PartialEq)]
 pub enum Error {
     File(FileError),
+    Graph(GraphError),
     Lexical(LexicalError),
     Syntax(SyntaxError),
     Semantic(SemanticError),
 }
 
 impl Error {
+    ///
+    /// Renders the errors of a single file one after another in the `format`.
+    ///
+    pub fn format_all(errors: Vec<Self>, context: &[&str], format: MessageFormat) -> String {
+        errors
+            .into_iter()
+            .map(|error| error.render(context, format))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
     pub fn format(self, context: &[&str]) -> String {
-        match self {
-            Self::File(inner) => inner.to_string(),
+        self.diagnostic().format(context)
+    }
+
+    ///
+    /// Renders the error in the `format`.
+    ///
+    pub fn render(self, context: &[&str], format: MessageFormat) -> String {
+        self.diagnostic().render(context, format)
+    }
+
+    ///
+    /// Converts the error into a diagnostic, which can be rendered in any message format.
+    ///
+    pub fn diagnostic(self) -> Diagnostic {
+        let code = self.code();
+
+        let diagnostic = match self {
+            Self::File(inner) => Diagnostic::error_message(inner.to_string().as_str(), None),
+
+            Self::Graph(GraphError::FileNotFound {
+                location,
+                name,
+                expected: (file, nested),
+            }) => Diagnostic::error_line(
+                format!("file not found for module `{}`", name).as_str(),
+                location,
+                Some(
+                    format!(
+                        "create either `{}` or `{}`",
+                        file.to_string_lossy(),
+                        nested.to_string_lossy()
+                    )
+                    .as_str(),
+                ),
+            ),
+            Self::Graph(GraphError::FileAmbiguous {
+                location,
+                name,
+                found: (file, nested),
+            }) => Diagnostic::error_line(
+                format!(
+                    "file for module `{}` found at both `{}` and `{}`",
+                    name,
+                    file.to_string_lossy(),
+                    nested.to_string_lossy()
+                )
+                .as_str(),
+                location,
+                Some("remove one of the files"),
+            ),
+            Self::Graph(GraphError::FileUnreadable {
+                location,
+                name,
+                reason,
+            }) => Diagnostic::error_line(
+                format!("file of module `{}` cannot be read: {}", name, reason).as_str(),
+                location,
+                None,
+            ),
+            Self::Graph(GraphError::Cycle {
+                location,
+                name,
+                cycle,
+            }) => Diagnostic::error_line(
+                format!(
+                    "module `{}` depends on itself: {}",
+                    name,
+                    cycle
+                        .iter()
+                        .map(|path| path.to_string_lossy())
+                        .collect::<Vec<_>>()
+                        .join(" -> ")
+                )
+                .as_str(),
+                location,
+                Some("cyclic module dependencies are not supported"),
+            ),
 
             Self::Lexical(LexicalError::UnterminatedBlockComment { start, end }) => {
-                Self::format_range(context, "unterminated block comment", start, end, None)
+                Diagnostic::error_range("unterminated block comment", start, end, None)
             }
             Self::Lexical(LexicalError::UnterminatedDoubleQuoteString { start, end }) => {
-                Self::format_range(
-                    context,
+                Diagnostic::error_range(
                     "unterminated double quote string",
                     start,
                     end,
@@ -55,8 +142,7 @@ impl Error {
                               location,
                               expected,
                               found,
-                          }) => Self::format_line(
-                context,
+                          }) => Diagnostic::error_line(
                 format!(
                     "expected one of binary symbols {} or '_', found `{}`",
                     expected, found
@@ -69,8 +155,7 @@ impl Error {
                               location,
                               expected,
                               found,
-                          }) => Self::format_line(
-                context,
+                          }) => Diagnostic::error_line(
                 format!(
                     "expected one of octal symbols {} or '_', found `{}`",
                     expected, found
@@ -83,8 +168,7 @@ impl Error {
                 location,
                 expected,
                 found,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::error_line(
                 format!(
                     "expected one of decimal symbols {} or '_', found `{}`",
                     expected, found
@@ -97,8 +181,7 @@ impl Error {
                 location,
                 expected,
                 found,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::error_line(
                 format!(
                     "expected one of hexadecimal symbols {} or '_', found `{}`",
                     expected, found
@@ -107,14 +190,13 @@ impl Error {
                 location,
                 None,
             ),
-            Self::Lexical(LexicalError::InvalidCharacter { location, found }) => Self::format_line(
-                context,
+            Self::Lexical(LexicalError::InvalidCharacter { location, found }) => Diagnostic::error_line(
                 format!("invalid character `{}`", found).as_str(),
                 location,
                 None,
             ),
             Self::Lexical(LexicalError::UnexpectedEnd { location }) => {
-                Self::format_line(context, "unexpected end of input", location, None)
+                Diagnostic::error_line("unexpected end of input", location, None)
             }
 
             Self::Syntax(SyntaxError::ExpectedOneOf {
@@ -122,8 +204,7 @@ impl Error {
                 expected,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::error_line(
                 format!("expected one of {}, found `{}`", expected, found).as_str(),
                 location,
                 help,
@@ -133,8 +214,7 @@ impl Error {
                 expected,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::error_line(
                 format!(
                     "expected one of {} or an operator, found `{}`",
                     expected, found
@@ -147,8 +227,7 @@ impl Error {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::error_line(
                 format!("expected identifier, found `{}`", found).as_str(),
                 location,
                 help,
@@ -157,8 +236,7 @@ impl Error {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::error_line(
                 format!("expected `mut` or identifier, found `{}`", found).as_str(),
                 location,
                 help,
@@ -167,8 +245,7 @@ impl Error {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::error_line(
                 format!("expected field identifier, found `{}`", found).as_str(),
                 location,
                 help,
@@ -177,8 +254,7 @@ impl Error {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::error_line(
                 format!("expected type, found `{}`", found).as_str(),
                 location,
                 help,
@@ -187,8 +263,7 @@ impl Error {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::error_line(
                 format!(
                     "expected `:` with type or `=` with value, found `{}`",
                     found
@@ -201,39 +276,34 @@ impl Error {
                 location,
                 found,
                 help,
-            }) => Self::format_line(
-                context,
+            }) => Diagnostic::error_line(
                 format!("expected `=` with value, found `{}`", found).as_str(),
                 location,
                 help,
             ),
             Self::Syntax(SyntaxError::ExpectedExpressionOrOperand { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!("expected expression or operand, found `{}`", found).as_str(),
                     location,
                     None,
                 )
             }
             Self::Syntax(SyntaxError::ExpectedIntegerLiteral { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!("expected integer literal, found `{}`", found).as_str(),
                     location,
                     None,
                 )
             }
             Self::Syntax(SyntaxError::ExpectedBindingPattern { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!("expected identifier or `_`, found `{}`", found).as_str(),
                     location,
                     None,
                 )
             }
             Self::Syntax(SyntaxError::ExpectedMatchPattern { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "expected identifier, boolean or integer literal, path, or `_`, found `{}`",
                         found
@@ -245,8 +315,7 @@ impl Error {
             }
 
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the assignment operator `=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -257,8 +326,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the assignment operator `=` expected a value as the second operand, found `{}`",
                         found,
@@ -269,8 +337,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseOrFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the assignment bitwise OR operator `|=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -281,10 +348,9 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseOrSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
-                        "the assignment bitwise OR operator `|=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
+                        "the assignment bitwise OR operator `|=` expected a value as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -293,8 +359,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseXorFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the assignment bitwise XOR operator `^=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -305,10 +370,9 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseXorSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
-                        "the assignment bitwise XOR operator `^=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
+                        "the assignment bitwise XOR operator `^=` expected a value as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -317,8 +381,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseAndFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the assignment bitwise AND operator `&=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -329,10 +392,9 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseAndSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
-                        "the assignment bitwise AND operator `&=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
+                        "the assignment bitwise AND operator `&=` expected a value as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -341,8 +403,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseShiftLeftFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the assignment bitwise shift left operator `<<=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -353,10 +414,9 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseShiftLeftSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
-                        "the assignment bitwise shift left operator `<<=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
+                        "the assignment bitwise shift left operator `<<=` expected a value as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -365,8 +425,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseShiftRightFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the assignment bitwise shift right operator `>>=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -377,10 +436,9 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentBitwiseShiftRightSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
-                        "the assignment bitwise shift right operator `>>=` expected a constant as the second operand, found `{}`", // TODO: constant -> value
+                        "the assignment bitwise shift right operator `>>=` expected a value as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -389,8 +447,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentAdditionFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the assignment operator `+=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -401,8 +458,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentAdditionSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the assignment operator `+=` expected a value as the second operand, found `{}`",
                         found,
@@ -413,8 +469,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentSubtractionFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the assignment operator `-=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -425,8 +480,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentSubtractionSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the assignment operator `-=` expected a value as the second operand, found `{}`",
                         found,
@@ -437,8 +491,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentMultiplicationFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the assignment operator `*=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -449,8 +502,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentMultiplicationSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the assignment operator `*=` expected a value as the second operand, found `{}`",
                         found,
@@ -461,8 +513,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentDivisionFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the assignment operator `/=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -473,8 +524,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentDivisionSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the assignment operator `/=` expected a value as the second operand, found `{}`",
                         found,
@@ -485,8 +535,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentRemainderFirstOperandExpectedPlace{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the assignment operator `%=` expected a memory place as the first operand, found `{}`",
                         found,
@@ -497,8 +546,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAssignmentRemainderSecondOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the assignment operator `%=` expected a value as the second operand, found `{}`",
                         found,
@@ -510,8 +558,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorRangeInclusiveFirstOperandExpectedConstant{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRangeInclusiveFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the inclusive range operator `..=` expected an integer constant as the first operand, found `{}`",
                         found,
@@ -523,8 +570,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorRangeInclusiveSecondOperandExpectedConstant{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRangeInclusiveSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the inclusive range operator `..=` expected an integer constant as the second operand, found `{}`",
                         found,
@@ -536,8 +582,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorRangeFirstOperandExpectedConstant{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRangeFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the range operator `..` expected an integer constant as the first operand, found `{}`",
                         found,
@@ -549,8 +594,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorRangeSecondOperandExpectedConstant{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRangeSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the range operator `..` expected an integer constant as the second operand, found `{}`",
                         found,
@@ -563,8 +607,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorOrFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorOrFirstOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorOrFirstOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the OR operator `||` expected a boolean as the first operand, found `{}`",
                         found,
@@ -577,8 +620,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorOrSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorOrSecondOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorOrSecondOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the OR operator `||` expected a boolean as the second operand, found `{}`",
                         found,
@@ -591,8 +633,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorXorFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorXorFirstOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorXorFirstOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the XOR operator `^^` expected a boolean as the first operand, found `{}`",
                         found,
@@ -605,8 +646,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorXorSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorXorSecondOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorXorSecondOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the XOR operator `^^` expected a boolean as the second operand, found `{}`",
                         found,
@@ -619,8 +659,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAndFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorAndFirstOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorAndFirstOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the AND operator `&&` expected a boolean as the first operand, found `{}`",
                         found,
@@ -633,8 +672,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAndSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorAndSecondOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorAndSecondOperandExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the AND operator `&&` expected a boolean as the second operand, found `{}`",
                         found,
@@ -647,8 +685,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorEqualsFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorEqualsFirstOperandExpectedPrimitiveType{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorEqualsFirstOperandExpectedPrimitiveType{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the equals operator `==` expected a unit, boolean or integer as the first operand, found `{}`",
                         found,
@@ -665,8 +702,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorEqualsSecondOperandExpectedUnit{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorEqualsSecondOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorEqualsSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the equals operator `==` expected a unit, boolean or integer as the second operand, found `{}`",
                         found,
@@ -679,8 +715,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorNotEqualsFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorNotEqualsFirstOperandExpectedPrimitiveType{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotEqualsFirstOperandExpectedPrimitiveType{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the not equals operator `!=` expected a boolean or integer as the first operand, found `{}`",
                         found,
@@ -697,8 +732,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotEqualsSecondOperandExpectedUnit{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotEqualsSecondOperandExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotEqualsSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the not equals operator `!=` expected a boolean or integer as the second operand, found `{}`",
                         found,
@@ -711,8 +745,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorGreaterEqualsFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorGreaterEqualsFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorGreaterEqualsFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the greater equals operator `>=` expected an integer as the first operand, found `{}`",
                         found,
@@ -725,8 +758,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorGreaterEqualsSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorGreaterEqualsSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorGreaterEqualsSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the greater equals operator `>=` expected an integer as the second operand, found `{}`",
                         found,
@@ -739,8 +771,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorLesserEqualsFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorLesserEqualsFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorLesserEqualsFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the lesser equals operator `<=` expected an integer as the first operand, found `{}`",
                         found,
@@ -753,8 +784,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorLesserEqualsSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorLesserEqualsSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorLesserEqualsSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the lesser equals operator `<=` expected an integer as the second operand, found `{}`",
                         found,
@@ -767,8 +797,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorGreaterFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorGreaterFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorGreaterFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the greater operator `>` expected an integer as the first operand, found `{}`",
                         found,
@@ -781,8 +810,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorGreaterSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorGreaterSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorGreaterSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the greater operator `>` expected an integer as the second operand, found `{}`",
                         found,
@@ -795,8 +823,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorLesserFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorLesserFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorLesserFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the lesser operator `<` expected an integer as the first operand, found `{}`",
                         found,
@@ -809,8 +836,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorLesserSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorLesserSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorLesserSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the lesser operator `<` expected an integer as the second operand, found `{}`",
                         found,
@@ -823,10 +849,9 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseOrFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseOrFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseOrFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
-                        "the bitwise OR operator `|` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
+                        "the bitwise OR operator `|` expected an integer as the first operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -837,10 +862,9 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseOrSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseOrSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseOrSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
-                        "the bitwise OR operator `|` expected an integer constant as the second operand, found `{}`", // TODO: constant -> ''
+                        "the bitwise OR operator `|` expected an integer as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -851,10 +875,9 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseXorFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseXorFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseXorFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
-                        "the bitwise XOR operator `^` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
+                        "the bitwise XOR operator `^` expected an integer as the first operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -865,10 +888,9 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseXorSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseXorSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseXorSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
-                        "the bitwise XOR operator `^` expected an integer constant as the second operand, found `{}`", // TODO: constant -> ''
+                        "the bitwise XOR operator `^` expected an integer as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -879,10 +901,9 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseAndFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseAndFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseAndFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
-                        "the bitwise AND operator `&` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
+                        "the bitwise AND operator `&` expected an integer as the first operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -893,10 +914,9 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseAndSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseAndSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseAndSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
-                        "the bitwise AND operator `&` expected an integer constant as the second operand, found `{}`", // TODO: constant -> ''
+                        "the bitwise AND operator `&` expected an integer as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -907,10 +927,9 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftLeftFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseShiftLeftFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseShiftLeftFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
-                        "the bitwise shift left operator `<<` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
+                        "the bitwise shift left operator `<<` expected an integer as the first operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -918,15 +937,14 @@ impl Error {
                     None,
                 )
             }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftLeftSecondOperandExpectedConstant{ found })) |
+            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftLeftSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseShiftLeftSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned { found })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseShiftLeftSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned { found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
-                        "the bitwise shift left operator `<<` expected an unsigned integer constant as the second operand, found `{}`",
+                        "the bitwise shift left operator `<<` expected an unsigned integer as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -937,10 +955,9 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftRightFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseShiftRightFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseShiftRightFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
-                        "the bitwise shift right operator `>>` expected an integer constant as the first operand, found `{}`", // TODO: constant -> ''
+                        "the bitwise shift right operator `>>` expected an integer as the first operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -948,15 +965,14 @@ impl Error {
                     None,
                 )
             }
-            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftRightSecondOperandExpectedConstant{ found })) |
+            Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseShiftRightSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseShiftRightSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned { found })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseShiftRightSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned { found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
-                        "the bitwise shift right operator `>>` expected an unsigned integer constant as the second operand, found `{}`",
+                        "the bitwise shift right operator `>>` expected an unsigned integer as the second operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -967,8 +983,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAdditionFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorAdditionFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorAdditionFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the addition operator `+` expected an integer as the first operand, found `{}`",
                         found,
@@ -981,8 +996,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorAdditionSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorAdditionSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorAdditionSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the addition operator `+` expected an integer as the second operand, found `{}`",
                         found,
@@ -995,8 +1009,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorSubtractionFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorSubtractionFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorSubtractionFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the subtraction operator `-` expected an integer as the first operand, found `{}`",
                         found,
@@ -1009,8 +1022,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorSubtractionSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorSubtractionSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorSubtractionSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the subtraction operator `-` expected an integer as the second operand, found `{}`",
                         found,
@@ -1023,8 +1035,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorMultiplicationFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorMultiplicationFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorMultiplicationFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the multiplication operator `*` expected an integer as the first operand, found `{}`",
                         found,
@@ -1037,8 +1048,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorMultiplicationSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorMultiplicationSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorMultiplicationSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the multiplication operator `*` expected an integer as the second operand, found `{}`",
                         found,
@@ -1051,8 +1061,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorDivisionFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorDivisionFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorDivisionFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the division operator `/` expected an integer as the first operand, found `{}`",
                         found,
@@ -1065,8 +1074,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorDivisionSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorDivisionSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorDivisionSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the division operator `/` expected an integer as the second operand, found `{}`",
                         found,
@@ -1079,8 +1087,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorRemainderFirstOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorRemainderFirstOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRemainderFirstOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the remainder operator `%` expected an integer as the first operand, found `{}`",
                         found,
@@ -1093,8 +1100,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorRemainderSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorRemainderSecondOperandExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorRemainderSecondOperandExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the remainder operator `%` expected an integer as the second operand, found `{}`",
                         found,
@@ -1105,8 +1111,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorCastingFirstOperandExpectedEvaluable{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the casting operator `as` expected a value as the first operand, found `{}`",
                         found,
@@ -1117,8 +1122,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorCastingSecondOperandExpectedType{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the casting operator `as` expected a type as the second operand, found `{}`",
                         found,
@@ -1132,8 +1136,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Casting(CastingError::CastingToInvalidType { from, to })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Casting(CastingError::CastingFromInvalidType { from, to })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Casting(CastingError::CastingToInvalidType { from, to })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "cannot cast from `{}` to `{}`",
                         from, to,
@@ -1146,8 +1149,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorNotExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorNotExpectedBoolean{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNotExpectedBoolean{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the NOT operator `!` expected a boolean, found `{}`",
                         found,
@@ -1160,8 +1162,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorBitwiseNotExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorBitwiseNotExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorBitwiseNotExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the bitwise NOT operator `~` expected an integer, found `{}`",
                         found,
@@ -1174,8 +1175,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorNegationExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorNegationExpectedInteger{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::OperatorNegationExpectedInteger{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the negation operator `-` expected an integer, found `{}`",
                         found,
@@ -1188,8 +1188,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorIndexFirstOperandExpectedPlaceOrEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::OperatorIndexFirstOperandExpectedArray{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorIndexFirstOperandExpectedArray{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the index operator `[]` expected an array as the first operand, found `{}`",
                         found,
@@ -1202,8 +1201,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorIndexSecondOperandExpectedEvaluable{ found })) |
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::OperatorIndexSecondOperandExpectedIntegerOrRange{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorIndexSecondOperandExpectedIntegerOrRange{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the index operator `[]` expected an integer or range as the second operand, found `{}`",
                         found,
@@ -1218,8 +1216,7 @@ impl Error {
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::OperatorFieldFirstOperandExpectedStructure{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorFieldFirstOperandExpectedTuple{ found }))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::OperatorFieldFirstOperandExpectedStructure{ found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the field access operator `.` expected a tuple or structure as the first operand, found `{}`",
                         found,
@@ -1230,8 +1227,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorFieldSecondOperandExpectedIdentifier { found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the field access operator `.` expected a tuple or structure field identifier as the second operand, found `{}`",
                         found,
@@ -1242,8 +1238,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorPathFirstOperandExpectedPath{ found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the path resolution operator `::` expected an item identifier as the first operand, found `{}`",
                         found,
@@ -1254,8 +1249,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::OperatorPathSecondOperandExpectedIdentifier { found })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the path resolution operator `::` expected an item identifier as the second operand, found `{}`",
                         found,
@@ -1267,8 +1261,7 @@ impl Error {
             }
 
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Array(ArrayValueError::PushingInvalidType { expected, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "expected `{}`, found `{}`",
                         expected, found,
@@ -1280,8 +1273,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Array(ArrayValueError::SliceStartOutOfRange { start })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::ArraySliceStartOutOfRange { start }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "left slice bound `{}` is negative",
                         start,
@@ -1293,8 +1285,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Array(ArrayValueError::SliceEndOutOfRange { end, size })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::ArraySliceEndOutOfRange { end, size }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "right slice bound `{}` is out of range of the array of size {}",
                         end, size,
@@ -1306,8 +1297,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Array(ArrayValueError::SliceEndLesserThanStart { start, end })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::ArraySliceEndLesserThanStart { start, end }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "left slice bound `{}` is greater than right slice bound `{}`",
                         start, end,
@@ -1320,8 +1310,7 @@ impl Error {
 
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Tuple(TupleValueError::FieldDoesNotExist { type_identifier, field_index })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::TupleFieldDoesNotExist { type_identifier, field_index }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "tuple `{}` has no field with index `{}`",
                         type_identifier, field_index,
@@ -1334,8 +1323,7 @@ impl Error {
 
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Structure(StructureValueError::FieldDoesNotExist { type_identifier, field_name })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::StructureFieldDoesNotExist { type_identifier, field_name }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "field `{}` does not exist in structure `{}`",
                         field_name, type_identifier,
@@ -1346,16 +1334,14 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::MutatingWithDifferentType { expected, found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!("expected `{}`, found `{}`", expected, found).as_str(),
                     location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Place(PlaceError::MutatingImmutableMemory { name, reference }))) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::error_line_with_reference(
                     format!("cannot assign twice to immutable variable `{}`", name).as_str(),
                     location,
                     reference,
@@ -1363,8 +1349,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Structure(StructureValueError::FieldExpected { type_identifier, position, expected, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "structure `{}` expected field `{}` at position {}, found `{}`",
                         type_identifier, expected, position, found,
@@ -1375,8 +1360,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Structure(StructureValueError::FieldInvalidType { type_identifier, field_name, expected, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "field `{}` of structure `{}` expected type `{}`, found `{}`",
                         field_name, type_identifier, expected, found,
@@ -1387,8 +1371,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Structure(StructureValueError::FieldOutOfRange { type_identifier, expected, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "structure `{}` expected {} fields, found {}",
                         type_identifier, expected, found,
@@ -1400,8 +1383,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchEquals{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchEquals{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the equals operator `==` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1413,8 +1395,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchNotEquals{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchNotEquals{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the not equals operator `!=` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1426,8 +1407,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchGreaterEquals{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchGreaterEquals{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the greater equals operator `>=` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1439,8 +1419,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchLesserEquals{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchLesserEquals{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the lesser equals operator `<=` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1452,8 +1431,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchGreater{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchGreater{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the greater operator `>` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1465,8 +1443,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchLesser{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchLesser{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the lesser operator `<` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1478,8 +1455,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchBitwiseOr{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchBitwiseOr{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the bitwise OR operator `|` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1491,8 +1467,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchBitwiseXor{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchBitwiseXor{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the bitwise XOR operator `^` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1504,8 +1479,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchBitwiseAnd{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchBitwiseAnd{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the bitwise AND operator `&` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1517,8 +1491,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchAddition{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchAddition{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the addition operator `+` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1530,8 +1503,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchSubtraction{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchSubtraction{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the subtraction operator `-` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1543,8 +1515,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchMultiplication{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchMultiplication{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the multiplication operator `*` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1556,8 +1527,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchDivision{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchDivision{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the division operator `/` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1569,8 +1539,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::TypesMismatchRemainder{ first, second })))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::TypesMismatchRemainder{ first, second })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the remainder operator `%` expected two integers of the same type, found `{}` and `{}`",
                         first, second,
@@ -1581,8 +1550,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowAddition { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the addition operator `+` overflow, as the value `{}` cannot be represeneted by type `{}`",
                         value, r#type,
@@ -1593,8 +1561,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowSubtraction { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the subtraction operator `-` overflow, as the value `{}` cannot be represeneted by type `{}`",
                         value, r#type,
@@ -1605,8 +1572,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowMultiplication { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the multiplication operator `*` overflow, as the value `{}` cannot be represeneted by type `{}`",
                         value, r#type,
@@ -1617,8 +1583,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowDivision { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the division operator `/` overflow, as the value `{}` cannot be represeneted by type `{}`",
                         value, r#type,
@@ -1629,8 +1594,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowRemainder { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the remainder operator `%` overflow, as the value `{}` cannot be represeneted by type `{}`",
                         value, r#type,
@@ -1641,8 +1605,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowCasting { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the casting operator `as` overflow, as the value `{}` cannot be represeneted by type `{}`",
                         value, r#type,
@@ -1653,8 +1616,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::OverflowNegation { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the negation operator `-` overflow, as the value `{}` cannot be represeneted by type `{}`",
                         value, r#type,
@@ -1666,8 +1628,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenFieldDivision)))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ForbiddenFieldDivision)))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     "the division operator `/` is forbidden for the `field` type",
                     location,
                     Some("for inversion consider using `std::ff::invert`"),
@@ -1675,8 +1636,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenFieldRemainder)))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ForbiddenFieldRemainder)))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     "the remainder operator `%` is forbidden for the `field` type",
                     location,
                     Some("`field` type values cannot be used to get a remainder"),
@@ -1684,8 +1644,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenFieldBitwise)))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ForbiddenFieldBitwise)))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     "the bitwise operators are forbidden for the `field` type",
                     location,
                     None,
@@ -1693,48 +1652,42 @@ impl Error {
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Value(ValueError::Integer(IntegerValueError::ForbiddenFieldNegation)))) |
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ForbiddenFieldNegation)))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     "the negation operator `-` is forbidden for the `field` type",
                     location,
                     Some("`field` type values cannot be negative"),
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ZeroDivision)))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     "division by zero",
                     location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::ZeroRemainder)))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     "remainder of division by zero",
                     location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::IntegerTooLarge { value, bitlength })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!("integer `{}` is larger than `{}` bits", value, bitlength).as_str(),
                     location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Constant(ConstantError::Integer(IntegerConstantError::UnsignedNegative { value, r#type })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!("found a negative value `{}` of unsigned type `{}`", value, r#type).as_str(),
                     location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::AliasDoesNotPointToType { found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "expected type, found `{}`",
                         found
@@ -1745,8 +1698,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::AliasDoesNotPointToStructure { found }))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "expected structure type, found `{}`",
                         found
@@ -1758,8 +1710,7 @@ impl Error {
             }
 
             Self::Semantic(SemanticError::Scope(location, ScopeError::ItemRedeclared { name, reference })) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::error_line_with_reference(
                     format!(
                         "item `{}` already declared here",
                         name
@@ -1771,8 +1722,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Scope(location, ScopeError::ItemUndeclared { name })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "cannot find item `{}` in this scope",
                         name
@@ -1783,8 +1733,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Scope(location, ScopeError::ItemIsNotNamespace { name })) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "item `{}` is not a namespace",
                         name
@@ -1794,10 +1743,21 @@ impl Error {
                     Some("only modules, structures, and enumerations can contain items within their namespaces"),
                 )
             }
+            Self::Semantic(SemanticError::Scope(location, ScopeError::ItemIsPrivate { name, reference })) => {
+                Diagnostic::error_line_with_reference(
+                    format!(
+                        "item `{}` is private",
+                        name
+                    )
+                        .as_str(),
+                    location,
+                    reference,
+                    Some("consider declaring the item with the `pub` keyword"),
+                )
+            }
 
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentCount { function, expected, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "function `{}` expected {} arguments, found {}",
                         function, expected, found
@@ -1808,8 +1768,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentType { function, name, position, expected, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "function `{}` expected type `{}` as the argument `{}` (#{}), found `{}`",
                         function, expected, name, position, found
@@ -1820,8 +1779,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentConstantness { function, name, position, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "function `{}` expected a constant as the argument `{}` (#{}), found a non-constant of type `{}`",
                         function, name, position, found
@@ -1832,8 +1790,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ArgumentNotEvaluable { function, position, found })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "function `{}` expected a value as the argument #{}, found `{}`",
                         function, position, found
@@ -1844,8 +1801,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::ReturnType { function, expected, found, reference })))) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::error_line_with_reference(
                     format!(
                         "function `{}` must return a value of type `{}`, found `{}`",
                         function, expected, found
@@ -1857,8 +1813,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::NonCallable { name })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "attempt to call a non-callable item `{}`",
                         name
@@ -1868,9 +1823,41 @@ impl Error {
                     Some("only functions may be called"),
                 )
             }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::GenericParameterNotInferred { function, name })))) => {
+                Diagnostic::error_line(
+                    format!(
+                        "function `{}` generic parameter `{}` cannot be inferred",
+                        function, name
+                    )
+                        .as_str(),
+                    location,
+                    Some("generic parameters must be used in the argument types"),
+                )
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::GenericParameterBoundNotSatisfied { function, name, found, bound })))) => {
+                Diagnostic::error_line(
+                    format!(
+                        "function `{}` generic parameter `{}` requires the trait `{}`, which is not implemented for `{}`",
+                        function, name, bound, found
+                    )
+                        .as_str(),
+                    location,
+                    Some(format!("implement the trait for the type, e.g. `impl {} for ... {{ ... }}`", bound).as_str()),
+                )
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::GenericArgumentCount { function, expected, found })))) => {
+                Diagnostic::error_line(
+                    format!(
+                        "function `{}` expected {} generic arguments, found {}",
+                        function, expected, found
+                    )
+                        .as_str(),
+                    location,
+                    Some("only the type parameters can be specified explicitly, e.g. `digest::<Sha256>(...)`"),
+                )
+            }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(BuiltInFunctionTypeError::Unknown { function }))))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "attempt to call a non-builtin function `{}` with `!` specifier",
                         function
@@ -1881,8 +1868,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(BuiltInFunctionTypeError::SpecifierMissing { function }))))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "attempt to call a builtin function `{}` without `!` specifier",
                         function
@@ -1893,8 +1879,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(BuiltInFunctionTypeError::DebugArgumentCount { expected, found }))))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "the `dbg!` function expected {} arguments, but got {}",
                         expected, found,
@@ -1905,8 +1890,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(StandardLibraryFunctionTypeError::ArrayTruncatingToBiggerSize { from, to }))))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "attempt to truncate an array from size `{}` to bigger size `{}`",
                         from, to,
@@ -1917,8 +1901,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(StandardLibraryFunctionTypeError::ArrayPaddingToLesserSize { from, to }))))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "attempt to pad an array from size `{}` to lesser size `{}`",
                         from, to,
@@ -1929,8 +1912,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Function(FunctionTypeError::StandardLibrary(StandardLibraryFunctionTypeError::ArrayNewLengthInvalid { value }))))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "new array length `{}` cannot act as an index",
                         value,
@@ -1941,8 +1923,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Structure(StructureTypeError::DuplicateField { type_identifier, field_name })))) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "structure `{}` has a duplicate field `{}`",
                         type_identifier, field_name,
@@ -1952,50 +1933,125 @@ impl Error {
                     Some("consider giving the field a unique name"),
                 )
             }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Enumeration(EnumerationTypeError::DuplicateVariantValue { type_identifier, variant_name, value })))) => {
+                Diagnostic::error_line(
+                    format!(
+                        "enumeration `{}` variant `{}` has a duplicate value `{}`",
+                        type_identifier, variant_name, value,
+                    )
+                        .as_str(),
+                    location,
+                    Some("consider giving the variant a unique value"),
+                )
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Enumeration(EnumerationTypeError::DuplicateField { type_identifier, variant_name, field_name })))) => {
+                Diagnostic::error_line(
+                    format!(
+                        "enumeration `{}` variant `{}` has a duplicate field `{}`",
+                        type_identifier, variant_name, field_name,
+                    )
+                        .as_str(),
+                    location,
+                    Some("consider giving the field a unique name"),
+                )
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Enumeration(EnumerationTypeError::VariantPayloadMissing { type_identifier, variant_name })))) => {
+                Diagnostic::error_line(
+                    format!(
+                        "enumeration `{}` variant `{}` must be constructed with its payload",
+                        type_identifier, variant_name,
+                    )
+                        .as_str(),
+                    location,
+                    Some(format!("consider specifying the fields, e.g. `{}::{}(...)` or `{}::{} {{ ... }}`", type_identifier, variant_name, type_identifier, variant_name).as_str()),
+                )
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Enumeration(EnumerationTypeError::VariantNotTuple { type_identifier, variant_name })))) => {
+                Diagnostic::error_line(
+                    format!(
+                        "enumeration `{}` variant `{}` is not a tuple-like variant",
+                        type_identifier, variant_name,
+                    )
+                        .as_str(),
+                    location,
+                    Some(format!("consider using the structure syntax, e.g. `{}::{} {{ ... }}`", type_identifier, variant_name).as_str()),
+                )
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Enumeration(EnumerationTypeError::VariantNotStructure { type_identifier, variant_name })))) => {
+                Diagnostic::error_line(
+                    format!(
+                        "enumeration `{}` variant `{}` is not a structure-like variant",
+                        type_identifier, variant_name,
+                    )
+                        .as_str(),
+                    location,
+                    Some(format!("consider using the tuple syntax, e.g. `{}::{}(...)`", type_identifier, variant_name).as_str()),
+                )
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Enumeration(EnumerationTypeError::VariantFieldCount { type_identifier, variant_name, expected, found })))) => {
+                Diagnostic::error_line(
+                    format!(
+                        "enumeration `{}` variant `{}` expected {} fields, found {}",
+                        type_identifier, variant_name, expected, found,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Enumeration(EnumerationTypeError::VariantFieldDoesNotExist { type_identifier, variant_name, field_name })))) => {
+                Diagnostic::error_line(
+                    format!(
+                        "enumeration `{}` variant `{}` has no field named `{}`",
+                        type_identifier, variant_name, field_name,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::Element(location, ElementError::Type(TypeError::Enumeration(EnumerationTypeError::VariantFieldInvalidType { type_identifier, variant_name, field_name, expected, found })))) => {
+                Diagnostic::error_line(
+                    format!(
+                        "enumeration `{}` variant `{}` field `{}` expected a value of type `{}`, found `{}`",
+                        type_identifier, variant_name, field_name, expected, found,
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
 
             Self::Semantic(SemanticError::MatchScrutineeInvalidType { location, found }) => {
-                Self::format_line(
-                    context,
-                    format!("match scrutinee expected a boolean or integer expression, found `{}`", found).as_str(),
+                Diagnostic::error_line(
+                    format!("match scrutinee expected a boolean, integer, enumeration, tuple, or structure expression, found `{}`", found).as_str(),
                     location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::MatchNotExhausted { location }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     "match expression must be exhaustive",
                     location,
                     Some("ensure that all possible cases are being handled, possibly by adding wildcards or more match arms"),
                 )
             }
             Self::Semantic(SemanticError::MatchLessThanTwoBranches { location }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     "match expression must have at least two branches",
                     location,
                     Some("consider adding some branches to make the expression useful"),
                 )
             }
-            Self::Semantic(SemanticError::MatchBranchUnreachable { location }) => {
-                Self::format_line(
-                    context,
-                    "match expression branch is unreachable",
-                    location,
-                    Some("consider removing the branch or moving it above the branch with a wildcard or irrefutable binding"),
-                )
-            }
             Self::Semantic(SemanticError::MatchBranchPatternPathExpectedConstant { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!("expected path to a constant, found `{}`", found).as_str(),
                     location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::MatchBranchPatternInvalidType { location, expected, found, reference }) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::error_line_with_reference(
                     format!("expected `{}`, found `{}`", expected, found).as_str(),
                     location,
                     Some(reference),
@@ -2003,8 +2059,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::MatchBranchExpressionInvalidType { location, expected, found, reference }) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::error_line_with_reference(
                     format!("expected `{}`, found `{}`", expected, found).as_str(),
                     location,
                     Some(reference),
@@ -2012,8 +2067,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::MatchBranchDuplicate { location, reference }) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::error_line_with_reference(
                     "match expression contains a duplicate branch pattern",
                     location,
                     Some(reference),
@@ -2021,34 +2075,87 @@ impl Error {
                 )
             }
 
+            Self::Semantic(SemanticError::PatternTupleExpected { location, found }) => {
+                Diagnostic::error_line(
+                    format!("tuple pattern expected a tuple value, found `{}`", found).as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::PatternTupleElementCount { location, expected, found }) => {
+                Diagnostic::error_line(
+                    format!("tuple pattern expected {} elements, found {}", expected, found).as_str(),
+                    location,
+                    Some("the pattern must list all the tuple elements, possibly using wildcards"),
+                )
+            }
+            Self::Semantic(SemanticError::PatternStructureInvalidType { location, expected, found }) => {
+                Diagnostic::error_line(
+                    format!("structure pattern expected `{}`, found `{}`", expected, found).as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::PatternStructureFieldDoesNotExist { location, type_identifier, field_name }) => {
+                Diagnostic::error_line(
+                    format!("field `{}` does not exist in structure `{}`", field_name, type_identifier).as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::PatternStructureDuplicateField { location, type_identifier, field_name }) => {
+                Diagnostic::error_line(
+                    format!("field `{}` of structure `{}` is bound more than once", field_name, type_identifier).as_str(),
+                    location,
+                    None,
+                )
+            }
+
             Self::Semantic(SemanticError::LoopWhileExpectedBooleanCondition { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!("expected `bool`, found `{}`", found).as_str(),
                     location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::LoopBoundsExpectedConstantRangeExpression { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!("expected a constant range expression, found `{}`", found).as_str(),
                     location,
                     Some("only constant ranges allowed, e.g. `for i in 0..42 { ... }`"),
                 )
             }
+            Self::Semantic(SemanticError::LoopBreakOutsideOfLoop { location }) => {
+                Diagnostic::error_line(
+                    "`break` outside of a loop",
+                    location,
+                    Some("`break` can only be used inside a `for` loop body"),
+                )
+            }
+            Self::Semantic(SemanticError::LoopContinueOutsideOfLoop { location }) => {
+                Diagnostic::error_line(
+                    "`continue` outside of a loop",
+                    location,
+                    Some("`continue` can only be used inside a `for` loop body"),
+                )
+            }
+            Self::Semantic(SemanticError::ReturnOutsideOfFunction { location }) => {
+                Diagnostic::error_line(
+                    "`return` outside of a function",
+                    location,
+                    Some("`return` can only be used inside a function body"),
+                )
+            }
 
             Self::Semantic(SemanticError::ConditionalExpectedBooleanCondition { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!("expected `bool`, found `{}`", found).as_str(),
                     location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::ConditionalBranchTypesMismatch { location, expected, found, reference }) => {
-                Self::format_line_with_reference(
-                    context,
+                Diagnostic::error_line_with_reference(
                     format!("if and else branches return incompatible types `{}` and `{}`", expected, found).as_str(),
                     location,
                     Some(reference),
@@ -2056,14 +2163,20 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::EntryPointMissing) => {
-                Self::format_message(
+                Diagnostic::error_message(
                     "function `main` is missing",
                     Some("create the `main` function in the entry point file `main.zn`"),
                 )
             }
+            Self::Semantic(SemanticError::EntryPointGeneric { location }) => {
+                Diagnostic::error_line(
+                    "function `main` cannot have generic parameters",
+                    location,
+                    Some("the circuit entry point must have concrete input and output types"),
+                )
+            }
             Self::Semantic(SemanticError::ModuleNotFound { location, name }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "file not found for module `{}`",
                         name
@@ -2074,8 +2187,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::UseExpectedPath { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "`use` expected an item path, but got `{}`",
                         found
@@ -2086,8 +2198,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::ImplStatementExpectedStructureOrEnumeration { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!(
                         "`impl` expected a type with namespace, found `{}`",
                         found
@@ -2097,186 +2208,187 @@ impl Error {
                     Some("only structures and enumerations can have an implementation"),
                 )
             }
+            Self::Semantic(SemanticError::ImplStatementExpectedTrait { location, found }) => {
+                Diagnostic::error_line(
+                    format!(
+                        "`impl ... for` expected a trait, found `{}`",
+                        found
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::ImplStatementFunctionMissing { location, function, trait_identifier }) => {
+                Diagnostic::error_line(
+                    format!(
+                        "function `{}` of the trait `{}` is not implemented",
+                        function, trait_identifier
+                    )
+                        .as_str(),
+                    location,
+                    Some("only the trait functions with a default body may be omitted"),
+                )
+            }
+            Self::Semantic(SemanticError::ImplStatementFunctionNotInTrait { location, function, trait_identifier }) => {
+                Diagnostic::error_line(
+                    format!(
+                        "function `{}` is not a member of the trait `{}`",
+                        function, trait_identifier
+                    )
+                        .as_str(),
+                    location,
+                    Some("move the function to an ordinar `impl` block of the type"),
+                )
+            }
+            Self::Semantic(SemanticError::ImplStatementFunctionSignatureMismatch { location, function, trait_identifier, expected, found }) => {
+                Diagnostic::error_line(
+                    format!(
+                        "function `{}` expected the signature `{}` declared in the trait `{}`, found `{}`",
+                        function, expected, trait_identifier, found
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::TraitFunctionGeneric { location, function }) => {
+                Diagnostic::error_line(
+                    format!(
+                        "trait function `{}` cannot have generic parameters",
+                        function
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::TraitExpectedValue { location, name }) => {
+                Diagnostic::error_line(
+                    format!(
+                        "trait `{}` cannot be used as a value",
+                        name
+                    )
+                        .as_str(),
+                    location,
+                    Some("traits can be only implemented or used as generic parameter bounds"),
+                )
+            }
+            Self::Semantic(SemanticError::FunctionBodyMissing { location, function }) => {
+                Diagnostic::error_line(
+                    format!(
+                        "function `{}` must have a body",
+                        function
+                    )
+                        .as_str(),
+                    location,
+                    Some("only trait functions can be declared without a body"),
+                )
+            }
+            Self::Semantic(SemanticError::FunctionSelfNotFirst { location, function }) => {
+                Diagnostic::error_line(
+                    format!(
+                        "function `{}` must have `self` as the first argument",
+                        function
+                    )
+                        .as_str(),
+                    location,
+                    Some("move `self` to the beginning of the argument list, e.g. `fn f(self, a: u8)`"),
+                )
+            }
+            Self::Semantic(SemanticError::FunctionSelfOutsideImplementation { location, function }) => {
+                Diagnostic::error_line(
+                    format!(
+                        "function `{}` cannot have `self` outside of a type implementation",
+                        function
+                    )
+                        .as_str(),
+                    location,
+                    Some("only functions declared in `impl` and `trait` blocks may be methods"),
+                )
+            }
+            Self::Semantic(SemanticError::MethodNotFound { location, name, r#type }) => {
+                Diagnostic::error_line(
+                    format!(
+                        "no method named `{}` found for `{}`",
+                        name, r#type
+                    )
+                        .as_str(),
+                    location,
+                    Some("methods must be declared with `self` as the first argument, otherwise call the function as `Type::function(...)`"),
+                )
+            }
+            Self::Semantic(SemanticError::GenericParameterBoundExpectedTrait { location, found }) => {
+                Diagnostic::error_line(
+                    format!(
+                        "generic parameter bound expected a trait, found `{}`",
+                        found
+                    )
+                        .as_str(),
+                    location,
+                    None,
+                )
+            }
             Self::Semantic(SemanticError::ConstantExpressionHasNonConstantElement { location, found }) => {
-                Self::format_line(
-                    context,
+                Diagnostic::error_line(
                     format!("attempt to use a non-constant value `{}` in a constant expression", found).as_str(),
                     location,
                     None,
                 )
             }
-        }
-    }
+        };
 
-    fn format_message(message: &str, help: Option<&str>) -> String {
-        let mut strings = Vec::with_capacity(8);
-        strings.push(String::new());
-        strings.push(format!(
-            "{}: {}",
-            "error".bright_red(),
-            message.bright_white()
-        ));
-        if let Some(help) = help {
-            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
-        }
-        strings.push(String::new());
-        strings.join("\n")
+        diagnostic.with_code(code)
     }
 
-    fn format_line(
-        context: &[&str],
-        message: &str,
-        location: Location,
-        help: Option<&str>,
-    ) -> String {
-        let line_number_length = location.line.to_string().len();
+    ///
+    /// Returns the stable error code, which is the snake-cased path of the error variant,
+    /// e.g. `semantic::scope::item_undeclared`.
+    ///
+    pub fn code(&self) -> String {
+        let debug = format!("{:?}", self);
 
-        let mut strings = Vec::with_capacity(8);
-        strings.push(String::new());
-        strings.push(format!(
-            "{}: {}",
-            "error".bright_red(),
-            message.bright_white()
-        ));
-        strings.push(format!(" {} {}", "-->".bright_cyan(), location));
-        strings.push(format!(
-            "{}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan()
-        ));
-        if let Some(line) = context.get(location.line - 1) {
-            strings.push(format!(
-                "{}{}",
-                (location.line.to_string() + " | ").bright_cyan(),
-                line
-            ));
-        }
-        strings.push(format!(
-            "{}{} {}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan(),
-            "_".repeat(location.column - 1).bright_red(),
-            "^".bright_red()
-        ));
-        if let Some(help) = help {
-            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
-        }
-        strings.push(String::new());
-        strings.join("\n")
-    }
-
-    fn format_line_with_reference(
-        context: &[&str],
-        message: &str,
-        location: Location,
-        reference: Option<Location>,
-        help: Option<&str>,
-    ) -> String {
-        let line_number_length = location.line.to_string().len();
-
-        let mut strings = Vec::with_capacity(11);
-        strings.push(String::new());
-        strings.push(format!(
-            "{}: {}",
-            "error".bright_red(),
-            message.bright_white()
-        ));
-
-        if let Some(reference) = reference {
-            let line_number_length = reference.line.to_string().len();
-            strings.push(format!(
-                "{}{}",
-                " ".repeat(line_number_length + 1),
-                "|".bright_cyan()
-            ));
-            if let Some(line) = context.get(reference.line - 1) {
-                strings.push(format!(
-                    "{}{}",
-                    (reference.line.to_string() + " | ").bright_cyan(),
-                    line
-                ));
-            }
-            strings.push(format!(
-                "{}{} {}{}",
-                " ".repeat(line_number_length + 1),
-                "|".bright_cyan(),
-                "_".repeat(reference.column - 1).bright_red(),
-                "^".bright_red()
-            ));
-        }
-
-        strings.push(format!(" {} {}", "-->".bright_cyan(), location));
+        let mut path = Vec::with_capacity(8);
+        let mut rest = debug.as_str();
+        loop {
+            if rest.starts_with("Location {") {
+                rest = match rest.find("}, ") {
+                    Some(index) => &rest[index + 3..],
+                    None => break,
+                };
+            }
 
-        strings.push(format!(
-            "{}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan()
-        ));
-        if let Some(line) = context.get(location.line - 1) {
-            strings.push(format!(
-                "{}{}",
-                (location.line.to_string() + " | ").bright_cyan(),
-                line
-            ));
-        }
-        strings.push(format!(
-            "{}{} {}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan(),
-            "_".repeat(location.column - 1).bright_red(),
-            "^".bright_red()
-        ));
+            let length = rest
+                .find(|character: char| !character.is_ascii_alphanumeric() && character != '_')
+                .unwrap_or(rest.len());
+            let name = &rest[..length];
+            match name {
+                "" | "Some" | "None" => break,
+                name if name.starts_with(|character: char| character.is_ascii_digit()) => break,
+                name => path.push(Self::to_snake_case(name)),
+            }
 
-        if let Some(help) = help {
-            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
+            if !rest[length..].starts_with('(') {
+                break;
+            }
+            rest = &rest[length + 1..];
         }
-        strings.push(String::new());
-        strings.join("\n")
+        path.join("::")
     }
 
-    fn format_range(
-        context: &[&str],
-        message: &'static str,
-        start: Location,
-        end: Location,
-        help: Option<&str>,
-    ) -> String {
-        let line_number_length = end.line.to_string().len();
-
-        let mut strings = Vec::with_capacity(8 + end.line - start.line);
-        strings.push(String::new());
-        strings.push(format!(
-            "{}: {}",
-            "error".bright_red(),
-            message.bright_white()
-        ));
-        strings.push(format!(" {} {}", "-->".bright_cyan(), start));
-        strings.push(format!(
-            "{}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan()
-        ));
-        for line_number in start.line..=end.line {
-            if let Some(line) = context.get(line_number - 1) {
-                strings.push(format!(
-                    "{}{}",
-                    (line_number.to_string() + " | ").bright_cyan(),
-                    line
-                ));
+    fn to_snake_case(name: &str) -> String {
+        let mut result = String::with_capacity(name.len() + 4);
+        for (index, character) in name.chars().enumerate() {
+            if character.is_ascii_uppercase() {
+                if index > 0 {
+                    result.push('_');
+                }
+                result.push(character.to_ascii_lowercase());
+            } else {
+                result.push(character);
             }
         }
-        strings.push(format!(
-            "{}{} {}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan(),
-            "_".repeat(end.column - 1).bright_red(),
-            "^".bright_red()
-        ));
-        if let Some(help) = help {
-            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
-        }
-        strings.push(String::new());
-        strings.join("\n")
+        result
     }
 }
 
@@ -0,0 +1,31 @@
+//!
+//! The module graph error.
+//!
+
+use std::path::PathBuf;
+
+use crate::lexical::token::location::Location;
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    FileNotFound {
+        location: Location,
+        name: String,
+        expected: (PathBuf, PathBuf),
+    },
+    FileAmbiguous {
+        location: Location,
+        name: String,
+        found: (PathBuf, PathBuf),
+    },
+    FileUnreadable {
+        location: Location,
+        name: String,
+        reason: String,
+    },
+    Cycle {
+        location: Location,
+        name: String,
+        cycle: Vec<PathBuf>,
+    },
+}
@@ -0,0 +1,261 @@
+//!
+//! The source file module graph.
+//!
+
+mod tests;
+
+pub mod error;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::ffi::OsStr;
+use std::path::Path;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use crate::diagnostic::message_format::MessageFormat;
+use crate::diagnostic::Diagnostic;
+use crate::error::Error as CompilerError;
+use crate::file::File;
+use crate::semantic::scope::Scope;
+use crate::syntax::tree::identifier::Identifier;
+
+use self::error::Error;
+
+static SOURCE_FILE_EXTENSION: &str = "zn";
+
+///
+/// The module graph of a circuit, where each source file is connected to the files its
+/// `mod` statements are resolved to.
+///
+#[derive(Debug, Default)]
+pub struct Graph {
+    /// The source files in the order they must be compiled, that is, each file goes after
+    /// all its submodules.
+    pub files: Vec<PathBuf>,
+    /// The submodules of each source file, which are the `mod` statement names mapped to
+    /// the resolved file paths.
+    pub modules: HashMap<PathBuf, Vec<(String, PathBuf)>>,
+}
+
+impl Graph {
+    ///
+    /// Builds the module graph by following the `mod` statements of the source files.
+    ///
+    /// Missing, ambiguous, and unreadable module files, and cyclic module dependencies, are
+    /// reported with the location of the `mod` statement they are caused by. The invalid files
+    /// and modules are skipped, so the errors of all the files are reported at once.
+    ///
+    pub fn new(source_files: Vec<PathBuf>, format: MessageFormat) -> Result<Self, String> {
+        let mut graph = Self::default();
+        let mut stack = Vec::new();
+        let mut errors = Vec::new();
+
+        for path in source_files.into_iter() {
+            graph.visit(path, None, &mut stack, &mut errors, format);
+        }
+
+        if !errors.is_empty() {
+            return Err(errors.join("\n"));
+        }
+
+        Ok(graph)
+    }
+
+    ///
+    /// Collects the already compiled submodules of the source file, keyed by their `mod` names.
+    ///
+    pub fn dependencies(
+        &self,
+        path: &PathBuf,
+        compiled: &HashMap<PathBuf, Rc<RefCell<Scope>>>,
+    ) -> HashMap<String, Rc<RefCell<Scope>>> {
+        self.modules
+            .get(path)
+            .map(|children| {
+                children
+                    .iter()
+                    .filter_map(|(name, path)| {
+                        compiled
+                            .get(path)
+                            .map(|module| (name.to_owned(), module.to_owned()))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Checks if any submodule of the source file is among the `failed` ones, so the file
+    /// cannot be compiled.
+    ///
+    pub fn has_failed_dependency(&self, path: &Path, failed: &HashSet<PathBuf>) -> bool {
+        self.modules
+            .get(path)
+            .map(|children| children.iter().any(|(_name, path)| failed.contains(path)))
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Returns the module paths of the source files, e.g. `["utils", "math"]` for the file of
+    /// the `mod math;` statement in the `utils` module, where the root files have empty paths.
+    ///
+    pub fn module_paths(&self) -> HashMap<PathBuf, Vec<String>> {
+        let mut paths = HashMap::with_capacity(self.files.len());
+
+        for file in self.files.iter().rev() {
+            if !paths.contains_key(file) {
+                paths.insert(file.to_owned(), Vec::new());
+            }
+            let parent = paths[file].to_owned();
+            if let Some(children) = self.modules.get(file) {
+                for (name, child) in children.iter() {
+                    let mut path = parent.to_owned();
+                    path.push(name.to_owned());
+                    paths.insert(child.to_owned(), path);
+                }
+            }
+        }
+
+        paths
+    }
+
+    ///
+    /// Visits the source file and its submodules depth-first, so the submodules are appended
+    /// to the ordered file list before the file itself.
+    ///
+    /// The `parent` is the `mod` statement identifier the file is resolved from along with the
+    /// parent file lines, which is `None` for the root files.
+    /// The `stack` contains the files being visited, which is used to detect cycles.
+    /// The errors are collected into `errors`, and the invalid file is not visited again.
+    ///
+    fn visit(
+        &mut self,
+        path: PathBuf,
+        parent: Option<(&Identifier, &[&str])>,
+        stack: &mut Vec<PathBuf>,
+        errors: &mut Vec<String>,
+        format: MessageFormat,
+    ) {
+        if self.modules.contains_key(&path) {
+            return;
+        }
+
+        let file = match File::try_from(path.clone()) {
+            Ok(file) => file,
+            Err(error) => {
+                errors.push(match parent {
+                    Some((identifier, lines)) => CompilerError::Graph(Error::FileUnreadable {
+                        location: identifier.location,
+                        name: identifier.name.to_owned(),
+                        reason: error,
+                    })
+                    .render(lines, format),
+                    None => Diagnostic::error_message(error.as_str(), None).render(&[], format),
+                });
+                self.modules.insert(path, Vec::new());
+                return;
+            }
+        };
+        let code = file.code.clone();
+        let lines = code.lines().collect::<Vec<&str>>();
+        let identifiers = match file.find_modules(format) {
+            Ok(identifiers) => identifiers,
+            Err(error) => {
+                errors.push(error);
+                self.modules.insert(path, Vec::new());
+                return;
+            }
+        };
+
+        stack.push(path.clone());
+
+        let directory = Self::module_directory(&path);
+        let mut children = Vec::with_capacity(identifiers.len());
+        for identifier in identifiers.into_iter() {
+            let child = match Self::module_path(&directory, &identifier) {
+                Ok(child) => child,
+                Err(error) => {
+                    errors.push(CompilerError::Graph(error).render(&lines, format));
+                    continue;
+                }
+            };
+
+            if let Some(position) = stack.iter().position(|visited| visited == &child) {
+                let mut cycle = stack[position..].to_vec();
+                cycle.push(child);
+                errors.push(
+                    CompilerError::Graph(Error::Cycle {
+                        location: identifier.location,
+                        name: identifier.name,
+                        cycle,
+                    })
+                    .render(&lines, format),
+                );
+                continue;
+            }
+
+            self.visit(
+                child.clone(),
+                Some((&identifier, lines.as_slice())),
+                stack,
+                errors,
+                format,
+            );
+            children.push((identifier.name, child));
+        }
+
+        stack.pop();
+
+        self.modules.insert(path.clone(), children);
+        self.files.push(path);
+    }
+
+    ///
+    /// Returns the directory where the submodules of the source file are located.
+    ///
+    /// The `main.zn`, `lib.zn`, and `mod.zn` files own the directory they are located in,
+    /// whereas any other `foo.zn` file owns the `foo/` directory next to it.
+    ///
+    fn module_directory(path: &Path) -> PathBuf {
+        let directory = path.parent().map(PathBuf::from).unwrap_or_default();
+
+        match path.file_stem() {
+            Some(stem)
+                if stem != OsStr::new("main")
+                    && stem != OsStr::new("lib")
+                    && stem != OsStr::new("mod") =>
+            {
+                directory.join(stem)
+            }
+            _ => directory,
+        }
+    }
+
+    ///
+    /// Resolves the `mod <name>;` statement to either the `<name>.zn` or `<name>/mod.zn` file.
+    ///
+    fn module_path(directory: &Path, identifier: &Identifier) -> Result<PathBuf, Error> {
+        let file = directory.join(format!("{}.{}", identifier.name, SOURCE_FILE_EXTENSION));
+        let nested = directory
+            .join(identifier.name.as_str())
+            .join(format!("mod.{}", SOURCE_FILE_EXTENSION));
+
+        match (file.exists(), nested.exists()) {
+            (true, false) => Ok(file),
+            (false, true) => Ok(nested),
+            (true, true) => Err(Error::FileAmbiguous {
+                location: identifier.location,
+                name: identifier.name.to_owned(),
+                found: (file, nested),
+            }),
+            (false, false) => Err(Error::FileNotFound {
+                location: identifier.location,
+                name: identifier.name.to_owned(),
+                expected: (file, nested),
+            }),
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//!
+//! The module graph tests.
+//!
+
+#![cfg(test)]
+
+use std::fs;
+use std::path::PathBuf;
+
+use crate::diagnostic::message_format::MessageFormat;
+
+use super::Graph;
+
+static PANIC_TEST_FILES: &str = "Test files are always writable";
+
+///
+/// Creates the source files in a clean temporary directory, which is unique for each test.
+///
+fn create_files(test: &str, files: &[(&str, &str)]) -> PathBuf {
+    let directory = std::env::temp_dir().join("zinc-graph-tests").join(test);
+    if directory.exists() {
+        fs::remove_dir_all(&directory).expect(PANIC_TEST_FILES);
+    }
+
+    for (path, code) in files.iter() {
+        let path = directory.join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect(PANIC_TEST_FILES);
+        }
+        fs::write(path, code).expect(PANIC_TEST_FILES);
+    }
+
+    directory
+}
+
+#[test]
+fn ok_nested() {
+    let directory = create_files(
+        "ok_nested",
+        &[
+            ("main.zn", "mod geometry;\nmod util;\n"),
+            ("geometry/mod.zn", "pub mod shapes;\n"),
+            ("geometry/shapes.zn", "pub mod square;\n"),
+            ("geometry/shapes/square.zn", ""),
+            ("util.zn", ""),
+        ],
+    );
+
+    let graph = Graph::new(vec![directory.join("main.zn")], MessageFormat::Human)
+        .expect("Must be a valid graph");
+
+    let expected = vec![
+        directory.join("geometry/shapes/square.zn"),
+        directory.join("geometry/shapes.zn"),
+        directory.join("geometry/mod.zn"),
+        directory.join("util.zn"),
+        directory.join("main.zn"),
+    ];
+    assert_eq!(graph.files, expected);
+
+    let expected = vec![
+        ("geometry".to_owned(), directory.join("geometry/mod.zn")),
+        ("util".to_owned(), directory.join("util.zn")),
+    ];
+    assert_eq!(
+        graph.modules.get(&directory.join("main.zn")),
+        Some(&expected)
+    );
+
+    let paths = graph.module_paths();
+    assert_eq!(paths[&directory.join("main.zn")], Vec::<String>::new());
+    assert_eq!(
+        paths[&directory.join("geometry/shapes/square.zn")],
+        vec!["geometry", "shapes", "square"]
+    );
+    assert_eq!(paths[&directory.join("util.zn")], vec!["util"]);
+}
+
+#[test]
+fn error_file_not_found() {
+    let directory = create_files("error_file_not_found", &[("main.zn", "mod missing;\n")]);
+
+    let result = Graph::new(vec![directory.join("main.zn")], MessageFormat::Human);
+
+    match result {
+        Err(error) => {
+            assert!(error.contains("file not found for module `missing`"));
+            assert!(error.contains("main.zn:1:5"));
+        }
+        Ok(_) => panic!("The module file must be missing"),
+    }
+}
+
+#[test]
+fn error_file_ambiguous() {
+    let directory = create_files(
+        "error_file_ambiguous",
+        &[
+            ("main.zn", "mod util;\n"),
+            ("util.zn", ""),
+            ("util/mod.zn", ""),
+        ],
+    );
+
+    let result = Graph::new(vec![directory.join("main.zn")], MessageFormat::Human);
+
+    match result {
+        Err(error) => assert!(error.contains("file for module `util` found at both")),
+        Ok(_) => panic!("The module file must be ambiguous"),
+    }
+}
+
+#[test]
+fn error_cycle() {
+    let directory = create_files(
+        "error_cycle",
+        &[
+            ("main.zn", "mod util;\n"),
+            ("util.zn", "mod main;\n"),
+            ("util/main.zn", "mod main;\n"),
+        ],
+    );
+
+    let result = Graph::new(vec![directory.join("main.zn")], MessageFormat::Human);
+
+    let cycle = format!(
+        "{} -> {}",
+        directory.join("util/main.zn").to_string_lossy(),
+        directory.join("util/main.zn").to_string_lossy(),
+    );
+    match result {
+        Err(error) => {
+            assert!(error.contains("module `main` depends on itself"));
+            assert!(error.contains(cycle.as_str()));
+        }
+        Ok(_) => panic!("The module graph must be cyclic"),
+    }
+}
+
+#[test]
+fn error_syntax_multiple_files() {
+    let directory = create_files(
+        "error_syntax_multiple_files",
+        &[
+            ("main.zn", "mod first;\nmod second;\n"),
+            ("first.zn", "fn one() -> u8 { 1 + }\n"),
+            ("second.zn", "fn two() -> u8 { let = 2; 2 }\n"),
+        ],
+    );
+
+    let result = Graph::new(vec![directory.join("main.zn")], MessageFormat::Human);
+
+    match result {
+        Err(error) => {
+            assert!(error.contains("first.zn:1:22"));
+            assert!(error.contains("second.zn:1:22"));
+        }
+        Ok(_) => panic!("The module files must be invalid"),
+    }
+}
+
+#[test]
+fn error_file_not_found_json() {
+    let directory = create_files(
+        "error_file_not_found_json",
+        &[("main.zn", "mod missing;\n")],
+    );
+
+    let result = Graph::new(vec![directory.join("main.zn")], MessageFormat::Json);
+
+    match result {
+        Err(error) => {
+            let diagnostic: serde_json::Value =
+                serde_json::from_str(error.as_str()).expect("Must be a valid JSON");
+            assert_eq!(diagnostic["code"], "graph::file_not_found");
+            assert_eq!(
+                diagnostic["file"],
+                directory.join("main.zn").to_string_lossy().as_ref()
+            );
+            assert_eq!(diagnostic["line"], 1);
+            assert_eq!(diagnostic["column"], 5);
+            assert_eq!(diagnostic["span"]["end"]["column"], 12);
+        }
+        Ok(_) => panic!("The module file must be missing"),
+    }
+}
+
+#[test]
+fn error_file_unreadable() {
+    let directory = create_files("error_file_unreadable", &[("main.zn", "mod util;\n")]);
+    fs::create_dir_all(directory.join("util.zn")).expect(PANIC_TEST_FILES);
+
+    let result = Graph::new(vec![directory.join("main.zn")], MessageFormat::Human);
+
+    match result {
+        Err(error) => {
+            assert!(error.contains("file of module `util` cannot be read"));
+            assert!(error.contains("main.zn:1:5"));
+        }
+        Ok(_) => panic!("The module file must be unreadable"),
+    }
+}
+
+#[test]
+fn error_multiple_files() {
+    let directory = create_files(
+        "error_multiple_files",
+        &[
+            ("main.zn", "mod first;\nmod missing;\nmod util;\n"),
+            ("first.zn", "fn one() -> u8 { 1 + }\n"),
+            ("util.zn", "mod main;\n"),
+            ("util/main.zn", "mod main;\n"),
+        ],
+    );
+
+    let result = Graph::new(vec![directory.join("main.zn")], MessageFormat::Human);
+
+    match result {
+        Err(error) => {
+            assert!(error.contains("first.zn:1:22"));
+            assert!(error.contains("file not found for module `missing`"));
+            assert!(error.contains("main.zn:2:5"));
+            assert!(error.contains("module `main` depends on itself"));
+        }
+        Ok(_) => panic!("The module files must be invalid"),
+    }
+}
@@ -6,22 +6,36 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::io::Read;
+use std::path::Path;
 use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::RwLock;
 
 use lazy_static::lazy_static;
 
+use crate::diagnostic::message_format::MessageFormat;
+use crate::diagnostic::Diagnostic;
+use crate::emit::Emit;
+use crate::error::Error as CompilerError;
+use crate::formatter::Formatter;
 use crate::generator::bytecode::Bytecode;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::Token;
 use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 use crate::semantic::analyzer::module::Analyzer as ModuleAnalyzer;
 use crate::semantic::scope::Scope;
 use crate::syntax::parser::Parser;
+use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::statement::local_mod::Statement;
+use crate::syntax::tree::Tree;
+use crate::warning::lint::Lints;
+use crate::warning::Warning;
 
 use self::error::Error;
 
 pub mod error;
+pub mod graph;
 
 pub struct File {
     pub path: PathBuf,
@@ -34,79 +48,225 @@ lazy_static! {
 }
 
 impl File {
+    ///
+    /// Compiles the circuit entry file.
+    ///
+    /// Returns the warnings, or all the errors found in the file, rendered in the `format`.
+    /// The warnings are reported as errors if any of them is denied by `lints`.
+    ///
     pub fn try_into_entry(
         self,
         bytecode: Rc<RefCell<Bytecode>>,
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
-    ) -> Result<(), String> {
+        packages: HashMap<String, Rc<RefCell<Scope>>>,
+        lints: &Lints,
+        format: MessageFormat,
+    ) -> Result<Vec<String>, String> {
         let lines = self.code.lines().collect::<Vec<&str>>();
 
-        let next_file_id = INDEX.read().expect(crate::PANIC_MUTEX_SYNC).len();
-        INDEX
-            .write()
-            .expect(crate::PANIC_MUTEX_SYNC)
-            .push(self.path);
+        self.compile_entry(bytecode, dependencies, packages, lints)
+            .map(|warnings| Diagnostic::render_all(warnings, &lines, format))
+            .map_err(|errors| Diagnostic::render_all(errors, &lines, format).join("\n"))
+    }
+
+    ///
+    /// Compiles the module file.
+    ///
+    /// Returns the module scope and the warnings, or all the errors found in the file, rendered
+    /// in the `format`. The warnings are reported as errors if any of them is denied by `lints`.
+    ///
+    pub fn try_into_module(
+        self,
+        bytecode: Rc<RefCell<Bytecode>>,
+        dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+        packages: HashMap<String, Rc<RefCell<Scope>>>,
+        lints: &Lints,
+        format: MessageFormat,
+    ) -> Result<(Rc<RefCell<Scope>>, Vec<String>), String> {
+        let lines = self.code.lines().collect::<Vec<&str>>();
+
+        self.compile_module(bytecode, dependencies, packages, lints)
+            .map(|(scope, warnings)| (scope, Diagnostic::render_all(warnings, &lines, format)))
+            .map_err(|errors| Diagnostic::render_all(errors, &lines, format).join("\n"))
+    }
+
+    ///
+    /// Compiles the circuit entry file.
+    ///
+    /// Returns the warnings, or all the errors found in the file. The warnings are reported as
+    /// errors if any of them is denied by `lints`.
+    ///
+    pub fn compile_entry(
+        &self,
+        bytecode: Rc<RefCell<Bytecode>>,
+        dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+        packages: HashMap<String, Rc<RefCell<Scope>>>,
+        lints: &Lints,
+    ) -> Result<Vec<Diagnostic>, Vec<Diagnostic>> {
+        let file_index = self.register();
 
         let syntax_tree = Parser::default()
-            .parse(&self.code, Some(next_file_id))
-            .map_err(|error| error.format(&lines))?;
+            .parse(&self.code, Some(file_index))
+            .map_err(Self::diagnostics)?;
 
-        EntryAnalyzer::new()
+        let intermediate = EntryAnalyzer::new_with_packages(packages)
             .compile(syntax_tree, dependencies)
-            .map_err(|error| error.format(&lines))?
-            .write_all_to_bytecode(bytecode);
+            .map_err(|errors| {
+                Warning::take_all();
+                Self::diagnostics(errors)
+            })?;
+
+        Emit::record_intermediate(&self.path, &intermediate);
+        intermediate.write_all_to_bytecode(bytecode);
 
-        Ok(())
+        Warning::diagnostics(Warning::take_all(), lints)
     }
 
-    pub fn try_into_module(
-        self,
+    ///
+    /// Compiles the module file.
+    ///
+    /// Returns the module scope and the warnings, or all the errors found in the file.
+    /// The warnings are reported as errors if any of them is denied by `lints`.
+    ///
+    #[allow(clippy::type_complexity)]
+    pub fn compile_module(
+        &self,
         bytecode: Rc<RefCell<Bytecode>>,
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
-    ) -> Result<Rc<RefCell<Scope>>, String> {
-        let lines = self.code.lines().collect::<Vec<&str>>();
-
-        let next_file_id = INDEX.read().expect(crate::PANIC_MUTEX_SYNC).len();
-        INDEX
-            .write()
-            .expect(crate::PANIC_MUTEX_SYNC)
-            .push(self.path);
+        packages: HashMap<String, Rc<RefCell<Scope>>>,
+        lints: &Lints,
+    ) -> Result<(Rc<RefCell<Scope>>, Vec<Diagnostic>), Vec<Diagnostic>> {
+        let file_index = self.register();
 
         let syntax_tree = Parser::default()
-            .parse(&self.code, Some(next_file_id))
-            .map_err(|error| error.format(&lines))?;
+            .parse(&self.code, Some(file_index))
+            .map_err(Self::diagnostics)?;
 
-        let (scope, intermediate) = ModuleAnalyzer::new()
+        let (scope, intermediate) = ModuleAnalyzer::new_with_packages(packages)
             .compile(syntax_tree, dependencies)
-            .map_err(|error| error.format(&lines))?;
+            .map_err(|errors| {
+                Warning::take_all();
+                Self::diagnostics(errors)
+            })?;
 
+        Emit::record_intermediate(&self.path, &intermediate);
         intermediate.write_all_to_bytecode(bytecode);
 
-        Ok(scope)
+        let warnings = Warning::diagnostics(Warning::take_all(), lints)?;
+
+        Ok((scope, warnings))
     }
 
-    pub fn find_modules(self) -> Result<Vec<String>, String> {
+    ///
+    /// Returns the identifiers of the `mod` statements declared in the file.
+    ///
+    pub fn find_modules(self, format: MessageFormat) -> Result<Vec<Identifier>, String> {
         let lines = self.code.lines().collect::<Vec<&str>>();
 
-        let next_file_id = INDEX.read().expect(crate::PANIC_MUTEX_SYNC).len();
-        INDEX
-            .write()
-            .expect(crate::PANIC_MUTEX_SYNC)
-            .push(self.path);
+        let file_index = self.register();
 
         let syntax_tree = Parser::default()
-            .parse(&self.code, Some(next_file_id))
-            .map_err(|error| error.format(&lines))?;
+            .parse(&self.code, Some(file_index))
+            .map_err(|errors| CompilerError::format_all(errors, &lines, format))?;
 
-        Ok(syntax_tree.statements
+        Ok(syntax_tree
+            .statements
             .into_iter()
-            .fold(Vec::new(), |mut modules, statement| {
-                if let Statement::Mod(s) = statement {
-                    modules.push(s.identifier.name);
-                }
-                modules
+            .filter_map(|statement| match statement {
+                Statement::Mod(statement) => Some(statement.identifier),
+                _ => None,
             })
-        )
+            .collect())
+    }
+
+    ///
+    /// Parses the file, so its items can be documented.
+    ///
+    pub fn syntax_tree(&self) -> Result<Tree, Vec<Diagnostic>> {
+        let file_index = self.register();
+
+        Parser::default()
+            .parse(&self.code, Some(file_index))
+            .map_err(Self::diagnostics)
+    }
+
+    ///
+    /// Splits the file into tokens, so they can be dumped.
+    ///
+    pub fn tokens(&self) -> Result<Vec<Token>, Vec<Diagnostic>> {
+        let file_index = self.register();
+
+        let mut stream = TokenStream::new_with_file(&self.code, file_index);
+        let mut tokens = Vec::new();
+        loop {
+            let token = stream
+                .next()
+                .map_err(|error| Self::diagnostics(vec![CompilerError::Lexical(error)]))?;
+            if let Lexeme::Eof = token.lexeme {
+                break;
+            }
+            tokens.push(token);
+        }
+
+        Ok(tokens)
+    }
+
+    ///
+    /// Formats the file code.
+    ///
+    /// Returns the formatted code, or the syntax errors found in the file, which cannot be
+    /// formatted then. The formatted code is checked to consist of the same tokens and comments,
+    /// so the file is never broken by the formatter.
+    ///
+    pub fn format(&self) -> Result<String, Vec<Diagnostic>> {
+        let file_index = self.register();
+
+        Parser::default()
+            .parse(&self.code, Some(file_index))
+            .map_err(Self::diagnostics)?;
+
+        let formatted = Formatter::format(&self.code)
+            .map_err(|error| Self::diagnostics(vec![CompilerError::Lexical(error)]))?;
+        if Formatter::tokens(&formatted).ok() != Formatter::tokens(&self.code).ok() {
+            return Err(vec![Diagnostic::error_message(
+                "the formatter has changed the tokens, so the file is left unformatted",
+                None,
+            )]);
+        }
+
+        Ok(formatted)
+    }
+
+    ///
+    /// Returns the index of the file in the global file index, if it has been registered.
+    ///
+    pub fn index(path: &Path) -> Option<usize> {
+        INDEX
+            .read()
+            .expect(crate::PANIC_MUTEX_SYNC)
+            .iter()
+            .position(|registered| registered == path)
+    }
+
+    ///
+    /// Registers the file in the global file index, so the locations can refer to it.
+    ///
+    /// The file, which has been registered before, keeps its index, so it does not grow when
+    /// the same files are compiled again.
+    ///
+    fn register(&self) -> usize {
+        let mut index = INDEX.write().expect(crate::PANIC_MUTEX_SYNC);
+        match index.iter().position(|path| path == &self.path) {
+            Some(position) => position,
+            None => {
+                index.push(self.path.to_owned());
+                index.len() - 1
+            }
+        }
+    }
+
+    fn diagnostics(errors: Vec<CompilerError>) -> Vec<Diagnostic> {
+        errors.into_iter().map(CompilerError::diagnostic).collect()
     }
 }
 
@@ -0,0 +1,498 @@
+//!
+//! The source code formatter.
+//!
+
+mod tests;
+
+use crate::lexical::error::Error as LexicalError;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::lexeme::symbol::Symbol;
+use crate::lexical::token::lexeme::Lexeme;
+
+static INDENT: &str = "    ";
+
+///
+/// The source code formatter, which prints the tokens of the file with the canonical spacing,
+/// line breaks, and indentation.
+///
+/// Only the whitespace between the tokens is changed, so the comments are preserved as they are
+/// written. The line breaks are partially kept from the original code, that is, a bracket group
+/// written on a single line stays on a single line, whereas a group broken into lines gets each
+/// of its statements or comma-separated elements on a separate line.
+///
+pub struct Formatter {
+    /// The tokens and comments of the file.
+    elements: Vec<Element>,
+    /// The closing bracket indexes, which are set for the opening brackets.
+    closers: Vec<Option<usize>>,
+    /// The flags, which are set for the opening brackets of the groups broken into lines.
+    is_multiline: Vec<bool>,
+    /// The flags, which are set for the `<` and `>` enclosing the generic parameters, and for
+    /// the commas between them.
+    is_generic: Vec<bool>,
+    /// The flags, which are set for the `-`, `~`, and `!` unary operators.
+    is_unary: Vec<bool>,
+    /// The output code.
+    output: String,
+    /// The indentation level of the current output line.
+    line_indent: usize,
+}
+
+///
+/// The token or comment with its original text and position.
+///
+struct Element {
+    /// The token lexeme.
+    lexeme: Lexeme,
+    /// The original text of the token.
+    text: String,
+    /// The line the token starts at.
+    line: usize,
+    /// The line the token ends at, which is only different for multi-line block comments.
+    end_line: usize,
+}
+
+///
+/// The bracket group, which is being printed.
+///
+struct Group {
+    /// The opening bracket index, which is `None` for the file top level.
+    opener: Option<usize>,
+    /// The closing bracket index, which is `None` for the file top level.
+    closer: Option<usize>,
+    /// Whether the group is a block of statements, items, or fields.
+    is_curly: bool,
+    /// Whether the group elements are written on separate lines.
+    is_multiline: bool,
+    /// The indentation level of the group elements.
+    indent: usize,
+}
+
+///
+/// The whitespace written between two tokens.
+///
+enum Separator {
+    None,
+    Space,
+    Line {
+        indent: usize,
+        is_blank_allowed: bool,
+    },
+}
+
+impl Formatter {
+    ///
+    /// Formats the code, which must be free of syntax errors.
+    ///
+    pub fn format(code: &str) -> Result<String, LexicalError> {
+        let elements = Self::elements(code)?;
+        let size = elements.len();
+
+        let mut formatter = Self {
+            elements,
+            closers: vec![None; size],
+            is_multiline: vec![false; size],
+            is_generic: vec![false; size],
+            is_unary: vec![false; size],
+            output: String::with_capacity(code.len()),
+            line_indent: 0,
+        };
+        formatter.match_brackets();
+        formatter.mark_operators();
+        formatter.print();
+
+        Ok(formatter.output)
+    }
+
+    ///
+    /// Returns the texts of the tokens and comments of the code, which are compared to check
+    /// that the formatting has not changed anything but the whitespace.
+    ///
+    pub fn tokens(code: &str) -> Result<Vec<String>, LexicalError> {
+        Ok(Self::elements(code)?
+            .into_iter()
+            .map(|element| element.text)
+            .collect())
+    }
+
+    fn elements(code: &str) -> Result<Vec<Element>, LexicalError> {
+        let mut stream = TokenStream::new_with_comments(code);
+        let mut elements = Vec::new();
+
+        loop {
+            let start = stream.offset();
+            let token = stream.next()?;
+            if let Lexeme::Eof = token.lexeme {
+                break;
+            }
+
+            let text = code
+                .get(start..stream.offset())
+                .unwrap_or_default()
+                .trim()
+                .to_owned();
+            let end_line = token.location.line + text.matches('\n').count();
+            elements.push(Element {
+                lexeme: token.lexeme,
+                text,
+                line: token.location.line,
+                end_line,
+            });
+        }
+
+        Ok(elements)
+    }
+
+    ///
+    /// Finds the closing bracket of each opening one, and checks whether the group is broken
+    /// into lines.
+    ///
+    /// A group is multi-line if there is a line break between its immediate elements, or if
+    /// it is a curly bracket group with several statements.
+    ///
+    fn match_brackets(&mut self) {
+        let mut stack = Vec::new();
+        let mut depths = Vec::with_capacity(self.elements.len());
+
+        for (index, element) in self.elements.iter().enumerate() {
+            if Self::is_opener(&element.lexeme) {
+                stack.push(index);
+            } else if Self::is_closer(&element.lexeme) {
+                if let Some(opener) = stack.pop() {
+                    self.closers[opener] = Some(index);
+                }
+            }
+            depths.push(stack.len());
+        }
+
+        for opener in 0..self.elements.len() {
+            let closer = match self.closers[opener] {
+                Some(closer) => closer,
+                None => continue,
+            };
+            if closer == opener + 1 {
+                continue;
+            }
+
+            let is_curly = self.is_symbol(opener, Symbol::BracketCurlyLeft);
+            let depth = depths[opener];
+            self.is_multiline[opener] = (opener..closer)
+                .filter(|index| depths[*index] == depth)
+                .any(|index| {
+                    self.is_line_break(index, index + 1)
+                        || (is_curly && index != opener && self.is_symbol(index, Symbol::Semicolon))
+                });
+        }
+    }
+
+    ///
+    /// Marks the generic parameter brackets, which are only written after the function name
+    /// and after `::` in paths, and the unary operators, which do not follow an operand.
+    ///
+    fn mark_operators(&mut self) {
+        for index in 0..self.elements.len() {
+            if self.is_symbol(index, Symbol::Lesser) && self.is_generic_opener(index) {
+                self.is_generic[index] = true;
+
+                let mut depth = 0;
+                for next in index + 1..self.elements.len() {
+                    if depth == 0 && self.is_symbol(next, Symbol::Comma) {
+                        self.is_generic[next] = true;
+                    } else if self.is_symbol(next, Symbol::Lesser) {
+                        depth += 1;
+                    } else if self.is_symbol(next, Symbol::Greater) {
+                        if depth == 0 {
+                            self.is_generic[next] = true;
+                            break;
+                        }
+                        depth -= 1;
+                    }
+                }
+            }
+
+            if self.is_symbol(index, Symbol::Minus)
+                || self.is_symbol(index, Symbol::Tilde)
+                || self.is_symbol(index, Symbol::ExclamationMark)
+            {
+                self.is_unary[index] = match self.previous_token(index) {
+                    Some(previous) => !self.is_operand_end(previous),
+                    None => true,
+                };
+            }
+        }
+    }
+
+    fn print(&mut self) {
+        let mut stack = vec![Group {
+            opener: None,
+            closer: None,
+            is_curly: true,
+            is_multiline: true,
+            indent: 0,
+        }];
+
+        for index in 0..self.elements.len() {
+            if index > 0 {
+                let group = stack.last().expect(crate::PANIC_FORMATTER_TOP_LEVEL);
+                match self.separator(group, index - 1, index) {
+                    Separator::None => {}
+                    Separator::Space => self.output.push(' '),
+                    Separator::Line {
+                        indent,
+                        is_blank_allowed,
+                    } => {
+                        self.output.push('\n');
+                        if is_blank_allowed
+                            && self.elements[index].line > self.elements[index - 1].end_line + 1
+                        {
+                            self.output.push('\n');
+                        }
+                        self.output.push_str(INDENT.repeat(indent).as_str());
+                        self.line_indent = indent;
+                    }
+                }
+            }
+            self.output.push_str(self.elements[index].text.as_str());
+
+            if let Some(closer) = self.closers[index] {
+                stack.push(Group {
+                    opener: Some(index),
+                    closer: Some(closer),
+                    is_curly: self.is_symbol(index, Symbol::BracketCurlyLeft),
+                    is_multiline: self.is_multiline[index],
+                    indent: self.line_indent + 1,
+                });
+            } else if stack.len() > 1 && stack.last().and_then(|group| group.closer) == Some(index)
+            {
+                stack.pop();
+            }
+        }
+
+        if !self.output.is_empty() {
+            self.output.push('\n');
+        }
+    }
+
+    ///
+    /// Chooses the whitespace between the `previous` and `next` tokens of the `group`.
+    ///
+    fn separator(&self, group: &Group, previous: usize, next: usize) -> Separator {
+        let is_opener = group.opener == Some(previous);
+        let is_closer = group.closer == Some(next);
+        let line = |is_continuation: bool, is_blank_allowed: bool| {
+            let indent = if is_closer {
+                group.indent - 1
+            } else if is_continuation {
+                group.indent + 1
+            } else {
+                group.indent
+            };
+            Separator::Line {
+                indent,
+                is_blank_allowed: is_blank_allowed && !is_opener && !is_closer,
+            }
+        };
+
+        if self.is_line_comment(previous) {
+            return line(false, true);
+        }
+        if self.is_line_comment(next) && !self.is_line_break(previous, next) {
+            return Separator::Space;
+        }
+
+        if group.is_multiline {
+            if is_opener || is_closer {
+                return line(false, false);
+            }
+            if (self.is_symbol(previous, Symbol::Comma) && !self.is_generic[previous])
+                || (group.is_curly && self.is_symbol(previous, Symbol::Semicolon))
+            {
+                return line(false, true);
+            }
+            if self.is_symbol(previous, Symbol::BracketCurlyRight) {
+                if let Lexeme::Keyword(Keyword::Else) = self.elements[next].lexeme {
+                    return Separator::Space;
+                }
+                if group.is_curly
+                    && (self.is_statement_start(next) || self.is_line_break(previous, next))
+                {
+                    return line(false, true);
+                }
+            }
+            if self.is_line_break(previous, next) && !self.is_symbol(next, Symbol::BracketCurlyLeft)
+            {
+                return line(true, true);
+            }
+        }
+
+        if self.is_spaced(previous, next) {
+            Separator::Space
+        } else {
+            Separator::None
+        }
+    }
+
+    ///
+    /// Checks whether the tokens written on the same line are separated with a space.
+    ///
+    fn is_spaced(&self, previous: usize, next: usize) -> bool {
+        match self.elements[next].lexeme {
+            Lexeme::Symbol(Symbol::Comma)
+            | Lexeme::Symbol(Symbol::Semicolon)
+            | Lexeme::Symbol(Symbol::Colon)
+            | Lexeme::Symbol(Symbol::DoubleColon)
+            | Lexeme::Symbol(Symbol::Dot)
+            | Lexeme::Symbol(Symbol::DoubleDot)
+            | Lexeme::Symbol(Symbol::DoubleDotEquals)
+            | Lexeme::Symbol(Symbol::ParenthesisRight)
+            | Lexeme::Symbol(Symbol::BracketSquareRight) => return false,
+            Lexeme::Symbol(Symbol::ParenthesisLeft) | Lexeme::Symbol(Symbol::BracketSquareLeft)
+                if self.is_callee_end(previous) =>
+            {
+                return false
+            }
+            Lexeme::Symbol(Symbol::ExclamationMark) if !self.is_unary[next] => return false,
+            _ => {}
+        }
+
+        match self.elements[previous].lexeme {
+            Lexeme::Symbol(Symbol::ParenthesisLeft)
+            | Lexeme::Symbol(Symbol::BracketSquareLeft)
+            | Lexeme::Symbol(Symbol::DoubleColon)
+            | Lexeme::Symbol(Symbol::Dot)
+            | Lexeme::Symbol(Symbol::DoubleDot)
+            | Lexeme::Symbol(Symbol::DoubleDotEquals) => return false,
+            Lexeme::Symbol(Symbol::BracketCurlyLeft)
+                if self.is_symbol(next, Symbol::BracketCurlyRight) =>
+            {
+                return false
+            }
+            Lexeme::Symbol(Symbol::Minus)
+            | Lexeme::Symbol(Symbol::Tilde)
+            | Lexeme::Symbol(Symbol::ExclamationMark)
+                if self.is_unary[previous] =>
+            {
+                return false
+            }
+            _ => {}
+        }
+
+        !(self.is_generic[next]
+            || (self.is_generic[previous] && self.is_symbol(previous, Symbol::Lesser)))
+    }
+
+    ///
+    /// Checks whether the token may be followed by the call or indexing brackets.
+    ///
+    fn is_callee_end(&self, index: usize) -> bool {
+        match self.elements[index].lexeme {
+            Lexeme::Identifier(_)
+            | Lexeme::Literal(_)
+            | Lexeme::Keyword(Keyword::SelfLowercase)
+            | Lexeme::Keyword(Keyword::SelfUppercase)
+            | Lexeme::Symbol(Symbol::ParenthesisRight)
+            | Lexeme::Symbol(Symbol::BracketSquareRight) => true,
+            Lexeme::Symbol(Symbol::Greater) => self.is_generic[index],
+            Lexeme::Symbol(Symbol::ExclamationMark) => !self.is_unary[index],
+            _ => false,
+        }
+    }
+
+    ///
+    /// Checks whether the token may end an operand, so the next `-` is a binary operator.
+    ///
+    fn is_operand_end(&self, index: usize) -> bool {
+        match self.elements[index].lexeme {
+            Lexeme::Keyword(Keyword::True)
+            | Lexeme::Keyword(Keyword::False)
+            | Lexeme::Keyword(Keyword::Bool)
+            | Lexeme::Keyword(Keyword::IntegerUnsigned { .. })
+            | Lexeme::Keyword(Keyword::IntegerSigned { .. })
+            | Lexeme::Keyword(Keyword::Field)
+            | Lexeme::Symbol(Symbol::Underscore)
+            | Lexeme::Symbol(Symbol::BracketCurlyRight) => true,
+            _ => self.is_callee_end(index),
+        }
+    }
+
+    ///
+    /// Checks whether the `<` opens the generic parameters, that is, it follows either `::` or
+    /// the function name.
+    ///
+    fn is_generic_opener(&self, index: usize) -> bool {
+        let previous = match self.previous_token(index) {
+            Some(previous) => previous,
+            None => return false,
+        };
+        if self.is_symbol(previous, Symbol::DoubleColon) {
+            return true;
+        }
+
+        match (
+            self.previous_token(previous)
+                .map(|index| &self.elements[index].lexeme),
+            &self.elements[previous].lexeme,
+        ) {
+            (Some(Lexeme::Keyword(Keyword::Fn)), Lexeme::Identifier(_)) => true,
+            _ => false,
+        }
+    }
+
+    ///
+    /// Checks whether the token starts a statement, so it is written on a new line after
+    /// the previous block.
+    ///
+    fn is_statement_start(&self, index: usize) -> bool {
+        match self.elements[index].lexeme {
+            Lexeme::Keyword(Keyword::Else) | Lexeme::Keyword(Keyword::As) => false,
+            Lexeme::Keyword(_)
+            | Lexeme::Identifier(_)
+            | Lexeme::Literal(_)
+            | Lexeme::Symbol(Symbol::Underscore) => true,
+            _ => false,
+        }
+    }
+
+    fn previous_token(&self, index: usize) -> Option<usize> {
+        (0..index)
+            .rev()
+            .find(|index| match self.elements[*index].lexeme {
+                Lexeme::Comment(_) => false,
+                _ => true,
+            })
+    }
+
+    fn is_line_break(&self, previous: usize, next: usize) -> bool {
+        self.elements[next].line > self.elements[previous].end_line
+    }
+
+    fn is_line_comment(&self, index: usize) -> bool {
+        match self.elements[index].lexeme {
+            Lexeme::Comment(_) => self.elements[index].text.starts_with("//"),
+            _ => false,
+        }
+    }
+
+    fn is_symbol(&self, index: usize, symbol: Symbol) -> bool {
+        self.elements[index].lexeme == Lexeme::Symbol(symbol)
+    }
+
+    fn is_opener(lexeme: &Lexeme) -> bool {
+        match lexeme {
+            Lexeme::Symbol(Symbol::ParenthesisLeft)
+            | Lexeme::Symbol(Symbol::BracketSquareLeft)
+            | Lexeme::Symbol(Symbol::BracketCurlyLeft) => true,
+            _ => false,
+        }
+    }
+
+    fn is_closer(lexeme: &Lexeme) -> bool {
+        match lexeme {
+            Lexeme::Symbol(Symbol::ParenthesisRight)
+            | Lexeme::Symbol(Symbol::BracketSquareRight)
+            | Lexeme::Symbol(Symbol::BracketCurlyRight) => true,
+            _ => false,
+        }
+    }
+}
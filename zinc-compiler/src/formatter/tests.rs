@@ -0,0 +1,216 @@
+//!
+//! The formatter tests.
+//!
+
+#![cfg(test)]
+
+use std::path::PathBuf;
+
+use crate::file::File;
+use crate::formatter::Formatter;
+
+#[test]
+fn ok_spacing() {
+    let input =
+        r#"fn main(a:u8,b : u8)->u8{let c=a+b*2;let d=-c as i16;dbg!("{}",c);(c+1)*(-2 as u8)}"#;
+
+    let expected = r#"fn main(a: u8, b: u8) -> u8 {
+    let c = a + b * 2;
+    let d = -c as i16;
+    dbg!("{}", c);
+    (c + 1) * (-2 as u8)
+}
+"#;
+
+    let result = Formatter::format(input).expect("Syntax error");
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_indentation() {
+    let input = r#"
+struct Point {
+x: u8,
+        y: u8,
+}
+
+fn main(witness: [u8; 4]) -> Point {
+let mut sum = 0;
+  for i in 0..4 {
+        if witness[i] > 2 {
+    sum += witness[i];
+        } else {
+sum -= 1;
+        }
+  }
+    Point { x: sum, y: 0 }
+}
+"#;
+
+    let expected = r#"struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main(witness: [u8; 4]) -> Point {
+    let mut sum = 0;
+    for i in 0..4 {
+        if witness[i] > 2 {
+            sum += witness[i];
+        } else {
+            sum -= 1;
+        }
+    }
+    Point { x: sum, y: 0 }
+}
+"#;
+
+    let result = Formatter::format(input).expect("Syntax error");
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_comments() {
+    let input = r#"//! The circuit.
+
+/// The entry.
+fn main(a: u8) -> u8 {
+        // the sum
+    let b = a + 1; // trailing
+    /* block */ b
+}
+"#;
+
+    let expected = r#"//! The circuit.
+
+/// The entry.
+fn main(a: u8) -> u8 {
+    // the sum
+    let b = a + 1; // trailing
+    /* block */ b
+}
+"#;
+
+    let result = Formatter::format(input).expect("Syntax error");
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_blank_lines() {
+    let input = r#"
+
+const A: u8 = 1;
+
+
+
+const B: u8 = 2;
+fn main() -> u8 {
+
+    A + B
+
+}
+"#;
+
+    let expected = r#"const A: u8 = 1;
+
+const B: u8 = 2;
+fn main() -> u8 {
+    A + B
+}
+"#;
+
+    let result = Formatter::format(input).expect("Syntax error");
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_generics() {
+    let input = r#"fn sum < T , const N : u64 > ( array : [ T ; N ] ) -> T { array [ 0 ] }
+fn main ( ) -> bool { sum :: < u8 , 2 > ( [ 1 , 2 ] ) < 3 && ! false }"#;
+
+    let expected = r#"fn sum<T, const N: u64>(array: [T; N]) -> T { array[0] }
+fn main() -> bool { sum::<u8, 2>([1, 2]) < 3 && !false }
+"#;
+
+    let result = Formatter::format(input).expect("Syntax error");
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_multiline_groups() {
+    let input = r#"fn main(value: u8) -> u8 {
+    let result = match value { 1 => 10, 2 => { 20 }
+        _ => 30 };
+    double(result,
+        1)
+}
+"#;
+
+    let expected = r#"fn main(value: u8) -> u8 {
+    let result = match value {
+        1 => 10,
+        2 => { 20 }
+        _ => 30
+    };
+    double(
+        result,
+        1
+    )
+}
+"#;
+
+    let result = Formatter::format(input).expect("Syntax error");
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_idempotent() {
+    let input = r#"mod util;
+
+use util::double;
+
+/// The point.
+struct Point { x: u8, y: u8 }
+
+impl Point {
+    pub fn new(x: u8,
+        y: u8) -> Self {
+        Self { x: x, y: y } // the point
+    }
+}
+
+fn main(witness: u8) -> u8 {
+    let point = Point::new(witness, 1);
+    let first = double(point.x)
+        + point.y;
+    first
+}
+"#;
+
+    let once = Formatter::format(input).expect("Syntax error");
+    let twice = Formatter::format(once.as_str()).expect("Syntax error");
+
+    assert_eq!(once, twice);
+    assert_eq!(
+        Formatter::tokens(input).expect("Syntax error"),
+        Formatter::tokens(once.as_str()).expect("Syntax error")
+    );
+}
+
+#[test]
+fn error_syntax() {
+    let file = File {
+        path: PathBuf::from("format.zn"),
+        code: "fn main( {}".to_owned(),
+    };
+
+    let result = file.format();
+
+    assert!(result.is_err());
+}
@@ -0,0 +1,84 @@
+//!
+//! The generator pattern binding.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_bytecode::Instruction;
+
+use crate::generator::bytecode::Bytecode;
+use crate::generator::r#type::Type;
+use crate::lexical::token::location::Location;
+
+///
+/// The part of a destructured value bound to a variable by a pattern.
+///
+/// The `offset` is the position of the part within the destructured value.
+///
+#[derive(Debug, Clone)]
+pub struct Binding {
+    name: String,
+    offset: usize,
+    r#type: Type,
+    is_casted: bool,
+}
+
+impl Binding {
+    pub fn new(name: String, offset: usize, r#type: Type, is_casted: bool) -> Self {
+        Self {
+            name,
+            offset,
+            r#type,
+            is_casted,
+        }
+    }
+
+    ///
+    /// Copies the part of the value located at `address` to the binding variable.
+    ///
+    /// The enumeration variant payload is stored as field elements, so if the binding is
+    /// located inside a payload, each cell is cast back to its scalar type.
+    ///
+    pub fn write_all_to_bytecode(
+        self,
+        bytecode: Rc<RefCell<Bytecode>>,
+        address: usize,
+        location: Location,
+    ) {
+        let size = self.r#type.size();
+        let scalar_types = self.r#type.scalar_types();
+
+        let binding_address = bytecode
+            .borrow_mut()
+            .declare_variable(Some(self.name), self.r#type);
+        if size == 0 {
+            return;
+        }
+
+        if self.is_casted {
+            for (index, scalar_type) in scalar_types.into_iter().enumerate() {
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_bytecode::Load::new(address + self.offset + index)),
+                    Some(location),
+                );
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::Cast(zinc_bytecode::Cast::new(scalar_type)),
+                    Some(location),
+                );
+            }
+        } else {
+            bytecode.borrow_mut().push_instruction(
+                Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(
+                    address + self.offset,
+                    size,
+                )),
+                Some(location),
+            );
+        }
+        bytecode.borrow_mut().push_instruction(
+            Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(binding_address, size)),
+            Some(location),
+        );
+    }
+}
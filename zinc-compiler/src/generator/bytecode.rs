@@ -26,7 +26,10 @@ pub struct Bytecode {
 
     data_stack_pointer: usize,
     variable_addresses: HashMap<String, usize>,
+    variable_scopes: Vec<HashMap<String, usize>>,
     function_addresses: HashMap<usize, usize>,
+    loop_flags: Vec<(usize, usize)>,
+    function_flags: Option<(usize, usize, usize)>,
 
     current_file: String,
     current_location: Location,
@@ -57,9 +60,12 @@ impl Bytecode {
             variable_addresses: HashMap::with_capacity(
                 Self::VARIABLE_ADDRESSES_HASHMAP_INITIAL_SIZE,
             ),
+            variable_scopes: Vec::new(),
             function_addresses: HashMap::with_capacity(
                 Self::FUNCTION_ADDRESSES_HASHMAP_INITIAL_SIZE,
             ),
+            loop_flags: Vec::new(),
+            function_flags: None,
 
             current_file: String::new(),
             current_location: Location::new_beginning(None),
@@ -74,6 +80,8 @@ impl Bytecode {
         let address = self.instructions.len();
         self.function_addresses.insert(unique_id, address);
         self.data_stack_pointer = 0;
+        self.loop_flags.clear();
+        self.function_flags = None;
 
         self.instructions.push(Instruction::FileMarker(
             zinc_bytecode::instructions::FileMarker::new(self.current_file.clone()),
@@ -106,6 +114,8 @@ impl Bytecode {
         self.instructions[0] = Instruction::Call(zinc_bytecode::Call::new(address, input_size));
         self.instructions[1] = Instruction::Exit(zinc_bytecode::Exit::new(output_size));
         self.data_stack_pointer = 0;
+        self.loop_flags.clear();
+        self.function_flags = None;
 
         self.instructions.push(Instruction::FileMarker(
             zinc_bytecode::instructions::FileMarker::new(self.current_file.clone()),
@@ -128,6 +138,24 @@ impl Bytecode {
         start_address
     }
 
+    ///
+    /// Enters a nested scope, whose variables may shadow the variables declared outside.
+    ///
+    pub fn push_variable_scope(&mut self) {
+        self.variable_scopes.push(self.variable_addresses.clone());
+    }
+
+    ///
+    /// Leaves the nested scope, restoring the addresses of the shadowed variables.
+    ///
+    /// The data stack memory is not reclaimed.
+    ///
+    pub fn pop_variable_scope(&mut self) {
+        if let Some(variable_addresses) = self.variable_scopes.pop() {
+            self.variable_addresses = variable_addresses;
+        }
+    }
+
     pub fn push_instruction(&mut self, instruction: Instruction, location: Option<Location>) {
         if let Some(location) = location {
             if self.current_location != location {
@@ -148,6 +176,67 @@ impl Bytecode {
         self.instructions.push(instruction)
     }
 
+    ///
+    /// Enters a loop with `break`, `continue`, or `return` statements. The `allowed` flag is
+    /// cleared by `break` and `return`, and the current iteration `active` flag is cleared by
+    /// all of them.
+    ///
+    pub fn push_loop_flags(&mut self, allowed_address: usize, active_address: usize) {
+        self.loop_flags.push((allowed_address, active_address));
+    }
+
+    pub fn pop_loop_flags(&mut self) {
+        self.loop_flags.pop();
+    }
+
+    ///
+    /// Returns the `allowed` and `active` flag addresses of the innermost loop.
+    ///
+    pub fn get_loop_flags(&self) -> Option<(usize, usize)> {
+        self.loop_flags.last().copied()
+    }
+
+    ///
+    /// Returns the `allowed` and `active` flag addresses of all the loops the current
+    /// statement is located within.
+    ///
+    pub fn get_all_loop_flags(&self) -> Vec<(usize, usize)> {
+        self.loop_flags.clone()
+    }
+
+    ///
+    /// Enters a function body with `return` statements. The `active` flag is cleared by
+    /// `return`, which also stores the returned value of `result_size` at `result_address`.
+    ///
+    pub fn set_function_flags(
+        &mut self,
+        active_address: usize,
+        result_address: usize,
+        result_size: usize,
+    ) {
+        self.function_flags = Some((active_address, result_address, result_size));
+    }
+
+    ///
+    /// Returns the `active` flag, `result` address, and size of the current function.
+    ///
+    pub fn get_function_flags(&self) -> Option<(usize, usize, usize)> {
+        self.function_flags
+    }
+
+    ///
+    /// Returns the address of the flag, which is cleared if the rest of the innermost loop
+    /// iteration or function body must be skipped.
+    ///
+    pub fn get_active_flag(&self) -> Option<usize> {
+        match self.loop_flags.last() {
+            Some((_allowed_address, active_address)) => Some(*active_address),
+            None => self
+                .function_flags
+                .map(|(active_address, _result_address, _result_size)| active_address),
+        }
+    }
+
     pub fn get_function_address(&self, unique_id: usize) -> Option<usize> {
         self.function_addresses.get(&unique_id).copied()
     }
@@ -178,6 +267,13 @@ impl Bytecode {
         }
     }
 
+    ///
+    /// The instructions generated so far, including the file, function, line and column markers.
+    ///
+    pub fn instructions(&self) -> &[Instruction] {
+        self.instructions.as_slice()
+    }
+
     pub fn into_bytes(self) -> Vec<u8> {
         for (index, instruction) in self.instructions.iter().enumerate() {
             log::debug!("{:03} {:?}", index, instruction)
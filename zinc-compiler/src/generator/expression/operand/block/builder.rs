@@ -4,12 +4,15 @@
 
 use crate::generator::expression::operand::block::Expression as BlockExpression;
 use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::r#type::Type;
 use crate::generator::statement::Statement;
 
 #[derive(Debug, Default, Clone)]
 pub struct Builder {
     statements: Vec<Statement>,
     expression: Option<GeneratorExpression>,
+    control_guards: Vec<usize>,
+    expression_type: Option<Type>,
 }
 
 impl Builder {
@@ -17,11 +20,33 @@ impl Builder {
         self.statements.push(value);
     }
 
+    ///
+    /// Marks the statements pushed after this call, and the block result expression, as guarded
+    /// by the innermost loop or function `active` flag.
+    ///
+    /// Each call opens a nested guard, since the flag may be changed by the statements pushed
+    /// before it.
+    ///
+    pub fn set_control_guard(&mut self) {
+        if self.control_guards.last() != Some(&self.statements.len()) {
+            self.control_guards.push(self.statements.len());
+        }
+    }
+
     pub fn set_expression(&mut self, value: GeneratorExpression) {
         self.expression = Some(value);
     }
 
+    pub fn set_expression_type(&mut self, value: Type) {
+        self.expression_type = Some(value);
+    }
+
     pub fn finish(self) -> BlockExpression {
-        BlockExpression::new(self.statements, self.expression)
+        BlockExpression::new(
+            self.statements,
+            self.expression,
+            self.control_guards,
+            self.expression_type.unwrap_or_else(Type::unit),
+        )
     }
 }
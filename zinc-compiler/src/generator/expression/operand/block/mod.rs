@@ -7,33 +7,113 @@ pub mod builder;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use zinc_bytecode::Instruction;
+
 use crate::generator::bytecode::Bytecode;
 use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::r#type::Type;
 use crate::generator::statement::Statement;
 
 ///
 /// The block expression which is translated to Zinc VM bytecode.
 ///
+/// Each index in `control_guards` is the first statement after one which may execute a `break`,
+/// `continue`, or `return`. A conditional block, which is executed only if the innermost loop
+/// iteration or function body is still active, is opened there and spans the rest of the block,
+/// so the variables declared in it stay available to the following statements. The guards are
+/// nested, since each of them checks the flag changed by the statements before it. If the block
+/// is not active, it yields a zero value of `expression_type`.
+///
 #[derive(Debug, Clone)]
 pub struct Expression {
     statements: Vec<Statement>,
     expression: Option<GeneratorExpression>,
+    control_guards: Vec<usize>,
+    expression_type: Type,
 }
 
 impl Expression {
-    pub fn new(statements: Vec<Statement>, expression: Option<GeneratorExpression>) -> Self {
+    pub fn new(
+        statements: Vec<Statement>,
+        expression: Option<GeneratorExpression>,
+        control_guards: Vec<usize>,
+        expression_type: Type,
+    ) -> Self {
         Self {
             statements,
             expression,
+            control_guards,
+            expression_type,
         }
     }
 
     pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
-        for statement in self.statements.into_iter() {
+        bytecode.borrow_mut().push_variable_scope();
+
+        let active_address = bytecode.borrow().get_active_flag();
+        let control_guards = match active_address {
+            Some(_) => self.control_guards,
+            None => vec![],
+        };
+        let statements_count = self.statements.len();
+
+        let mut guards_opened = 0;
+        for (index, statement) in self.statements.into_iter().enumerate() {
+            if let Some(active_address) = active_address {
+                if control_guards.contains(&index) {
+                    Self::write_guard(bytecode.clone(), active_address);
+                    guards_opened += 1;
+                }
+            }
             statement.write_all_to_bytecode(bytecode.clone());
         }
+
+        let mut scalar_types = vec![];
         if let Some(expression) = self.expression {
-            expression.write_all_to_bytecode(bytecode);
+            if let Some(active_address) = active_address {
+                if control_guards.contains(&statements_count) {
+                    Self::write_guard(bytecode.clone(), active_address);
+                    guards_opened += 1;
+                }
+            }
+            expression.write_all_to_bytecode(bytecode.clone());
+            scalar_types = self.expression_type.scalar_types();
         }
+
+        for _ in 0..guards_opened {
+            if !scalar_types.is_empty() {
+                bytecode
+                    .borrow_mut()
+                    .push_instruction(Instruction::Else(zinc_bytecode::Else), None);
+                for scalar_type in scalar_types.iter() {
+                    bytecode.borrow_mut().push_instruction(
+                        Instruction::PushConst(zinc_bytecode::PushConst::new(
+                            0.into(),
+                            *scalar_type,
+                        )),
+                        None,
+                    );
+                }
+            }
+            bytecode
+                .borrow_mut()
+                .push_instruction(Instruction::EndIf(zinc_bytecode::EndIf), None);
+        }
+
+        bytecode.borrow_mut().pop_variable_scope();
+    }
+
+    ///
+    /// Opens the conditional block, which is executed only if the loop iteration or function
+    /// body is active.
+    ///
+    fn write_guard(bytecode: Rc<RefCell<Bytecode>>, active_address: usize) {
+        bytecode.borrow_mut().push_instruction(
+            Instruction::Load(zinc_bytecode::Load::new(active_address)),
+            None,
+        );
+        bytecode
+            .borrow_mut()
+            .push_instruction(Instruction::If(zinc_bytecode::If), None);
     }
 }
@@ -2,7 +2,8 @@
 //! The generator expression match operand builder.
 //!
 
-use crate::generator::expression::operand::constant::Constant;
+use crate::generator::binding::Binding;
+use crate::generator::expression::operand::r#match::check::Check;
 use crate::generator::expression::operand::r#match::Expression as MatchExpression;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type;
@@ -13,9 +14,9 @@ pub struct Builder {
     location: Option<Location>,
     scrutinee: Option<GeneratorExpression>,
     scrutinee_type: Option<Type>,
-    branches: Vec<(Constant, GeneratorExpression)>,
+    branches: Vec<(Vec<Check>, Vec<Binding>, GeneratorExpression)>,
     binding_branch: Option<(GeneratorExpression, String)>,
-    wildcard_branch: Option<GeneratorExpression>,
+    wildcard_branch: Option<(Vec<Binding>, GeneratorExpression)>,
 }
 
 impl Builder {
@@ -28,16 +29,21 @@ impl Builder {
         self.scrutinee_type = Some(r#type);
     }
 
-    pub fn push_branch(&mut self, pattern: Constant, expression: GeneratorExpression) {
-        self.branches.push((pattern, expression));
+    pub fn push_branch(
+        &mut self,
+        checks: Vec<Check>,
+        bindings: Vec<Binding>,
+        expression: GeneratorExpression,
+    ) {
+        self.branches.push((checks, bindings, expression));
     }
 
     pub fn set_binding_branch(&mut self, expression: GeneratorExpression, name: String) {
         self.binding_branch = Some((expression, name));
     }
 
-    pub fn set_wildcard_branch(&mut self, value: GeneratorExpression) {
-        self.wildcard_branch = Some(value);
+    pub fn set_wildcard_branch(&mut self, bindings: Vec<Binding>, value: GeneratorExpression) {
+        self.wildcard_branch = Some((bindings, value));
     }
 
     pub fn finish(mut self) -> MatchExpression {
@@ -0,0 +1,63 @@
+//!
+//! The generator expression match operand branch check.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_bytecode::Instruction;
+
+use crate::generator::bytecode::Bytecode;
+use crate::generator::expression::operand::constant::Constant;
+use crate::lexical::token::location::Location;
+
+///
+/// The comparison of a scrutinee cell with a branch pattern constant.
+///
+/// The `offset` is the cell position within the scrutinee. If the cell is located inside
+/// an enumeration variant payload, it is stored as a field element and is compared with the
+/// constant converted to a field element.
+///
+/// The payload cell is not cast to the constant type, since the checks of all the branches
+/// are evaluated, and the cell may hold a value of another variant, which does not fit the type.
+///
+#[derive(Debug, Clone)]
+pub struct Check {
+    offset: usize,
+    constant: Constant,
+    is_payload: bool,
+}
+
+impl Check {
+    pub fn new(offset: usize, constant: Constant, is_payload: bool) -> Self {
+        Self {
+            offset,
+            constant,
+            is_payload,
+        }
+    }
+
+    ///
+    /// Leaves the boolean result of the comparison on the evaluation stack.
+    ///
+    pub fn write_all_to_bytecode(
+        self,
+        bytecode: Rc<RefCell<Bytecode>>,
+        scrutinee_address: usize,
+        location: Location,
+    ) {
+        bytecode.borrow_mut().push_instruction(
+            Instruction::Load(zinc_bytecode::Load::new(scrutinee_address + self.offset)),
+            Some(location),
+        );
+        let constant = if self.is_payload {
+            Constant::new_integer(self.constant.value, false, crate::BITLENGTH_FIELD)
+        } else {
+            self.constant
+        };
+        constant.write_all_to_bytecode(bytecode.clone());
+        bytecode
+            .borrow_mut()
+            .push_instruction(Instruction::Eq(zinc_bytecode::Eq), Some(location));
+    }
+}
@@ -3,29 +3,36 @@
 //!
 
 pub mod builder;
+pub mod check;
 
 use std::cell::RefCell;
 use std::rc::Rc;
 
 use zinc_bytecode::Instruction;
 
+use crate::generator::binding::Binding;
 use crate::generator::bytecode::Bytecode;
 use crate::generator::expression::operand::constant::Constant;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type;
 use crate::lexical::token::location::Location;
 
+use self::check::Check;
+
 ///
 /// The match expression which is translated to Zinc VM conditional series.
 ///
+/// Each branch is taken if all its scrutinee cell `checks` succeed, and the destructured
+/// parts of the scrutinee are copied to the branch `bindings` before the branch expression.
+///
 #[derive(Debug, Clone)]
 pub struct Expression {
     location: Location,
     scrutinee: GeneratorExpression,
     scrutinee_type: Type,
-    branches: Vec<(Constant, GeneratorExpression)>,
+    branches: Vec<(Vec<Check>, Vec<Binding>, GeneratorExpression)>,
     binding_branch: Option<(GeneratorExpression, String)>,
-    wildcard_branch: Option<GeneratorExpression>,
+    wildcard_branch: Option<(Vec<Binding>, GeneratorExpression)>,
 }
 
 impl Expression {
@@ -33,9 +40,9 @@ impl Expression {
         location: Location,
         scrutinee: GeneratorExpression,
         scrutinee_type: Type,
-        branches: Vec<(Constant, GeneratorExpression)>,
+        branches: Vec<(Vec<Check>, Vec<Binding>, GeneratorExpression)>,
         binding_branch: Option<(GeneratorExpression, String)>,
-        wildcard_branch: Option<GeneratorExpression>,
+        wildcard_branch: Option<(Vec<Binding>, GeneratorExpression)>,
     ) -> Self {
         Self {
             location,
@@ -56,6 +63,7 @@ impl Expression {
             None => (None, None),
         };
 
+        bytecode.borrow_mut().push_variable_scope();
         let scrutinee_address = bytecode
             .borrow_mut()
             .declare_variable(binding_name, self.scrutinee_type);
@@ -69,22 +77,28 @@ impl Expression {
             Some(self.location),
         );
 
-        for (branch_pattern, branch_expression) in self.branches.into_iter() {
-            bytecode.borrow_mut().push_instruction(
-                Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(
-                    scrutinee_address,
-                    scrutinee_size,
-                )),
-                Some(self.location),
-            );
-            branch_pattern.write_all_to_bytecode(bytecode.clone());
-            bytecode
-                .borrow_mut()
-                .push_instruction(Instruction::Eq(zinc_bytecode::Eq), Some(self.location));
+        for (branch_checks, branch_bindings, branch_expression) in self.branches.into_iter() {
+            if branch_checks.is_empty() {
+                Constant::new_boolean(true).write_all_to_bytecode(bytecode.clone());
+            }
+            for (index, check) in branch_checks.into_iter().enumerate() {
+                check.write_all_to_bytecode(bytecode.clone(), scrutinee_address, self.location);
+                if index > 0 {
+                    bytecode.borrow_mut().push_instruction(
+                        Instruction::And(zinc_bytecode::And),
+                        Some(self.location),
+                    );
+                }
+            }
             bytecode
                 .borrow_mut()
                 .push_instruction(Instruction::If(zinc_bytecode::If), Some(self.location));
+            bytecode.borrow_mut().push_variable_scope();
+            for binding in branch_bindings.into_iter() {
+                binding.write_all_to_bytecode(bytecode.clone(), scrutinee_address, self.location);
+            }
             branch_expression.write_all_to_bytecode(bytecode.clone());
+            bytecode.borrow_mut().pop_variable_scope();
             bytecode
                 .borrow_mut()
                 .push_instruction(Instruction::Else(zinc_bytecode::Else), Some(self.location));
@@ -92,19 +106,19 @@ impl Expression {
 
         if let Some(binding_branch) = binding_branch {
             binding_branch.write_all_to_bytecode(bytecode.clone());
-        } else if let Some(wildcard_branch) = self.wildcard_branch {
+        } else if let Some((wildcard_bindings, wildcard_branch)) = self.wildcard_branch {
+            for binding in wildcard_bindings.into_iter() {
+                binding.write_all_to_bytecode(bytecode.clone(), scrutinee_address, self.location);
+            }
             wildcard_branch.write_all_to_bytecode(bytecode.clone());
         }
 
-        bytecode.borrow_mut().push_instruction(
-            Instruction::EndIf(zinc_bytecode::EndIf),
-            Some(self.location),
-        );
-        for _ in 0..branch_count - 1 {
+        for _ in 0..branch_count {
             bytecode.borrow_mut().push_instruction(
                 Instruction::EndIf(zinc_bytecode::EndIf),
                 Some(self.location),
             );
         }
+        bytecode.borrow_mut().pop_variable_scope();
     }
 }
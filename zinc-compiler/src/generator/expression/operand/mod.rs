@@ -10,6 +10,7 @@ pub mod group;
 pub mod list;
 pub mod r#match;
 pub mod place;
+pub mod variant;
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -26,6 +27,7 @@ use self::group::Expression as GroupExpression;
 use self::list::Expression as ListExpression;
 use self::place::Place;
 use self::r#match::Expression as MatchExpression;
+use self::variant::Expression as VariantExpression;
 
 ///
 /// The expression operand which is translated to Zinc VM data.
@@ -40,6 +42,7 @@ pub enum Operand {
     Block(BlockExpression),
     Conditional(ConditionalExpression),
     Match(MatchExpression),
+    Variant(VariantExpression),
 }
 
 impl Operand {
@@ -83,6 +86,7 @@ impl Operand {
             Self::Block(inner) => inner.write_all_to_bytecode(bytecode),
             Self::Conditional(inner) => inner.write_all_to_bytecode(bytecode),
             Self::Match(inner) => inner.write_all_to_bytecode(bytecode),
+            Self::Variant(inner) => inner.write_all_to_bytecode(bytecode),
         }
     }
 }
@@ -0,0 +1,94 @@
+//!
+//! The generator expression enumeration variant operand.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use num_bigint::BigInt;
+use num_traits::Zero;
+
+use zinc_bytecode::scalar::ScalarType;
+use zinc_bytecode::Instruction;
+
+use crate::generator::bytecode::Bytecode;
+use crate::generator::expression::operand::constant::Constant;
+use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::r#type::Type;
+use crate::lexical::token::location::Location;
+
+///
+/// The algebraic enumeration variant expression, which is translated to the tag followed by
+/// the union slots.
+///
+/// The payload is converted to field elements and padded with zeros up to the `union_size`,
+/// so the values of every variant have the same runtime layout.
+///
+#[derive(Debug, Clone)]
+pub struct Expression {
+    location: Location,
+    tag: Constant,
+    payload: Vec<GeneratorExpression>,
+    payload_size: usize,
+    union_size: usize,
+}
+
+impl Expression {
+    pub fn new(
+        location: Location,
+        tag: Constant,
+        payload: Vec<GeneratorExpression>,
+        payload_size: usize,
+        union_size: usize,
+    ) -> Self {
+        Self {
+            location,
+            tag,
+            payload,
+            payload_size,
+            union_size,
+        }
+    }
+
+    pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
+        self.tag.write_all_to_bytecode(bytecode.clone());
+
+        if self.payload_size > 0 {
+            let payload_address = bytecode
+                .borrow_mut()
+                .declare_variable(None, Type::array(Type::field(), self.payload_size));
+
+            for expression in self.payload.into_iter() {
+                expression.write_all_to_bytecode(bytecode.clone());
+            }
+            bytecode.borrow_mut().push_instruction(
+                Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(
+                    payload_address,
+                    self.payload_size,
+                )),
+                Some(self.location),
+            );
+
+            for index in 0..self.payload_size {
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_bytecode::Load::new(payload_address + index)),
+                    Some(self.location),
+                );
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::Cast(zinc_bytecode::Cast::new(ScalarType::Field)),
+                    Some(self.location),
+                );
+            }
+        }
+
+        for _ in self.payload_size..self.union_size {
+            bytecode.borrow_mut().push_instruction(
+                Instruction::PushConst(zinc_bytecode::PushConst::new(
+                    BigInt::zero(),
+                    ScalarType::Field,
+                )),
+                Some(self.location),
+            );
+        }
+    }
+}
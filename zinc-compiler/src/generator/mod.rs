@@ -2,6 +2,7 @@
 //! The intermediate representation for Zinc VM bytecode generating.
 //!
 
+pub mod binding;
 pub mod bytecode;
 pub mod expression;
 pub mod statement;
@@ -13,7 +14,7 @@ use std::rc::Rc;
 use self::bytecode::Bytecode;
 use self::statement::Statement;
 
-#[derive(Default)]
+#[derive(Debug, Default)]
 pub struct Tree {
     pub statements: Vec<Statement>,
 }
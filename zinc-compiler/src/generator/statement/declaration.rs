@@ -41,12 +41,14 @@ impl Statement {
 
     pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
         let size = self.r#type.size();
+
+        // the expression is written first, since it may refer to the variable being shadowed
+        self.expression.write_all_to_bytecode(bytecode.clone());
+
         let address = bytecode
             .borrow_mut()
             .declare_variable(Some(self.name), self.r#type.clone());
 
-        self.expression.write_all_to_bytecode(bytecode.clone());
-
         if let Some(scalar_type) = self.r#type.into() {
             bytecode.borrow_mut().push_instruction(
                 Instruction::Cast(zinc_bytecode::Cast::new(scalar_type)),
@@ -0,0 +1,57 @@
+//!
+//! The generator destructuring statement.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_bytecode::Instruction;
+
+use crate::generator::binding::Binding;
+use crate::generator::bytecode::Bytecode;
+use crate::generator::expression::Expression;
+use crate::generator::r#type::Type;
+use crate::lexical::token::location::Location;
+
+///
+/// The Zinc VM statement, which stores a value in an anonymous variable and copies its parts
+/// to the pattern bindings.
+///
+#[derive(Debug, Clone)]
+pub struct Statement {
+    pub location: Location,
+    pub r#type: Type,
+    pub expression: Expression,
+    pub bindings: Vec<Binding>,
+}
+
+impl Statement {
+    pub fn new(
+        location: Location,
+        r#type: Type,
+        expression: Expression,
+        bindings: Vec<Binding>,
+    ) -> Self {
+        Self {
+            location,
+            r#type,
+            expression,
+            bindings,
+        }
+    }
+
+    pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
+        let size = self.r#type.size();
+        let address = bytecode.borrow_mut().declare_variable(None, self.r#type);
+
+        self.expression.write_all_to_bytecode(bytecode.clone());
+        bytecode.borrow_mut().push_instruction(
+            Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(address, size)),
+            Some(self.location),
+        );
+
+        for binding in self.bindings.into_iter() {
+            binding.write_all_to_bytecode(bytecode.clone(), address, self.location);
+        }
+    }
+}
@@ -3,12 +3,15 @@
 //!
 
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::rc::Rc;
 
 use zinc_bytecode::Instruction;
 
+use crate::generator::binding::Binding;
 use crate::generator::bytecode::Bytecode;
 use crate::generator::expression::operand::block::Expression;
+use crate::generator::expression::operand::constant::Constant;
 use crate::generator::r#type::Type;
 use crate::lexical::token::location::Location;
 use crate::semantic::element::r#type::Type as SemanticType;
@@ -16,15 +19,22 @@ use crate::semantic::element::r#type::Type as SemanticType;
 ///
 /// The Zinc VM function statement.
 ///
+/// The destructured arguments are copied to their `argument_bindings` before the body.
+///
+/// If the body contains `return` statements, the returned value is stored in an anonymous result
+/// variable, which is assigned the body result only if no `return` has been executed.
+///
 #[derive(Debug, Clone)]
 pub struct Statement {
     pub location: Location,
     pub identifier: String,
     pub input_arguments: Vec<(String, Type)>,
+    pub argument_bindings: Vec<(String, Vec<Binding>)>,
     pub body: Expression,
     pub output_type: Option<Type>,
     pub unique_id: usize,
     pub is_main: bool,
+    pub has_returns: bool,
 }
 
 impl Statement {
@@ -32,10 +42,12 @@ impl Statement {
         location: Location,
         identifier: String,
         input_arguments: Vec<(String, SemanticType)>,
+        argument_bindings: Vec<(String, Vec<Binding>)>,
         body: Expression,
         output_type: SemanticType,
         unique_id: usize,
         is_main: bool,
+        has_returns: bool,
     ) -> Self {
         let input_arguments = input_arguments
             .into_iter()
@@ -51,19 +63,18 @@ impl Statement {
             location,
             identifier,
             input_arguments,
+            argument_bindings,
             body,
             output_type,
             unique_id,
             is_main,
+            has_returns,
         }
     }
 
     pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
-        let output_size = self
-            .output_type
-            .as_ref()
-            .map(|r#type| r#type.size())
-            .unwrap_or(0);
+        let output_type = self.output_type.clone().unwrap_or_else(Type::unit);
+        let output_size = output_type.size();
 
         if self.is_main {
             bytecode.borrow_mut().start_main_function(
@@ -77,13 +88,86 @@ impl Statement {
                 .start_function(self.unique_id, self.identifier);
         }
 
+        let mut argument_addresses = HashMap::with_capacity(self.input_arguments.len());
         for (argument_name, argument_type) in self.input_arguments.into_iter() {
-            bytecode
+            let address = bytecode
                 .borrow_mut()
-                .declare_variable(Some(argument_name), argument_type);
+                .declare_variable(Some(argument_name.clone()), argument_type);
+            argument_addresses.insert(argument_name, address);
+        }
+        for (argument_name, bindings) in self.argument_bindings.into_iter() {
+            let address = match argument_addresses.get(&argument_name) {
+                Some(address) => *address,
+                None => continue,
+            };
+            for binding in bindings.into_iter() {
+                binding.write_all_to_bytecode(bytecode.clone(), address, self.location);
+            }
         }
 
-        self.body.write_all_to_bytecode(bytecode.clone());
+        if self.has_returns {
+            let active_address = bytecode
+                .borrow_mut()
+                .declare_variable(None, Type::boolean());
+            Constant::new_boolean(true).write_all_to_bytecode(bytecode.clone());
+            bytecode.borrow_mut().push_instruction(
+                Instruction::Store(zinc_bytecode::Store::new(active_address)),
+                Some(self.location),
+            );
+            let result_address = bytecode
+                .borrow_mut()
+                .declare_variable(None, output_type.clone());
+            let body_result_address = bytecode.borrow_mut().declare_variable(None, output_type);
+            bytecode
+                .borrow_mut()
+                .set_function_flags(active_address, result_address, output_size);
+
+            self.body.write_all_to_bytecode(bytecode.clone());
+
+            if output_size > 0 {
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(
+                        body_result_address,
+                        output_size,
+                    )),
+                    Some(self.location),
+                );
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_bytecode::Load::new(active_address)),
+                    Some(self.location),
+                );
+                bytecode
+                    .borrow_mut()
+                    .push_instruction(Instruction::If(zinc_bytecode::If), Some(self.location));
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(
+                        body_result_address,
+                        output_size,
+                    )),
+                    Some(self.location),
+                );
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(
+                        result_address,
+                        output_size,
+                    )),
+                    Some(self.location),
+                );
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::EndIf(zinc_bytecode::EndIf),
+                    Some(self.location),
+                );
+                bytecode.borrow_mut().push_instruction(
+                    Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(
+                        result_address,
+                        output_size,
+                    )),
+                    Some(self.location),
+                );
+            }
+        } else {
+            self.body.write_all_to_bytecode(bytecode.clone());
+        }
 
         bytecode.borrow_mut().push_instruction(
             Instruction::Return(zinc_bytecode::Return::new(output_size)),
@@ -0,0 +1,70 @@
+//!
+//! The generator function return statement.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_bytecode::Instruction;
+
+use crate::generator::bytecode::Bytecode;
+use crate::generator::expression::operand::constant::Constant;
+use crate::generator::expression::Expression;
+use crate::lexical::token::location::Location;
+
+///
+/// The Zinc VM early return statement.
+///
+/// Since the function body is always executed completely, the statement stores the returned
+/// value in the function result variable and clears the function and enclosing loops flags,
+/// which mask the rest of the body via the conditions stack.
+///
+#[derive(Debug, Clone)]
+pub struct Statement {
+    pub location: Location,
+    pub expression: Option<Expression>,
+}
+
+impl Statement {
+    pub fn new(location: Location, expression: Option<Expression>) -> Self {
+        Self {
+            location,
+            expression,
+        }
+    }
+
+    pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
+        let (active_address, result_address, result_size) = bytecode
+            .borrow()
+            .get_function_flags()
+            .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        if let Some(expression) = self.expression {
+            expression.write_all_to_bytecode(bytecode.clone());
+        }
+        if result_size > 0 {
+            bytecode.borrow_mut().push_instruction(
+                Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(
+                    result_address,
+                    result_size,
+                )),
+                Some(self.location),
+            );
+        }
+
+        let mut addresses = Vec::new();
+        for (allowed_address, active_address) in bytecode.borrow().get_all_loop_flags() {
+            addresses.push(allowed_address);
+            addresses.push(active_address);
+        }
+        addresses.push(active_address);
+
+        for address in addresses.into_iter() {
+            Constant::new_boolean(false).write_all_to_bytecode(bytecode.clone());
+            bytecode.borrow_mut().push_instruction(
+                Instruction::Store(zinc_bytecode::Store::new(address)),
+                Some(self.location),
+            );
+        }
+    }
+}
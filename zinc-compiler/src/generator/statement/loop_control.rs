@@ -0,0 +1,46 @@
+//!
+//! The generator loop control statement.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_bytecode::Instruction;
+
+use crate::generator::bytecode::Bytecode;
+use crate::generator::expression::operand::constant::Constant;
+use crate::lexical::token::location::Location;
+
+///
+/// The Zinc VM loop control statement.
+///
+/// Since loops are always unrolled to their static bounds, the statements do not jump anywhere,
+/// but clear the loop flags, which mask the rest of the loop body via the conditions stack.
+///
+#[derive(Debug, Clone)]
+pub enum Statement {
+    Break(Location),
+    Continue(Location),
+}
+
+impl Statement {
+    pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
+        let (allowed_address, active_address) = bytecode
+            .borrow()
+            .get_loop_flags()
+            .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        let (location, addresses) = match self {
+            Self::Break(location) => (location, vec![allowed_address, active_address]),
+            Self::Continue(location) => (location, vec![active_address]),
+        };
+
+        for address in addresses.into_iter() {
+            Constant::new_boolean(false).write_all_to_bytecode(bytecode.clone());
+            bytecode.borrow_mut().push_instruction(
+                Instruction::Store(zinc_bytecode::Store::new(address)),
+                Some(location),
+            );
+        }
+    }
+}
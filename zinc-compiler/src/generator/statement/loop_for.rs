@@ -30,6 +30,7 @@ pub struct Statement {
     pub index_variable_is_signed: bool,
     pub index_variable_bitlength: usize,
     pub while_condition: Option<GeneratorExpression>,
+    pub has_loop_controls: bool,
     pub body: BlockExpression,
 }
 
@@ -43,6 +44,7 @@ impl Statement {
         index_variable_is_signed: bool,
         index_variable_bitlength: usize,
         while_condition: Option<GeneratorExpression>,
+        has_loop_controls: bool,
         body: BlockExpression,
     ) -> Self {
         Self {
@@ -54,11 +56,14 @@ impl Statement {
             index_variable_is_signed,
             index_variable_bitlength,
             while_condition,
+            has_loop_controls,
             body,
         }
     }
 
     pub fn write_all_to_bytecode(self, bytecode: Rc<RefCell<Bytecode>>) {
+        bytecode.borrow_mut().push_variable_scope();
+
         let index_type =
             Type::integer(self.index_variable_is_signed, self.index_variable_bitlength);
         let index_size = index_type.size();
@@ -79,17 +84,26 @@ impl Statement {
             Some(self.location),
         );
 
-        let while_allowed_address = if self.while_condition.is_some() {
-            let while_allowed = Constant::new_boolean(true);
-            let while_allowed_address = bytecode
+        let allowed_address = if self.while_condition.is_some() || self.has_loop_controls {
+            let allowed = Constant::new_boolean(true);
+            let allowed_address = bytecode
                 .borrow_mut()
-                .declare_variable(None, while_allowed.r#type());
-            while_allowed.write_all_to_bytecode(bytecode.clone());
+                .declare_variable(None, allowed.r#type());
+            allowed.write_all_to_bytecode(bytecode.clone());
             bytecode.borrow_mut().push_instruction(
-                Instruction::Store(zinc_bytecode::Store::new(while_allowed_address)),
+                Instruction::Store(zinc_bytecode::Store::new(allowed_address)),
                 Some(self.location),
             );
-            Some(while_allowed_address)
+            Some(allowed_address)
+        } else {
+            None
+        };
+        let active_address = if self.has_loop_controls {
+            Some(
+                bytecode
+                    .borrow_mut()
+                    .declare_variable(None, Type::boolean()),
+            )
         } else {
             None
         };
@@ -99,8 +113,8 @@ impl Statement {
             Some(self.location),
         );
 
-        if let (Some(while_condition), Some(while_allowed_address)) =
-            (self.while_condition, while_allowed_address)
+        if let (Some(while_condition), Some(allowed_address)) =
+            (self.while_condition, allowed_address)
         {
             while_condition.write_all_to_bytecode(bytecode.clone());
             bytecode
@@ -112,7 +126,7 @@ impl Statement {
             Constant::new_boolean(false).write_all_to_bytecode(bytecode.clone());
             bytecode.borrow_mut().push_instruction(
                 Instruction::StoreSequence(zinc_bytecode::StoreSequence::new(
-                    while_allowed_address,
+                    allowed_address,
                     Type::boolean().size(),
                 )),
                 Some(self.location),
@@ -121,10 +135,26 @@ impl Statement {
                 Instruction::EndIf(zinc_bytecode::EndIf),
                 Some(self.location),
             );
+        }
 
+        if let (Some(allowed_address), Some(active_address)) = (allowed_address, active_address) {
+            bytecode.borrow_mut().push_instruction(
+                Instruction::Load(zinc_bytecode::Load::new(allowed_address)),
+                Some(self.location),
+            );
+            bytecode.borrow_mut().push_instruction(
+                Instruction::Store(zinc_bytecode::Store::new(active_address)),
+                Some(self.location),
+            );
+            bytecode
+                .borrow_mut()
+                .push_loop_flags(allowed_address, active_address);
+        }
+
+        if let Some(allowed_address) = allowed_address {
             bytecode.borrow_mut().push_instruction(
                 Instruction::LoadSequence(zinc_bytecode::LoadSequence::new(
-                    while_allowed_address,
+                    allowed_address,
                     Type::boolean().size(),
                 )),
                 Some(self.location),
@@ -141,6 +171,10 @@ impl Statement {
             self.body.write_all_to_bytecode(bytecode.clone());
         }
 
+        if active_address.is_some() {
+            bytecode.borrow_mut().pop_loop_flags();
+        }
+
         if self.is_reversed {
             bytecode.borrow_mut().push_instruction(
                 Instruction::Load(zinc_bytecode::Load::new(index_address)),
@@ -214,5 +248,7 @@ impl Statement {
             Instruction::LoopEnd(zinc_bytecode::LoopEnd),
             Some(self.location),
         );
+
+        bytecode.borrow_mut().pop_variable_scope();
     }
 }
@@ -3,7 +3,10 @@
 //!
 
 pub mod declaration;
+pub mod destructuring;
 pub mod function;
+pub mod function_return;
+pub mod loop_control;
 pub mod loop_for;
 
 use std::cell::RefCell;
@@ -12,7 +15,10 @@ use std::rc::Rc;
 use crate::generator::bytecode::Bytecode;
 use crate::generator::expression::Expression;
 use crate::generator::statement::declaration::Statement as DeclarationStatement;
+use crate::generator::statement::destructuring::Statement as DestructuringStatement;
 use crate::generator::statement::function::Statement as FunctionStatement;
+use crate::generator::statement::function_return::Statement as FunctionReturnStatement;
+use crate::generator::statement::loop_control::Statement as LoopControlStatement;
 use crate::generator::statement::loop_for::Statement as ForLoopStatement;
 
 ///
@@ -22,8 +28,11 @@ use crate::generator::statement::loop_for::Statement as ForLoopStatement;
 pub enum Statement {
     Expression(Expression),
     Declaration(DeclarationStatement),
+    Destructuring(DestructuringStatement),
     Loop(ForLoopStatement),
+    LoopControl(LoopControlStatement),
     Function(FunctionStatement),
+    Return(FunctionReturnStatement),
     Implementation(Vec<Self>),
 }
 
@@ -32,8 +41,11 @@ impl Statement {
         match self {
             Self::Expression(inner) => inner.write_all_to_bytecode(bytecode),
             Self::Declaration(inner) => inner.write_all_to_bytecode(bytecode),
+            Self::Destructuring(inner) => inner.write_all_to_bytecode(bytecode),
             Self::Loop(inner) => inner.write_all_to_bytecode(bytecode),
+            Self::LoopControl(inner) => inner.write_all_to_bytecode(bytecode),
             Self::Function(inner) => inner.write_all_to_bytecode(bytecode),
+            Self::Return(inner) => inner.write_all_to_bytecode(bytecode),
             Self::Implementation(inner) => {
                 for element in inner.into_iter() {
                     element.write_all_to_bytecode(bytecode.clone());
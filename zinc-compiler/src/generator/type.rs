@@ -2,7 +2,10 @@
 //! The generator type.
 //!
 
+use num_bigint::BigInt;
+
 use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::types::EnumVariant;
 use zinc_bytecode::scalar::IntegerType;
 use zinc_bytecode::scalar::ScalarType;
 
@@ -21,8 +24,24 @@ pub enum Type {
     Array { r#type: Box<Self>, size: usize },
     Tuple { types: Vec<Self> },
     Structure { fields: Vec<(String, Self)> },
+    Enumeration(Enumeration),
 }
 
+///
+/// The algebraic enumeration type, which is the tag followed by the union of the variant
+/// payloads.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Enumeration {
+    pub bitlength: usize,
+    pub variants: Vec<EnumerationVariant>,
+}
+
+///
+/// The enumeration variant name, tag value, and payload fields.
+///
+pub type EnumerationVariant = (String, BigInt, Vec<(String, Type)>);
+
 impl Type {
     pub fn unit() -> Self {
         Self::Unit
@@ -67,6 +86,13 @@ impl Type {
         Self::Structure { fields }
     }
 
+    pub fn enumeration(bitlength: usize, variants: Vec<EnumerationVariant>) -> Self {
+        Self::Enumeration(Enumeration {
+            bitlength,
+            variants,
+        })
+    }
+
     pub fn size(&self) -> usize {
         match self {
             Self::Unit => 0,
@@ -77,6 +103,55 @@ impl Type {
             Self::Array { r#type, size } => r#type.size() * size,
             Self::Tuple { types } => types.iter().map(|r#type| r#type.size()).sum(),
             Self::Structure { fields } => fields.iter().map(|(_name, r#type)| r#type.size()).sum(),
+            Self::Enumeration(enumeration) => {
+                1 + enumeration
+                    .variants
+                    .iter()
+                    .map(|(_name, _value, fields)| {
+                        fields
+                            .iter()
+                            .map(|(_name, r#type)| r#type.size())
+                            .sum::<usize>()
+                    })
+                    .max()
+                    .unwrap_or_default()
+            }
+        }
+    }
+
+    ///
+    /// Flattens the type into the scalar types of its runtime cells.
+    ///
+    /// The enumeration union slots are always stored as field elements.
+    ///
+    pub fn scalar_types(&self) -> Vec<ScalarType> {
+        match self {
+            Self::Unit => vec![],
+            Self::Array { r#type, size } => {
+                let element_types = r#type.scalar_types();
+                let mut types = Vec::with_capacity(element_types.len() * size);
+                for _ in 0..*size {
+                    types.extend(element_types.iter().cloned());
+                }
+                types
+            }
+            Self::Tuple { types } => types.iter().flat_map(Self::scalar_types).collect(),
+            Self::Structure { fields } => fields
+                .iter()
+                .flat_map(|(_name, r#type)| r#type.scalar_types())
+                .collect(),
+            Self::Enumeration(enumeration) => {
+                let mut types = vec![ScalarType::Integer(IntegerType {
+                    is_signed: false,
+                    bitlength: enumeration.bitlength,
+                })];
+                types.extend(vec![ScalarType::Field; self.size() - 1]);
+                types
+            }
+            scalar => {
+                let scalar_type: Option<ScalarType> = scalar.to_owned().into();
+                vec![scalar_type.expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS)]
+            }
         }
     }
 
@@ -113,6 +188,29 @@ impl Type {
                     _ => None,
                 }
             }
+            SemanticType::Enumeration(enumeration) if enumeration.is_algebraic() => {
+                Some(Self::enumeration(
+                    enumeration.bitlength,
+                    enumeration
+                        .variants
+                        .iter()
+                        .map(|variant| {
+                            (
+                                variant.name.to_owned(),
+                                variant.value.to_owned(),
+                                variant
+                                    .fields
+                                    .iter()
+                                    .filter_map(|(name, r#type)| {
+                                        Self::try_from_semantic(r#type)
+                                            .map(|r#type| (name.to_owned(), r#type))
+                                    })
+                                    .collect(),
+                            )
+                        })
+                        .collect(),
+                ))
+            }
             SemanticType::Enumeration(enumeration) => {
                 Some(Self::integer_unsigned(enumeration.bitlength))
             }
@@ -152,6 +250,21 @@ impl Into<DataType> for Type {
                     .map(|(name, r#type)| (name, r#type.into()))
                     .collect(),
             ),
+            Self::Enumeration(enumeration) => DataType::Enum {
+                bitlength: enumeration.bitlength,
+                variants: enumeration
+                    .variants
+                    .into_iter()
+                    .map(|(name, value, fields)| EnumVariant {
+                        name,
+                        value,
+                        fields: fields
+                            .into_iter()
+                            .map(|(name, r#type)| (name, r#type.into()))
+                            .collect(),
+                    })
+                    .collect(),
+            },
         }
     }
 }
@@ -8,6 +8,7 @@ pub mod string;
 pub mod symbol;
 pub mod word;
 
+use std::collections::HashMap;
 use std::collections::VecDeque;
 
 use crate::lexical::error::Error;
@@ -28,6 +29,13 @@ pub struct TokenStream<'a> {
     offset: usize,
     location: Location,
     look_ahead: VecDeque<Token>,
+    is_comment_preserved: bool,
+    /// The `///` doc comment lines, which are not attached to a token yet.
+    doc: Vec<String>,
+    /// The `///` doc comments attached to the locations of the tokens written after them.
+    docs: HashMap<Location, String>,
+    /// The `//!` doc comment lines of the module.
+    module_doc: Vec<String>,
 }
 
 impl<'a> TokenStream<'a> {
@@ -43,6 +51,22 @@ impl<'a> TokenStream<'a> {
             offset: 0,
             location: Location::new_beginning(None),
             look_ahead: VecDeque::with_capacity(Self::DEQUE_LOOK_AHEAD_INITIAL_CAPACITY),
+            is_comment_preserved: false,
+            doc: Vec::new(),
+            docs: HashMap::new(),
+            module_doc: Vec::new(),
+        }
+    }
+
+    ///
+    /// Initializes a stream without a file identifier, which yields the comments as tokens
+    /// instead of skipping them.
+    /// Used by the formatter, which must not lose the comments.
+    ///
+    pub fn new_with_comments(input: &'a str) -> Self {
+        Self {
+            is_comment_preserved: true,
+            ..Self::new(input)
         }
     }
 
@@ -56,9 +80,21 @@ impl<'a> TokenStream<'a> {
             offset: 0,
             location: Location::new_beginning(Some(file)),
             look_ahead: VecDeque::with_capacity(Self::DEQUE_LOOK_AHEAD_INITIAL_CAPACITY),
+            is_comment_preserved: false,
+            doc: Vec::new(),
+            docs: HashMap::new(),
+            module_doc: Vec::new(),
         }
     }
 
+    ///
+    /// Returns the input offset the stream has advanced to, which is right after the last
+    /// token taken from the input.
+    ///
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
     ///
     /// Picks a character from the look-ahead queue.
     /// If the queue is empty, advances the stream iterator.
@@ -87,10 +123,46 @@ impl<'a> TokenStream<'a> {
             .ok_or_else(|| Error::unexpected_end(self.location))
     }
 
+    ///
+    /// Takes the `///` doc comment written right before the token at `location`.
+    ///
+    pub fn take_doc(&mut self, location: Location) -> Option<String> {
+        self.docs.remove(&location)
+    }
+
+    ///
+    /// Takes the `//!` doc comment of the module, which has been read so far.
+    ///
+    pub fn take_module_doc(&mut self) -> Option<String> {
+        if self.module_doc.is_empty() {
+            None
+        } else {
+            Some(
+                self.module_doc
+                    .drain(..)
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            )
+        }
+    }
+
+    ///
+    /// Reads the next token and attaches the doc comment written before it, if any.
+    ///
+    fn advance(&mut self) -> Result<Token, Error> {
+        let token = self.read()?;
+        if !self.doc.is_empty() {
+            let doc = self.doc.drain(..).collect::<Vec<String>>().join("\n");
+            self.docs.insert(token.location, doc);
+        }
+        Ok(token)
+    }
+
     ///
     /// The function checks if a character:
     /// 1. Is a whitespace -> skip
-    /// 2. Starts a comment -> start the comment subparser
+    /// 2. Starts a comment -> start the comment subparser, skip the comment unless preserved,
+    ///    keeping the doc comment text
     /// 3. Starts a string literal -> start the string subparser
     /// 4. Starts a number -> start the number subparser
     /// 5. Starts a word -> start the word subparser
@@ -99,7 +171,7 @@ impl<'a> TokenStream<'a> {
     ///
     /// If the end of input has been reached, an 'EOF' token is returned for consequent calls.
     ///
-    fn advance(&mut self) -> Result<Token, Error> {
+    fn read(&mut self) -> Result<Token, Error> {
         while let Some(character) = self.input.chars().nth(self.offset) {
             if character.is_ascii_whitespace() {
                 if character == '\n' {
@@ -114,10 +186,26 @@ impl<'a> TokenStream<'a> {
 
             if character == '/' {
                 match self::comment::parse(&self.input[self.offset..]) {
-                    Ok((size, lines, column, _comment)) => {
-                        self.location.line += lines;
-                        self.location.column = column;
+                    Ok((size, lines, column, comment)) => {
+                        let location = self.location;
+                        if lines == 0 {
+                            self.location.column += size;
+                        } else if self.input[self.offset..].starts_with("//") {
+                            self.location.line += lines;
+                            self.location.column = 1;
+                        } else {
+                            self.location.line += lines;
+                            self.location.column = column;
+                        }
                         self.offset += size;
+                        if self.is_comment_preserved {
+                            return Ok(Token::new(Lexeme::Comment(comment), location));
+                        }
+                        if let Some(doc) = comment.outer_doc() {
+                            self.doc.push(doc.to_owned());
+                        } else if let Some(doc) = comment.inner_doc() {
+                            self.module_doc.push(doc.to_owned());
+                        }
                         continue;
                     }
                     Err(CommentParserError::NotAComment) => {}
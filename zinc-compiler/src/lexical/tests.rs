@@ -138,6 +138,49 @@ fn error_expected_one_of_octal() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_doc_comments() {
+    let input = r#"//! The module.
+//!
+//! Has two items.
+
+/// The first line.
+///   The indented line.
+// Not a doc comment.
+const A: u8 = 1;
+
+//// Not a doc comment either.
+/// The function.
+fn main() {}
+"#;
+
+    let mut stream = TokenStream::new(input);
+    loop {
+        match stream.next() {
+            Ok(Token {
+                lexeme: Lexeme::Eof,
+                ..
+            }) => break,
+            Ok(_) => continue,
+            Err(error) => panic!("An unexpected lexical error: {:?}", error),
+        }
+    }
+
+    assert_eq!(
+        stream.take_doc(Location::new(8, 1)),
+        Some("The first line.\n  The indented line.".to_owned())
+    );
+    assert_eq!(
+        stream.take_doc(Location::new(12, 1)),
+        Some("The function.".to_owned())
+    );
+    assert_eq!(stream.take_doc(Location::new(8, 7)), None);
+    assert_eq!(
+        stream.take_module_doc(),
+        Some("The module.\n\nHas two items.".to_owned())
+    );
+}
+
 #[test]
 fn error_expected_one_of_decimal() {
     let input = "42x";
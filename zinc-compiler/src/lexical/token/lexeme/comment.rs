@@ -13,6 +13,40 @@ impl Comment {
     pub fn new(inner: String) -> Self {
         Self { inner }
     }
+
+    ///
+    /// Returns the text of the `///` doc comment, which documents the item written after it.
+    ///
+    pub fn outer_doc(&self) -> Option<&str> {
+        if self.inner.starts_with('/') && !self.inner.starts_with("//") {
+            Some(Self::doc_text(&self.inner[1..]))
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Returns the text of the `//!` doc comment, which documents the module it is written in.
+    ///
+    pub fn inner_doc(&self) -> Option<&str> {
+        if self.inner.starts_with('!') {
+            Some(Self::doc_text(&self.inner[1..]))
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Strips the space separating the doc comment text from its opening slashes.
+    ///
+    fn doc_text(text: &str) -> &str {
+        let text = text.trim_end_matches('\r');
+        if text.starts_with(' ') {
+            &text[1..]
+        } else {
+            text
+        }
+    }
 }
 
 impl fmt::Display for Comment {
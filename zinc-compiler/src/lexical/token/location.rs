@@ -3,8 +3,9 @@
 //!
 
 use std::fmt;
+use std::path::PathBuf;
 
-#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Location {
     pub file_index: Option<usize>,
     pub line: usize,
@@ -58,6 +59,20 @@ impl Location {
             column: self.column + columns,
         }
     }
+
+    ///
+    /// Returns the path of the file the location belongs to, if the file has been registered
+    /// in the global file index.
+    ///
+    pub fn file_path(&self) -> Option<PathBuf> {
+        self.file_index.and_then(|file_index| {
+            crate::file::INDEX
+                .read()
+                .expect(crate::PANIC_MUTEX_SYNC)
+                .get(file_index)
+                .cloned()
+        })
+    }
 }
 
 impl fmt::Display for Location {
@@ -7,21 +7,42 @@
 #![allow(clippy::should_implement_trait)]
 #![allow(clippy::too_many_arguments)]
 
+pub(crate) mod diagnostic;
+pub(crate) mod doc;
+pub(crate) mod emit;
 pub(crate) mod error;
 pub(crate) mod file;
+pub(crate) mod formatter;
 pub(crate) mod generator;
 pub(crate) mod lexical;
 pub(crate) mod semantic;
 pub(crate) mod syntax;
+pub(crate) mod warning;
 
+pub use self::diagnostic::message_format::MessageFormat;
+pub use self::diagnostic::Diagnostic;
+pub use self::diagnostic::Severity;
+pub use self::doc::format::Format as DocumentationFormat;
+pub use self::doc::Documentation;
+pub use self::emit::assembly::listing as assembly_listing;
+pub use self::emit::Emit;
 pub use self::error::Error;
+pub use self::file::graph::Graph;
 pub use self::file::File;
 pub use self::generator::bytecode::Bytecode;
+pub use self::lexical::token::location::Location;
 pub use self::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 pub use self::semantic::analyzer::module::Analyzer as ModuleAnalyzer;
 pub use self::semantic::scope::Scope;
+pub use self::semantic::symbol::Completion;
+pub use self::semantic::symbol::Kind as SymbolKind;
+pub use self::semantic::symbol::Symbol;
+pub use self::semantic::symbol::Table as SymbolTable;
 pub use self::syntax::parser::Parser;
 pub use self::syntax::tree::Tree;
+pub use self::warning::lint::Level as LintLevel;
+pub use self::warning::lint::Lints;
+pub use self::warning::lint::GROUP_WARNINGS as LINT_GROUP_WARNINGS;
 
 pub const BASE_BINARY: usize = 2;
 pub const BASE_OCTAL: usize = 8;
@@ -44,6 +65,8 @@ pub static PANIC_VALIDATED_DURING_LEXICAL_ANALYSIS: &str = "Validated during lex
 pub static PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS: &str = "Validated during syntax analysis";
 pub static PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS: &str = "Validated during semantic analysis";
 pub static PANIC_LAST_SHARED_REFERENCE: &str = "There are no other references at this point";
+pub static PANIC_GLOBAL_SCOPE_INSTANCES: &str = "The global scope always has the instances";
 pub static PANIC_MUTEX_SYNC: &str = "Mutexes never panic";
 pub static PANIC_FILE_INDEX: &str = "File record always exists";
+pub static PANIC_FORMATTER_TOP_LEVEL: &str = "The top level group is never removed";
 pub static PANIC_BUILDER_REQUIRES_VALUE: &str = "The builder requires a value: ";
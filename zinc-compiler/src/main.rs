@@ -3,9 +3,12 @@
 //!
 
 use std::cell::RefCell;
-use std::collections::{HashMap, VecDeque};
+use std::collections::HashMap;
+use std::collections::HashSet;
 use std::convert::TryFrom;
+use std::env;
 use std::ffi::OsString;
+use std::fs;
 use std::fs::File;
 use std::io::Write;
 use std::path::PathBuf;
@@ -17,10 +20,22 @@ use log::debug;
 use structopt::StructOpt;
 
 use zinc_compiler::Bytecode;
+use zinc_compiler::Diagnostic;
+use zinc_compiler::Documentation;
+use zinc_compiler::DocumentationFormat;
+use zinc_compiler::Emit;
 use zinc_compiler::File as ZincFile;
+use zinc_compiler::Graph;
+use zinc_compiler::LintLevel;
+use zinc_compiler::Lints;
+use zinc_compiler::MessageFormat;
 use zinc_compiler::Scope;
+use zinc_compiler::SymbolTable;
+use zinc_compiler::LINT_GROUP_WARNINGS;
 
 static ZINC_SOURCE_FILE_EXTENSION: &str = "zn";
+static FORMAT_SUBCOMMAND: &str = "fmt";
+static DOC_SUBCOMMAND: &str = "doc";
 
 const EXIT_CODE_SUCCESS: i32 = 0;
 const EXIT_CODE_FAILURE: i32 = 1;
@@ -53,6 +68,112 @@ struct Arguments {
     help = "The *.znb bytecode output path"
     )]
     bytecode_output_path: PathBuf,
+    #[structopt(
+    long = "dependency",
+    number_of_values = 1,
+    help = "The dependency package as `[<dependent>/]<name>=<path to lib.zn>`, use multiple times for more packages"
+    )]
+    dependencies: Vec<String>,
+    #[structopt(
+    long = "allow",
+    number_of_values = 1,
+    help = "Allows the lint, or all the lints if set to `warnings`"
+    )]
+    allow: Vec<String>,
+    #[structopt(
+    long = "warn",
+    number_of_values = 1,
+    help = "Reports the lint as a warning, or all the lints if set to `warnings`"
+    )]
+    warn: Vec<String>,
+    #[structopt(
+    long = "deny",
+    number_of_values = 1,
+    help = "Reports the lint as an error, or all the lints if set to `warnings`"
+    )]
+    deny: Vec<String>,
+    #[structopt(
+    long = "message-format",
+    default_value = "human",
+    help = "The diagnostics format, either `human` or `json`"
+    )]
+    message_format: MessageFormat,
+    #[structopt(
+    long = "emit",
+    use_delimiter = true,
+    help = "Dumps the compiler output next to the bytecode file, comma-separated `tokens`, `ast`, `ir` or `asm`"
+    )]
+    emit: Vec<Emit>,
+    #[structopt(parse(from_os_str), help = "The *.zn source file names")]
+    source_files: Vec<PathBuf>,
+}
+
+#[derive(Debug, StructOpt)]
+#[structopt(name = "znc fmt", about = "Formats the Zinc source files")]
+struct FormatArguments {
+    #[structopt(
+        short = "v",
+        parse(from_occurrences),
+        help = "Shows verbose logs, use multiple times for more verbosity"
+    )]
+    verbosity: usize,
+    #[structopt(
+        long = "check",
+        help = "Checks the files without writing them, and fails if any of them is not formatted"
+    )]
+    check: bool,
+    #[structopt(
+        long = "message-format",
+        default_value = "human",
+        help = "The diagnostics format, either `human` or `json`"
+    )]
+    message_format: MessageFormat,
+    #[structopt(parse(from_os_str), help = "The *.zn source file names")]
+    source_files: Vec<PathBuf>,
+}
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "znc doc",
+    about = "Generates the documentation of the Zinc source files"
+)]
+struct DocArguments {
+    #[structopt(
+        short = "v",
+        parse(from_occurrences),
+        help = "Shows verbose logs, use multiple times for more verbosity"
+    )]
+    verbosity: usize,
+    #[structopt(
+        long = "name",
+        help = "The package name, which is the entry file name by default"
+    )]
+    name: Option<String>,
+    #[structopt(
+        short = "o",
+        long = "output",
+        parse(from_os_str),
+        help = "The documentation output directory"
+    )]
+    output_directory: PathBuf,
+    #[structopt(
+        long = "format",
+        default_value = "html",
+        help = "The documentation format, either `html` or `markdown`"
+    )]
+    format: DocumentationFormat,
+    #[structopt(
+        long = "dependency",
+        number_of_values = 1,
+        help = "The dependency package as `[<dependent>/]<name>=<path to lib.zn>`, use multiple times for more packages"
+    )]
+    dependencies: Vec<String>,
+    #[structopt(
+        long = "message-format",
+        default_value = "human",
+        help = "The diagnostics format, either `human` or `json`"
+    )]
+    message_format: MessageFormat,
     #[structopt(parse(from_os_str), help = "The *.zn source file names")]
     source_files: Vec<PathBuf>,
 }
@@ -63,14 +184,167 @@ enum Error {
     SourceFile(FileError),
     #[fail(display = "{}", _0)]
     Compiler(String),
+    #[fail(display = "{}", _0)]
+    SourceFileReading(String),
+    #[fail(display = "source file writing: {}", _0)]
+    SourceFileWriting(std::io::Error),
+    #[fail(display = "the source files are not formatted:\n{}", _0)]
+    SourceFilesUnformatted(String),
     #[fail(display = "witness template output: {}", _0)]
     WitnessTemplateOutput(OutputError),
     #[fail(display = "public data template output: {}", _0)]
     PublicDataTemplateOutput(OutputError),
     #[fail(display = "bytecode output: {}", _0)]
     BytecodeOutput(OutputError),
+    #[fail(display = "documentation output: {}", _0)]
+    DocumentationOutput(OutputError),
+    #[fail(display = "{} output: {}", _0, _1)]
+    EmitOutput(Emit, OutputError),
     #[fail(display = "the 'main.zn' source file is missing")]
     EntrySourceFileNotFound,
+    #[fail(
+        display = "dependency '{}' is invalid, expected '[<dependent>/]<name>=<path>'",
+        _0
+    )]
+    DependencyInvalid(String),
+    #[fail(display = "dependency package '{}' has no path", _0)]
+    DependencyPathNotFound(String),
+    #[fail(display = "dependency package '{}' depends on itself: {}", _0, _1)]
+    DependencyCycle(String, String),
+    #[fail(display = "lint: {}", _0)]
+    Lint(String),
+}
+
+///
+/// The dependency packages passed with the `--dependency` options.
+///
+#[derive(Debug, Default)]
+struct Packages {
+    /// The `lib.zn` paths of the packages.
+    paths: HashMap<String, PathBuf>,
+    /// The direct dependencies of the circuit being compiled.
+    root: Vec<String>,
+    /// The direct dependencies of each package.
+    dependencies: HashMap<String, Vec<String>>,
+}
+
+impl Packages {
+    ///
+    /// Parses the `[<dependent>/]<name>=<path>` specifications.
+    ///
+    pub fn new(specifications: Vec<String>) -> Result<Self, Error> {
+        let mut packages = Self::default();
+
+        for specification in specifications.into_iter() {
+            let (package, path) = match specification.find('=') {
+                Some(index) => (&specification[..index], &specification[index + 1..]),
+                None => return Err(Error::DependencyInvalid(specification)),
+            };
+            let (dependent, name) = match package.find('/') {
+                Some(index) => (Some(&package[..index]), &package[index + 1..]),
+                None => (None, package),
+            };
+            if name.is_empty() || path.is_empty() || dependent == Some("") {
+                return Err(Error::DependencyInvalid(specification));
+            }
+
+            match dependent {
+                Some(dependent) => packages
+                    .dependencies
+                    .entry(dependent.to_owned())
+                    .or_default()
+                    .push(name.to_owned()),
+                None => packages.root.push(name.to_owned()),
+            }
+            packages.paths.insert(name.to_owned(), PathBuf::from(path));
+        }
+
+        Ok(packages)
+    }
+
+    ///
+    /// Compiles the package after its own dependencies, and stores its `lib.zn` scope
+    /// to `compiled`.
+    ///
+    /// The `stack` contains the packages being compiled, which is used to detect cycles.
+    ///
+    pub fn compile(
+        &self,
+        name: &str,
+        bytecode: Rc<RefCell<Bytecode>>,
+        compiled: &mut HashMap<String, Rc<RefCell<Scope>>>,
+        stack: &mut Vec<String>,
+        lints: &Lints,
+        format: MessageFormat,
+    ) -> Result<(), Error> {
+        if compiled.contains_key(name) {
+            return Ok(());
+        }
+        if stack.iter().any(|package| package == name) {
+            let mut cycle = stack.to_owned();
+            cycle.push(name.to_owned());
+            return Err(Error::DependencyCycle(name.to_owned(), cycle.join(" -> ")));
+        }
+        let path = self
+            .paths
+            .get(name)
+            .ok_or_else(|| Error::DependencyPathNotFound(name.to_owned()))?;
+
+        stack.push(name.to_owned());
+        let dependencies = self.dependencies.get(name).cloned().unwrap_or_default();
+        for dependency in dependencies.iter() {
+            self.compile(dependency, bytecode.clone(), compiled, stack, lints, format)?;
+        }
+        stack.pop();
+
+        let packages = Self::scopes(dependencies.as_slice(), compiled);
+        let graph = Graph::new(vec![path.to_owned()], format).map_err(Error::Compiler)?;
+        let mut modules = HashMap::<PathBuf, Rc<RefCell<Scope>>>::new();
+        for source_file_path in graph.files.iter().cloned() {
+            let dependencies = graph.dependencies(&source_file_path, &modules);
+            bytecode
+                .borrow_mut()
+                .start_new_file(source_file_path.to_string_lossy().as_ref());
+
+            log::info!("Compiling {:?} of package `{}`", source_file_path, name);
+            let (module, warnings) = ZincFile::try_from(source_file_path.clone())
+                .map_err(Error::SourceFileReading)?
+                .try_into_module(
+                    bytecode.clone(),
+                    dependencies,
+                    packages.clone(),
+                    lints,
+                    format,
+                )
+                .map_err(Error::Compiler)?;
+            self::print_diagnostics(warnings, format);
+
+            modules.insert(source_file_path, module);
+        }
+
+        if let Some(module) = modules.remove(path) {
+            compiled.insert(name.to_owned(), module);
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Collects the compiled scopes of the packages, keyed by their names.
+    ///
+    pub fn scopes(
+        names: &[String],
+        compiled: &HashMap<String, Rc<RefCell<Scope>>>,
+    ) -> HashMap<String, Rc<RefCell<Scope>>> {
+        names
+            .iter()
+            .filter_map(|name| {
+                compiled
+                    .get(name)
+                    .map(|scope| (name.to_owned(), scope.to_owned()))
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug, Fail)]
@@ -92,141 +366,228 @@ enum OutputError {
 }
 
 fn main() {
+    match env::args_os().nth(1) {
+        Some(ref argument) if argument == FORMAT_SUBCOMMAND => {
+            let args = FormatArguments::from_iter(env::args_os().skip(1));
+            let format = args.message_format;
+            process::exit(self::exit_code(format_inner(args), format));
+        }
+        Some(ref argument) if argument == DOC_SUBCOMMAND => {
+            let args = DocArguments::from_iter(env::args_os().skip(1));
+            let format = args.message_format;
+            process::exit(self::exit_code(doc_inner(args), format));
+        }
+        _ => {}
+    }
+
     let args: Arguments = Arguments::from_args();
+    let format = args.message_format;
 
-    process::exit(match main_inner(args) {
+    process::exit(self::exit_code(main_inner(args), format))
+}
+
+///
+/// Prints the error, if any, and returns the process exit code.
+///
+fn exit_code(result: Result<(), Error>, format: MessageFormat) -> i32 {
+    match result {
         Ok(()) => EXIT_CODE_SUCCESS,
+        Err(Error::Compiler(diagnostics)) => {
+            self::print_diagnostics(vec![diagnostics], format);
+            EXIT_CODE_FAILURE
+        }
         Err(error) => {
-            eprintln!("{}", error);
+            let diagnostic = match format {
+                MessageFormat::Human => error.to_string(),
+                MessageFormat::Json => {
+                    Diagnostic::error_message(error.to_string().as_str(), None)
+                        .render(&[], format)
+                }
+            };
+            self::print_diagnostics(vec![diagnostic], format);
             EXIT_CODE_FAILURE
         }
-    })
+    }
 }
 
-// Topologically sort the module path into L
-
-// L ← Empty list that will contain the sorted nodes
-// while exists nodes without a permanent mark do
-//     select an unmarked node n
-//     visit(n)
-//
-// function visit(node n)
-//     if n has a permanent mark then
-//         return
-//     if n has a temporary mark then
-//         stop   (not a DAG)
-//
-//     mark n with a temporary mark
-//
-//     for each node m with an edge from n to m do
-//         visit(m)
-//
-//     remove temporary mark from n
-//     mark n with a permanent mark
-//     add n to head of L
-fn visit(n: PathBuf, L: &mut VecDeque<PathBuf>, temp_marks: &mut Vec<PathBuf>) -> Result<(), Error> {
-    debug!("Visiting module {}", n.display());
-    // if n has a permanent mark then
-    //         return
-    if L.contains(&n) {
-        debug!("Already sorted: {}", n.display());
-
-        return Ok(());
-    } // already in sorted list
-
-
-    // if n has a temporary mark then
-    //         stop   (not a DAG)
-    debug!("TEMP MARK - CHECK : {}", n.display());
-    if temp_marks.contains(&n) {
-        debug!("TEMP MARK - CHECK : {}", "FOUND!");
-        return Err(Error::Compiler(format!("Cyclic module dependencies are not supported. Found in: {}", n.display())));
+///
+/// Prints the rendered diagnostics to the standard error, or to the standard output in
+/// the JSON format, so they can be read by tools.
+///
+fn print_diagnostics(diagnostics: Vec<String>, format: MessageFormat) {
+    for diagnostic in diagnostics.into_iter() {
+        match format {
+            MessageFormat::Human => eprintln!("{}", diagnostic),
+            MessageFormat::Json => println!("{}", diagnostic),
+        }
     }
+}
 
-    // mark n with a temporary mark
-    debug!("TEMP MARK - ADD   : {}", n.display());
-    temp_marks.push(n.clone());
+fn main_inner(args: Arguments) -> Result<(), Error> {
+    zinc_bytecode::logger::init_logger("znc", args.verbosity);
 
+    self::check_extensions(args.source_files.as_slice())?;
+
+    let mut lint_settings = Vec::new();
+    for (names, level) in vec![
+        (args.allow, LintLevel::Allow),
+        (args.warn, LintLevel::Warn),
+        (args.deny, LintLevel::Deny),
+    ]
+    .into_iter()
+    {
+        lint_settings.extend(names.into_iter().map(|name| (name, level)));
+    }
+    let lints = Lints::new(lint_settings).map_err(Error::Lint)?;
 
-    //  for each node m with an edge from n to m do
-    //         visit(m)
-    let found_modules = ZincFile::try_from(n.clone())
-        .map_err(Error::Compiler)?
-        .find_modules()
-        .map_err(Error::Compiler)?;
+    let message_format = args.message_format;
+    let is_intermediate_emitted = args.emit.contains(&Emit::Ir);
+    if is_intermediate_emitted {
+        Emit::start_intermediate();
+    }
+    let result = self::compile(
+        args.source_files,
+        args.dependencies,
+        &lints,
+        args.message_format,
+        true,
+    );
+    let mut intermediate = if is_intermediate_emitted {
+        Some(Emit::take_intermediate())
+    } else {
+        None
+    };
+    let (graph, bytecode) = result?;
 
-    debug!("Found # modules: {}", found_modules.len());
+    if !args.witness_template_path.exists() {
+        File::create(&args.witness_template_path)
+            .map_err(OutputError::Creating)
+            .map_err(Error::WitnessTemplateOutput)?
+            .write_all(bytecode.borrow().input_template_bytes().as_slice())
+            .map_err(OutputError::Writing)
+            .map_err(Error::WitnessTemplateOutput)?;
+        log::info!(
+            "Witness template written to {:?}",
+            args.witness_template_path
+        );
+    }
 
-    found_modules.into_iter().try_for_each(|m| {
-        // We assume that all modules are in the root path, next main.zn.
-        // File name equals: <module name>.zn
-        let module_path = n.with_file_name(m + ".zn");
-        visit(module_path, L, temp_marks)
-    }).expect("Compilation failed during module graph ordering");
+    File::create(&args.public_data_template_path)
+        .map_err(OutputError::Creating)
+        .map_err(Error::PublicDataTemplateOutput)?
+        .write_all(bytecode.borrow().output_template_bytes().as_slice())
+        .map_err(OutputError::Writing)
+        .map_err(Error::PublicDataTemplateOutput)?;
+    log::info!(
+        "Public data template written to {:?}",
+        args.public_data_template_path
+    );
 
-    //     remove temporary mark from n
-    debug!("TEMP MARK - REMOVE: {}", n.display());
-    if let Some(pos) = temp_marks.iter().position(|x| *x == n) {
-        temp_marks.remove(pos);
+    for emit in args.emit.iter().copied() {
+        let dump = match emit {
+            Emit::Tokens | Emit::Ast => {
+                let mut dump = String::new();
+                for source_file_path in graph.files.iter() {
+                    let file = ZincFile::try_from(source_file_path.to_owned())
+                        .map_err(Error::SourceFileReading)?;
+                    let result = match emit {
+                        Emit::Tokens => file
+                            .tokens()
+                            .map(|tokens| Emit::tokens(&file.path, tokens.as_slice())),
+                        _ => file
+                            .syntax_tree()
+                            .map(|tree| Emit::syntax_tree(&file.path, &tree)),
+                    };
+                    dump.push_str(
+                        result
+                            .map_err(|diagnostics| {
+                                let lines = file.code.lines().collect::<Vec<&str>>();
+                                Error::Compiler(
+                                    Diagnostic::render_all(
+                                        diagnostics,
+                                        lines.as_slice(),
+                                        message_format,
+                                    )
+                                    .join("\n"),
+                                )
+                            })?
+                            .as_str(),
+                    );
+                }
+                dump
+            }
+            Emit::Ir => intermediate.take().unwrap_or_default(),
+            Emit::Asm => {
+                zinc_compiler::assembly_listing(bytecode.borrow().instructions(), |file| {
+                    fs::read_to_string(file).ok()
+                })
+            }
+        };
+
+        let path = args.bytecode_output_path.with_extension(emit.extension());
+        fs::write(&path, dump)
+            .map_err(OutputError::Writing)
+            .map_err(|error| Error::EmitOutput(emit, error))?;
+        log::info!("Output `{}` written to {:?}", emit, path);
     }
 
-    //     mark n with a permanent mark. In our case, that is same as adding to head of L
-    //     add n to head of L
-    debug!("Adding to sorted list: {}", n.display());
-    L.push_back(PathBuf::from(&n));
+    let bytecode = Rc::try_unwrap(bytecode)
+        .expect(zinc_compiler::PANIC_LAST_SHARED_REFERENCE)
+        .into_inner();
+
+    File::create(&args.bytecode_output_path)
+        .map_err(OutputError::Creating)
+        .map_err(Error::BytecodeOutput)?
+        .write_all(bytecode.into_bytes().as_slice())
+        .map_err(OutputError::Writing)
+        .map_err(Error::BytecodeOutput)?;
+    log::info!("Compiled to {:?}", args.bytecode_output_path);
 
     Ok(())
 }
 
-fn ordered_source_files(source_files: Vec<PathBuf>) -> Result<VecDeque<PathBuf>, Error> {
-    let mut L = VecDeque::<PathBuf>::new();
-    let mut temp_marks = Vec::<PathBuf>::new();
-
-    for source_file_path in source_files.into_iter() {
-        let source_file_extension = source_file_path
-            .extension()
-            .ok_or(FileError::ExtensionNotFound)
-            .map_err(Error::SourceFile)?;
-        if source_file_extension != ZINC_SOURCE_FILE_EXTENSION {
-            return Err(FileError::ExtensionInvalid(
-                source_file_extension.to_owned(),
-            ))
-                .map_err(Error::SourceFile);
-        }
-
-        visit(source_file_path, &mut L, &mut temp_marks)
-            .expect("Compilation failed during module graph ordering");
+///
+/// Compiles the dependency packages and the circuit source files.
+///
+/// The `main.zn` entry file is required unless `is_entry_required` is unset, which is the case
+/// for the library packages being documented.
+///
+/// Returns the module graph and the bytecode, or all the errors found in the files.
+///
+fn compile(
+    source_files: Vec<PathBuf>,
+    dependencies: Vec<String>,
+    lints: &Lints,
+    format: MessageFormat,
+    is_entry_required: bool,
+) -> Result<(Graph, Rc<RefCell<Bytecode>>), Error> {
+    let graph = Graph::new(source_files, format).map_err(Error::Compiler)?;
+    for source_file_path in graph.files.iter() {
+        debug!("Ordered file: {}", source_file_path.display());
     }
-    Ok(L)
-}
-
-fn main_inner(args: Arguments) -> Result<(), Error> {
-    zinc_bytecode::logger::init_logger("znc", args.verbosity);
-
-    let ordered_source_files = ordered_source_files(args.source_files)
-        .map_err(|e| {
-            Error::Compiler("Could not determine ordered source files: ".to_string())
-        })?;
-
-    ordered_source_files.iter().for_each(|file| debug!("Ordered file: {}", file.display()));
 
     let bytecode = Rc::new(RefCell::new(Bytecode::new()));
 
-    let mut modules = HashMap::<String, Rc<RefCell<Scope>>>::new();
-    let mut entry_file_path = None;
+    let packages = Packages::new(dependencies)?;
+    let mut compiled_packages = HashMap::<String, Rc<RefCell<Scope>>>::new();
+    for name in packages.root.iter() {
+        packages.compile(
+            name,
+            bytecode.clone(),
+            &mut compiled_packages,
+            &mut Vec::new(),
+            &lints,
+            format,
+        )?;
+    }
+    let root_packages = Packages::scopes(packages.root.as_slice(), &compiled_packages);
 
-    for source_file_path in ordered_source_files.into_iter() {
-        let source_file_extension = source_file_path
-            .extension()
-            .ok_or(FileError::ExtensionNotFound)
-            .map_err(Error::SourceFile)?;
-        if source_file_extension != ZINC_SOURCE_FILE_EXTENSION {
-            return Err(FileError::ExtensionInvalid(
-                source_file_extension.to_owned(),
-            ))
-                .map_err(Error::SourceFile);
-        }
+    let mut modules = HashMap::<PathBuf, Rc<RefCell<Scope>>>::new();
+    let mut entry_file_path = None;
 
+    let mut errors = Vec::new();
+    let mut failed = HashSet::<PathBuf>::new();
+    for source_file_path in graph.files.iter().cloned() {
         let source_file_stem = source_file_path
             .file_stem()
             .ok_or(FileError::StemNotFound)
@@ -236,71 +597,216 @@ fn main_inner(args: Arguments) -> Result<(), Error> {
             continue;
         }
 
-        let module_name = source_file_stem.to_string_lossy().to_string();
+        if graph.has_failed_dependency(&source_file_path, &failed) {
+            failed.insert(source_file_path);
+            continue;
+        }
+
+        let dependencies = graph.dependencies(&source_file_path, &modules);
         bytecode
             .borrow_mut()
             .start_new_file(source_file_path.to_string_lossy().as_ref());
 
         log::info!("Compiling {:?}", source_file_path);
-        let module = ZincFile::try_from(source_file_path)
-            .map_err(Error::Compiler)?
-            .try_into_module(bytecode.clone(), modules.clone())
-            .map_err(Error::Compiler)?;
-
-        modules.insert(module_name, module);
+        let (module, warnings) = match ZincFile::try_from(source_file_path.clone())
+            .map_err(Error::SourceFileReading)?
+            .try_into_module(
+                bytecode.clone(),
+                dependencies,
+                root_packages.clone(),
+                &lints,
+                format,
+            ) {
+            Ok(result) => result,
+            Err(error) => {
+                errors.push(error);
+                failed.insert(source_file_path);
+                continue;
+            }
+        };
+        self::print_diagnostics(warnings, format);
+
+        modules.insert(source_file_path, module);
     }
 
     match entry_file_path.take() {
-        Some(entry_file_path) => {
+        Some(entry_file_path) if !graph.has_failed_dependency(&entry_file_path, &failed) => {
             bytecode
                 .borrow_mut()
                 .start_new_file(entry_file_path.to_string_lossy().as_ref());
 
+            let dependencies = graph.dependencies(&entry_file_path, &modules);
+
             log::info!("Compiling {:?}", entry_file_path);
-            ZincFile::try_from(entry_file_path)
-                .map_err(Error::Compiler)?
-                .try_into_entry(bytecode.clone(), modules)
-                .map_err(Error::Compiler)?;
+            match ZincFile::try_from(entry_file_path)
+                .map_err(Error::SourceFileReading)?
+                .try_into_entry(
+                    bytecode.clone(),
+                    dependencies,
+                    root_packages,
+                    &lints,
+                    format,
+                ) {
+                Ok(warnings) => self::print_diagnostics(warnings, format),
+                Err(error) => errors.push(error),
+            }
         }
-        None => return Err(Error::EntrySourceFileNotFound),
+        Some(_) => {}
+        None if is_entry_required => return Err(Error::EntrySourceFileNotFound),
+        None => {}
     }
 
-    if !args.witness_template_path.exists() {
-        File::create(&args.witness_template_path)
-            .map_err(OutputError::Creating)
-            .map_err(Error::WitnessTemplateOutput)?
-            .write_all(bytecode.borrow().input_template_bytes().as_slice())
-            .map_err(OutputError::Writing)
-            .map_err(Error::WitnessTemplateOutput)?;
-        log::info!(
-            "Witness template written to {:?}",
-            args.witness_template_path
-        );
+    if !errors.is_empty() {
+        return Err(Error::Compiler(errors.join("\n")));
     }
 
-    File::create(&args.public_data_template_path)
-        .map_err(OutputError::Creating)
-        .map_err(Error::PublicDataTemplateOutput)?
-        .write_all(bytecode.borrow().output_template_bytes().as_slice())
-        .map_err(OutputError::Writing)
-        .map_err(Error::PublicDataTemplateOutput)?;
-    log::info!(
-        "Public data template written to {:?}",
-        args.public_data_template_path
+    Ok((graph, bytecode))
+}
+
+///
+/// Formats the source files in place, or only checks them in the `--check` mode.
+///
+/// The files with syntax errors are left as they are, and their errors are reported after all
+/// the other files are formatted.
+///
+fn format_inner(args: FormatArguments) -> Result<(), Error> {
+    zinc_bytecode::logger::init_logger("znc", args.verbosity);
+
+    self::check_extensions(args.source_files.as_slice())?;
+
+    let mut errors = Vec::new();
+    let mut unformatted = Vec::new();
+    for source_file_path in args.source_files.into_iter() {
+        let file = ZincFile::try_from(source_file_path).map_err(Error::SourceFileReading)?;
+        let formatted = match file.format() {
+            Ok(formatted) => formatted,
+            Err(diagnostics) => {
+                let lines = file.code.lines().collect::<Vec<&str>>();
+                errors.extend(Diagnostic::render_all(
+                    diagnostics,
+                    lines.as_slice(),
+                    args.message_format,
+                ));
+                continue;
+            }
+        };
+        if formatted == file.code {
+            continue;
+        }
+
+        if args.check {
+            let line = file
+                .code
+                .lines()
+                .zip(formatted.lines())
+                .position(|(original, formatted)| original != formatted)
+                .unwrap_or_else(|| file.code.lines().count().min(formatted.lines().count()));
+            unformatted.push(format!("{}:{}", file.path.display(), line + 1));
+        } else {
+            log::info!("Formatting {:?}", file.path);
+            fs::write(&file.path, formatted).map_err(Error::SourceFileWriting)?;
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(Error::Compiler(errors.join("\n")));
+    }
+    if !unformatted.is_empty() {
+        return Err(Error::SourceFilesUnformatted(unformatted.join("\n")));
+    }
+
+    Ok(())
+}
+
+///
+/// Generates the documentation of the source files, whose item signatures are resolved by
+/// compiling them, and writes a page per module to the output directory.
+///
+/// The warnings are not reported, since the documented code is not being built.
+///
+fn doc_inner(args: DocArguments) -> Result<(), Error> {
+    zinc_bytecode::logger::init_logger("znc", args.verbosity);
+
+    self::check_extensions(args.source_files.as_slice())?;
+
+    let name = match args.name {
+        Some(name) => name,
+        None => args
+            .source_files
+            .first()
+            .and_then(|path| path.file_stem())
+            .ok_or(FileError::StemNotFound)
+            .map_err(Error::SourceFile)?
+            .to_string_lossy()
+            .to_string(),
+    };
+    let message_format = args.message_format;
+    let lints = Lints::new(vec![(LINT_GROUP_WARNINGS.to_owned(), LintLevel::Allow)])
+        .map_err(Error::Lint)?;
+
+    SymbolTable::start();
+    let result = self::compile(
+        args.source_files,
+        args.dependencies,
+        &lints,
+        message_format,
+        false,
     );
+    let table = SymbolTable::take();
+    let (graph, _bytecode) = result?;
+
+    let module_paths = graph.module_paths();
+    let mut documentation = Documentation::new(name);
+    for source_file_path in graph.files.iter().rev() {
+        let mut path = module_paths
+            .get(source_file_path)
+            .cloned()
+            .unwrap_or_default();
+        let source_file_stem = source_file_path
+            .file_stem()
+            .ok_or(FileError::StemNotFound)
+            .map_err(Error::SourceFile)?;
+        if path.is_empty() && source_file_stem != "main" && source_file_stem != "lib" {
+            path.push(source_file_stem.to_string_lossy().to_string());
+        }
 
-    let bytecode = Rc::try_unwrap(bytecode)
-        .expect(zinc_compiler::PANIC_LAST_SHARED_REFERENCE)
-        .into_inner();
+        let file =
+            ZincFile::try_from(source_file_path.to_owned()).map_err(Error::SourceFileReading)?;
+        let tree = file.syntax_tree().map_err(|diagnostics| {
+            let lines = file.code.lines().collect::<Vec<&str>>();
+            Error::Compiler(
+                Diagnostic::render_all(diagnostics, lines.as_slice(), message_format).join("\n"),
+            )
+        })?;
+        documentation.add(path, tree, &table);
+    }
 
-    File::create(&args.bytecode_output_path)
+    fs::create_dir_all(&args.output_directory)
         .map_err(OutputError::Creating)
-        .map_err(Error::BytecodeOutput)?
-        .write_all(bytecode.into_bytes().as_slice())
-        .map_err(OutputError::Writing)
-        .map_err(Error::BytecodeOutput)?;
-    log::info!("Compiled to {:?}", args.bytecode_output_path);
+        .map_err(Error::DocumentationOutput)?;
+    for (name, page) in documentation.render(args.format).into_iter() {
+        fs::write(args.output_directory.join(name), page)
+            .map_err(OutputError::Writing)
+            .map_err(Error::DocumentationOutput)?;
+    }
+    log::info!("Documentation written to {:?}", args.output_directory);
 
     Ok(())
 }
 
+fn check_extensions(source_file_paths: &[PathBuf]) -> Result<(), Error> {
+    for source_file_path in source_file_paths.iter() {
+        let source_file_extension = source_file_path
+            .extension()
+            .ok_or(FileError::ExtensionNotFound)
+            .map_err(Error::SourceFile)?;
+        if source_file_extension != ZINC_SOURCE_FILE_EXTENSION {
+            return Err(FileError::ExtensionInvalid(
+                source_file_extension.to_owned(),
+            ))
+            .map_err(Error::SourceFile);
+        }
+    }
+
+    Ok(())
+}
@@ -12,7 +12,9 @@ use crate::semantic::analyzer::statement::Analyzer as StatementAnalyzer;
 use crate::semantic::error::Error;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
+use crate::semantic::symbol::Table as SymbolTable;
 use crate::syntax::tree::Tree as SyntaxTree;
+use crate::warning::Warning;
 
 ///
 /// Analyzes the circuit entry, which must be located in the `main.zn` file.
@@ -36,29 +38,59 @@ impl Analyzer {
         }
     }
 
+    ///
+    /// Initializes an analyzer with the dependency packages declared in the global scope.
+    ///
+    pub fn new_with_packages(packages: HashMap<String, Rc<RefCell<Scope>>>) -> Self {
+        let analyzer = Self::new();
+        for (name, package) in packages.into_iter() {
+            Scope::declare_package(analyzer.scope_stack.top(), name, package);
+        }
+        analyzer
+    }
+
+    ///
+    /// Analyzes the entry statements, going on after an invalid statement, so all the errors
+    /// of independent items are returned at once.
+    ///
     pub fn compile(
         self,
         program: SyntaxTree,
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
-    ) -> Result<Tree, CompilerError> {
+    ) -> Result<Tree, Vec<CompilerError>> {
         let mut intermediate = Tree::new();
 
+        let instances = Scope::instances(self.scope_stack.top());
         let mut analyzer = StatementAnalyzer::new(self.scope_stack.top(), dependencies);
+        Warning::take_all();
+        let mut errors = Vec::new();
         for statement in program.statements.into_iter() {
-            if let Some(statement) = analyzer
-                .local_mod(statement)
-                .map_err(CompilerError::Semantic)?
-            {
+            SymbolTable::record_scope(statement.location(), &self.scope_stack.top(), false);
+            let statement = match analyzer.local_mod(statement) {
+                Ok(statement) => statement,
+                Err(error) => {
+                    errors.push(CompilerError::Semantic(error));
+                    analyzer.recover();
+                    continue;
+                }
+            };
+            intermediate.statements.extend(instances.take());
+            if let Some(statement) = statement {
                 intermediate.statements.push(statement);
             }
         }
 
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
         Scope::resolve_item(
             self.scope_stack.top(),
             crate::semantic::element::r#type::function::user::FUNCTION_MAIN_IDENTIFIER,
         )
-        .map_err(|_| Error::EntryPointMissing)
-        .map_err(CompilerError::Semantic)?;
+        .map_err(|_| vec![CompilerError::Semantic(Error::EntryPointMissing)])?;
+
+        self.scope_stack.top().borrow().check_unused_items();
 
         Ok(intermediate)
     }
@@ -67,7 +99,9 @@ impl Analyzer {
 #[cfg(test)]
 mod tests {
     use crate::error::Error;
+    use crate::lexical::token::location::Location;
     use crate::semantic::error::Error as SemanticError;
+    use crate::semantic::scope::error::Error as ScopeError;
 
     #[test]
     fn error_test() {
@@ -83,4 +117,40 @@ fn another() -> u8 {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn error_independent_items() {
+        let input = r#"
+fn first() -> u8 {
+    undeclared
+}
+
+struct Data {
+    value: Undeclared,
+}
+
+fn main() -> u8 {
+    first()
+}
+"#;
+
+        let expected = Err(vec![
+            Error::Semantic(SemanticError::Scope(
+                Location::new(3, 5),
+                ScopeError::ItemUndeclared {
+                    name: "undeclared".to_owned(),
+                },
+            )),
+            Error::Semantic(SemanticError::Scope(
+                Location::new(7, 12),
+                ScopeError::ItemUndeclared {
+                    name: "Undeclared".to_owned(),
+                },
+            )),
+        ]);
+
+        let result = crate::semantic::tests::compile_entry_errors(input);
+
+        assert_eq!(result, expected);
+    }
 }
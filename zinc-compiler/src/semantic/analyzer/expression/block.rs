@@ -8,14 +8,17 @@ use std::rc::Rc;
 
 use crate::generator::expression::operand::block::builder::Builder as GeneratorBlockExpressionBuilder;
 use crate::generator::expression::operand::block::Expression as GeneratorBlockExpression;
+use crate::generator::r#type::Type as GeneratorType;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::analyzer::statement::Analyzer as StatementAnalyzer;
+use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
+use crate::semantic::symbol::Table as SymbolTable;
 use crate::syntax::tree::expression::block::Expression as BlockExpression;
 
 pub struct Analyzer {}
@@ -34,13 +37,22 @@ impl Analyzer {
 
         let mut scope_stack = ScopeStack::new(scope);
         scope_stack.push();
+        SymbolTable::record_scope(block.location, &scope_stack.top(), true);
 
+        let mut is_control_guarded = false;
         for statement in block.statements.into_iter() {
+            let location = statement.location();
+            let controls = Scope::controls(scope_stack.top());
             if let Some(statement) =
                 StatementAnalyzer::new(scope_stack.top(), HashMap::new()).local_fn(statement)?
             {
                 builder.push_statement(statement);
             }
+            SymbolTable::record_scope(location, &scope_stack.top(), true);
+            if Scope::controls(scope_stack.top()) > controls {
+                builder.set_control_guard();
+                is_control_guarded = true;
+            }
         }
 
         let element = match block.expression {
@@ -48,6 +60,12 @@ impl Analyzer {
                 let (element, expression) = ExpressionAnalyzer::new(scope_stack.top())
                     .analyze(*expression, TranslationHint::Value)?;
                 builder.set_expression(expression);
+                if is_control_guarded {
+                    let r#type = Type::from_element(&element, scope_stack.top())?;
+                    if let Some(r#type) = GeneratorType::try_from_semantic(&r#type) {
+                        builder.set_expression_type(r#type);
+                    }
+                }
                 element
             }
             None => Element::Value(Value::Unit),
@@ -8,6 +8,7 @@ use std::rc::Rc;
 
 use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
 use crate::lexical::token::location::Location;
+use crate::semantic::analyzer::statement::Analyzer as StatementAnalyzer;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::builtin::error::Error as BuiltInFunctionTypeError;
@@ -104,6 +105,7 @@ impl Analyzer {
                             })?;
 
                         let intermediate = GeneratorExpressionOperator::call_assert(message);
+                        Scope::constrain_flow(scope.clone());
 
                         (return_type, intermediate)
                     }
@@ -151,10 +153,44 @@ impl Analyzer {
 
                 let intermediate = GeneratorExpressionOperator::call(unique_id, input_size);
 
+                (return_type, intermediate)
+            }
+            FunctionType::Generic(function) => {
+                if is_call_builtin {
+                    return Err(Error::Element(
+                        location,
+                        ElementError::Type(TypeError::Function(FunctionTypeError::BuiltIn(
+                            BuiltInFunctionTypeError::unknown(function.identifier().to_owned()),
+                        ))),
+                    ));
+                }
+
+                let generic_arguments =
+                    function
+                        .infer(argument_elements.as_slice())
+                        .map_err(|error| {
+                            Error::Element(location, ElementError::Type(TypeError::Function(error)))
+                        })?;
+                let function =
+                    StatementAnalyzer::instantiate(scope.clone(), &function, generic_arguments)?;
+
+                let unique_id = function.unique_id();
+
+                let return_type = function.call(argument_elements).map_err(|error| {
+                    Error::Element(location, ElementError::Type(TypeError::Function(error)))
+                })?;
+
+                let intermediate = GeneratorExpressionOperator::call(unique_id, input_size);
+
                 (return_type, intermediate)
             }
         };
 
+        // the functions without a result may only be called for their `assert!` checks
+        if !is_call_builtin && return_type == Type::Unit {
+            Scope::constrain_flow(scope);
+        }
+
         let element = Element::Value(
             Value::try_from(&return_type)
                 .map_err(ElementError::Value)
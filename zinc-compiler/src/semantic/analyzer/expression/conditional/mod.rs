@@ -15,6 +15,7 @@ use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::flow::Kind as FlowKind;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
 use crate::syntax::tree::expression::conditional::Expression as ConditionalExpression;
@@ -57,8 +58,10 @@ impl Analyzer {
 
         let mut scope_stack = ScopeStack::new(scope);
 
+        Scope::enter_flow(scope_stack.top());
         let (condition_result, condition) = ExpressionAnalyzer::new(scope_stack.top())
             .analyze(*conditional.condition, TranslationHint::Value)?;
+        Scope::leave_flow(scope_stack.top(), FlowKind::Condition);
         match Type::from_element(&condition_result, scope_stack.top())? {
             Type::Boolean => {}
             r#type => {
@@ -88,6 +91,7 @@ impl Analyzer {
         } else {
             Type::Unit
         };
+        Scope::leave_flow_condition(scope_stack.top());
 
         // check if the two branches return equals types
         if main_type != else_type {
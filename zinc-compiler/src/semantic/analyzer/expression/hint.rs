@@ -81,6 +81,7 @@ impl Hint {
             ExpressionOperator::Call => Self::Type,
 
             ExpressionOperator::Path => Self::Path,
+            ExpressionOperator::GenericArguments => Self::Type,
         }
     }
 
@@ -142,6 +143,7 @@ impl Hint {
             ExpressionOperator::Call => Self::Value,
 
             ExpressionOperator::Path => Self::Path,
+            ExpressionOperator::GenericArguments => Self::Value,
         }
     }
 }
@@ -2,62 +2,224 @@
 //! The match expression exhausting data.
 //!
 
-use std::collections::HashMap;
-
 use num_bigint::BigInt;
-use num_traits::One;
-use num_traits::Zero;
 
-use crate::lexical::token::location::Location;
-use crate::semantic::element::r#type::enumeration::Enumeration;
+use crate::semantic::element::r#type::Type;
+
+///
+/// The branch pattern reduced to the form used for checking exhaustiveness and reachability.
+///
+/// Bindings are reduced to wildcards, enumeration variants to their values, and structures to
+/// tuples with their fields in the declaration order.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pattern {
+    Wildcard,
+    Constructor(Constructor, Vec<Pattern>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constructor {
+    Boolean(bool),
+    Integer(BigInt),
+    Tuple,
+}
 
+///
+/// The matrix of the previous branch patterns.
+///
+/// The reachability check is the pattern usefulness algorithm described by Luc Maranget in
+/// "Warnings for pattern matching".
+///
 pub struct Data {
-    patterns: HashMap<BigInt, Location>,
-    enumeration_type: Option<Enumeration>,
+    r#type: Type,
+    rows: Vec<Vec<Pattern>>,
 }
 
 impl Data {
-    const DEFAULT_INITIAL_PATTERN_HASHMAP_SIZE: usize = 4;
-
-    pub fn new() -> Self {
+    pub fn new(r#type: Type) -> Self {
         Self {
-            patterns: HashMap::with_capacity(Self::DEFAULT_INITIAL_PATTERN_HASHMAP_SIZE),
-            enumeration_type: None,
+            r#type,
+            rows: Vec::new(),
         }
     }
 
-    pub fn insert_boolean(&mut self, value: bool, location: Location) -> Option<Location> {
-        self.patterns
-            .insert(if value { BigInt::one() } else { BigInt::zero() }, location)
+    ///
+    /// Checks if the pattern matches any value not matched by the previous patterns.
+    ///
+    pub fn is_useful(&self, pattern: &Pattern) -> bool {
+        Self::useful(
+            self.rows.as_slice(),
+            &[pattern.to_owned()],
+            &[self.r#type.to_owned()],
+        )
     }
 
-    pub fn insert_integer(
-        &mut self,
-        value: BigInt,
-        enumeration_type: Option<Enumeration>,
-        location: Location,
-    ) -> Option<Location> {
-        self.enumeration_type = enumeration_type;
-        self.patterns.insert(value, location)
+    ///
+    /// Checks if the previous patterns match every value of the scrutinee type.
+    ///
+    pub fn is_exhausted(&self) -> bool {
+        !self.is_useful(&Pattern::Wildcard)
     }
 
-    pub fn is_exhausted_boolean(&self) -> bool {
-        let mut current = self.patterns.keys().cloned().collect::<Vec<BigInt>>();
-        current.sort();
-        let full = vec![BigInt::zero(), BigInt::one()];
+    ///
+    /// Returns the index of the previous pattern, which is equal to `pattern`.
+    ///
+    pub fn position(&self, pattern: &Pattern) -> Option<usize> {
+        self.rows.iter().position(|row| &row[0] == pattern)
+    }
 
-        current == full
+    pub fn push(&mut self, pattern: Pattern) {
+        self.rows.push(vec![pattern]);
     }
 
-    pub fn is_exhausted_integer(&self) -> bool {
-        match self.enumeration_type {
-            Some(ref enumeration) => {
-                let mut current = self.patterns.keys().cloned().collect::<Vec<BigInt>>();
-                current.sort();
+    fn useful(rows: &[Vec<Pattern>], row: &[Pattern], types: &[Type]) -> bool {
+        if row.is_empty() {
+            return rows.is_empty();
+        }
+
+        match row[0] {
+            Pattern::Constructor(ref constructor, ref arguments) => {
+                let mut specialized = arguments.to_owned();
+                specialized.extend_from_slice(&row[1..]);
+                Self::useful_specialized(
+                    rows,
+                    specialized.as_slice(),
+                    types,
+                    constructor,
+                    arguments.len(),
+                )
+            }
+            Pattern::Wildcard => {
+                let used: Vec<&Constructor> = rows
+                    .iter()
+                    .filter_map(|row| match row[0] {
+                        Pattern::Constructor(ref constructor, _) => Some(constructor),
+                        Pattern::Wildcard => None,
+                    })
+                    .collect();
 
-                current == enumeration.values
+                match Self::constructors(&types[0]) {
+                    Some(constructors)
+                        if constructors
+                            .iter()
+                            .all(|(constructor, _arity)| used.contains(&constructor)) =>
+                    {
+                        constructors.into_iter().any(|(constructor, arity)| {
+                            let mut specialized = vec![Pattern::Wildcard; arity];
+                            specialized.extend_from_slice(&row[1..]);
+                            Self::useful_specialized(
+                                rows,
+                                specialized.as_slice(),
+                                types,
+                                &constructor,
+                                arity,
+                            )
+                        })
+                    }
+                    _ => {
+                        let default: Vec<Vec<Pattern>> = rows
+                            .iter()
+                            .filter(|row| row[0] == Pattern::Wildcard)
+                            .map(|row| row[1..].to_vec())
+                            .collect();
+                        Self::useful(default.as_slice(), &row[1..], &types[1..])
+                    }
+                }
             }
-            None => false,
+        }
+    }
+
+    ///
+    /// Checks the usefulness of the `row`, whose first column has been already replaced with
+    /// the `constructor` arguments, in the rows matched by the `constructor`.
+    ///
+    fn useful_specialized(
+        rows: &[Vec<Pattern>],
+        row: &[Pattern],
+        types: &[Type],
+        constructor: &Constructor,
+        arity: usize,
+    ) -> bool {
+        let specialized_rows: Vec<Vec<Pattern>> = rows
+            .iter()
+            .filter_map(|row| match row[0] {
+                Pattern::Constructor(ref current, ref arguments) if current == constructor => {
+                    let mut specialized = arguments.to_owned();
+                    specialized.extend_from_slice(&row[1..]);
+                    Some(specialized)
+                }
+                Pattern::Constructor(..) => None,
+                Pattern::Wildcard => {
+                    let mut specialized = vec![Pattern::Wildcard; arity];
+                    specialized.extend_from_slice(&row[1..]);
+                    Some(specialized)
+                }
+            })
+            .collect();
+
+        let mut specialized_types = Self::arguments(&types[0], constructor);
+        specialized_types.resize(arity, Type::unit());
+        specialized_types.extend_from_slice(&types[1..]);
+
+        Self::useful(
+            specialized_rows.as_slice(),
+            row,
+            specialized_types.as_slice(),
+        )
+    }
+
+    ///
+    /// Returns all the type constructors with their arities, or `None` if the type values
+    /// cannot be listed, e.g. for integers.
+    ///
+    fn constructors(r#type: &Type) -> Option<Vec<(Constructor, usize)>> {
+        match r#type {
+            Type::Boolean => Some(vec![
+                (Constructor::Boolean(false), 0),
+                (Constructor::Boolean(true), 0),
+            ]),
+            Type::Enumeration(enumeration) => Some(
+                enumeration
+                    .values
+                    .iter()
+                    .map(|value| {
+                        let arity = enumeration
+                            .variant(value)
+                            .map(|variant| variant.fields.len())
+                            .unwrap_or_default();
+                        (Constructor::Integer(value.to_owned()), arity)
+                    })
+                    .collect(),
+            ),
+            Type::Tuple { types } => Some(vec![(Constructor::Tuple, types.len())]),
+            Type::Structure(structure) => Some(vec![(Constructor::Tuple, structure.fields.len())]),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the types of the `constructor` arguments.
+    ///
+    fn arguments(r#type: &Type, constructor: &Constructor) -> Vec<Type> {
+        match (r#type, constructor) {
+            (Type::Tuple { types }, Constructor::Tuple) => types.to_owned(),
+            (Type::Structure(structure), Constructor::Tuple) => structure
+                .fields
+                .iter()
+                .map(|(_name, r#type)| r#type.to_owned())
+                .collect(),
+            (Type::Enumeration(enumeration), Constructor::Integer(value)) => enumeration
+                .variant(value)
+                .map(|variant| {
+                    variant
+                        .fields
+                        .iter()
+                        .map(|(_name, r#type)| r#type.to_owned())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            _ => vec![],
         }
     }
 }
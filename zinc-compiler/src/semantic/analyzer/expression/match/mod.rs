@@ -10,10 +10,13 @@ use std::cell::RefCell;
 use std::convert::TryFrom;
 use std::rc::Rc;
 
+use crate::generator::binding::Binding as GeneratorBinding;
 use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
 use crate::generator::expression::operand::r#match::builder::Builder as GeneratorMatchExpressionBuilder;
+use crate::generator::expression::operand::r#match::check::Check as GeneratorCheck;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::generator::r#type::Type as GeneratorType;
+use crate::lexical::token::location::Location;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
@@ -21,19 +24,43 @@ use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::enumeration::error::Error as EnumerationTypeError;
+use crate::semantic::element::r#type::enumeration::variant::Variant as EnumerationVariant;
+use crate::semantic::element::r#type::enumeration::Enumeration as EnumerationType;
+use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::flow::Kind as FlowKind;
 use crate::semantic::scope::item::variant::variable::Variable as ScopeVariableItem;
+use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
 use crate::syntax::tree::expression::r#match::Expression as MatchExpression;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::pattern_match::variant::Variant as MatchPatternVariant;
+use crate::syntax::tree::pattern_match::Pattern as MatchPattern;
+use crate::warning::Warning;
 
+use self::exhausting::Constructor as ExhaustingConstructor;
 use self::exhausting::Data as ExhaustingData;
+use self::exhausting::Pattern as ExhaustingPattern;
 
 pub struct Analyzer {}
 
+///
+/// The scrutinee cell checks and the variable bindings of a branch pattern.
+///
+/// The bindings are the variable identifier, the offset within the scrutinee, the type, and
+/// whether the value is stored in an enumeration variant payload.
+///
+#[derive(Default)]
+struct Branch {
+    checks: Vec<GeneratorCheck>,
+    bindings: Vec<(Identifier, usize, Type, bool)>,
+}
+
 impl Analyzer {
     ///
     /// Analyzes the match expression.
@@ -53,21 +80,28 @@ impl Analyzer {
         let mut scope_stack = ScopeStack::new(scope);
 
         let scrutinee_location = r#match.scrutinee.location;
+        Scope::enter_flow(scope_stack.top());
         let (scrutinee_result, scrutinee_expression) =
             ExpressionAnalyzer::new(scope_stack.top())
                 .analyze(r#match.scrutinee, TranslationHint::Value)?;
+        Scope::leave_flow(scope_stack.top(), FlowKind::Condition);
         let scrutinee_type = Type::from_element(&scrutinee_result, scope_stack.top())?;
-        if scrutinee_type.is_scalar() {
-            builder.set_scrutinee(
-                scrutinee_expression,
-                GeneratorType::try_from_semantic(&scrutinee_type)
-                    .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
-            );
-        } else {
-            return Err(Error::MatchScrutineeInvalidType {
-                location: scrutinee_location,
-                found: scrutinee_type.to_string(),
-            });
+        let is_destructurable = match scrutinee_type {
+            Type::Enumeration(ref enumeration) => enumeration.is_algebraic(),
+            Type::Tuple { .. } => true,
+            Type::Structure(_) => true,
+            _ => false,
+        };
+        match GeneratorType::try_from_semantic(&scrutinee_type) {
+            Some(r#type) if scrutinee_type.is_scalar() || is_destructurable => {
+                builder.set_scrutinee(scrutinee_expression, r#type)
+            }
+            _ => {
+                return Err(Error::MatchScrutineeInvalidType {
+                    location: scrutinee_location,
+                    found: scrutinee_type.to_string(),
+                });
+            }
         }
 
         if r#match.branches.len() < 2 {
@@ -75,202 +109,560 @@ impl Analyzer {
         }
 
         let first_branch_expression_location = r#match.branches[0].1.location;
-        let mut is_exhausted = false;
-        let mut exhausting_data = ExhaustingData::new();
+        let mut exhausting_data = ExhaustingData::new(scrutinee_type.clone());
+        let mut pattern_locations = Vec::with_capacity(r#match.branches.len());
         let mut branch_results = Vec::with_capacity(r#match.branches.len());
 
         for (pattern, expression) in r#match.branches.into_iter() {
             let pattern_location = pattern.location;
             let expression_location = expression.location;
 
-            if is_exhausted {
-                return Err(Error::MatchBranchUnreachable {
-                    location: pattern.location,
-                });
+            let mut is_reachable = !exhausting_data.is_exhausted();
+
+            let head_location = match pattern.variant {
+                MatchPatternVariant::Path(ref path) => path.location,
+                MatchPatternVariant::EnumerationTuple { ref path, .. } => path.location,
+                MatchPatternVariant::Structure { ref path, .. } => path.location,
+                _ => pattern_location,
+            };
+            let binding_name = match pattern.variant {
+                MatchPatternVariant::Binding(ref identifier) => Some(identifier.name.to_owned()),
+                _ => None,
+            };
+
+            let mut branch = Branch::default();
+            let exhausting_pattern = Self::pattern(
+                scope_stack.top(),
+                pattern,
+                &scrutinee_type,
+                0,
+                false,
+                scrutinee_location,
+                &mut branch,
+            )?;
+
+            if is_reachable && !exhausting_data.is_useful(&exhausting_pattern) {
+                if let Some(index) = exhausting_data.position(&exhausting_pattern) {
+                    return Err(Error::MatchBranchDuplicate {
+                        location: head_location,
+                        reference: pattern_locations[index],
+                    });
+                }
+                is_reachable = false;
+            }
+            if is_reachable {
+                exhausting_data.push(exhausting_pattern);
+                pattern_locations.push(head_location);
+            } else {
+                Warning::UnreachablePattern {
+                    location: pattern_location,
+                }
+                .emit();
             }
 
-            let result = match pattern.variant {
-                MatchPatternVariant::BooleanLiteral(boolean) => {
-                    let location = boolean.location;
+            let mut bindings = Vec::with_capacity(branch.bindings.len());
+            scope_stack.push();
+            for (identifier, offset, r#type, is_casted) in branch.bindings.into_iter() {
+                let location = identifier.location;
+
+                if binding_name.is_none() {
+                    bindings.push(GeneratorBinding::new(
+                        identifier.name.to_owned(),
+                        offset,
+                        GeneratorType::try_from_semantic(&r#type)
+                            .unwrap_or_else(GeneratorType::unit),
+                        is_casted,
+                    ));
+                }
+                Scope::declare_variable(
+                    scope_stack.top(),
+                    identifier,
+                    ScopeVariableItem::new(false, r#type),
+                )
+                .map_err(|error| Error::Scope(location, error))?;
+            }
+            let (result, branch_expression) = ExpressionAnalyzer::new(scope_stack.top())
+                .analyze(expression, TranslationHint::Value)?;
+            scope_stack.pop();
+
+            match binding_name {
+                _ if !is_reachable => {}
+                Some(binding_name) => builder.set_binding_branch(branch_expression, binding_name),
+                None if exhausting_data.is_exhausted() => {
+                    builder.set_wildcard_branch(bindings, branch_expression)
+                }
+                None => builder.push_branch(branch.checks, bindings, branch_expression),
+            }
 
-                    let constant = BooleanConstant::from(boolean);
-                    let pattern_type = constant.r#type();
-                    if pattern_type != scrutinee_type {
-                        return Err(Error::MatchBranchPatternInvalidType {
-                            location: pattern_location,
-                            expected: scrutinee_type.to_string(),
-                            found: pattern_type.to_string(),
-                            reference: scrutinee_location,
-                        });
-                    }
+            let result_type = Type::from_element(&result, scope_stack.top())?;
+            if let Some(first_branch_result) = branch_results.get(0) {
+                let first_branch_result_type =
+                    Type::from_element(first_branch_result, scope_stack.top())?;
+                if result_type != first_branch_result_type {
+                    return Err(Error::MatchBranchExpressionInvalidType {
+                        location: expression_location,
+                        expected: first_branch_result_type.to_string(),
+                        found: result_type.to_string(),
+                        reference: first_branch_expression_location,
+                    });
+                }
+            }
 
-                    if let Some(duplicate) =
-                        exhausting_data.insert_boolean(constant.inner, location)
-                    {
-                        return Err(Error::MatchBranchDuplicate {
-                            location,
-                            reference: duplicate,
-                        });
-                    }
+            branch_results.push(result);
+        }
+        Scope::leave_flow_condition(scope_stack.top());
 
-                    let constant =
-                        GeneratorConstant::try_from_semantic(&Constant::Boolean(constant))
-                            .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
-                    let (result, branch) = ExpressionAnalyzer::new(scope_stack.top())
-                        .analyze(expression, TranslationHint::Value)?;
-
-                    if exhausting_data.is_exhausted_boolean() {
-                        is_exhausted = true;
-                        builder.set_wildcard_branch(branch);
-                    } else {
-                        builder.push_branch(constant, branch);
-                    }
+        if !exhausting_data.is_exhausted() {
+            return Err(Error::MatchNotExhausted { location });
+        }
 
-                    result
-                }
-                MatchPatternVariant::IntegerLiteral(integer) => {
-                    let location = integer.location;
+        let element = match branch_results.pop() {
+            Some(result) => result,
+            None => Element::Constant(Constant::Unit),
+        };
+        let intermediate = GeneratorExpressionOperand::Match(builder.finish());
 
-                    let constant = IntegerConstant::try_from(&integer).map_err(|error| {
-                        Error::Element(
-                            location,
-                            ElementError::Constant(ConstantError::Integer(error)),
+        Ok((element, intermediate))
+    }
+
+    ///
+    /// Analyzes the branch pattern matched against the scrutinee part of the `type` located at
+    /// `offset`.
+    ///
+    /// The `is_casted` flag is set for the parts stored in an enumeration variant payload.
+    ///
+    /// Returns the pattern for the exhaustiveness check and collects the checks and bindings
+    /// into the `branch`.
+    ///
+    fn pattern(
+        scope: Rc<RefCell<Scope>>,
+        pattern: MatchPattern,
+        r#type: &Type,
+        offset: usize,
+        is_casted: bool,
+        scrutinee_location: Location,
+        branch: &mut Branch,
+    ) -> Result<ExhaustingPattern, Error> {
+        let pattern_location = pattern.location;
+
+        match pattern.variant {
+            MatchPatternVariant::BooleanLiteral(boolean) => {
+                let constant = BooleanConstant::from(boolean);
+                Self::pattern_constant(
+                    Constant::Boolean(constant),
+                    r#type,
+                    offset,
+                    is_casted,
+                    pattern_location,
+                    scrutinee_location,
+                    branch,
+                )
+            }
+            MatchPatternVariant::IntegerLiteral(integer) => {
+                let location = integer.location;
+
+                let constant = IntegerConstant::try_from(&integer).map_err(|error| {
+                    Error::Element(
+                        location,
+                        ElementError::Constant(ConstantError::Integer(error)),
+                    )
+                })?;
+                Self::pattern_constant(
+                    Constant::Integer(constant),
+                    r#type,
+                    offset,
+                    is_casted,
+                    pattern_location,
+                    scrutinee_location,
+                    branch,
+                )
+            }
+            MatchPatternVariant::Path(path) => {
+                let location = path.location;
+
+                match Self::pattern_path(scope, path)? {
+                    ScopeItemVariant::Constant(constant) => {
+                        if let Constant::Integer(ref integer) = constant {
+                            if let Some(ref enumeration) = integer.enumeration {
+                                let variant = enumeration
+                                    .variant(&integer.value)
+                                    .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+                                if !variant.fields.is_empty() && &constant.r#type() == r#type {
+                                    return Err(Self::variant_error(
+                                        location,
+                                        EnumerationTypeError::VariantPayloadMissing {
+                                            type_identifier: enumeration.identifier.to_owned(),
+                                            variant_name: variant.name.to_owned(),
+                                        },
+                                    ));
+                                }
+                            }
+                        }
+
+                        Self::pattern_constant(
+                            constant,
+                            r#type,
+                            offset,
+                            is_casted,
+                            pattern_location,
+                            scrutinee_location,
+                            branch,
                         )
-                    })?;
-                    let pattern_type = constant.r#type();
-                    if pattern_type != scrutinee_type {
-                        return Err(Error::MatchBranchPatternInvalidType {
+                    }
+                    item => Err(Error::MatchBranchPatternPathExpectedConstant {
+                        location,
+                        found: item.to_string(),
+                    }),
+                }
+            }
+            MatchPatternVariant::Tuple(elements) => {
+                let types = match r#type {
+                    Type::Tuple { types } => types,
+                    r#type => {
+                        return Err(Error::PatternTupleExpected {
                             location: pattern_location,
-                            expected: scrutinee_type.to_string(),
-                            found: pattern_type.to_string(),
-                            reference: scrutinee_location,
+                            found: r#type.to_string(),
                         });
                     }
+                };
+                if elements.len() != types.len() {
+                    return Err(Error::PatternTupleElementCount {
+                        location: pattern_location,
+                        expected: types.len(),
+                        found: elements.len(),
+                    });
+                }
 
-                    if let Some(duplicate) =
-                        exhausting_data.insert_integer(constant.value.clone(), None, location)
-                    {
-                        return Err(Error::MatchBranchDuplicate {
+                let mut arguments = Vec::with_capacity(elements.len());
+                let mut offset = offset;
+                for (element, r#type) in elements.into_iter().zip(types.iter()) {
+                    arguments.push(Self::pattern(
+                        scope.clone(),
+                        element,
+                        r#type,
+                        offset,
+                        is_casted,
+                        scrutinee_location,
+                        branch,
+                    )?);
+                    offset += r#type.size();
+                }
+
+                Ok(ExhaustingPattern::Constructor(
+                    ExhaustingConstructor::Tuple,
+                    arguments,
+                ))
+            }
+            MatchPatternVariant::EnumerationTuple { path, elements } => {
+                let location = path.location;
+
+                let (enumeration, variant) = match Self::pattern_path(scope.clone(), path)? {
+                    ScopeItemVariant::Constant(Constant::Integer(integer)) => {
+                        Self::pattern_variant(
+                            integer,
+                            r#type,
+                            pattern_location,
+                            scrutinee_location,
+                        )?
+                    }
+                    item => {
+                        return Err(Error::MatchBranchPatternPathExpectedConstant {
                             location,
-                            reference: duplicate,
+                            found: item.to_string(),
                         });
                     }
+                };
+                if !variant.is_tuple {
+                    return Err(Self::variant_error(
+                        location,
+                        EnumerationTypeError::VariantNotTuple {
+                            type_identifier: enumeration.identifier,
+                            variant_name: variant.name,
+                        },
+                    ));
+                }
+                if elements.len() != variant.fields.len() {
+                    return Err(Self::variant_error(
+                        location,
+                        EnumerationTypeError::VariantFieldCount {
+                            type_identifier: enumeration.identifier,
+                            variant_name: variant.name,
+                            expected: variant.fields.len(),
+                            found: elements.len(),
+                        },
+                    ));
+                }
 
-                    let constant =
-                        GeneratorConstant::try_from_semantic(&Constant::Integer(constant))
-                            .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
-                    let (result, branch) = ExpressionAnalyzer::new(scope_stack.top())
-                        .analyze(expression, TranslationHint::Value)?;
-
-                    if exhausting_data.is_exhausted_integer() {
-                        is_exhausted = true;
-                        builder.set_wildcard_branch(branch);
-                    } else {
-                        builder.push_branch(constant, branch);
-                    }
-
-                    result
+                branch.checks.push(GeneratorCheck::new(
+                    offset,
+                    Self::variant_tag(&enumeration, &variant),
+                    is_casted,
+                ));
+
+                let mut arguments = Vec::with_capacity(elements.len());
+                for (index, element) in elements.into_iter().enumerate() {
+                    let (field_offset, field_type) = variant
+                        .field(index.to_string().as_str())
+                        .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+                    arguments.push(Self::pattern(
+                        scope.clone(),
+                        element,
+                        &field_type,
+                        offset + field_offset,
+                        true,
+                        scrutinee_location,
+                        branch,
+                    )?);
                 }
-                MatchPatternVariant::Path(path) => {
-                    let location = path.location;
 
-                    let constant = match ExpressionAnalyzer::new(scope_stack.top())
-                        .analyze(path, TranslationHint::Value)?
+                Ok(ExhaustingPattern::Constructor(
+                    ExhaustingConstructor::Integer(variant.value),
+                    arguments,
+                ))
+            }
+            MatchPatternVariant::Structure { path, fields } => {
+                let location = path.location;
+
+                match Self::pattern_path(scope.clone(), path)? {
+                    ScopeItemVariant::Constant(Constant::Integer(integer)) => {
+                        let (enumeration, variant) = Self::pattern_variant(
+                            integer,
+                            r#type,
+                            pattern_location,
+                            scrutinee_location,
+                        )?;
+                        if variant.is_tuple || variant.fields.is_empty() {
+                            return Err(Self::variant_error(
+                                location,
+                                EnumerationTypeError::VariantNotStructure {
+                                    type_identifier: enumeration.identifier,
+                                    variant_name: variant.name,
+                                },
+                            ));
+                        }
+
+                        branch.checks.push(GeneratorCheck::new(
+                            offset,
+                            Self::variant_tag(&enumeration, &variant),
+                            is_casted,
+                        ));
+
+                        let mut arguments = vec![ExhaustingPattern::Wildcard; variant.fields.len()];
+                        let mut field_names: Vec<String> = Vec::with_capacity(fields.len());
+                        for (field, pattern) in fields.into_iter() {
+                            let index = variant
+                                .fields
+                                .iter()
+                                .position(|(name, _type)| name == &field.name);
+                            let (index, (field_offset, field_type)) =
+                                match (index, variant.field(field.name.as_str())) {
+                                    (Some(index), Some(field)) => (index, field),
+                                    _ => {
+                                        return Err(Self::variant_error(
+                                            field.location,
+                                            EnumerationTypeError::VariantFieldDoesNotExist {
+                                                type_identifier: enumeration.identifier,
+                                                variant_name: variant.name,
+                                                field_name: field.name,
+                                            },
+                                        ));
+                                    }
+                                };
+                            if field_names.contains(&field.name) {
+                                return Err(Self::variant_error(
+                                    field.location,
+                                    EnumerationTypeError::DuplicateField {
+                                        type_identifier: enumeration.identifier,
+                                        variant_name: variant.name,
+                                        field_name: field.name,
+                                    },
+                                ));
+                            }
+                            field_names.push(field.name);
+
+                            arguments[index] = Self::pattern(
+                                scope.clone(),
+                                pattern,
+                                &field_type,
+                                offset + field_offset,
+                                true,
+                                scrutinee_location,
+                                branch,
+                            )?;
+                        }
+
+                        Ok(ExhaustingPattern::Constructor(
+                            ExhaustingConstructor::Integer(variant.value),
+                            arguments,
+                        ))
+                    }
+                    ScopeItemVariant::Type(Type::Structure(structure))
+                        if &Type::Structure(structure.clone()) == r#type =>
                     {
-                        (Element::Constant(constant), _intermediate) => {
-                            if let Constant::Integer(ref integer) = constant {
-                                if let Some(duplicate) = exhausting_data.insert_integer(
-                                    integer.value.to_owned(),
-                                    integer.enumeration.to_owned(),
-                                    location,
-                                ) {
-                                    return Err(Error::MatchBranchDuplicate {
-                                        location,
-                                        reference: duplicate,
+                        let mut arguments =
+                            vec![ExhaustingPattern::Wildcard; structure.fields.len()];
+                        let mut field_names: Vec<String> = Vec::with_capacity(fields.len());
+                        for (field, pattern) in fields.into_iter() {
+                            let index = match structure
+                                .fields
+                                .iter()
+                                .position(|(name, _type)| name == &field.name)
+                            {
+                                Some(index) => index,
+                                None => {
+                                    return Err(Error::PatternStructureFieldDoesNotExist {
+                                        location: field.location,
+                                        type_identifier: structure.identifier.to_owned(),
+                                        field_name: field.name,
                                     });
                                 }
+                            };
+                            if field_names.contains(&field.name) {
+                                return Err(Error::PatternStructureDuplicateField {
+                                    location: field.location,
+                                    type_identifier: structure.identifier.to_owned(),
+                                    field_name: field.name,
+                                });
                             }
-                            constant
+                            field_names.push(field.name);
+
+                            let field_offset: usize = structure.fields[..index]
+                                .iter()
+                                .map(|(_name, r#type)| r#type.size())
+                                .sum();
+                            arguments[index] = Self::pattern(
+                                scope.clone(),
+                                pattern,
+                                &structure.fields[index].1,
+                                offset + field_offset,
+                                is_casted,
+                                scrutinee_location,
+                                branch,
+                            )?;
                         }
-                        (element, _intermediate) => {
-                            return Err(Error::MatchBranchPatternPathExpectedConstant {
-                                location,
-                                found: element.to_string(),
-                            });
-                        }
-                    };
-
-                    let constant = GeneratorConstant::try_from_semantic(&constant)
-                        .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
-                    let (result, branch) = ExpressionAnalyzer::new(scope_stack.top())
-                        .analyze(expression, TranslationHint::Value)?;
-
-                    if exhausting_data.is_exhausted_integer() {
-                        is_exhausted = true;
-                        builder.set_wildcard_branch(branch);
-                    } else {
-                        builder.push_branch(constant, branch);
-                    }
 
-                    result
+                        Ok(ExhaustingPattern::Constructor(
+                            ExhaustingConstructor::Tuple,
+                            arguments,
+                        ))
+                    }
+                    item => Err(Error::PatternStructureInvalidType {
+                        location: pattern_location,
+                        expected: r#type.to_string(),
+                        found: item.to_string(),
+                    }),
                 }
-                MatchPatternVariant::Binding(identifier) => {
-                    let location = identifier.location;
-                    is_exhausted = true;
-
-                    scope_stack.push();
-                    Scope::declare_variable(
-                        scope_stack.top(),
-                        identifier.clone(),
-                        ScopeVariableItem::new(false, scrutinee_type.clone()),
-                    )
-                    .map_err(|error| Error::Scope(location, error))?;
-                    let (result, branch) = ExpressionAnalyzer::new(scope_stack.top())
-                        .analyze(expression, TranslationHint::Value)?;
-                    scope_stack.pop();
+            }
+            MatchPatternVariant::Binding(identifier) => {
+                branch
+                    .bindings
+                    .push((identifier, offset, r#type.to_owned(), is_casted));
+                Ok(ExhaustingPattern::Wildcard)
+            }
+            MatchPatternVariant::Wildcard => Ok(ExhaustingPattern::Wildcard),
+        }
+    }
 
-                    builder.set_binding_branch(branch, identifier.name);
+    ///
+    /// Checks the constant pattern type and adds the scrutinee part comparison to the `branch`.
+    ///
+    fn pattern_constant(
+        constant: Constant,
+        r#type: &Type,
+        offset: usize,
+        is_casted: bool,
+        pattern_location: Location,
+        scrutinee_location: Location,
+        branch: &mut Branch,
+    ) -> Result<ExhaustingPattern, Error> {
+        let pattern_type = constant.r#type();
+        if &pattern_type != r#type {
+            return Err(Error::MatchBranchPatternInvalidType {
+                location: pattern_location,
+                expected: r#type.to_string(),
+                found: pattern_type.to_string(),
+                reference: scrutinee_location,
+            });
+        }
 
-                    result
-                }
-                MatchPatternVariant::Wildcard => {
-                    is_exhausted = true;
-                    let (result, branch) = ExpressionAnalyzer::new(scope_stack.top())
-                        .analyze(expression, TranslationHint::Value)?;
+        let constructor = match constant {
+            Constant::Boolean(ref boolean) => ExhaustingConstructor::Boolean(boolean.inner),
+            Constant::Integer(ref integer) => {
+                ExhaustingConstructor::Integer(integer.value.to_owned())
+            }
+            ref constant => {
+                return Err(Error::MatchBranchPatternPathExpectedConstant {
+                    location: pattern_location,
+                    found: constant.to_string(),
+                });
+            }
+        };
 
-                    builder.set_wildcard_branch(branch);
+        branch.checks.push(GeneratorCheck::new(
+            offset,
+            GeneratorConstant::try_from_semantic(&constant)
+                .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+            is_casted,
+        ));
 
-                    result
-                }
-            };
+        Ok(ExhaustingPattern::Constructor(constructor, vec![]))
+    }
 
-            let result_type = Type::from_element(&result, scope_stack.top())?;
-            if let Some(first_branch_result) = branch_results.get(0) {
-                let first_branch_result_type =
-                    Type::from_element(first_branch_result, scope_stack.top())?;
-                if result_type != first_branch_result_type {
-                    return Err(Error::MatchBranchExpressionInvalidType {
-                        location: expression_location,
-                        expected: first_branch_result_type.to_string(),
-                        found: result_type.to_string(),
-                        reference: first_branch_expression_location,
-                    });
-                }
-            }
+    ///
+    /// Resolves the pattern path, e.g. `Op::Burn` or `module::CONSTANT`, to a scope item.
+    ///
+    fn pattern_path(
+        scope: Rc<RefCell<Scope>>,
+        path: ExpressionTree,
+    ) -> Result<ScopeItemVariant, Error> {
+        let location = path.location;
 
-            branch_results.push(result);
+        match ExpressionAnalyzer::new(scope.clone()).analyze(path, TranslationHint::Path)? {
+            (Element::Path(path), _intermediate) => {
+                Scope::resolve_path(scope, &path).map(|item| item.variant)
+            }
+            (element, _intermediate) => Err(Error::MatchBranchPatternPathExpectedConstant {
+                location,
+                found: element.to_string(),
+            }),
         }
+    }
 
-        if !is_exhausted {
-            return Err(Error::MatchNotExhausted { location });
+    ///
+    /// Checks that the resolved variant constant belongs to the matched enumeration.
+    ///
+    fn pattern_variant(
+        integer: IntegerConstant,
+        r#type: &Type,
+        pattern_location: Location,
+        scrutinee_location: Location,
+    ) -> Result<(EnumerationType, EnumerationVariant), Error> {
+        let pattern_type = integer.r#type();
+        match (integer.enumeration, r#type) {
+            (Some(enumeration), Type::Enumeration(expected)) if &enumeration == expected => {
+                let variant = enumeration
+                    .variant(&integer.value)
+                    .cloned()
+                    .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+                Ok((enumeration, variant))
+            }
+            _ => Err(Error::MatchBranchPatternInvalidType {
+                location: pattern_location,
+                expected: r#type.to_string(),
+                found: pattern_type.to_string(),
+                reference: scrutinee_location,
+            }),
         }
+    }
 
-        let element = match branch_results.pop() {
-            Some(result) => result,
-            None => Element::Constant(Constant::Unit),
-        };
-        let intermediate = GeneratorExpressionOperand::Match(builder.finish());
+    fn variant_tag(
+        enumeration: &EnumerationType,
+        variant: &EnumerationVariant,
+    ) -> GeneratorConstant {
+        GeneratorConstant::new_integer(variant.value.to_owned(), false, enumeration.bitlength)
+    }
 
-        Ok((element, intermediate))
+    fn variant_error(location: Location, error: EnumerationTypeError) -> Error {
+        Error::Element(location, ElementError::Type(TypeError::Enumeration(error)))
     }
 }
@@ -10,9 +10,13 @@ use std::rc::Rc;
 
 use crate::error::Error;
 use crate::lexical::token::location::Location;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::enumeration::error::Error as EnumerationTypeError;
+use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
 use crate::semantic::scope::Scope;
+use crate::warning::Warning;
 
 static PANIC_COMPILE_DEPENDENCY: &str = "Dependencies must be successfully compiled";
 
@@ -80,11 +84,11 @@ fn main() {
 }
 
 #[test]
-fn error_match_branch_unreachable() {
+fn warning_match_branch_unreachable() {
     let input = r#"
 fn main() {
     let scrutinee = 42;
-    let result = match scrutinee {
+    let _result = match scrutinee {
         1 => 10,
         _ => 101,
         2 => 20,
@@ -92,21 +96,21 @@ fn main() {
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::MatchBranchUnreachable {
+    let expected = Ok(vec![Warning::UnreachablePattern {
         location: Location::new(7, 9),
-    }));
+    }]);
 
-    let result = crate::semantic::tests::compile_entry(input);
+    let result = crate::semantic::tests::compile_entry_warnings(input);
 
     assert_eq!(result, expected);
 }
 
 #[test]
-fn error_match_branch_unreachable_exhausted_boolean() {
+fn warning_match_branch_unreachable_exhausted_boolean() {
     let input = r#"
 fn main() {
     let scrutinee = true;
-    let result = match scrutinee {
+    let _result = match scrutinee {
         false => 10,
         true => 101,
         _ => 20,
@@ -114,17 +118,17 @@ fn main() {
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::MatchBranchUnreachable {
+    let expected = Ok(vec![Warning::UnreachablePattern {
         location: Location::new(7, 9),
-    }));
+    }]);
 
-    let result = crate::semantic::tests::compile_entry(input);
+    let result = crate::semantic::tests::compile_entry_warnings(input);
 
     assert_eq!(result, expected);
 }
 
 #[test]
-fn error_match_branch_unreachable_exhausted_enumeration() {
+fn warning_match_branch_unreachable_exhausted_enumeration() {
     let input = r#"
 enum List {
     One = 1,
@@ -134,7 +138,7 @@ enum List {
 
 fn main() {
     let scrutinee = List::One;
-    let result = match scrutinee {
+    let _result = match scrutinee {
         List::One => 1,
         List::Two => 2,
         List::Three => 3,
@@ -143,11 +147,11 @@ fn main() {
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::MatchBranchUnreachable {
+    let expected = Ok(vec![Warning::UnreachablePattern {
         location: Location::new(14, 9),
-    }));
+    }]);
 
-    let result = crate::semantic::tests::compile_entry(input);
+    let result = crate::semantic::tests::compile_entry_warnings(input);
 
     assert_eq!(result, expected);
 }
@@ -155,7 +159,7 @@ fn main() {
 #[test]
 fn error_match_branch_pattern_path_expected_constant() {
     let module_1 = r#"
-type X = field;
+pub type X = field;
 "#;
 
     let binary = r#"
@@ -284,3 +288,307 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_match_branch_pattern_variant_not_tuple() {
+    let input = r#"
+enum Op {
+    Transfer { to: field, amount: u64 },
+    Noop = 10,
+}
+
+fn main(op: Op) -> u64 {
+    match op {
+        Op::Transfer(to, amount) => amount,
+        Op::Noop => 0 as u64,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(9, 11),
+        ElementError::Type(TypeError::Enumeration(
+            EnumerationTypeError::VariantNotTuple {
+                type_identifier: "Op".to_owned(),
+                variant_name: "Transfer".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_match_branch_pattern_variant_field_count() {
+    let input = r#"
+enum Op {
+    Burn(u64),
+    Noop = 10,
+}
+
+fn main(op: Op) -> u64 {
+    match op {
+        Op::Burn(amount, extra) => amount,
+        Op::Noop => 0 as u64,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(9, 11),
+        ElementError::Type(TypeError::Enumeration(
+            EnumerationTypeError::VariantFieldCount {
+                type_identifier: "Op".to_owned(),
+                variant_name: "Burn".to_owned(),
+                expected: 1,
+                found: 2,
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_match_branch_pattern_variant_field_does_not_exist() {
+    let input = r#"
+enum Op {
+    Transfer { to: field, amount: u64 },
+    Noop = 10,
+}
+
+fn main(op: Op) -> u64 {
+    match op {
+        Op::Transfer { value } => value,
+        Op::Noop => 0 as u64,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(9, 24),
+        ElementError::Type(TypeError::Enumeration(
+            EnumerationTypeError::VariantFieldDoesNotExist {
+                type_identifier: "Op".to_owned(),
+                variant_name: "Transfer".to_owned(),
+                field_name: "value".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_match_branch_pattern_variant_payload_missing() {
+    let input = r#"
+enum Op {
+    Burn(u64),
+    Noop = 10,
+}
+
+fn main(op: Op) -> u64 {
+    match op {
+        Op::Burn => 1 as u64,
+        Op::Noop => 0 as u64,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(9, 11),
+        ElementError::Type(TypeError::Enumeration(
+            EnumerationTypeError::VariantPayloadMissing {
+                type_identifier: "Op".to_owned(),
+                variant_name: "Burn".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_match_not_exhausted_algebraic_enumeration() {
+    let input = r#"
+enum Op {
+    Transfer { to: field, amount: u64 },
+    Burn(u64),
+    Noop = 10,
+}
+
+fn main(op: Op) -> u64 {
+    match op {
+        Op::Transfer { amount } => amount,
+        Op::Burn(amount) => amount,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchNotExhausted {
+        location: Location::new(9, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_match_branch_duplicate_algebraic_enumeration() {
+    let input = r#"
+enum Op {
+    Burn(u64),
+    Noop = 10,
+}
+
+fn main(op: Op) -> u64 {
+    match op {
+        Op::Burn(amount) => amount,
+        Op::Burn(_) => 0 as u64,
+        Op::Noop => 0 as u64,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchBranchDuplicate {
+        location: Location::new(10, 11),
+        reference: Location::new(9, 11),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_match_tuple_and_structure() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: bool,
+}
+
+enum Op {
+    Move(Point),
+    Noop = 10,
+}
+
+fn main(pair: (bool, bool), op: Op) -> u8 {
+    let a = match pair {
+        (true, true) => 1,
+        (false, flag) => 2,
+        (_, false) => 3,
+    };
+    let b = match op {
+        Op::Move(Point { x, y: true }) => x,
+        Op::Move(Point { y: false }) => 0,
+        Op::Noop => 0,
+    };
+    a + b
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn error_match_not_exhausted_tuple() {
+    let input = r#"
+fn main(pair: (bool, bool)) -> u8 {
+    match pair {
+        (true, true) => 1,
+        (false, _) => 2,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchNotExhausted {
+        location: Location::new(3, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_match_branch_unreachable_tuple() {
+    let input = r#"
+fn main(pair: (bool, bool)) -> u8 {
+    match pair {
+        (true, _) => 1,
+        (false, true) => 2,
+        (_, true) => 3,
+        _ => 4,
+    }
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnreachablePattern {
+        location: Location::new(6, 9),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_match_branch_duplicate_structure() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: bool,
+}
+
+fn main(point: Point) -> u8 {
+    match point {
+        Point { y: true, x } => x,
+        Point { x: value, y: true } => value,
+        _ => 0,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchBranchDuplicate {
+        location: Location::new(10, 9),
+        reference: Location::new(9, 9),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_match_pattern_tuple_element_count() {
+    let input = r#"
+fn main(pair: (bool, bool)) -> u8 {
+    match pair {
+        (true, true, true) => 1,
+        _ => 2,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::PatternTupleElementCount {
+        location: Location::new(4, 9),
+        expected: 2,
+        found: 3,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
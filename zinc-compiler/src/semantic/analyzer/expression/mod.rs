@@ -20,26 +20,38 @@ pub mod stack;
 pub mod structure;
 pub mod tuple;
 pub mod r#type;
+pub mod variant;
 
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use num_bigint::BigInt;
+
 use crate::generator::expression::operand::constant::Constant as GeneratorExpressionConstant;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::lexical::token::location::Location;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::place::element::Element as PlaceElement;
 use crate::semantic::element::place::error::Error as PlaceError;
 use crate::semantic::element::place::Place;
+use crate::semantic::element::r#type::enumeration::error::Error as EnumerationTypeError;
+use crate::semantic::element::r#type::enumeration::Enumeration as EnumerationType;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::error::Error as FunctionTypeError;
+use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
+use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
+use crate::syntax::tree::expression::structure::Expression as StructureExpression;
 use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
 use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
 use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
@@ -62,6 +74,7 @@ use self::stack::element::Element as StackElement;
 use self::stack::Stack as EvaluationStack;
 use self::structure::Analyzer as StructureAnalyzer;
 use self::tuple::Analyzer as TupleAnalyzer;
+use self::variant::Analyzer as VariantAnalyzer;
 
 ///
 /// The expression semantic analyzer.
@@ -73,6 +86,8 @@ pub struct Analyzer {
     evaluation_stack: EvaluationStack,
     intermediate: GeneratorExpression,
     is_next_call_builtin: bool,
+    is_next_field_method: bool,
+    next_call_receiver: Option<Element>,
 }
 
 impl Analyzer {
@@ -85,6 +100,8 @@ impl Analyzer {
             evaluation_stack: EvaluationStack::new(),
             intermediate: GeneratorExpression::new(),
             is_next_call_builtin: false,
+            is_next_field_method: false,
+            next_call_receiver: None,
         }
     }
 
@@ -465,19 +482,39 @@ impl Analyzer {
                 }
 
                 ExpressionOperator::Field => {
-                    self.left_local(tree.left, operator)?;
-                    self.right_local(tree.right, operator)?;
-                    let intermediate = self.field(tree.location)?;
-                    if let Some(intermediate) = intermediate {
-                        self.intermediate.push_operator(tree.location, intermediate);
+                    if self.is_next_field_method {
+                        self.is_next_field_method = false;
+                        self.receiver_local(tree.left)?;
+                        self.right_local(tree.right, operator)?;
+                        self.method(tree.location)?;
+                    } else {
+                        self.left_local(tree.left, operator)?;
+                        self.right_local(tree.right, operator)?;
+                        let intermediate = self.field(tree.location)?;
+                        if let Some(intermediate) = intermediate {
+                            self.intermediate.push_operator(tree.location, intermediate);
+                        }
                     }
                 }
 
                 ExpressionOperator::Call => {
+                    if let Some(ExpressionTreeNode::Operator(ExpressionOperator::Field)) =
+                        tree.left.as_ref().map(|left| left.value.as_ref())
+                    {
+                        self.is_next_field_method = true;
+                    }
                     self.left_local(tree.left, operator)?;
-                    self.right_local(tree.right, operator)?;
-                    let operator = self.call(tree.location)?;
-                    self.intermediate.push_operator(tree.location, operator);
+                    match self.variant_constructor()? {
+                        Some((enumeration, value)) => {
+                            let payload = self.right_global(tree.right, operator)?;
+                            self.variant_tuple(enumeration, value, payload, tree.location)?;
+                        }
+                        None => {
+                            self.right_local(tree.right, operator)?;
+                            let operator = self.call(tree.location)?;
+                            self.intermediate.push_operator(tree.location, operator);
+                        }
+                    }
                 }
                 ExpressionOperator::CallBuiltIn => {
                     self.is_next_call_builtin = true;
@@ -486,8 +523,36 @@ impl Analyzer {
 
                 ExpressionOperator::Path => {
                     self.left_local(tree.left, operator)?;
-                    self.right_local(tree.right, operator)?;
-                    self.path(tree.location)?;
+                    let right = match tree.right {
+                        Some(right) => {
+                            let right_location = right.location;
+                            match *right.value {
+                                ExpressionTreeNode::Operand(ExpressionOperand::Structure(
+                                    structure,
+                                )) if structure.is_struct => self
+                                    .variant_structure(structure, tree.location)?
+                                    .map(|structure| {
+                                        Box::new(ExpressionTree::new(
+                                            right_location,
+                                            ExpressionTreeNode::Operand(
+                                                ExpressionOperand::Structure(structure),
+                                            ),
+                                        ))
+                                    }),
+                                _ => Some(right),
+                            }
+                        }
+                        None => panic!(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
+                    };
+                    if let Some(right) = right {
+                        self.right_local(Some(right), operator)?;
+                        self.path(tree.location)?;
+                    }
+                }
+                ExpressionOperator::GenericArguments => {
+                    self.left_local(tree.left, operator)?;
+                    self.right_global(tree.right, operator)?;
+                    self.generic_arguments(tree.location)?;
                 }
             },
         }
@@ -583,6 +648,27 @@ impl Analyzer {
         Ok(intermediate)
     }
 
+    ///
+    /// Analyzes the method call receiver, which is the left operand of the field operator.
+    ///
+    /// The receiver is always evaluated as a value, since it is passed to the method as the
+    /// first argument, so its IR must precede the other arguments.
+    ///
+    pub fn receiver_local(&mut self, left: Option<Box<ExpressionTree>>) -> Result<(), Error> {
+        match left {
+            Some(left) => {
+                let (element, intermediate) = self.traverse(*left, TranslationHint::Value)?;
+
+                self.evaluation_stack.push(StackElement::Evaluated(element));
+                if let Some(intermediate) = intermediate {
+                    self.intermediate.push_operand(intermediate);
+                }
+            }
+            None => panic!(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
+        }
+        Ok(())
+    }
+
     ///
     /// Analyzes the assignment operation.
     ///
@@ -618,6 +704,7 @@ impl Analyzer {
                 }),
             ));
         }
+        Scope::mark_mutated(self.scope_stack.top(), place.identifier.as_str());
         if place.r#type != r#type {
             return Err(Error::Element(
                 location,
@@ -842,14 +929,89 @@ impl Analyzer {
         }
     }
 
+    ///
+    /// Analyzes the method resolution operation.
+    ///
+    /// The method is looked for in the scope of the receiver type. The receiver is saved to be
+    /// passed as the first argument by the following call operation.
+    ///
+    fn method(&mut self, location: Location) -> Result<(), Error> {
+        let (operand_2, _) = Self::evaluate(
+            self.scope_stack.top(),
+            self.evaluation_stack.pop(),
+            TranslationHint::Field,
+        )?;
+        let (operand_1, _) = Self::evaluate(
+            self.scope_stack.top(),
+            self.evaluation_stack.pop(),
+            TranslationHint::Value,
+        )?;
+
+        let identifier = match operand_2 {
+            Element::Identifier(identifier) => identifier,
+            element => {
+                return Err(Error::Element(
+                    location,
+                    ElementError::OperatorFieldSecondOperandExpectedIdentifier {
+                        found: element.to_string(),
+                    },
+                ))
+            }
+        };
+        let r#type = match operand_1 {
+            Element::Value(ref value) => value.r#type(),
+            Element::Constant(ref constant) => constant.r#type(),
+            element => {
+                return Err(Error::Element(
+                    location,
+                    ElementError::OperatorFieldFirstOperandExpectedPlaceOrEvaluable {
+                        found: element.to_string(),
+                    },
+                ))
+            }
+        };
+
+        let scope = match r#type {
+            Type::Structure(ref structure) => Some(structure.scope.clone()),
+            Type::Enumeration(ref enumeration) => Some(enumeration.scope.clone()),
+            _ => None,
+        };
+        let function = match scope
+            .and_then(|scope| Scope::resolve_item_local(scope, identifier.name.as_str()).ok())
+            .map(|item| item.variant)
+        {
+            Some(ScopeItemVariant::Type(Type::Function(function))) if function.is_method() => {
+                function
+            }
+            _ => {
+                return Err(Error::MethodNotFound {
+                    location: identifier.location,
+                    name: identifier.name,
+                    r#type: r#type.to_string(),
+                })
+            }
+        };
+
+        self.next_call_receiver = Some(operand_1);
+        self.evaluation_stack
+            .push(StackElement::Evaluated(Element::Type(Type::Function(
+                function,
+            ))));
+
+        Ok(())
+    }
+
     ///
     /// Analyzes the function call operation.
     ///
+    /// If the function is a method, the receiver saved by the method resolution operation is
+    /// passed as the first argument.
+    ///
     fn call(&mut self, location: Location) -> Result<GeneratorExpressionOperator, Error> {
         let is_call_builtin = self.is_next_call_builtin;
         self.is_next_call_builtin = false;
 
-        let (operand_2, _intermediate_2) = Self::evaluate(
+        let (mut operand_2, _intermediate_2) = Self::evaluate(
             self.scope_stack.top(),
             self.evaluation_stack.pop(),
             TranslationHint::Value,
@@ -860,6 +1022,12 @@ impl Analyzer {
             TranslationHint::Type,
         )?;
 
+        if let Some(receiver) = self.next_call_receiver.take() {
+            if let Element::ArgumentList(ref mut elements) = operand_2 {
+                elements.insert(0, receiver);
+            }
+        }
+
         let (element, operator) = CallAnalyzer::analyze(
             self.scope_stack.top(),
             operand_1,
@@ -873,6 +1041,133 @@ impl Analyzer {
         Ok(operator)
     }
 
+    ///
+    /// Checks whether the call operand on the top of the evaluation stack is a tuple-like
+    /// variant of an algebraic enumeration, e.g. `Op::Burn` in `Op::Burn(42)`.
+    ///
+    /// If it is, the operand is taken from the stack, and the variant tag is returned.
+    ///
+    fn variant_constructor(&mut self) -> Result<Option<(EnumerationType, BigInt)>, Error> {
+        let element = self.evaluation_stack.pop();
+
+        if let StackElement::Evaluated(Element::Path(ref path)) = element {
+            if let Ok(ScopeItem {
+                variant:
+                    ScopeItemVariant::Constant(Constant::Integer(IntegerConstant {
+                        enumeration: Some(enumeration),
+                        value,
+                        ..
+                    })),
+                ..
+            }) = Scope::resolve_path(self.scope_stack.top(), path)
+            {
+                if enumeration.is_algebraic() {
+                    return Ok(Some((enumeration, value)));
+                }
+            }
+        }
+
+        self.evaluation_stack.push(element);
+        Ok(None)
+    }
+
+    ///
+    /// Analyzes the tuple-like variant construction with the already translated `payload`.
+    ///
+    fn variant_tuple(
+        &mut self,
+        enumeration: EnumerationType,
+        value: BigInt,
+        payload: GeneratorExpression,
+        location: Location,
+    ) -> Result<(), Error> {
+        let (arguments, _) = Self::evaluate(
+            self.scope_stack.top(),
+            self.evaluation_stack.pop(),
+            TranslationHint::Value,
+        )?;
+
+        let (element, intermediate) = VariantAnalyzer::tuple(
+            self.scope_stack.top(),
+            location,
+            enumeration,
+            value,
+            arguments,
+            payload,
+        )?;
+
+        self.evaluation_stack.push(StackElement::Evaluated(element));
+        self.intermediate.push_operand(intermediate);
+
+        Ok(())
+    }
+
+    ///
+    /// Analyzes the structure-like variant construction, e.g. `Op::Transfer { to: 0, amount: 42 }`.
+    ///
+    /// If the path operand on the top of the evaluation stack is not an enumeration, the
+    /// `structure` is given back to be analyzed as an ordinar path element.
+    ///
+    fn variant_structure(
+        &mut self,
+        structure: StructureExpression,
+        location: Location,
+    ) -> Result<Option<StructureExpression>, Error> {
+        let (operand_1, _) = Self::evaluate(
+            self.scope_stack.top(),
+            self.evaluation_stack.pop(),
+            TranslationHint::Type,
+        )?;
+
+        let enumeration = match operand_1 {
+            Element::Type(Type::Enumeration(enumeration)) => enumeration,
+            operand_1 => {
+                self.evaluation_stack
+                    .push(StackElement::Evaluated(operand_1));
+                return Ok(Some(structure));
+            }
+        };
+
+        let identifier_location = structure.identifier.location;
+        let value = match Scope::resolve_item_local(
+            enumeration.scope.clone(),
+            structure.identifier.name.as_str(),
+        )
+        .map_err(|error| Error::Scope(identifier_location, error))?
+        .variant
+        {
+            ScopeItemVariant::Constant(Constant::Integer(integer))
+                if enumeration.is_algebraic() =>
+            {
+                integer.value
+            }
+            _ => {
+                return Err(Error::Element(
+                    identifier_location,
+                    ElementError::Type(TypeError::Enumeration(
+                        EnumerationTypeError::VariantNotStructure {
+                            type_identifier: enumeration.identifier,
+                            variant_name: structure.identifier.name,
+                        },
+                    )),
+                ));
+            }
+        };
+
+        let (element, intermediate) = VariantAnalyzer::structure(
+            self.scope_stack.top(),
+            location,
+            enumeration,
+            value,
+            structure,
+        )?;
+
+        self.evaluation_stack.push(StackElement::Evaluated(element));
+        self.intermediate.push_operand(intermediate);
+
+        Ok(None)
+    }
+
     ///
     /// Analyzes the path resolution operation.
     ///
@@ -895,6 +1190,56 @@ impl Analyzer {
         Ok(())
     }
 
+    ///
+    /// Analyzes the explicit generic arguments specification.
+    ///
+    fn generic_arguments(&mut self, location: Location) -> Result<(), Error> {
+        let (operand_2, _) = Self::evaluate(
+            self.scope_stack.top(),
+            self.evaluation_stack.pop(),
+            TranslationHint::Value,
+        )?;
+        let (operand_1, _) = Self::evaluate(
+            self.scope_stack.top(),
+            self.evaluation_stack.pop(),
+            TranslationHint::Type,
+        )?;
+
+        let mut arguments = Vec::new();
+        if let Element::ArgumentList(elements) = operand_2 {
+            for element in elements.into_iter() {
+                match element {
+                    Element::Type(r#type) => arguments.push(r#type),
+                    _ => panic!(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
+                }
+            }
+        }
+
+        let function = match operand_1 {
+            Element::Type(Type::Function(FunctionType::Generic(function))) => function
+                .with_arguments(arguments)
+                .map(FunctionType::Generic),
+            Element::Type(Type::Function(function)) => {
+                Err(FunctionTypeError::generic_argument_count(
+                    function.identifier(),
+                    0,
+                    arguments.len(),
+                ))
+            }
+            element => Err(FunctionTypeError::non_callable(element.to_string())),
+        }
+        .map_err(|error| {
+            Error::Element(location, ElementError::Type(TypeError::Function(error)))
+        })?;
+
+        self.evaluation_stack
+            .push(StackElement::Evaluated(Element::Type(Type::Function(
+                function,
+            ))));
+
+        Ok(())
+    }
+
     ///
     /// Evaluates the element, turning it to the state specified with `hint`.
     ///
@@ -10,6 +10,9 @@ use crate::generator::expression::operand::constant::Constant as GeneratorConsta
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::generator::r#type::Type as GeneratorType;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
+use crate::semantic::analyzer::expression::variant::Analyzer as VariantAnalyzer;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::path::Path;
 use crate::semantic::element::place::Place;
@@ -49,6 +52,10 @@ impl Translator {
                 ScopeItemVariant::Constant(constant) => Ok((Element::Constant(constant), None)),
                 ScopeItemVariant::Type(r#type) => Ok((Element::Type(r#type), None)),
                 ScopeItemVariant::Module(_) => Ok((Element::Module(path_last_element_name), None)),
+                ScopeItemVariant::Trait(_) => Err(Error::TraitExpectedValue {
+                    location,
+                    name: path_last_element_name,
+                }),
             },
             TranslationHint::Value => match Scope::resolve_path(scope, &path)?.variant {
                 ScopeItemVariant::Variable(variable) => {
@@ -70,6 +77,14 @@ impl Translator {
                     let element = Element::Value(value);
                     Ok((element, intermediate))
                 }
+                ScopeItemVariant::Constant(Constant::Integer(IntegerConstant {
+                    enumeration: Some(enumeration),
+                    value,
+                    ..
+                })) if enumeration.is_algebraic() => {
+                    VariantAnalyzer::unit(location, enumeration, value)
+                        .map(|(element, intermediate)| (element, Some(intermediate)))
+                }
                 ScopeItemVariant::Constant(constant) => {
                     let intermediate = GeneratorConstant::try_from_semantic(&constant)
                         .map(GeneratorExpressionOperand::Constant);
@@ -78,6 +93,10 @@ impl Translator {
                 }
                 ScopeItemVariant::Type(r#type) => Ok((Element::Type(r#type), None)),
                 ScopeItemVariant::Module(_) => Ok((Element::Module(path_last_element_name), None)),
+                ScopeItemVariant::Trait(_) => Err(Error::TraitExpectedValue {
+                    location,
+                    name: path_last_element_name,
+                }),
             },
 
             TranslationHint::Type => match Scope::resolve_path(scope, &path)?.variant {
@@ -19,6 +19,7 @@ use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
 use crate::semantic::scope::Scope;
+use crate::semantic::symbol::Table as SymbolTable;
 use crate::syntax::tree::expression::structure::Expression as StructureExpression;
 
 pub struct Analyzer {}
@@ -37,10 +38,11 @@ impl Analyzer {
 
         let mut builder = GeneratorGroupExpressionBuilder::default();
 
-        let structure_type = match Scope::resolve_item(scope.clone(), &structure.identifier.name)
-            .map_err(|error| Error::Scope(identifier_location, error))?
-            .variant
-        {
+        let item = Scope::resolve_item(scope.clone(), &structure.identifier.name)
+            .map_err(|error| Error::Scope(identifier_location, error))?;
+        SymbolTable::record_symbol(identifier_location, &structure.identifier.name, &item);
+
+        let structure_type = match item.variant {
             ScopeItemVariant::Type(Type::Structure(structure)) => structure,
             item => {
                 return Err(Error::Element(
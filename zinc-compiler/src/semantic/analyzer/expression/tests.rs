@@ -37,3 +37,55 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_method_not_found() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+impl Data {
+    fn new(value: u8) -> Self {
+        Data {
+            value: value,
+        }
+    }
+}
+
+fn main() {
+    let data = Data::new(42);
+    let result = data.new();
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MethodNotFound {
+        location: Location::new(16, 23),
+        name: "new".to_owned(),
+        r#type: Type::structure("Data".to_owned(), 1, vec![], None).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_method_not_found_primitive() {
+    let input = r#"
+fn main() {
+    let value = 42;
+    let result = value.sum();
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MethodNotFound {
+        location: Location::new(4, 24),
+        name: "sum".to_owned(),
+        r#type: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,244 @@
+//!
+//! The enumeration variant semantic analyzer.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use num_bigint::BigInt;
+
+use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
+use crate::generator::expression::operand::variant::Expression as GeneratorVariantExpression;
+use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::generator::expression::Expression as GeneratorExpression;
+use crate::lexical::token::location::Location;
+use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::enumeration::error::Error as EnumerationTypeError;
+use crate::semantic::element::r#type::enumeration::variant::Variant;
+use crate::semantic::element::r#type::enumeration::Enumeration;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::value::Value;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+use crate::syntax::tree::expression::structure::Expression as StructureExpression;
+
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Analyzes the payload-less variant of an algebraic enumeration, e.g. `Op::None`.
+    ///
+    /// Returns the semantic element and the intermediate representation.
+    ///
+    pub fn unit(
+        location: Location,
+        enumeration: Enumeration,
+        value: BigInt,
+    ) -> Result<(Element, GeneratorExpressionOperand), Error> {
+        let variant = Self::variant(&enumeration, &value);
+        if !variant.fields.is_empty() {
+            return Err(Self::error(
+                location,
+                EnumerationTypeError::VariantPayloadMissing {
+                    type_identifier: enumeration.identifier,
+                    variant_name: variant.name,
+                },
+            ));
+        }
+
+        Ok(Self::finish(location, enumeration, variant, vec![]))
+    }
+
+    ///
+    /// Analyzes the tuple-like variant construction, e.g. `Op::Burn(42)`.
+    ///
+    /// The `arguments` are the call argument list with its already translated `payload`.
+    ///
+    /// Returns the semantic element and the intermediate representation.
+    ///
+    pub fn tuple(
+        scope: Rc<RefCell<Scope>>,
+        location: Location,
+        enumeration: Enumeration,
+        value: BigInt,
+        arguments: Element,
+        payload: GeneratorExpression,
+    ) -> Result<(Element, GeneratorExpressionOperand), Error> {
+        let variant = Self::variant(&enumeration, &value);
+        if !variant.is_tuple {
+            return Err(Self::error(
+                location,
+                EnumerationTypeError::VariantNotTuple {
+                    type_identifier: enumeration.identifier,
+                    variant_name: variant.name,
+                },
+            ));
+        }
+
+        let arguments = match arguments {
+            Element::ArgumentList(arguments) => arguments,
+            _ => panic!(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
+        };
+        if arguments.len() != variant.fields.len() {
+            return Err(Self::error(
+                location,
+                EnumerationTypeError::VariantFieldCount {
+                    type_identifier: enumeration.identifier,
+                    variant_name: variant.name,
+                    expected: variant.fields.len(),
+                    found: arguments.len(),
+                },
+            ));
+        }
+
+        for (argument, (field_name, field_type)) in arguments.iter().zip(variant.fields.iter()) {
+            let argument_type = Type::from_element(argument, scope.clone())?;
+            if &argument_type != field_type {
+                return Err(Self::error(
+                    location,
+                    EnumerationTypeError::VariantFieldInvalidType {
+                        type_identifier: enumeration.identifier,
+                        variant_name: variant.name,
+                        field_name: field_name.to_owned(),
+                        expected: field_type.to_string(),
+                        found: argument_type.to_string(),
+                    },
+                ));
+            }
+        }
+
+        Ok(Self::finish(location, enumeration, variant, vec![payload]))
+    }
+
+    ///
+    /// Analyzes the structure-like variant construction, e.g. `Op::Transfer { to: 0, amount: 42 }`.
+    ///
+    /// The fields may be specified in any order, but are written in the declaration order.
+    ///
+    /// Returns the semantic element and the intermediate representation.
+    ///
+    pub fn structure(
+        scope: Rc<RefCell<Scope>>,
+        location: Location,
+        enumeration: Enumeration,
+        value: BigInt,
+        structure: StructureExpression,
+    ) -> Result<(Element, GeneratorExpressionOperand), Error> {
+        let variant = Self::variant(&enumeration, &value);
+        if variant.is_tuple || variant.fields.is_empty() {
+            return Err(Self::error(
+                location,
+                EnumerationTypeError::VariantNotStructure {
+                    type_identifier: enumeration.identifier,
+                    variant_name: variant.name,
+                },
+            ));
+        }
+
+        let mut payload: Vec<(usize, String, GeneratorExpression)> =
+            Vec::with_capacity(structure.fields.len());
+        for (identifier, expression) in structure.fields.into_iter() {
+            let identifier_location = identifier.location;
+
+            let (offset, field_type) = match variant.field(identifier.name.as_str()) {
+                Some(field) => field,
+                None => {
+                    return Err(Self::error(
+                        identifier_location,
+                        EnumerationTypeError::VariantFieldDoesNotExist {
+                            type_identifier: enumeration.identifier,
+                            variant_name: variant.name,
+                            field_name: identifier.name,
+                        },
+                    ));
+                }
+            };
+            if payload
+                .iter()
+                .any(|(_offset, name, _expression)| name == &identifier.name)
+            {
+                return Err(Self::error(
+                    identifier_location,
+                    EnumerationTypeError::DuplicateField {
+                        type_identifier: enumeration.identifier,
+                        variant_name: variant.name,
+                        field_name: identifier.name,
+                    },
+                ));
+            }
+
+            let (element, expression) = ExpressionAnalyzer::new(scope.clone())
+                .analyze(expression, TranslationHint::Value)?;
+            let element_type = Type::from_element(&element, scope.clone())?;
+            if element_type != field_type {
+                return Err(Self::error(
+                    identifier_location,
+                    EnumerationTypeError::VariantFieldInvalidType {
+                        type_identifier: enumeration.identifier,
+                        variant_name: variant.name,
+                        field_name: identifier.name,
+                        expected: field_type.to_string(),
+                        found: element_type.to_string(),
+                    },
+                ));
+            }
+
+            payload.push((offset, identifier.name, expression));
+        }
+
+        if payload.len() != variant.fields.len() {
+            return Err(Self::error(
+                location,
+                EnumerationTypeError::VariantFieldCount {
+                    type_identifier: enumeration.identifier,
+                    variant_name: variant.name,
+                    expected: variant.fields.len(),
+                    found: payload.len(),
+                },
+            ));
+        }
+
+        payload.sort_by_key(|(offset, _name, _expression)| *offset);
+        let payload = payload
+            .into_iter()
+            .map(|(_offset, _name, expression)| expression)
+            .collect();
+
+        Ok(Self::finish(location, enumeration, variant, payload))
+    }
+
+    fn variant(enumeration: &Enumeration, value: &BigInt) -> Variant {
+        enumeration
+            .variant(value)
+            .cloned()
+            .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS)
+    }
+
+    fn finish(
+        location: Location,
+        enumeration: Enumeration,
+        variant: Variant,
+        payload: Vec<GeneratorExpression>,
+    ) -> (Element, GeneratorExpressionOperand) {
+        let tag =
+            GeneratorConstant::new_integer(variant.value.clone(), false, enumeration.bitlength);
+        let intermediate = GeneratorExpressionOperand::Variant(GeneratorVariantExpression::new(
+            location,
+            tag,
+            payload,
+            variant.size(),
+            enumeration.size() - 1,
+        ));
+        let element = Element::Value(Value::Enumeration(enumeration));
+
+        (element, intermediate)
+    }
+
+    fn error(location: Location, error: EnumerationTypeError) -> Error {
+        Error::Element(location, ElementError::Type(TypeError::Enumeration(error)))
+    }
+}
@@ -5,4 +5,5 @@
 pub mod entry;
 pub mod expression;
 pub mod module;
+pub mod pattern;
 pub mod statement;
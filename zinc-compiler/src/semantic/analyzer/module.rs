@@ -9,9 +9,11 @@ use std::rc::Rc;
 use crate::error::Error as CompilerError;
 use crate::generator::Tree;
 use crate::semantic::analyzer::statement::Analyzer as StatementAnalyzer;
-use crate::semantic::scope::Scope;
 use crate::semantic::scope::stack::Stack as ScopeStack;
+use crate::semantic::scope::Scope;
+use crate::semantic::symbol::Table as SymbolTable;
 use crate::syntax::tree::Tree as SyntaxTree;
+use crate::warning::Warning;
 
 ///
 /// Analyzes a module, which are located in non-`main.zn` files.
@@ -35,23 +37,54 @@ impl Analyzer {
         }
     }
 
+    ///
+    /// Initializes an analyzer with the dependency packages declared in the global scope.
+    ///
+    pub fn new_with_packages(packages: HashMap<String, Rc<RefCell<Scope>>>) -> Self {
+        let analyzer = Self::new();
+        for (name, package) in packages.into_iter() {
+            Scope::declare_package(analyzer.scope_stack.top(), name, package);
+        }
+        analyzer
+    }
+
+    ///
+    /// Analyzes the module statements, going on after an invalid statement, so all the errors
+    /// of independent items are returned at once.
+    ///
     pub fn compile(
         self,
         program: SyntaxTree,
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
-    ) -> Result<(Rc<RefCell<Scope>>, Tree), CompilerError> {
+    ) -> Result<(Rc<RefCell<Scope>>, Tree), Vec<CompilerError>> {
         let mut intermediate = Tree::new();
 
+        let instances = Scope::instances(self.scope_stack.top());
         let mut analyzer = StatementAnalyzer::new(self.scope_stack.top(), dependencies);
+        Warning::take_all();
+        let mut errors = Vec::new();
         for statement in program.statements.into_iter() {
-            if let Some(statement) = analyzer
-                .local_mod(statement)
-                .map_err(CompilerError::Semantic)?
-            {
+            SymbolTable::record_scope(statement.location(), &self.scope_stack.top(), false);
+            let statement = match analyzer.local_mod(statement) {
+                Ok(statement) => statement,
+                Err(error) => {
+                    errors.push(CompilerError::Semantic(error));
+                    analyzer.recover();
+                    continue;
+                }
+            };
+            intermediate.statements.extend(instances.take());
+            if let Some(statement) = statement {
                 intermediate.statements.push(statement);
             }
         }
 
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        self.scope_stack.top().borrow().check_unused_items();
+
         Ok((self.scope_stack.top(), intermediate))
     }
 }
@@ -0,0 +1,197 @@
+//!
+//! The binding pattern semantic analyzer.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::generator::binding::Binding as GeneratorBinding;
+use crate::generator::r#type::Type as GeneratorType;
+use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+use crate::semantic::scope::item::variant::variable::Variable as ScopeVariableItem;
+use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
+use crate::semantic::scope::Scope;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Checks the binding pattern against the bound value type and declares the pattern
+    /// variables in the `scope`.
+    ///
+    /// Returns the generator bindings, which copy the value parts to the variables.
+    ///
+    pub fn declare(
+        scope: Rc<RefCell<Scope>>,
+        pattern: BindingPatternVariant,
+        r#type: &Type,
+    ) -> Result<Vec<GeneratorBinding>, Error> {
+        let mut variables = Vec::new();
+        Self::variables(scope.clone(), pattern, r#type, 0, &mut variables)?;
+
+        let mut bindings = Vec::with_capacity(variables.len());
+        for (identifier, is_mutable, offset, r#type) in variables.into_iter() {
+            let location = identifier.location;
+
+            bindings.push(GeneratorBinding::new(
+                identifier.name.to_owned(),
+                offset,
+                GeneratorType::try_from_semantic(&r#type).unwrap_or_else(GeneratorType::unit),
+                false,
+            ));
+            Scope::declare_variable(
+                scope.clone(),
+                identifier,
+                ScopeVariableItem::new(is_mutable, r#type),
+            )
+            .map_err(|error| Error::Scope(location, error))?;
+        }
+
+        Ok(bindings)
+    }
+
+    ///
+    /// Collects the pattern variables with their mutability, offset within the bound value,
+    /// and type.
+    ///
+    fn variables(
+        scope: Rc<RefCell<Scope>>,
+        pattern: BindingPatternVariant,
+        r#type: &Type,
+        offset: usize,
+        variables: &mut Vec<(Identifier, bool, usize, Type)>,
+    ) -> Result<(), Error> {
+        match pattern {
+            BindingPatternVariant::Binding(identifier) => {
+                variables.push((identifier, false, offset, r#type.to_owned()));
+            }
+            BindingPatternVariant::MutableBinding(identifier) => {
+                variables.push((identifier, true, offset, r#type.to_owned()));
+            }
+            BindingPatternVariant::Wildcard => {}
+            BindingPatternVariant::SelfAlias { location, .. } => {
+                variables.push((
+                    Identifier::new(location, Keyword::SelfLowercase.to_string()),
+                    false,
+                    offset,
+                    r#type.to_owned(),
+                ));
+            }
+            BindingPatternVariant::Tuple { location, elements } => {
+                let types = match r#type {
+                    Type::Tuple { types } => types,
+                    r#type => {
+                        return Err(Error::PatternTupleExpected {
+                            location,
+                            found: r#type.to_string(),
+                        });
+                    }
+                };
+                if elements.len() != types.len() {
+                    return Err(Error::PatternTupleElementCount {
+                        location,
+                        expected: types.len(),
+                        found: elements.len(),
+                    });
+                }
+
+                let mut offset = offset;
+                for (element, r#type) in elements.into_iter().zip(types.iter()) {
+                    Self::variables(scope.clone(), element, r#type, offset, variables)?;
+                    offset += r#type.size();
+                }
+            }
+            BindingPatternVariant::Structure {
+                location,
+                path,
+                fields,
+            } => {
+                let structure = match (r#type, Self::path(scope.clone(), path, r#type)?) {
+                    (Type::Structure(expected), Type::Structure(found)) if *expected == found => {
+                        found
+                    }
+                    (expected, found) => {
+                        return Err(Error::PatternStructureInvalidType {
+                            location,
+                            expected: expected.to_string(),
+                            found: found.to_string(),
+                        });
+                    }
+                };
+
+                let mut field_names: Vec<String> = Vec::with_capacity(fields.len());
+                for (field, pattern) in fields.into_iter() {
+                    let mut field_offset = offset;
+                    let mut field_type = None;
+                    for (name, r#type) in structure.fields.iter() {
+                        if name == &field.name {
+                            field_type = Some(r#type);
+                            break;
+                        }
+                        field_offset += r#type.size();
+                    }
+                    let field_type = match field_type {
+                        Some(r#type) => r#type,
+                        None => {
+                            return Err(Error::PatternStructureFieldDoesNotExist {
+                                location: field.location,
+                                type_identifier: structure.identifier.to_owned(),
+                                field_name: field.name,
+                            });
+                        }
+                    };
+                    if field_names.contains(&field.name) {
+                        return Err(Error::PatternStructureDuplicateField {
+                            location: field.location,
+                            type_identifier: structure.identifier.to_owned(),
+                            field_name: field.name,
+                        });
+                    }
+                    field_names.push(field.name);
+
+                    Self::variables(scope.clone(), pattern, field_type, field_offset, variables)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Resolves the structure pattern path, e.g. `geometry::Point`, to a type.
+    ///
+    fn path(
+        scope: Rc<RefCell<Scope>>,
+        path: ExpressionTree,
+        expected: &Type,
+    ) -> Result<Type, Error> {
+        let location = path.location;
+
+        match ExpressionAnalyzer::new(scope.clone()).analyze(path, TranslationHint::Path)? {
+            (Element::Path(path), _intermediate) => {
+                match Scope::resolve_path(scope, &path)?.variant {
+                    ScopeItemVariant::Type(r#type) => Ok(r#type),
+                    item => Err(Error::PatternStructureInvalidType {
+                        location,
+                        expected: expected.to_string(),
+                        found: item.to_string(),
+                    }),
+                }
+            }
+            (Element::Type(r#type), _intermediate) => Ok(r#type),
+            (element, _intermediate) => Err(Error::PatternStructureInvalidType {
+                location,
+                expected: expected.to_string(),
+                found: element.to_string(),
+            }),
+        }
+    }
+}
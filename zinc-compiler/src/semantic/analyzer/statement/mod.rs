@@ -11,34 +11,50 @@ use std::rc::Rc;
 
 use num_traits::ToPrimitive;
 
+use crate::generator::r#type::Type as GeneratorType;
 use crate::generator::statement::declaration::Statement as GeneratorDeclarationStatement;
+use crate::generator::statement::destructuring::Statement as GeneratorDestructuringStatement;
 use crate::generator::statement::function::Statement as GeneratorFunctionStatement;
+use crate::generator::statement::function_return::Statement as GeneratorFunctionReturnStatement;
+use crate::generator::statement::loop_control::Statement as GeneratorLoopControlStatement;
 use crate::generator::statement::loop_for::Statement as GeneratorForLoopStatement;
 use crate::generator::statement::Statement as GeneratorStatement;
+use crate::lexical::token::lexeme::keyword::Keyword;
 use crate::semantic::analyzer::expression::block::Analyzer as BlockAnalyzer;
 use crate::semantic::analyzer::expression::hint::Hint as TranslationHint;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::pattern::Analyzer as PatternAnalyzer;
 use crate::semantic::element::constant::error::Error as ConstantError;
 use crate::semantic::element::constant::integer::error::Error as IntegerConstantError;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionTypeError;
+use crate::semantic::element::r#type::function::generic::Argument as GenericArgument;
+use crate::semantic::element::r#type::function::generic::Function as GenericFunctionType;
 use crate::semantic::element::r#type::function::user::Function as UserDefinedFunctionType;
 use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::structure::error::Error as StructureTypeError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::r#type::INDEX as TYPE_INDEX;
+use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::error::Error as ScopeError;
+use crate::semantic::scope::flow::Kind as FlowKind;
+use crate::semantic::scope::item::variant::r#trait::Trait as ScopeTraitItem;
 use crate::semantic::scope::item::variant::variable::Variable as ScopeVariableItem;
 use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
+use crate::syntax::tree::generic_parameter::variant::Variant as GenericParameterVariant;
+use crate::syntax::tree::generic_parameter::Parameter as GenericParameter;
+use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
 use crate::syntax::tree::statement::local_fn::Statement as FunctionLocalStatement;
 use crate::syntax::tree::statement::local_impl::Statement as ImplementationLocalStatement;
 use crate::syntax::tree::statement::local_mod::Statement as ModuleLocalStatement;
+use crate::syntax::tree::statement::local_trait::Statement as TraitLocalStatement;
 use crate::syntax::tree::statement::module::Statement as ModStatement;
 use crate::syntax::tree::statement::r#const::Statement as ConstStatement;
 use crate::syntax::tree::statement::r#enum::Statement as EnumStatement;
@@ -46,9 +62,12 @@ use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
 use crate::syntax::tree::statement::r#for::Statement as ForStatement;
 use crate::syntax::tree::statement::r#impl::Statement as ImplStatement;
 use crate::syntax::tree::statement::r#let::Statement as LetStatement;
+use crate::syntax::tree::statement::r#return::Statement as ReturnStatement;
 use crate::syntax::tree::statement::r#struct::Statement as StructStatement;
+use crate::syntax::tree::statement::r#trait::Statement as TraitStatement;
 use crate::syntax::tree::statement::r#type::Statement as TypeStatement;
 use crate::syntax::tree::statement::r#use::Statement as UseStatement;
+use crate::warning::Warning;
 
 ///
 /// Analyzes statements.
@@ -72,6 +91,14 @@ impl Analyzer {
         }
     }
 
+    ///
+    /// Drops the scopes left on the stack by a statement, whose analysis has failed, so the
+    /// next statements are analyzed in the scope the analyzer has been created with.
+    ///
+    pub fn recover(&mut self) {
+        self.scope_stack.unwind();
+    }
+
     ///
     /// Analyzes a statement local to a module.
     ///
@@ -99,8 +126,7 @@ impl Analyzer {
                 Ok(None)
             }
             ModuleLocalStatement::Fn(statement) => {
-                let intermediate = GeneratorStatement::Function(self.r#fn(statement)?);
-                Ok(Some(intermediate))
+                Ok(self.r#fn(statement)?.map(GeneratorStatement::Function))
             }
             ModuleLocalStatement::Mod(statement) => {
                 self.r#mod(statement)?;
@@ -114,6 +140,10 @@ impl Analyzer {
                 let intermediate = GeneratorStatement::Implementation(self.r#impl(statement)?);
                 Ok(Some(intermediate))
             }
+            ModuleLocalStatement::Trait(statement) => {
+                self.r#trait(statement)?;
+                Ok(None)
+            }
             ModuleLocalStatement::Empty(_location) => Ok(None),
         }
     }
@@ -129,18 +159,59 @@ impl Analyzer {
     ) -> Result<Option<GeneratorStatement>, Error> {
         match statement {
             FunctionLocalStatement::Let(statement) => {
-                Ok(self.r#let(statement)?.map(GeneratorStatement::Declaration))
+                Scope::enter_flow(self.scope_stack.top());
+                let intermediate = self.r#let(statement)?;
+                Scope::leave_flow(self.scope_stack.top(), FlowKind::Statement);
+                Ok(intermediate)
             }
             FunctionLocalStatement::Const(statement) => {
                 self.r#const(statement)?;
                 Ok(None)
             }
             FunctionLocalStatement::For(statement) => {
-                Ok(Some(GeneratorStatement::Loop(self.r#for(statement)?)))
+                Scope::enter_flow(self.scope_stack.top());
+                let intermediate = self.r#for(statement)?;
+                Scope::leave_flow(self.scope_stack.top(), FlowKind::Statement);
+                Ok(Some(GeneratorStatement::Loop(intermediate)))
+            }
+            FunctionLocalStatement::Return(statement) => {
+                Scope::enter_flow(self.scope_stack.top());
+                let intermediate = self.r#return(statement)?;
+                Scope::leave_flow(self.scope_stack.top(), FlowKind::Output);
+                Ok(Some(GeneratorStatement::Return(intermediate)))
+            }
+            FunctionLocalStatement::Break(location) => {
+                if !Scope::declare_loop_control(self.scope_stack.top()) {
+                    return Err(Error::LoopBreakOutsideOfLoop { location });
+                }
+                Scope::constrain_flow_conditions(self.scope_stack.top());
+                Ok(Some(GeneratorStatement::LoopControl(
+                    GeneratorLoopControlStatement::Break(location),
+                )))
+            }
+            FunctionLocalStatement::Continue(location) => {
+                if !Scope::declare_loop_control(self.scope_stack.top()) {
+                    return Err(Error::LoopContinueOutsideOfLoop { location });
+                }
+                Scope::constrain_flow_conditions(self.scope_stack.top());
+                Ok(Some(GeneratorStatement::LoopControl(
+                    GeneratorLoopControlStatement::Continue(location),
+                )))
             }
             FunctionLocalStatement::Expression(expression) => {
-                let (_result, expression) = ExpressionAnalyzer::new(self.scope_stack.top())
+                let location = expression.location;
+                Scope::enter_flow(self.scope_stack.top());
+                let (result, expression) = ExpressionAnalyzer::new(self.scope_stack.top())
                     .analyze(expression, TranslationHint::Value)?;
+                Scope::leave_flow(self.scope_stack.top(), FlowKind::Statement);
+                match Type::from_element(&result, self.scope_stack.top())? {
+                    Type::Unit => {}
+                    r#type => Warning::UnusedExpressionResult {
+                        location,
+                        r#type: r#type.to_string(),
+                    }
+                    .emit(),
+                }
                 let intermediate = GeneratorStatement::Expression(expression);
                 Ok(Some(intermediate))
             }
@@ -163,8 +234,7 @@ impl Analyzer {
                 Ok(None)
             }
             ImplementationLocalStatement::Fn(statement) => {
-                let intermediate = GeneratorStatement::Function(self.r#fn(statement)?);
-                Ok(Some(intermediate))
+                Ok(self.r#fn(statement)?.map(GeneratorStatement::Function))
             }
             ImplementationLocalStatement::Empty(_location) => Ok(None),
         }
@@ -173,18 +243,209 @@ impl Analyzer {
     ///
     /// Analyzes a function statement and returns its IR for the next compiler phase.
     ///
-    fn r#fn(&mut self, statement: FnStatement) -> Result<GeneratorFunctionStatement, Error> {
+    /// Generic functions yield no IR here, since they are analyzed at their call sites.
+    ///
+    fn r#fn(
+        &mut self,
+        statement: FnStatement,
+    ) -> Result<Option<GeneratorFunctionStatement>, Error> {
         let location = statement.location;
 
+        if statement.body.is_none() {
+            return Err(Error::FunctionBodyMissing {
+                location: statement.identifier.location,
+                function: statement.identifier.name,
+            });
+        }
+
+        if statement.is_generic() {
+            if statement.identifier.name.as_str()
+                == crate::semantic::element::r#type::function::user::FUNCTION_MAIN_IDENTIFIER
+            {
+                return Err(Error::EntryPointGeneric {
+                    location: statement.identifier.location,
+                });
+            }
+
+            for parameter in statement.generic_parameters.iter() {
+                let bounds = match parameter.variant {
+                    GenericParameterVariant::Type { ref bounds } => bounds,
+                    GenericParameterVariant::Constant { .. } => continue,
+                };
+                for bound in bounds.iter() {
+                    match Scope::resolve_item(self.scope_stack.top(), bound.name.as_str())
+                        .map_err(|error| Error::Scope(bound.location, error))?
+                        .variant
+                    {
+                        ScopeItemVariant::Trait(_) => {}
+                        item => {
+                            return Err(Error::GenericParameterBoundExpectedTrait {
+                                location: bound.location,
+                                found: item.to_string(),
+                            })
+                        }
+                    }
+                }
+            }
+
+            let r#type = Type::Function(FunctionType::new_generic(
+                statement.identifier.name.clone(),
+                statement.clone(),
+                self.scope_stack.top(),
+            ));
+            Scope::declare_type(
+                self.scope_stack.top(),
+                statement.identifier,
+                r#type,
+                statement.is_public,
+            )
+            .map_err(|error| Error::Scope(location, error))?;
+
+            return Ok(None);
+        }
+
+        let function_type = self.function_type(&statement, statement.identifier.name.clone())?;
+        let r#type = Type::Function(FunctionType::UserDefined(function_type.clone()));
+        Scope::declare_type(
+            self.scope_stack.top(),
+            statement.identifier.clone(),
+            r#type,
+            statement.is_public,
+        )
+        .map_err(|error| Error::Scope(location, error))?;
+
+        self.function_body(statement, function_type).map(Some)
+    }
+
+    ///
+    /// Instantiates a generic function with the generic arguments inferred at its call site.
+    ///
+    /// The arguments are declared in a scope nested into the function declaration scope, so
+    /// the function body is analyzed as if it were an ordinar function. Each set of arguments
+    /// is analyzed only once, and the instance IR is added to the instances of the file, where
+    /// the `caller` scope is located.
+    ///
+    pub fn instantiate(
+        caller: Rc<RefCell<Scope>>,
+        function: &GenericFunctionType,
+        arguments: Vec<(GenericParameter, Element)>,
+    ) -> Result<UserDefinedFunctionType, Error> {
+        let instances = Scope::instances(caller);
+        let scope = Scope::new_instance(function.scope(), instances.clone());
+
+        let mut names = Vec::with_capacity(arguments.len());
+        let mut key = Vec::with_capacity(arguments.len());
+        for (parameter, element) in arguments.into_iter() {
+            let location = parameter.identifier.location;
+            match (parameter.variant, element) {
+                (GenericParameterVariant::Type { .. }, Element::Type(r#type)) => {
+                    names.push(r#type.to_string());
+                    key.push(GenericArgument::Type(r#type.clone()));
+                    Scope::declare_type(scope.clone(), parameter.identifier, r#type, false)
+                        .map_err(|error| Error::Scope(location, error))?;
+                }
+                (GenericParameterVariant::Constant { r#type }, Element::Constant(constant)) => {
+                    let type_location = r#type.location;
+                    let r#type = Type::from_type_variant(&r#type.variant, scope.clone())?;
+                    let constant = constant
+                        .cast(r#type)
+                        .map_err(ElementError::Constant)
+                        .map_err(|error| Error::Element(type_location, error))?;
+                    names.push(match constant {
+                        Constant::Integer(ref integer) => integer.value.to_string(),
+                        ref constant => constant.to_string(),
+                    });
+                    key.push(GenericArgument::Constant(constant.clone()));
+                    Scope::declare_constant(scope.clone(), parameter.identifier, constant, false)
+                        .map_err(|error| Error::Scope(location, error))?;
+                }
+                _ => panic!(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+            }
+        }
+
+        if let Some(instance) = function.instance(key.as_slice()) {
+            return Ok(instance);
+        }
+
+        let mut analyzer = Self::new(scope, HashMap::new());
+        let statement = function.statement().to_owned();
+        let identifier = format!("{}<{}>", function.identifier(), names.join(", "));
+        let function_type = analyzer.function_type(&statement, identifier)?;
+        function.add_instance(key, function_type.clone());
+
+        let intermediate = analyzer.function_body(statement, function_type.clone())?;
+        instances.push(GeneratorStatement::Function(intermediate));
+
+        Ok(function_type)
+    }
+
+    ///
+    /// Resolves the function argument and return types, and allocates the function type.
+    ///
+    fn function_type(
+        &mut self,
+        statement: &FnStatement,
+        identifier: String,
+    ) -> Result<UserDefinedFunctionType, Error> {
+        let (arguments, expected_type) = self.function_signature(statement)?;
+
+        let unique_id = TYPE_INDEX.read().expect(crate::PANIC_MUTEX_SYNC).len();
+        let function_type =
+            UserDefinedFunctionType::new(identifier, unique_id, arguments, expected_type);
+
+        TYPE_INDEX.write().expect(crate::PANIC_MUTEX_SYNC).insert(
+            unique_id,
+            Type::Function(FunctionType::UserDefined(function_type.clone())).to_string(),
+        );
+
+        Ok(function_type)
+    }
+
+    ///
+    /// The name of the destructured argument, which is also used in the entry input data.
+    ///
+    fn argument_name(index: usize) -> String {
+        format!("_{}", index)
+    }
+
+    ///
+    /// Resolves the function argument and return types.
+    ///
+    fn function_signature(
+        &mut self,
+        statement: &FnStatement,
+    ) -> Result<(Vec<(String, Type)>, Type), Error> {
         let mut arguments = Vec::with_capacity(statement.argument_bindings.len());
-        for argument_binding in statement.argument_bindings.iter() {
-            let identifier = match argument_binding.variant {
-                BindingPatternVariant::Binding(ref identifier) => identifier,
-                BindingPatternVariant::MutableBinding(ref identifier) => identifier,
+        for (index, argument_binding) in statement.argument_bindings.iter().enumerate() {
+            let name = match argument_binding.variant {
+                BindingPatternVariant::Binding(ref identifier) => identifier.name.clone(),
+                BindingPatternVariant::MutableBinding(ref identifier) => identifier.name.clone(),
                 BindingPatternVariant::Wildcard => continue,
+                BindingPatternVariant::Tuple { .. } => Self::argument_name(index),
+                BindingPatternVariant::Structure { .. } => Self::argument_name(index),
+                BindingPatternVariant::SelfAlias { location, .. } => {
+                    if index != 0 {
+                        return Err(Error::FunctionSelfNotFirst {
+                            location,
+                            function: statement.identifier.name.to_owned(),
+                        });
+                    }
+                    if Scope::resolve_item(
+                        self.scope_stack.top(),
+                        Keyword::SelfUppercase.to_string().as_str(),
+                    )
+                    .is_err()
+                    {
+                        return Err(Error::FunctionSelfOutsideImplementation {
+                            location,
+                            function: statement.identifier.name.to_owned(),
+                        });
+                    }
+                    Keyword::SelfLowercase.to_string()
+                }
             };
             arguments.push((
-                identifier.name.clone(),
+                name,
                 Type::from_type_variant(&argument_binding.r#type.variant, self.scope_stack.top())?,
             ));
         }
@@ -193,28 +454,57 @@ impl Analyzer {
             None => Type::unit(),
         };
 
-        let unique_id = TYPE_INDEX.read().expect(crate::PANIC_MUTEX_SYNC).len();
-        let function_type = UserDefinedFunctionType::new(
-            statement.identifier.name.clone(),
-            unique_id,
-            arguments.clone(),
-            expected_type.clone(),
-        );
-        let r#type = Type::Function(FunctionType::UserDefined(function_type));
+        Ok((arguments, expected_type))
+    }
 
-        TYPE_INDEX
-            .write()
-            .expect(crate::PANIC_MUTEX_SYNC)
-            .insert(unique_id, r#type.to_string());
-        Scope::declare_type(self.scope_stack.top(), statement.identifier.clone(), r#type)
-            .map_err(|error| Error::Scope(location, error))?;
+    ///
+    /// Analyzes the function body and returns its IR for the next compiler phase.
+    ///
+    fn function_body(
+        &mut self,
+        statement: FnStatement,
+        function_type: UserDefinedFunctionType,
+    ) -> Result<GeneratorFunctionStatement, Error> {
+        let location = statement.location;
+        let body = statement
+            .body
+            .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+        let expected_type = function_type.return_type().to_owned();
+        let return_type_location = statement
+            .return_type
+            .as_ref()
+            .map(|r#type| r#type.location)
+            .unwrap_or(statement.location);
 
-        self.scope_stack.push();
-        for argument_binding in statement.argument_bindings.into_iter() {
+        self.scope_stack.push_scope(Scope::new_function(
+            self.scope_stack.top(),
+            function_type.identifier().to_owned(),
+            expected_type.clone(),
+            return_type_location,
+        ));
+        let mut argument_bindings = Vec::new();
+        for (index, argument_binding) in statement.argument_bindings.into_iter().enumerate() {
             let (identifier, is_mutable) = match argument_binding.variant {
                 BindingPatternVariant::Binding(identifier) => (identifier, false),
                 BindingPatternVariant::MutableBinding(identifier) => (identifier, true),
                 BindingPatternVariant::Wildcard => continue,
+                BindingPatternVariant::SelfAlias {
+                    location,
+                    is_mutable,
+                } => (
+                    Identifier::new(location, Keyword::SelfLowercase.to_string()),
+                    is_mutable,
+                ),
+                variant => {
+                    let r#type = Type::from_type_variant(
+                        &argument_binding.r#type.variant,
+                        self.scope_stack.top(),
+                    )?;
+                    let bindings =
+                        PatternAnalyzer::declare(self.scope_stack.top(), variant, &r#type)?;
+                    argument_bindings.push((Self::argument_name(index), bindings));
+                    continue;
+                }
             };
             let identifier_location = identifier.location;
             let r#type =
@@ -228,21 +518,23 @@ impl Analyzer {
             .map_err(|error| Error::Scope(identifier_location, error))?;
         }
 
-        let return_expression_location = match statement
-            .body
+        let return_expression_location = match body
             .expression
             .as_ref()
             .map(|expression| expression.location)
         {
             Some(location) => location,
-            None => statement
-                .body
+            None => body
                 .statements
                 .last()
                 .map(|statement| statement.location())
                 .unwrap_or(statement.location),
         };
-        let (result, body) = BlockAnalyzer::analyze(self.scope_stack.top(), statement.body)?;
+        Scope::enter_flow(self.scope_stack.top());
+        let (result, body) = BlockAnalyzer::analyze(self.scope_stack.top(), body)?;
+        Scope::leave_flow(self.scope_stack.top(), FlowKind::Output);
+        self.scope_stack.top().borrow().check_unconstrained_values();
+        let has_returns = Scope::controls(self.scope_stack.top()) > 0;
         self.scope_stack.pop();
 
         let result_type = Type::from_element(&result, self.scope_stack.top())?;
@@ -250,13 +542,10 @@ impl Analyzer {
             return Err(Error::Element(
                 return_expression_location,
                 ElementError::Type(TypeError::Function(FunctionTypeError::return_type(
-                    statement.identifier.name.clone(),
+                    function_type.identifier().to_owned(),
                     expected_type.to_string(),
                     result_type.to_string(),
-                    statement
-                        .return_type
-                        .map(|r#type| r#type.location)
-                        .unwrap_or(statement.location),
+                    return_type_location,
                 ))),
             ));
         }
@@ -266,30 +555,41 @@ impl Analyzer {
 
         Ok(GeneratorFunctionStatement::new(
             location,
-            statement.identifier.name,
-            arguments,
+            function_type.identifier().to_owned(),
+            function_type.formal_params().to_owned(),
+            argument_bindings,
             body,
             expected_type,
-            unique_id,
+            function_type.unique_id(),
             is_main,
+            has_returns,
         ))
     }
 
     ///
     /// Analyzes an implementation statement and returns its IR for the next compiler phase.
     ///
+    /// If the statement implements a trait, the function signatures are checked against the
+    /// trait declarations, and the missing functions are taken from the trait default ones.
+    ///
     fn r#impl(&mut self, statement: ImplStatement) -> Result<Vec<GeneratorStatement>, Error> {
         let identifier_location = statement.identifier.location;
 
         let mut intermediate = Vec::new();
 
-        let structure_scope =
+        let (r#type, structure_scope) =
             match Scope::resolve_item(self.scope_stack.top(), statement.identifier.name.as_str())
                 .map_err(|error| Error::Scope(identifier_location, error))?
                 .variant
             {
-                ScopeItemVariant::Type(Type::Structure(structure)) => structure.scope,
-                ScopeItemVariant::Type(Type::Enumeration(enumeration)) => enumeration.scope,
+                ScopeItemVariant::Type(Type::Structure(structure)) => {
+                    let scope = structure.scope.clone();
+                    (Type::Structure(structure), scope)
+                }
+                ScopeItemVariant::Type(Type::Enumeration(enumeration)) => {
+                    let scope = enumeration.scope.clone();
+                    (Type::Enumeration(enumeration), scope)
+                }
                 item => {
                     return Err(Error::ImplStatementExpectedStructureOrEnumeration {
                         location: identifier_location,
@@ -298,7 +598,45 @@ impl Analyzer {
                 }
             };
 
-        self.scope_stack.push_scope(structure_scope);
+        let r#trait = match statement.trait_identifier {
+            Some(ref identifier) => {
+                match Scope::resolve_item(self.scope_stack.top(), identifier.name.as_str())
+                    .map_err(|error| Error::Scope(identifier.location, error))?
+                    .variant
+                {
+                    ScopeItemVariant::Trait(r#trait) => Some(r#trait),
+                    item => {
+                        return Err(Error::ImplStatementExpectedTrait {
+                            location: identifier.location,
+                            found: item.to_string(),
+                        });
+                    }
+                }
+            }
+            None => None,
+        };
+
+        let mut implemented = Vec::with_capacity(statement.statements.len());
+        if let Some(ref r#trait) = r#trait {
+            for statement in statement.statements.iter() {
+                if let ImplementationLocalStatement::Fn(ref function) = statement {
+                    if !r#trait
+                        .functions
+                        .iter()
+                        .any(|declaration| declaration.identifier.name == function.identifier.name)
+                    {
+                        return Err(Error::ImplStatementFunctionNotInTrait {
+                            location: function.identifier.location,
+                            function: function.identifier.name.to_owned(),
+                            trait_identifier: r#trait.identifier.to_owned(),
+                        });
+                    }
+                    implemented.push(function.identifier.to_owned());
+                }
+            }
+        }
+
+        self.scope_stack.push_scope(structure_scope.clone());
         for statement in statement.statements.into_iter() {
             if let Some(statement) = self.local_impl(statement)? {
                 intermediate.push(statement);
@@ -306,16 +644,160 @@ impl Analyzer {
         }
         self.scope_stack.pop();
 
+        let r#trait = match r#trait {
+            Some(r#trait) => r#trait,
+            None => return Ok(intermediate),
+        };
+
+        let trait_scope = Scope::new_child(r#trait.scope.clone());
+        trait_scope.borrow_mut().declare_self(r#type.clone());
+        let mut trait_analyzer = Self::new(trait_scope.clone(), HashMap::new());
+
+        for declaration in r#trait.functions.iter() {
+            let (expected_arguments, expected_return_type) =
+                trait_analyzer.function_signature(declaration)?;
+
+            match implemented
+                .iter()
+                .find(|identifier| identifier.name == declaration.identifier.name)
+            {
+                Some(identifier) => {
+                    let function = match Scope::resolve_item(
+                        structure_scope.clone(),
+                        identifier.name.as_str(),
+                    )
+                    .map_err(|error| Error::Scope(identifier.location, error))?
+                    .variant
+                    {
+                        ScopeItemVariant::Type(Type::Function(FunctionType::UserDefined(
+                            function,
+                        ))) => function,
+                        _ => panic!(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                    };
+
+                    let is_matching = function.formal_params().len() == expected_arguments.len()
+                        && function
+                            .formal_params()
+                            .iter()
+                            .zip(expected_arguments.iter())
+                            .all(|((_, found), (_, expected))| found == expected)
+                        && function.return_type() == &expected_return_type;
+                    if !is_matching {
+                        return Err(Error::ImplStatementFunctionSignatureMismatch {
+                            location: identifier.location,
+                            function: identifier.name.to_owned(),
+                            trait_identifier: r#trait.identifier.to_owned(),
+                            expected: Self::signature_to_string(
+                                expected_arguments.as_slice(),
+                                &expected_return_type,
+                            ),
+                            found: Self::signature_to_string(
+                                function.formal_params(),
+                                function.return_type(),
+                            ),
+                        });
+                    }
+                }
+                None if declaration.body.is_some() => {
+                    let identifier = declaration.identifier.to_owned();
+                    if let Some(function) = trait_analyzer.r#fn(declaration.to_owned())? {
+                        intermediate.push(GeneratorStatement::Function(function));
+                    }
+                    let item = Scope::resolve_item(trait_scope.clone(), identifier.name.as_str())
+                        .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS);
+                    Scope::declare_item(structure_scope.clone(), identifier.clone(), item, true)
+                        .map_err(|error| Error::Scope(identifier.location, error))?;
+                }
+                None => {
+                    return Err(Error::ImplStatementFunctionMissing {
+                        location: identifier_location,
+                        function: declaration.identifier.name.to_owned(),
+                        trait_identifier: r#trait.identifier.to_owned(),
+                    });
+                }
+            }
+        }
+
+        r#trait.add_implementation(&r#type);
+
         Ok(intermediate)
     }
 
+    ///
+    /// Analyzes a trait statement and declares the trait in the current scope.
+    ///
+    /// The function declarations are only checked for being unique and non-generic here, since
+    /// their types are resolved for each implementation separately.
+    ///
+    fn r#trait(&mut self, statement: TraitStatement) -> Result<(), Error> {
+        let location = statement.location;
+
+        let mut functions: Vec<FnStatement> = Vec::with_capacity(statement.statements.len());
+        for statement in statement.statements.into_iter() {
+            match statement {
+                TraitLocalStatement::Fn(function) => {
+                    if function.is_generic() {
+                        return Err(Error::TraitFunctionGeneric {
+                            location: function.identifier.location,
+                            function: function.identifier.name,
+                        });
+                    }
+                    if let Some(declared) = functions
+                        .iter()
+                        .find(|declared| declared.identifier.name == function.identifier.name)
+                    {
+                        return Err(Error::Scope(
+                            function.identifier.location,
+                            ScopeError::ItemRedeclared {
+                                name: function.identifier.name,
+                                reference: Some(declared.identifier.location),
+                            },
+                        ));
+                    }
+                    functions.push(function);
+                }
+                TraitLocalStatement::Empty(_location) => {}
+            }
+        }
+
+        let r#trait = ScopeTraitItem::new(
+            statement.identifier.name.clone(),
+            functions,
+            self.scope_stack.top(),
+        );
+        Scope::declare_trait(
+            self.scope_stack.top(),
+            statement.identifier,
+            r#trait,
+            statement.is_public,
+        )
+        .map_err(|error| Error::Scope(location, error))?;
+
+        Ok(())
+    }
+
+    ///
+    /// Formats a function signature for error messages, e.g. `fn(a: u8) -> u8`.
+    ///
+    fn signature_to_string(arguments: &[(String, Type)], return_type: &Type) -> String {
+        format!(
+            "fn({}) -> {}",
+            arguments
+                .iter()
+                .map(|(name, r#type)| format!("{}: {}", name, r#type))
+                .collect::<Vec<String>>()
+                .join(", "),
+            return_type,
+        )
+    }
+
     ///
     /// Analyzes a variable declaration statement and returns its IR for the next compiler phase.
     ///
-    fn r#let(
-        &mut self,
-        statement: LetStatement,
-    ) -> Result<Option<GeneratorDeclarationStatement>, Error> {
+    /// The destructuring declarations store the value in an anonymous variable and copy its parts
+    /// to the pattern variables.
+    ///
+    fn r#let(&mut self, statement: LetStatement) -> Result<Option<GeneratorStatement>, Error> {
         let location = statement.location;
 
         let (element, expression) = ExpressionAnalyzer::new(self.scope_stack.top())
@@ -332,19 +814,30 @@ impl Analyzer {
             Type::from_element(&element, self.scope_stack.top())?
         };
 
+        let (identifier, is_mutable) = match statement.binding {
+            BindingPatternVariant::Binding(identifier) => (identifier, false),
+            BindingPatternVariant::MutableBinding(identifier) => (identifier, true),
+            variant => {
+                let bindings = PatternAnalyzer::declare(self.scope_stack.top(), variant, &r#type)?;
+                return Ok(GeneratorType::try_from_semantic(&r#type).map(|r#type| {
+                    GeneratorStatement::Destructuring(GeneratorDestructuringStatement::new(
+                        location, r#type, expression, bindings,
+                    ))
+                }));
+            }
+        };
+
         Scope::declare_variable(
             self.scope_stack.top(),
-            statement.identifier.clone(),
-            ScopeVariableItem::new(statement.is_mutable, r#type.clone()),
+            identifier.clone(),
+            ScopeVariableItem::new(is_mutable, r#type.clone()),
         )
         .map_err(|error| Error::Scope(location, error))?;
 
-        Ok(GeneratorDeclarationStatement::new(
-            location,
-            statement.identifier.name,
-            r#type,
-            expression,
-        ))
+        Ok(
+            GeneratorDeclarationStatement::new(location, identifier.name, r#type, expression)
+                .map(GeneratorStatement::Declaration),
+        )
     }
 
     ///
@@ -392,9 +885,11 @@ impl Analyzer {
 
         let while_condition = if let Some(expression) = statement.while_condition {
             let location = expression.location;
+            Scope::enter_flow(self.scope_stack.top());
             let (while_result, while_intermediate) =
                 ExpressionAnalyzer::new(self.scope_stack.top())
                     .analyze(expression, TranslationHint::Value)?;
+            Scope::leave_flow(self.scope_stack.top(), FlowKind::Condition);
 
             match Type::from_element(&while_result, self.scope_stack.top())? {
                 Type::Boolean => {}
@@ -411,7 +906,14 @@ impl Analyzer {
             None
         };
 
+        self.scope_stack
+            .push_scope(Scope::new_loop(self.scope_stack.top()));
         let (_result, body) = BlockAnalyzer::analyze(self.scope_stack.top(), statement.block)?;
+        let has_loop_controls = Scope::controls(self.scope_stack.top()) > 0;
+        self.scope_stack.pop();
+        if while_condition.is_some() {
+            Scope::leave_flow_condition(self.scope_stack.top());
+        }
 
         self.scope_stack.pop();
 
@@ -453,10 +955,50 @@ impl Analyzer {
             is_index_signed,
             index_bitlength,
             while_condition,
+            has_loop_controls,
             body,
         ))
     }
 
+    ///
+    /// Analyzes a return statement and returns its IR for the next compiler phase.
+    ///
+    fn r#return(
+        &mut self,
+        statement: ReturnStatement,
+    ) -> Result<GeneratorFunctionReturnStatement, Error> {
+        let location = statement.location;
+
+        let (identifier, expected_type, return_type_location) =
+            Scope::declare_return(self.scope_stack.top())
+                .ok_or(Error::ReturnOutsideOfFunction { location })?;
+
+        let (expression_location, result, expression) = match statement.expression {
+            Some(expression) => {
+                let expression_location = expression.location;
+                let (result, expression) = ExpressionAnalyzer::new(self.scope_stack.top())
+                    .analyze(expression, TranslationHint::Value)?;
+                (expression_location, result, Some(expression))
+            }
+            None => (location, Element::Value(Value::Unit), None),
+        };
+
+        let result_type = Type::from_element(&result, self.scope_stack.top())?;
+        if expected_type != result_type {
+            return Err(Error::Element(
+                expression_location,
+                ElementError::Type(TypeError::Function(FunctionTypeError::return_type(
+                    identifier,
+                    expected_type.to_string(),
+                    result_type.to_string(),
+                    return_type_location,
+                ))),
+            ));
+        }
+
+        Ok(GeneratorFunctionReturnStatement::new(location, expression))
+    }
+
     ///
     /// Analyzes a compile time only constant declaration statement.
     ///
@@ -483,8 +1025,13 @@ impl Analyzer {
             }
         };
 
-        Scope::declare_constant(self.scope_stack.top(), statement.identifier, constant)
-            .map_err(|error| Error::Scope(location, error))?;
+        Scope::declare_constant(
+            self.scope_stack.top(),
+            statement.identifier,
+            constant,
+            statement.is_public,
+        )
+        .map_err(|error| Error::Scope(location, error))?;
 
         Ok(())
     }
@@ -497,8 +1044,13 @@ impl Analyzer {
 
         let r#type = Type::from_type_variant(&statement.r#type.variant, self.scope_stack.top())?;
 
-        Scope::declare_type(self.scope_stack.top(), statement.identifier, r#type)
-            .map_err(|error| Error::Scope(location, error))?;
+        Scope::declare_type(
+            self.scope_stack.top(),
+            statement.identifier,
+            r#type,
+            statement.is_public,
+        )
+        .map_err(|error| Error::Scope(location, error))?;
 
         Ok(())
     }
@@ -541,8 +1093,13 @@ impl Analyzer {
             .write()
             .expect(crate::PANIC_MUTEX_SYNC)
             .insert(unique_id, r#type.to_string());
-        Scope::declare_type(self.scope_stack.top(), statement.identifier, r#type)
-            .map_err(|error| Error::Scope(location, error))?;
+        Scope::declare_type(
+            self.scope_stack.top(),
+            statement.identifier,
+            r#type,
+            statement.is_public,
+        )
+        .map_err(|error| Error::Scope(location, error))?;
 
         Ok(())
     }
@@ -565,8 +1122,13 @@ impl Analyzer {
             .write()
             .expect(crate::PANIC_MUTEX_SYNC)
             .insert(unique_id, r#type.to_string());
-        Scope::declare_type(self.scope_stack.top(), statement.identifier, r#type)
-            .map_err(|error| Error::Scope(location, error))?;
+        Scope::declare_type(
+            self.scope_stack.top(),
+            statement.identifier,
+            r#type,
+            statement.is_public,
+        )
+        .map_err(|error| Error::Scope(location, error))?;
 
         Ok(())
     }
@@ -587,8 +1149,13 @@ impl Analyzer {
                 });
             }
         };
-        Scope::declare_module(self.scope_stack.top(), statement.identifier, module)
-            .map_err(|error| Error::Scope(identifier_location, error))?;
+        Scope::declare_module(
+            self.scope_stack.top(),
+            statement.identifier,
+            module,
+            statement.is_public,
+        )
+        .map_err(|error| Error::Scope(identifier_location, error))?;
 
         Ok(())
     }
@@ -615,8 +1182,13 @@ impl Analyzer {
             .elements
             .last()
             .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
-        Scope::declare_item(self.scope_stack.top(), path_last_element.to_owned(), item)
-            .map_err(|error| Error::Scope(path_last_element.location, error))?;
+        Scope::declare_import(
+            self.scope_stack.top(),
+            path_last_element.to_owned(),
+            item,
+            statement.is_public,
+        )
+        .map_err(|error| Error::Scope(path_last_element.location, error))?;
 
         Ok(())
     }
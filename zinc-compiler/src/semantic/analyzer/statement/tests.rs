@@ -13,6 +13,7 @@ use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::error::Error as ElementError;
 use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::function::error::Error as FunctionTypeError;
 use crate::semantic::element::r#type::structure::error::Error as StructureTypeError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
@@ -63,6 +64,147 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_for_break_continue() {
+    let input = r#"
+fn main(limit: u8) -> u8 {
+    let mut sum = 0;
+    for i in 0..10 {
+        if i == limit {
+            break;
+        };
+        for j in 0..3 {
+            if j == 1 { continue };
+            sum += j;
+        }
+        sum += i
+    }
+    sum
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn error_for_break_outside_of_loop() {
+    let input = r#"
+fn main() {
+    let mut sum = 0;
+    for i in 0..10 {
+        sum = sum + i;
+    }
+    break;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::LoopBreakOutsideOfLoop {
+        location: Location::new(7, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_for_continue_outside_of_loop() {
+    let input = r#"
+fn main() {
+    let mut sum = 0;
+    if sum == 0 {
+        continue;
+    };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::LoopContinueOutsideOfLoop {
+        location: Location::new(5, 9),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_return() {
+    let input = r#"
+fn check(value: u8) -> bool {
+    if value == 0 {
+        return false;
+    };
+    for i in 0..10 {
+        if i == value {
+            return true
+        };
+    }
+    false
+}
+
+fn main(value: u8) -> bool {
+    check(value)
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn error_return_type_mismatch() {
+    let input = r#"
+fn check(value: u8) -> bool {
+    if value == 0 {
+        return 42;
+    };
+    true
+}
+
+fn main(value: u8) -> bool {
+    check(value)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(4, 16),
+        ElementError::Type(TypeError::Function(FunctionTypeError::return_type(
+            "check".to_owned(),
+            Type::boolean().to_string(),
+            Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            Location::new(2, 24),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_return_outside_of_function() {
+    let input = r#"
+const VALUE: u8 = {
+    return 42;
+};
+
+fn main() -> u8 {
+    VALUE
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ReturnOutsideOfFunction {
+        location: Location::new(3, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_structure_duplicate_field() {
     let input = r#"
@@ -149,3 +291,387 @@ fn main() {}
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_entry_point_generic() {
+    let input = r#"
+fn main<T>(value: T) -> T {
+    value
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::EntryPointGeneric {
+        location: Location::new(2, 4),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_impl_statement_expected_trait() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+impl Data for Data {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ImplStatementExpectedTrait {
+        location: Location::new(6, 6),
+        found: Type::structure("Data".to_owned(), 1, vec![], None).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_impl_statement_function_missing() {
+    let input = r#"
+trait Hasher {
+    fn hash(preimage: u8) -> u8;
+}
+
+struct Sha256 {}
+
+impl Hasher for Sha256 {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ImplStatementFunctionMissing {
+            location: Location::new(8, 17),
+            function: "hash".to_owned(),
+            trait_identifier: "Hasher".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_impl_statement_function_not_in_trait() {
+    let input = r#"
+trait Hasher {
+    fn hash(preimage: u8) -> u8;
+}
+
+struct Sha256 {}
+
+impl Hasher for Sha256 {
+    fn hash(preimage: u8) -> u8 {
+        preimage
+    }
+
+    fn reset() {}
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ImplStatementFunctionNotInTrait {
+            location: Location::new(13, 8),
+            function: "reset".to_owned(),
+            trait_identifier: "Hasher".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_impl_statement_function_signature_mismatch() {
+    let input = r#"
+trait Hasher {
+    fn hash(preimage: u8) -> u8;
+}
+
+struct Sha256 {}
+
+impl Hasher for Sha256 {
+    fn hash(preimage: u16) -> u8 {
+        0
+    }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ImplStatementFunctionSignatureMismatch {
+            location: Location::new(9, 8),
+            function: "hash".to_owned(),
+            trait_identifier: "Hasher".to_owned(),
+            expected: "fn(preimage: u8) -> u8".to_owned(),
+            found: "fn(preimage: u16) -> u8".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_trait_function_generic() {
+    let input = r#"
+trait Hasher {
+    fn hash<T>(preimage: T) -> T;
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TraitFunctionGeneric {
+        location: Location::new(3, 8),
+        function: "hash".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_function_body_missing() {
+    let input = r#"
+fn hash(preimage: u8) -> u8;
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionBodyMissing {
+        location: Location::new(2, 4),
+        function: "hash".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_parameter_bound_expected_trait() {
+    let input = r#"
+struct Sha256 {}
+
+fn digest<H: Sha256>(preimage: u8) -> u8 {
+    preimage
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::GenericParameterBoundExpectedTrait {
+            location: Location::new(4, 14),
+            found: Type::structure("Sha256".to_owned(), 1, vec![], None).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_function_self_not_first() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+impl Data {
+    fn add(value: u8, self) -> u8 {
+        self.value + value
+    }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionSelfNotFirst {
+        location: Location::new(7, 23),
+        function: "add".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_function_self_outside_implementation() {
+    let input = r#"
+fn value(self) -> u8 {
+    42
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionSelfOutsideImplementation {
+            location: Location::new(2, 10),
+            function: "value".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_let_destructuring() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn sum((a, b): (u8, u8)) -> u8 {
+    a + b
+}
+
+fn main() -> u8 {
+    let pair = (Point { x: 1, y: 2 }, 3);
+    let (Point { x, y: mut z }, w) = pair;
+    z = z + sum((x, w));
+    z
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn error_let_pattern_tuple_expected() {
+    let input = r#"
+fn main() {
+    let (a, b) = 42;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::PatternTupleExpected {
+        location: Location::new(3, 9),
+        found: Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_let_pattern_tuple_element_count() {
+    let input = r#"
+fn main() {
+    let (a, b) = (1, 2, 3);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::PatternTupleElementCount {
+        location: Location::new(3, 9),
+        expected: 3,
+        found: 2,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_let_pattern_structure_invalid_type() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main() {
+    let Point { x, y } = (1, 2);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::PatternStructureInvalidType {
+        location: Location::new(8, 9),
+        expected: Type::tuple(vec![
+            Type::integer_unsigned(crate::BITLENGTH_BYTE),
+            Type::integer_unsigned(crate::BITLENGTH_BYTE),
+        ])
+        .to_string(),
+        found: "struct Point".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_let_pattern_structure_field_does_not_exist() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main() {
+    let Point { x, z } = Point { x: 1, y: 2 };
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::PatternStructureFieldDoesNotExist {
+            location: Location::new(8, 20),
+            type_identifier: "Point".to_owned(),
+            field_name: "z".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_let_pattern_structure_duplicate_field() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main() {
+    let Point { x, x: y } = Point { x: 1, y: 2 };
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::PatternStructureDuplicateField {
+            location: Location::new(8, 20),
+            type_identifier: "Point".to_owned(),
+            field_name: "x".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
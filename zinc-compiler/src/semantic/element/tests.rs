@@ -1189,20 +1189,19 @@ fn main() {
 }
 
 #[test]
-fn error_operator_bitwise_shift_left_2nd_operand_expected_constant() {
+fn error_operator_bitwise_shift_left_2nd_operand_expected_evaluable() {
     let input = r#"
+type X = u8;
+
 fn main() {
-    let offset = 2;
-    let value = 42 << offset;
+    let value = 42 << X;
 }
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(
-        Location::new(4, 20),
-        ElementError::OperatorBitwiseShiftLeftSecondOperandExpectedConstant {
-            found: Value::try_from(&Type::integer_unsigned(crate::BITLENGTH_BYTE))
-                .expect(crate::semantic::tests::PANIC_TEST_DATA)
-                .to_string(),
+        Location::new(5, 20),
+        ElementError::OperatorBitwiseShiftLeftSecondOperandExpectedEvaluable {
+            found: Element::Type(Type::integer_unsigned(crate::BITLENGTH_BYTE)).to_string(),
         },
     )));
 
@@ -1234,20 +1233,19 @@ fn main() {
 }
 
 #[test]
-fn error_operator_bitwise_shift_right_2nd_operand_expected_constant() {
+fn error_operator_bitwise_shift_right_2nd_operand_expected_evaluable() {
     let input = r#"
+type X = u8;
+
 fn main() {
-    let offset = 2;
-    let value = 42 >> offset;
+    let value = 42 >> X;
 }
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Element(
-        Location::new(4, 20),
-        ElementError::OperatorBitwiseShiftRightSecondOperandExpectedConstant {
-            found: Value::try_from(&Type::integer_unsigned(crate::BITLENGTH_BYTE))
-                .expect(crate::semantic::tests::PANIC_TEST_DATA)
-                .to_string(),
+        Location::new(5, 20),
+        ElementError::OperatorBitwiseShiftRightSecondOperandExpectedEvaluable {
+            found: Element::Type(Type::integer_unsigned(crate::BITLENGTH_BYTE)).to_string(),
         },
     )));
 
@@ -0,0 +1,49 @@
+//!
+//! The semantic analyzer enumeration type element error.
+//!
+
+use num_bigint::BigInt;
+
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    DuplicateVariantValue {
+        type_identifier: String,
+        variant_name: String,
+        value: BigInt,
+    },
+    DuplicateField {
+        type_identifier: String,
+        variant_name: String,
+        field_name: String,
+    },
+    VariantPayloadMissing {
+        type_identifier: String,
+        variant_name: String,
+    },
+    VariantNotTuple {
+        type_identifier: String,
+        variant_name: String,
+    },
+    VariantNotStructure {
+        type_identifier: String,
+        variant_name: String,
+    },
+    VariantFieldCount {
+        type_identifier: String,
+        variant_name: String,
+        expected: usize,
+        found: usize,
+    },
+    VariantFieldDoesNotExist {
+        type_identifier: String,
+        variant_name: String,
+        field_name: String,
+    },
+    VariantFieldInvalidType {
+        type_identifier: String,
+        variant_name: String,
+        field_name: String,
+        expected: String,
+        found: String,
+    },
+}
@@ -0,0 +1,225 @@
+//!
+//! The semantic analyzer enumeration type element.
+//!
+
+mod tests;
+
+pub mod error;
+pub mod variant;
+
+use std::cell::RefCell;
+use std::convert::TryFrom;
+use std::fmt;
+use std::rc::Rc;
+
+use num_bigint::BigInt;
+use num_traits::One;
+use num_traits::Zero;
+
+use crate::semantic::element::constant::error::Error as ConstantError;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::variant::payload::Payload;
+use crate::syntax::tree::variant::Variant as SyntaxVariant;
+
+use self::error::Error as EnumerationTypeError;
+use self::variant::Variant;
+
+///
+/// Describes an enumeration type.
+///
+/// Consists of the local enumeration `identifier` within its scope, global `unique_id`,
+/// and the implementation `scope`, which contains the enumeration variants and
+/// reference to its parent scope.
+///
+/// If any of the `variants` carries a payload, the enumeration is algebraic and is
+/// represented at runtime as the tag followed by the union of the variant payloads.
+///
+#[derive(Debug, Clone)]
+pub struct Enumeration {
+    pub identifier: String,
+    pub unique_id: usize,
+    pub bitlength: usize,
+    pub values: Vec<BigInt>,
+    pub variants: Vec<Variant>,
+    pub scope: Rc<RefCell<Scope>>,
+}
+
+impl Enumeration {
+    pub fn new(
+        identifier: Identifier,
+        unique_id: usize,
+        variants: Vec<SyntaxVariant>,
+        scope_parent: Option<Rc<RefCell<Scope>>>,
+    ) -> Result<Self, Error> {
+        let scope = Rc::new(RefCell::new(Scope::new(scope_parent)));
+
+        let mut variants_semantic: Vec<(Identifier, Variant)> = Vec::with_capacity(variants.len());
+        let mut next_value = BigInt::zero();
+        for variant in variants.into_iter() {
+            let location = variant.identifier.location;
+
+            let (value, fields, is_tuple) = match (variant.literal, variant.payload) {
+                (Some(literal), _) => {
+                    let value = IntegerConstant::try_from(&literal).map_err(|error| {
+                        Error::Element(
+                            location,
+                            ElementError::Constant(ConstantError::Integer(error)),
+                        )
+                    })?;
+                    (value.value, Vec::new(), false)
+                }
+                (None, Some(Payload::Tuple(types))) => {
+                    let mut fields = Vec::with_capacity(types.len());
+                    for (index, r#type) in types.into_iter().enumerate() {
+                        fields.push((
+                            index.to_string(),
+                            Type::from_type_variant(&r#type.variant, scope.clone())?,
+                        ));
+                    }
+                    (next_value.clone(), fields, true)
+                }
+                (None, Some(Payload::Structure(structure_fields))) => {
+                    let mut fields: Vec<(String, Type)> =
+                        Vec::with_capacity(structure_fields.len());
+                    for field in structure_fields.into_iter() {
+                        if fields
+                            .iter()
+                            .any(|(name, _type)| name == &field.identifier.name)
+                        {
+                            return Err(Error::Element(
+                                field.location,
+                                ElementError::Type(TypeError::Enumeration(
+                                    EnumerationTypeError::DuplicateField {
+                                        type_identifier: identifier.name,
+                                        variant_name: variant.identifier.name,
+                                        field_name: field.identifier.name,
+                                    },
+                                )),
+                            ));
+                        }
+                        fields.push((
+                            field.identifier.name,
+                            Type::from_type_variant(&field.r#type.variant, scope.clone())?,
+                        ));
+                    }
+                    (next_value.clone(), fields, false)
+                }
+                (None, None) => panic!(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS),
+            };
+
+            if variants_semantic
+                .iter()
+                .any(|(_identifier, variant)| variant.value == value)
+            {
+                return Err(Error::Element(
+                    location,
+                    ElementError::Type(TypeError::Enumeration(
+                        EnumerationTypeError::DuplicateVariantValue {
+                            type_identifier: identifier.name,
+                            variant_name: variant.identifier.name,
+                            value,
+                        },
+                    )),
+                ));
+            }
+
+            next_value = value.clone() + BigInt::one();
+            variants_semantic.push((
+                variant.identifier.clone(),
+                Variant::new(variant.identifier.name, value, fields, is_tuple),
+            ));
+        }
+
+        let bigints: Vec<BigInt> = variants_semantic
+            .iter()
+            .map(|(_identifier, variant)| variant.value.to_owned())
+            .collect();
+
+        let minimal_bitlength = IntegerConstant::minimal_bitlength_bigints(
+            bigints.iter().collect::<Vec<&BigInt>>().as_slice(),
+            false,
+        )
+        .map_err(|error| {
+            Error::Element(
+                identifier.location,
+                ElementError::Constant(ConstantError::Integer(error)),
+            )
+        })?;
+
+        let mut enumeration = Self {
+            identifier: identifier.name,
+            unique_id,
+            bitlength: minimal_bitlength,
+            values: bigints,
+            variants: variants_semantic
+                .iter()
+                .map(|(_identifier, variant)| variant.to_owned())
+                .collect(),
+            scope: scope.clone(),
+        };
+
+        for (identifier, variant) in variants_semantic.into_iter() {
+            let location = identifier.location;
+
+            let mut constant = IntegerConstant::new(variant.value, false, minimal_bitlength);
+
+            constant.set_enumeration(enumeration.clone());
+
+            Scope::declare_constant(scope.clone(), identifier, Constant::Integer(constant), true)
+                .map_err(|error| Error::Scope(location, error))?;
+        }
+
+        scope
+            .borrow_mut()
+            .declare_self(Type::Enumeration(enumeration.clone()));
+
+        enumeration.values.sort();
+
+        Ok(enumeration)
+    }
+
+    ///
+    /// Checks if any variant carries a payload, which makes the enumeration values
+    /// non-scalar.
+    ///
+    pub fn is_algebraic(&self) -> bool {
+        self.variants
+            .iter()
+            .any(|variant| !variant.fields.is_empty())
+    }
+
+    ///
+    /// The runtime size, which is the tag and the largest payload.
+    ///
+    pub fn size(&self) -> usize {
+        1 + self
+            .variants
+            .iter()
+            .map(Variant::size)
+            .max()
+            .unwrap_or_default()
+    }
+
+    pub fn variant(&self, value: &BigInt) -> Option<&Variant> {
+        self.variants.iter().find(|variant| &variant.value == value)
+    }
+}
+
+impl PartialEq<Self> for Enumeration {
+    fn eq(&self, other: &Self) -> bool {
+        self.unique_id == other.unique_id
+    }
+}
+
+impl fmt::Display for Enumeration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "enum {}", self.identifier)
+    }
+}
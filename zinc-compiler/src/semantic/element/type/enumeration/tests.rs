@@ -0,0 +1,271 @@
+//!
+//! The semantic analyzer enumeration type element tests.
+//!
+
+#![cfg(test)]
+
+use num_bigint::BigInt;
+
+use crate::error::Error;
+use crate::lexical::token::location::Location;
+use crate::semantic::element::error::Error as ElementError;
+use crate::semantic::element::r#type::enumeration::error::Error as EnumerationTypeError;
+use crate::semantic::element::r#type::error::Error as TypeError;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_algebraic() {
+    let input = r#"
+enum Op {
+    Transfer { to: field, amount: u64 },
+    Burn(u64),
+    Noop = 10,
+}
+
+fn main() -> u64 {
+    let op = Op::Transfer { to: 42 as field, amount: 25 as u64 };
+    match op {
+        Op::Transfer { amount } => amount,
+        Op::Burn(amount) => amount,
+        Op::Noop => 0 as u64,
+    }
+}
+"#;
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert!(result.is_ok());
+}
+
+#[test]
+fn error_duplicate_variant_value() {
+    let input = r#"
+enum Op {
+    Noop = 1,
+    Burn(u64),
+    Mint = 2,
+}
+
+fn main() -> u8 {
+    42
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(5, 5),
+        ElementError::Type(TypeError::Enumeration(
+            EnumerationTypeError::DuplicateVariantValue {
+                type_identifier: "Op".to_owned(),
+                variant_name: "Mint".to_owned(),
+                value: BigInt::from(2),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_duplicate_field() {
+    let input = r#"
+enum Op {
+    Transfer { to: field, to: u64 },
+}
+
+fn main() -> u8 {
+    42
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 27),
+        ElementError::Type(TypeError::Enumeration(
+            EnumerationTypeError::DuplicateField {
+                type_identifier: "Op".to_owned(),
+                variant_name: "Transfer".to_owned(),
+                field_name: "to".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_variant_payload_missing() {
+    let input = r#"
+enum Op {
+    Burn(u64),
+    Noop = 10,
+}
+
+fn main() {
+    let op = Op::Burn;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(8, 14),
+        ElementError::Type(TypeError::Enumeration(
+            EnumerationTypeError::VariantPayloadMissing {
+                type_identifier: "Op".to_owned(),
+                variant_name: "Burn".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_variant_not_tuple() {
+    let input = r#"
+enum Op {
+    Transfer { to: field, amount: u64 },
+    Noop = 10,
+}
+
+fn main() {
+    let op = Op::Transfer(42 as field, 25 as u64);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(8, 26),
+        ElementError::Type(TypeError::Enumeration(
+            EnumerationTypeError::VariantNotTuple {
+                type_identifier: "Op".to_owned(),
+                variant_name: "Transfer".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_variant_not_structure() {
+    let input = r#"
+enum Op {
+    Burn(u64),
+    Noop = 10,
+}
+
+fn main() {
+    let op = Op::Burn { amount: 25 as u64 };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(8, 16),
+        ElementError::Type(TypeError::Enumeration(
+            EnumerationTypeError::VariantNotStructure {
+                type_identifier: "Op".to_owned(),
+                variant_name: "Burn".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_variant_field_count() {
+    let input = r#"
+enum Op {
+    Burn(u64),
+    Noop = 10,
+}
+
+fn main() {
+    let op = Op::Burn(25 as u64, 42 as u64);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(8, 22),
+        ElementError::Type(TypeError::Enumeration(
+            EnumerationTypeError::VariantFieldCount {
+                type_identifier: "Op".to_owned(),
+                variant_name: "Burn".to_owned(),
+                expected: 1,
+                found: 2,
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_variant_field_does_not_exist() {
+    let input = r#"
+enum Op {
+    Transfer { to: field, amount: u64 },
+    Noop = 10,
+}
+
+fn main() {
+    let op = Op::Transfer { to: 42 as field, value: 25 as u64 };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(8, 46),
+        ElementError::Type(TypeError::Enumeration(
+            EnumerationTypeError::VariantFieldDoesNotExist {
+                type_identifier: "Op".to_owned(),
+                variant_name: "Transfer".to_owned(),
+                field_name: "value".to_owned(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_variant_field_invalid_type() {
+    let input = r#"
+enum Op {
+    Burn(u64),
+    Noop = 10,
+}
+
+fn main() {
+    let op = Op::Burn(true);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(8, 22),
+        ElementError::Type(TypeError::Enumeration(
+            EnumerationTypeError::VariantFieldInvalidType {
+                type_identifier: "Op".to_owned(),
+                variant_name: "Burn".to_owned(),
+                field_name: "0".to_owned(),
+                expected: Type::integer_unsigned(crate::BITLENGTH_INDEX).to_string(),
+                found: Type::boolean().to_string(),
+            },
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,54 @@
+//!
+//! The semantic analyzer enumeration type variant.
+//!
+
+use num_bigint::BigInt;
+
+use crate::semantic::element::r#type::Type;
+
+///
+/// Describes an enumeration variant with its `value` used as the runtime tag and the
+/// payload `fields`, which are empty for the ordinar variants.
+///
+/// The tuple-like payload fields are named after their indexes.
+///
+#[derive(Debug, Clone)]
+pub struct Variant {
+    pub name: String,
+    pub value: BigInt,
+    pub fields: Vec<(String, Type)>,
+    pub is_tuple: bool,
+}
+
+impl Variant {
+    pub fn new(name: String, value: BigInt, fields: Vec<(String, Type)>, is_tuple: bool) -> Self {
+        Self {
+            name,
+            value,
+            fields,
+            is_tuple,
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.fields
+            .iter()
+            .map(|(_name, r#type)| r#type.size())
+            .sum()
+    }
+
+    ///
+    /// Returns the field type and its offset within the enumeration value, which starts
+    /// right after the tag.
+    ///
+    pub fn field(&self, name: &str) -> Option<(usize, Type)> {
+        let mut offset = 1;
+        for (field_name, r#type) in self.fields.iter() {
+            if field_name == name {
+                return Some((offset, r#type.to_owned()));
+            }
+            offset += r#type.size();
+        }
+        None
+    }
+}
@@ -2,6 +2,7 @@
 //! The semantic analyzer type error.
 //!
 
+use crate::semantic::element::r#type::enumeration::error::Error as EnumerationTypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionTypeError;
 use crate::semantic::element::r#type::structure::error::Error as StructureTypeError;
 
@@ -10,6 +11,7 @@ pub enum Error {
     AliasDoesNotPointToType { found: String },
     AliasDoesNotPointToStructure { found: String },
 
+    Enumeration(EnumerationTypeError),
     Function(FunctionTypeError),
     Structure(StructureTypeError),
 }
@@ -40,6 +40,21 @@ pub enum Error {
     NonCallable {
         name: String,
     },
+    GenericParameterNotInferred {
+        function: String,
+        name: String,
+    },
+    GenericParameterBoundNotSatisfied {
+        function: String,
+        name: String,
+        found: String,
+        bound: String,
+    },
+    GenericArgumentCount {
+        function: String,
+        expected: usize,
+        found: usize,
+    },
 
     BuiltIn(BuiltInFunctionTypeError),
     StandardLibrary(StandardLibraryFunctionTypeError),
@@ -109,4 +124,30 @@ impl Error {
     pub fn non_callable(name: String) -> Self {
         Self::NonCallable { name }
     }
+
+    pub fn generic_parameter_not_inferred(function: String, name: String) -> Self {
+        Self::GenericParameterNotInferred { function, name }
+    }
+
+    pub fn generic_parameter_bound_not_satisfied(
+        function: String,
+        name: String,
+        found: String,
+        bound: String,
+    ) -> Self {
+        Self::GenericParameterBoundNotSatisfied {
+            function,
+            name,
+            found,
+            bound,
+        }
+    }
+
+    pub fn generic_argument_count(function: String, expected: usize, found: usize) -> Self {
+        Self::GenericArgumentCount {
+            function,
+            expected,
+            found,
+        }
+    }
 }
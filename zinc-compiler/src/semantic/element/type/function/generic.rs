@@ -0,0 +1,306 @@
+//!
+//! The semantic analyzer generic function element.
+//!
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use num_bigint::BigInt;
+
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::function::error::Error;
+use crate::semantic::element::r#type::function::user::Function as UserFunction;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::scope::item::variant::Variant as ScopeItemVariant;
+use crate::semantic::scope::Scope;
+use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::generic_parameter::variant::Variant as GenericParameterVariant;
+use crate::syntax::tree::generic_parameter::Parameter as GenericParameter;
+use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
+use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
+
+///
+/// Describes a function with generic parameters.
+///
+/// The function body cannot be analyzed until the generic arguments are known, so the syntax
+/// tree is kept along with the declaration `scope`. Each distinct set of generic arguments is
+/// analyzed as a separate user-defined function, which is cached in `instances`.
+///
+/// The type `arguments` are set if they are specified explicitly at the call site, e.g.
+/// `digest::<Sha256>(...)`, and take precedence over the inferred ones.
+///
+#[derive(Debug, Clone)]
+#[allow(clippy::type_complexity)]
+pub struct Function {
+    identifier: String,
+    statement: FnStatement,
+    scope: Rc<RefCell<Scope>>,
+    instances: Rc<RefCell<Vec<(Vec<Argument>, UserFunction)>>>,
+    arguments: Vec<Type>,
+}
+
+///
+/// The generic argument an instance is identified by.
+///
+/// The structure and enumeration types are compared by their unique ids, so the types with
+/// the same name declared in different modules produce different instances.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Argument {
+    Type(Type),
+    Constant(Constant),
+}
+
+impl Function {
+    pub fn new(identifier: String, statement: FnStatement, scope: Rc<RefCell<Scope>>) -> Self {
+        Self {
+            identifier,
+            statement,
+            scope,
+            instances: Rc::new(RefCell::new(Vec::new())),
+            arguments: Vec::new(),
+        }
+    }
+
+    ///
+    /// Sets the explicitly specified type arguments.
+    ///
+    /// The number of the arguments must be equal to the number of the type parameters, since
+    /// the constant parameters are always inferred.
+    ///
+    pub fn with_arguments(mut self, arguments: Vec<Type>) -> Result<Self, Error> {
+        let expected = self
+            .statement
+            .generic_parameters
+            .iter()
+            .filter(|parameter| match parameter.variant {
+                GenericParameterVariant::Type { .. } => true,
+                GenericParameterVariant::Constant { .. } => false,
+            })
+            .count();
+        if arguments.len() != expected {
+            return Err(Error::generic_argument_count(
+                self.identifier,
+                expected,
+                arguments.len(),
+            ));
+        }
+
+        self.arguments = arguments;
+        Ok(self)
+    }
+
+    pub fn identifier(&self) -> &str {
+        self.identifier.as_str()
+    }
+
+    pub fn statement(&self) -> &FnStatement {
+        &self.statement
+    }
+
+    pub fn scope(&self) -> Rc<RefCell<Scope>> {
+        self.scope.clone()
+    }
+
+    pub fn instance(&self, arguments: &[Argument]) -> Option<UserFunction> {
+        self.instances
+            .borrow()
+            .iter()
+            .find(|(instance_arguments, _function)| instance_arguments.as_slice() == arguments)
+            .map(|(_arguments, function)| function.to_owned())
+    }
+
+    pub fn add_instance(&self, arguments: Vec<Argument>, function: UserFunction) {
+        self.instances.borrow_mut().push((arguments, function));
+    }
+
+    ///
+    /// Infers the generic arguments from the types of the actual arguments.
+    ///
+    /// Type parameters are bound to types, and constant parameters are bound to array sizes.
+    /// The actual arguments are not checked here, since it is done by the instance call.
+    ///
+    /// The type parameter bounds are checked against the traits implemented by the types.
+    ///
+    pub fn infer(
+        &self,
+        actual_elements: &[Element],
+    ) -> Result<Vec<(GenericParameter, Element)>, Error> {
+        let mut bindings = HashMap::with_capacity(self.statement.generic_parameters.len());
+        for (parameter, r#type) in self
+            .statement
+            .generic_parameters
+            .iter()
+            .filter(|parameter| match parameter.variant {
+                GenericParameterVariant::Type { .. } => true,
+                GenericParameterVariant::Constant { .. } => false,
+            })
+            .zip(self.arguments.iter())
+        {
+            bindings.insert(
+                parameter.identifier.name.to_owned(),
+                Element::Type(r#type.to_owned()),
+            );
+        }
+        for (binding, element) in self
+            .statement
+            .argument_bindings
+            .iter()
+            .zip(actual_elements.iter())
+        {
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                _ => continue,
+            };
+            self.infer_type(&binding.r#type.variant, &r#type, &mut bindings);
+        }
+
+        let mut arguments = Vec::with_capacity(self.statement.generic_parameters.len());
+        for parameter in self.statement.generic_parameters.iter() {
+            match bindings.remove(parameter.identifier.name.as_str()) {
+                Some(element) => {
+                    self.check_bounds(parameter, &element)?;
+                    arguments.push((parameter.to_owned(), element));
+                }
+                None => {
+                    return Err(Error::generic_parameter_not_inferred(
+                        self.identifier.to_owned(),
+                        parameter.identifier.name.to_owned(),
+                    ))
+                }
+            }
+        }
+
+        Ok(arguments)
+    }
+
+    ///
+    /// Checks whether the type bound to the parameter implements the parameter bounds.
+    ///
+    /// The bounds have been resolved to traits during the function declaration analysis.
+    ///
+    fn check_bounds(&self, parameter: &GenericParameter, element: &Element) -> Result<(), Error> {
+        let (bounds, r#type) = match (&parameter.variant, element) {
+            (GenericParameterVariant::Type { bounds }, Element::Type(r#type)) => (bounds, r#type),
+            _ => return Ok(()),
+        };
+
+        for bound in bounds.iter() {
+            match Scope::resolve_item(self.scope.clone(), bound.name.as_str())
+                .expect(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS)
+                .variant
+            {
+                ScopeItemVariant::Trait(ref r#trait) if r#trait.is_implemented_by(r#type) => {}
+                ScopeItemVariant::Trait(_) => {
+                    return Err(Error::generic_parameter_bound_not_satisfied(
+                        self.identifier.to_owned(),
+                        parameter.identifier.name.to_owned(),
+                        r#type.to_string(),
+                        bound.name.to_owned(),
+                    ))
+                }
+                _ => panic!(crate::PANIC_VALIDATED_DURING_SEMANTIC_ANALYSIS),
+            }
+        }
+
+        Ok(())
+    }
+
+    fn infer_type(
+        &self,
+        formal: &TypeVariant,
+        actual: &Type,
+        bindings: &mut HashMap<String, Element>,
+    ) {
+        match (formal, actual) {
+            (TypeVariant::Alias { path }, actual) => {
+                if let Some(name) = self.parameter_name(path, false) {
+                    bindings
+                        .entry(name)
+                        .or_insert_with(|| Element::Type(actual.to_owned()));
+                }
+            }
+            (
+                TypeVariant::Array { inner, size },
+                Type::Array {
+                    r#type,
+                    size: actual_size,
+                },
+            ) => {
+                self.infer_type(inner, r#type, bindings);
+                if let Some(name) = self.parameter_name(size, true) {
+                    bindings.entry(name).or_insert_with(|| {
+                        Element::Constant(Constant::Integer(IntegerConstant::new(
+                            BigInt::from(*actual_size),
+                            false,
+                            crate::BITLENGTH_INDEX,
+                        )))
+                    });
+                }
+            }
+            (TypeVariant::Tuple { inners }, Type::Tuple { types }) => {
+                for (inner, r#type) in inners.iter().zip(types.iter()) {
+                    self.infer_type(inner, r#type, bindings);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    ///
+    /// Returns the generic parameter name, if the expression consists of it only.
+    ///
+    fn parameter_name(&self, expression: &ExpressionTree, is_constant: bool) -> Option<String> {
+        let name = match (
+            expression.value.as_ref(),
+            expression.left.as_ref(),
+            expression.right.as_ref(),
+        ) {
+            (
+                ExpressionTreeNode::Operand(ExpressionOperand::Identifier(identifier)),
+                None,
+                None,
+            ) => identifier.name.as_str(),
+            _ => return None,
+        };
+
+        self.statement
+            .generic_parameters
+            .iter()
+            .find(|parameter| {
+                parameter.identifier.name == name
+                    && match parameter.variant {
+                        GenericParameterVariant::Type { .. } => !is_constant,
+                        GenericParameterVariant::Constant { .. } => is_constant,
+                    }
+            })
+            .map(|parameter| parameter.identifier.name.to_owned())
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "fn {}<{}>(..)",
+            self.identifier,
+            self.statement
+                .generic_parameters
+                .iter()
+                .map(|parameter| match parameter.variant {
+                    GenericParameterVariant::Type { .. } => parameter.identifier.name.to_owned(),
+                    GenericParameterVariant::Constant { .. } =>
+                        format!("const {}", parameter.identifier.name),
+                })
+                .collect::<Vec<String>>()
+                .join(", "),
+        )
+    }
+}
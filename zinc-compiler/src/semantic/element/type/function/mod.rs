@@ -6,16 +6,24 @@ mod tests;
 
 pub mod builtin;
 pub mod error;
+pub mod generic;
 pub mod stdlib;
 pub mod user;
 
+use std::cell::RefCell;
 use std::fmt;
+use std::rc::Rc;
 
 use zinc_bytecode::builtins::BuiltinIdentifier;
 
+use crate::lexical::token::lexeme::keyword::Keyword;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::scope::Scope;
+use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
 
 use self::builtin::Function as BuiltInFunction;
+use self::generic::Function as GenericFunction;
 use self::stdlib::Function as StandardLibraryFunction;
 use self::user::Function as UserFunction;
 
@@ -33,6 +41,9 @@ pub enum Function {
     /// Ordinar functions declared anywhere within a circuit. There is a special `main` function,
     /// which is also declared by user, but serves as the circuit entry point.
     UserDefined(UserFunction),
+    /// User-defined functions with generic parameters. Each call site instantiates an ordinar
+    /// function with the generic arguments inferred from the actual arguments.
+    Generic(GenericFunction),
 }
 
 impl Function {
@@ -62,11 +73,48 @@ impl Function {
         ))
     }
 
+    pub fn new_generic(
+        identifier: String,
+        statement: FnStatement,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Self {
+        Self::Generic(GenericFunction::new(identifier, statement, scope))
+    }
+
     pub fn identifier(&self) -> String {
         match self {
             Function::BuiltInFunction(inner) => inner.identifier().to_owned(),
             Function::StandardLibrary(inner) => inner.identifier().to_owned(),
             Function::UserDefined(inner) => inner.identifier().to_owned(),
+            Function::Generic(inner) => inner.identifier().to_owned(),
+        }
+    }
+
+    ///
+    /// Checks whether the function can be called with the method syntax, e.g. `value.f()`.
+    ///
+    /// The user-defined functions must be declared with `self` as the first argument, whereas
+    /// the standard library functions declared in type scopes always take the value as the
+    /// first argument, e.g. `std::crypto::schnorr::Signature::verify`.
+    ///
+    pub fn is_method(&self) -> bool {
+        match self {
+            Function::BuiltInFunction(_) => false,
+            Function::StandardLibrary(_) => true,
+            Function::UserDefined(inner) => inner
+                .formal_params()
+                .first()
+                .map(|(name, _type)| name == Keyword::SelfLowercase.to_string().as_str())
+                .unwrap_or_default(),
+            Function::Generic(inner) => inner
+                .statement()
+                .argument_bindings
+                .first()
+                .map(|binding| match binding.variant {
+                    BindingPatternVariant::SelfAlias { .. } => true,
+                    _ => false,
+                })
+                .unwrap_or_default(),
         }
     }
 }
@@ -77,6 +125,7 @@ impl fmt::Display for Function {
             Self::BuiltInFunction(inner) => write!(f, "{}", inner),
             Self::StandardLibrary(inner) => write!(f, "{}", inner),
             Self::UserDefined(inner) => write!(f, "{}", inner),
+            Self::Generic(inner) => write!(f, "{}", inner),
         }
     }
 }
@@ -4,13 +4,19 @@
 
 #![cfg(test)]
 
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
 use crate::error::Error;
+use crate::generator::statement::Statement as GeneratorStatement;
 use crate::lexical::token::location::Location;
 use crate::semantic::element::r#type::error::Error as TypeError;
 use crate::semantic::element::r#type::function::error::Error as FunctionTypeError;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Error as ElementError;
 use crate::semantic::error::Error as SemanticError;
+use crate::semantic::scope::Scope;
 
 #[test]
 fn error_argument_count() {
@@ -171,3 +177,227 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_generic_parameter_not_inferred() {
+    let input = r#"
+fn default<T>() -> T {
+    0
+}
+
+fn main() {
+    let value = default();
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(7, 24),
+        ElementError::Type(TypeError::Function(
+            FunctionTypeError::generic_parameter_not_inferred("default".to_owned(), "T".to_owned()),
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_argument_type() {
+    let input = r#"
+fn first<T>(a: T, b: T) -> T {
+    a
+}
+
+fn main() {
+    let value = first(42 as u8, true);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(7, 22),
+        ElementError::Type(TypeError::Function(FunctionTypeError::argument_type(
+            "first<u8>".to_owned(),
+            "b".to_owned(),
+            2,
+            Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            Type::boolean().to_string(),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_instance_return_type() {
+    let input = r#"
+fn length<T, const N: u8>(array: [T; N]) -> u8 {
+    array[0]
+}
+
+fn main() {
+    let value = length([true; 4]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(3, 10),
+        ElementError::Type(TypeError::Function(FunctionTypeError::return_type(
+            "length<bool, 4>".to_owned(),
+            Type::integer_unsigned(crate::BITLENGTH_BYTE).to_string(),
+            Type::boolean().to_string(),
+            Location::new(2, 45),
+        ))),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_parameter_bound_not_satisfied() {
+    let input = r#"
+trait Hasher {
+    fn hash(preimage: u8) -> u8;
+}
+
+struct Plain {}
+
+fn digest<H: Hasher>(preimage: u8) -> u8 {
+    H::hash(preimage)
+}
+
+fn main() {
+    let value = digest::<Plain>(42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(13, 32),
+        ElementError::Type(TypeError::Function(
+            FunctionTypeError::generic_parameter_bound_not_satisfied(
+                "digest".to_owned(),
+                "H".to_owned(),
+                "struct Plain".to_owned(),
+                "Hasher".to_owned(),
+            ),
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_argument_count() {
+    let input = r#"
+fn first<T>(a: T, b: T) -> T {
+    a
+}
+
+fn main() {
+    let value = first::<u8, u8>(42, 64);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Element(
+        Location::new(7, 22),
+        ElementError::Type(TypeError::Function(
+            FunctionTypeError::generic_argument_count("first".to_owned(), 1, 2),
+        )),
+    )));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_generic_instances_written_before_caller() {
+    let input = r#"
+fn second<T>(value: T) -> T {
+    value
+}
+
+fn first<T>(value: T) -> T {
+    second(value)
+}
+
+fn main() -> u8 {
+    first(42) + first(64)
+}
+"#;
+
+    let intermediate = crate::semantic::tests::compile_entry_intermediate(input)
+        .expect(crate::semantic::tests::PANIC_TEST_DATA);
+
+    let identifiers: Vec<String> = intermediate
+        .statements
+        .iter()
+        .map(|statement| match statement {
+            GeneratorStatement::Function(function) => function.identifier.to_owned(),
+            _ => panic!(crate::semantic::tests::PANIC_TEST_DATA),
+        })
+        .collect();
+
+    assert_eq!(identifiers, vec!["second<u8>", "first<u8>", "main"]);
+}
+
+#[test]
+fn ok_generic_instances_same_named_types() {
+    let module_1 = r#"
+struct Point {
+    x: u8,
+}
+
+pub fn new() -> Point {
+    Point { x: 1 }
+}
+"#;
+
+    let module_2 = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+pub fn new() -> Point {
+    Point { x: 2, y: 3 }
+}
+"#;
+
+    let binary = r#"
+mod module_1;
+mod module_2;
+
+fn identity<T>(value: T) -> T {
+    value
+}
+
+fn main() -> u8 {
+    let first = identity(module_1::new());
+    let second = identity(module_2::new());
+    first.x + second.y
+}
+"#;
+
+    let module_1 = crate::semantic::tests::compile_module(module_1)
+        .expect(crate::semantic::tests::PANIC_TEST_DATA);
+    let module_2 = crate::semantic::tests::compile_module(module_2)
+        .expect(crate::semantic::tests::PANIC_TEST_DATA);
+
+    let dependencies: HashMap<String, Rc<RefCell<Scope>>> = vec![
+        ("module_1".to_owned(), module_1),
+        ("module_2".to_owned(), module_2),
+    ]
+    .into_iter()
+    .collect();
+
+    let result = crate::semantic::tests::compile_entry_with_dependencies(binary, dependencies);
+
+    assert_eq!(result, Ok(()));
+}
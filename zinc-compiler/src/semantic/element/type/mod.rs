@@ -213,7 +213,7 @@ impl Type {
                 .iter()
                 .map(|(_name, r#type)| r#type.size())
                 .sum(),
-            Self::Enumeration { .. } => 1,
+            Self::Enumeration(enumeration) => enumeration.size(),
             Self::Function { .. } => 0,
         }
     }
@@ -224,7 +224,7 @@ impl Type {
             Self::IntegerUnsigned { .. } => true,
             Self::IntegerSigned { .. } => true,
             Self::Field => true,
-            Self::Enumeration { .. } => true,
+            Self::Enumeration(enumeration) => !enumeration.is_algebraic(),
             _ => false,
         }
     }
@@ -233,7 +233,7 @@ impl Type {
         match self {
             Self::IntegerUnsigned { .. } => true,
             Self::Field => true,
-            Self::Enumeration { .. } => true,
+            Self::Enumeration(enumeration) => !enumeration.is_algebraic(),
             _ => false,
         }
     }
@@ -173,7 +173,7 @@ impl Integer {
 
         if other.is_signed {
             return Err(
-                Error::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned {
+                Error::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned {
                     found: other.to_string(),
                 },
             );
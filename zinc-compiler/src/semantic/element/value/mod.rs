@@ -17,6 +17,7 @@ use crate::semantic::casting::Caster;
 use crate::semantic::element::access::Field as FieldAccess;
 use crate::semantic::element::access::Index as IndexAccess;
 use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::enumeration::Enumeration as EnumerationType;
 use crate::semantic::element::r#type::Type;
 
 use self::array::Array;
@@ -36,6 +37,8 @@ pub enum Value {
     Array(Array),
     Tuple(Tuple),
     Structure(Structure),
+    /// the algebraic enumeration value, which consists of the tag and the variant payload
+    Enumeration(EnumerationType),
 }
 
 impl Value {
@@ -47,6 +50,7 @@ impl Value {
             Self::Array(array) => array.r#type(),
             Self::Tuple(tuple) => tuple.r#type(),
             Self::Structure(structure) => structure.r#type(),
+            Self::Enumeration(enumeration) => Type::Enumeration(enumeration.to_owned()),
         }
     }
 
@@ -62,6 +66,7 @@ impl Value {
             (Self::Structure(value_1), Self::Structure(value_2)) => {
                 value_1.has_the_same_type_as(value_2)
             }
+            (Self::Enumeration(value_1), Self::Enumeration(value_2)) => value_1 == value_2,
             _ => false,
         }
     }
@@ -518,6 +523,9 @@ impl TryFrom<&Type> for Value {
             Type::Array { r#type, size } => Self::Array(Array::new(*r#type.to_owned(), *size)),
             Type::Tuple { types } => Self::Tuple(Tuple::new(types.to_owned())),
             Type::Structure(structure) => Self::Structure(Structure::new(structure.to_owned())),
+            Type::Enumeration(enumeration) if enumeration.is_algebraic() => {
+                Self::Enumeration(enumeration.to_owned())
+            }
             Type::Enumeration(enumeration) => {
                 let mut integer = Integer::new(false, enumeration.bitlength);
                 integer.set_enumeration(enumeration.to_owned());
@@ -545,6 +553,7 @@ impl fmt::Display for Value {
             Self::Array(inner) => write!(f, "{}", inner),
             Self::Tuple(inner) => write!(f, "{}", inner),
             Self::Structure(inner) => write!(f, "{}", inner),
+            Self::Enumeration(inner) => write!(f, "<enumeration> '{}'", inner.identifier),
         }
     }
 }
@@ -21,9 +21,6 @@ pub enum Error {
     MatchLessThanTwoBranches {
         location: Location,
     },
-    MatchBranchUnreachable {
-        location: Location,
-    },
     MatchBranchPatternPathExpectedConstant {
         location: Location,
         found: String,
@@ -45,6 +42,31 @@ pub enum Error {
         reference: Location,
     },
 
+    PatternTupleExpected {
+        location: Location,
+        found: String,
+    },
+    PatternTupleElementCount {
+        location: Location,
+        expected: usize,
+        found: usize,
+    },
+    PatternStructureInvalidType {
+        location: Location,
+        expected: String,
+        found: String,
+    },
+    PatternStructureFieldDoesNotExist {
+        location: Location,
+        type_identifier: String,
+        field_name: String,
+    },
+    PatternStructureDuplicateField {
+        location: Location,
+        type_identifier: String,
+        field_name: String,
+    },
+
     LoopWhileExpectedBooleanCondition {
         location: Location,
         found: String,
@@ -53,6 +75,15 @@ pub enum Error {
         location: Location,
         found: String,
     },
+    LoopBreakOutsideOfLoop {
+        location: Location,
+    },
+    LoopContinueOutsideOfLoop {
+        location: Location,
+    },
+    ReturnOutsideOfFunction {
+        location: Location,
+    },
 
     ConditionalExpectedBooleanCondition {
         location: Location,
@@ -66,6 +97,9 @@ pub enum Error {
     },
 
     EntryPointMissing,
+    EntryPointGeneric {
+        location: Location,
+    },
 
     ModuleNotFound {
         location: Location,
@@ -81,6 +115,58 @@ pub enum Error {
         location: Location,
         found: String,
     },
+    ImplStatementExpectedTrait {
+        location: Location,
+        found: String,
+    },
+    ImplStatementFunctionMissing {
+        location: Location,
+        function: String,
+        trait_identifier: String,
+    },
+    ImplStatementFunctionNotInTrait {
+        location: Location,
+        function: String,
+        trait_identifier: String,
+    },
+    ImplStatementFunctionSignatureMismatch {
+        location: Location,
+        function: String,
+        trait_identifier: String,
+        expected: String,
+        found: String,
+    },
+
+    TraitFunctionGeneric {
+        location: Location,
+        function: String,
+    },
+    TraitExpectedValue {
+        location: Location,
+        name: String,
+    },
+
+    FunctionBodyMissing {
+        location: Location,
+        function: String,
+    },
+    FunctionSelfNotFirst {
+        location: Location,
+        function: String,
+    },
+    FunctionSelfOutsideImplementation {
+        location: Location,
+        function: String,
+    },
+    MethodNotFound {
+        location: Location,
+        name: String,
+        r#type: String,
+    },
+    GenericParameterBoundExpectedTrait {
+        location: Location,
+        found: String,
+    },
 
     ConstantExpressionHasNonConstantElement {
         location: Location,
@@ -7,4 +7,5 @@ pub mod casting;
 pub mod element;
 pub mod error;
 pub mod scope;
+pub mod symbol;
 pub mod tests;
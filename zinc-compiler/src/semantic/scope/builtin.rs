@@ -35,16 +35,8 @@ impl BuiltInItems {
         let std_crypto_blake2s = FunctionType::new_std(BuiltinIdentifier::CryptoBlake2s);
 
         let mut std_crypto_schnorr = Scope::default();
-        let mut std_crypto_schnorr_signature_scope = Scope::default();
         let std_crypto_schnorr_verify =
             FunctionType::new_std(BuiltinIdentifier::CryptoSchnorrSignatureVerify);
-        std_crypto_schnorr_signature_scope.items.insert(
-            std_crypto_schnorr_verify.identifier(),
-            ScopeItem::new(
-                ScopeItemVariant::Type(Type::Function(std_crypto_schnorr_verify)),
-                None,
-            ),
-        );
         let std_crypto_ecc_point = StructureType::new(
             "Point".to_owned(),
             Self::TYPE_ID_STD_CRYPTO_ECC_POINT,
@@ -68,59 +60,56 @@ impl BuiltInItems {
                     Type::Structure(std_crypto_ecc_point.clone()),
                 ),
             ],
-            Some(Rc::new(RefCell::new(std_crypto_schnorr_signature_scope))),
+            None,
         );
+        std_crypto_schnorr_signature
+            .scope
+            .borrow_mut()
+            .items
+            .insert(
+                std_crypto_schnorr_verify.identifier(),
+                ScopeItem::new_built_in(ScopeItemVariant::Type(Type::Function(
+                    std_crypto_schnorr_verify,
+                ))),
+            );
         std_crypto_schnorr.items.insert(
             "Signature".to_owned(),
-            ScopeItem::new(
-                ScopeItemVariant::Type(Type::Structure(std_crypto_schnorr_signature)),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Type(Type::Structure(
+                std_crypto_schnorr_signature,
+            ))),
         );
 
         let mut std_crypto_ecc = Scope::default();
         std_crypto_ecc.items.insert(
             "Point".to_owned(),
-            ScopeItem::new(
-                ScopeItemVariant::Type(Type::Structure(std_crypto_ecc_point)),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Type(Type::Structure(
+                std_crypto_ecc_point,
+            ))),
         );
 
         std_crypto_scope.items.insert(
             std_crypto_sha256.identifier(),
-            ScopeItem::new(
-                ScopeItemVariant::Type(Type::Function(std_crypto_sha256)),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Type(Type::Function(std_crypto_sha256))),
         );
         std_crypto_scope.items.insert(
             std_crypto_pedersen.identifier(),
-            ScopeItem::new(
-                ScopeItemVariant::Type(Type::Function(std_crypto_pedersen)),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Type(Type::Function(std_crypto_pedersen))),
         );
         std_crypto_scope.items.insert(
             std_crypto_blake2s.identifier(),
-            ScopeItem::new(
-                ScopeItemVariant::Type(Type::Function(std_crypto_blake2s)),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Type(Type::Function(std_crypto_blake2s))),
         );
         std_crypto_scope.items.insert(
             "ecc".to_owned(),
-            ScopeItem::new(
-                ScopeItemVariant::Module(Rc::new(RefCell::new(std_crypto_ecc))),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Module(Rc::new(RefCell::new(
+                std_crypto_ecc,
+            )))),
         );
         std_crypto_scope.items.insert(
             "schnorr".to_owned(),
-            ScopeItem::new(
-                ScopeItemVariant::Module(Rc::new(RefCell::new(std_crypto_schnorr))),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Module(Rc::new(RefCell::new(
+                std_crypto_schnorr,
+            )))),
         );
 
         let mut std_convert_scope = Scope::default();
@@ -131,31 +120,25 @@ impl BuiltInItems {
         let std_convert_from_bits_field = FunctionType::new_std(BuiltinIdentifier::FieldFromBits);
         std_convert_scope.items.insert(
             std_convert_to_bits.identifier(),
-            ScopeItem::new(
-                ScopeItemVariant::Type(Type::Function(std_convert_to_bits)),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Type(Type::Function(std_convert_to_bits))),
         );
         std_convert_scope.items.insert(
             std_convert_from_bits_unsigned.identifier(),
-            ScopeItem::new(
-                ScopeItemVariant::Type(Type::Function(std_convert_from_bits_unsigned)),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Type(Type::Function(
+                std_convert_from_bits_unsigned,
+            ))),
         );
         std_convert_scope.items.insert(
             std_convert_from_bits_signed.identifier(),
-            ScopeItem::new(
-                ScopeItemVariant::Type(Type::Function(std_convert_from_bits_signed)),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Type(Type::Function(
+                std_convert_from_bits_signed,
+            ))),
         );
         std_convert_scope.items.insert(
             std_convert_from_bits_field.identifier(),
-            ScopeItem::new(
-                ScopeItemVariant::Type(Type::Function(std_convert_from_bits_field)),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Type(Type::Function(
+                std_convert_from_bits_field,
+            ))),
         );
 
         let mut std_array_scope = Scope::default();
@@ -164,58 +147,48 @@ impl BuiltInItems {
         let std_array_pad = FunctionType::new_std(BuiltinIdentifier::ArrayPad);
         std_array_scope.items.insert(
             std_array_reverse.identifier(),
-            ScopeItem::new(
-                ScopeItemVariant::Type(Type::Function(std_array_reverse)),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Type(Type::Function(std_array_reverse))),
         );
         std_array_scope.items.insert(
             std_array_truncate.identifier(),
-            ScopeItem::new(
-                ScopeItemVariant::Type(Type::Function(std_array_truncate)),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Type(Type::Function(std_array_truncate))),
         );
         std_array_scope.items.insert(
             std_array_pad.identifier(),
-            ScopeItem::new(ScopeItemVariant::Type(Type::Function(std_array_pad)), None),
+            ScopeItem::new_built_in(ScopeItemVariant::Type(Type::Function(std_array_pad))),
         );
 
         let mut std_ff_scope = Scope::default();
         let std_ff_invert = FunctionType::new_std(BuiltinIdentifier::FieldInverse);
         std_ff_scope.items.insert(
             std_ff_invert.identifier(),
-            ScopeItem::new(ScopeItemVariant::Type(Type::Function(std_ff_invert)), None),
+            ScopeItem::new_built_in(ScopeItemVariant::Type(Type::Function(std_ff_invert))),
         );
 
         let mut std_scope = Scope::default();
         std_scope.items.insert(
             "crypto".to_owned(),
-            ScopeItem::new(
-                ScopeItemVariant::Module(Rc::new(RefCell::new(std_crypto_scope))),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Module(Rc::new(RefCell::new(
+                std_crypto_scope,
+            )))),
         );
         std_scope.items.insert(
             "convert".to_owned(),
-            ScopeItem::new(
-                ScopeItemVariant::Module(Rc::new(RefCell::new(std_convert_scope))),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Module(Rc::new(RefCell::new(
+                std_convert_scope,
+            )))),
         );
         std_scope.items.insert(
             "array".to_owned(),
-            ScopeItem::new(
-                ScopeItemVariant::Module(Rc::new(RefCell::new(std_array_scope))),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Module(Rc::new(RefCell::new(
+                std_array_scope,
+            )))),
         );
         std_scope.items.insert(
             "ff".to_owned(),
-            ScopeItem::new(
-                ScopeItemVariant::Module(Rc::new(RefCell::new(std_ff_scope))),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Module(Rc::new(RefCell::new(
+                std_ff_scope,
+            )))),
         );
 
         let mut items = HashMap::with_capacity(3);
@@ -223,24 +196,17 @@ impl BuiltInItems {
         let builtin_function_assert = FunctionType::new_assert();
         items.insert(
             builtin_function_dbg.identifier(),
-            ScopeItem::new(
-                ScopeItemVariant::Type(Type::Function(builtin_function_dbg)),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Type(Type::Function(builtin_function_dbg))),
         );
         items.insert(
             builtin_function_assert.identifier(),
-            ScopeItem::new(
-                ScopeItemVariant::Type(Type::Function(builtin_function_assert)),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Type(Type::Function(
+                builtin_function_assert,
+            ))),
         );
         items.insert(
             "std".to_owned(),
-            ScopeItem::new(
-                ScopeItemVariant::Module(Rc::new(RefCell::new(std_scope))),
-                None,
-            ),
+            ScopeItem::new_built_in(ScopeItemVariant::Module(Rc::new(RefCell::new(std_scope)))),
         );
         items
     }
@@ -16,4 +16,8 @@ pub enum Error {
     ItemIsNotNamespace {
         name: String,
     },
+    ItemIsPrivate {
+        name: String,
+        reference: Option<Location>,
+    },
 }
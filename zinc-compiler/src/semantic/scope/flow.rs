@@ -0,0 +1,208 @@
+//!
+//! The semantic analyzer scope value flow.
+//!
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::lexical::token::location::Location;
+use crate::warning::Warning;
+
+///
+/// The flow of the variable values within a function body, which is used to report the
+/// bindings whose values never reach the function result or an `assert!` call, so they are
+/// not constrained by the circuit.
+///
+/// The variables are identified by their declaration locations, so the shadowed ones are
+/// tracked separately.
+///
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Flow {
+    /// The statements being analyzed, from the outermost to the innermost one.
+    frames: Vec<Frame>,
+    /// The variables referenced by the enclosing conditions, loop `while` conditions, and
+    /// match scrutinees, which decide on the values assigned within their branches.
+    conditions: Vec<HashSet<Location>>,
+    /// The variables, whose values each variable has been computed from.
+    dependencies: HashMap<Location, HashSet<Location>>,
+    /// The variables, whose values reach the function result or an `assert!` call directly.
+    sinks: HashSet<Location>,
+    /// The variables, which have been referenced at least once.
+    referenced: HashSet<Location>,
+    /// The variables declared within the function body, which are checked at its end.
+    bindings: Vec<(Location, String)>,
+}
+
+///
+/// The way the analyzed statement or expression is left.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kind {
+    /// The values are bound to the declared variables or assigned to the mutated ones.
+    Statement,
+    /// The values are also returned from the function.
+    Output,
+    /// The values decide on the following branches, until the condition is left.
+    Condition,
+}
+
+///
+/// The variables declared, referenced, and mutated by a statement.
+///
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Frame {
+    references: HashSet<Location>,
+    declared: Vec<(Location, String)>,
+    mutated: HashSet<Location>,
+    is_constrained: bool,
+}
+
+impl Flow {
+    ///
+    /// Starts the analysis of a statement or condition.
+    ///
+    pub fn enter(&mut self) {
+        self.frames.push(Frame::default());
+    }
+
+    ///
+    /// Finishes the analysis of a statement or condition.
+    ///
+    /// The declared and mutated variables are made dependent on the referenced ones and the
+    /// enclosing conditions. The referenced variables of an `Output` or constrained statement
+    /// become constrained. The condition frame is merged into the enclosing statement, since
+    /// the latter depends on the condition value.
+    ///
+    pub fn leave(&mut self, kind: Kind) {
+        let mut frame = match self.frames.pop() {
+            Some(frame) => frame,
+            None => return,
+        };
+
+        if let Kind::Condition = kind {
+            self.conditions.push(frame.references.clone());
+            if let Some(parent) = self.frames.last_mut() {
+                parent.references.extend(frame.references);
+                parent.declared.extend(frame.declared);
+                parent.mutated.extend(frame.mutated);
+                parent.is_constrained |= frame.is_constrained;
+            }
+            return;
+        }
+
+        for condition in self.conditions.iter() {
+            frame.references.extend(condition.iter().copied());
+        }
+
+        for (location, name) in frame.declared.into_iter() {
+            self.dependencies
+                .entry(location)
+                .or_default()
+                .extend(frame.references.iter().copied());
+            self.bindings.push((location, name));
+        }
+        for location in frame.mutated.into_iter() {
+            self.dependencies
+                .entry(location)
+                .or_default()
+                .extend(frame.references.iter().copied());
+        }
+        if frame.is_constrained || kind == Kind::Output {
+            self.sinks.extend(frame.references);
+        }
+    }
+
+    ///
+    /// Finishes the branches of the innermost condition.
+    ///
+    pub fn leave_condition(&mut self) {
+        self.conditions.pop();
+    }
+
+    ///
+    /// Marks the current statement as constrained, e.g. if it calls `assert!`.
+    ///
+    pub fn constrain(&mut self) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.is_constrained = true;
+        }
+    }
+
+    ///
+    /// Constrains the enclosing conditions of a `break` or `continue` statement, since they
+    /// decide on the values computed after the statement.
+    ///
+    pub fn constrain_conditions(&mut self) {
+        for condition in self.conditions.iter() {
+            self.sinks.extend(condition.iter().copied());
+        }
+    }
+
+    ///
+    /// Records the variable declaration. The function arguments, which are declared outside
+    /// of any statement, are not checked.
+    ///
+    pub fn declare(&mut self, location: Location, name: &str) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.declared.push((location, name.to_owned()));
+        }
+    }
+
+    ///
+    /// Records the variable reference.
+    ///
+    pub fn reference(&mut self, location: Location) {
+        self.referenced.insert(location);
+        if let Some(frame) = self.frames.last_mut() {
+            frame.references.insert(location);
+        }
+    }
+
+    ///
+    /// Records the assignment to the mutable variable.
+    ///
+    pub fn mutate(&mut self, location: Location) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.mutated.insert(location);
+        }
+    }
+
+    ///
+    /// Reports the variables, which are referenced, but do not reach the function result or
+    /// an `assert!` call either directly or through other variables.
+    ///
+    /// The unreferenced variables are reported as unused. The variables starting with an
+    /// underscore are ignored on purpose, so they are not reported along with the variables
+    /// they are computed from.
+    ///
+    pub fn check(&self) {
+        let mut constrained = HashSet::with_capacity(self.dependencies.len());
+        let mut queue: Vec<Location> = self.sinks.iter().copied().collect();
+        queue.extend(
+            self.bindings
+                .iter()
+                .filter(|(_location, name)| name.starts_with('_'))
+                .map(|(location, _name)| *location),
+        );
+        while let Some(location) = queue.pop() {
+            if !constrained.insert(location) {
+                continue;
+            }
+            if let Some(dependencies) = self.dependencies.get(&location) {
+                queue.extend(dependencies.iter().copied());
+            }
+        }
+
+        for (location, name) in self.bindings.iter() {
+            if !self.referenced.contains(location) || constrained.contains(location) {
+                continue;
+            }
+
+            Warning::UnconstrainedValue {
+                location: *location,
+                name: name.to_owned(),
+            }
+            .emit();
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//!
+//! The semantic analyzer scope generic function instances.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::generator::statement::Statement as GeneratorStatement;
+
+///
+/// The IR of the generic function instances created during the analysis of a file.
+///
+/// The list is shared by the file global scope and the instance scopes, so the instances
+/// created while analyzing another instance body are written to the output of the same file.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Instances {
+    statements: Rc<RefCell<Vec<GeneratorStatement>>>,
+}
+
+impl Instances {
+    pub fn push(&self, statement: GeneratorStatement) {
+        self.statements.borrow_mut().push(statement);
+    }
+
+    ///
+    /// Takes the instances created since the previous call, in the order of creation.
+    ///
+    /// The instances must be written before the statement, during which analysis they have been
+    /// created, since the latter may call them.
+    ///
+    pub fn take(&self) -> Vec<GeneratorStatement> {
+        self.statements.replace(Vec::new())
+    }
+}
+
+impl PartialEq for Instances {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.statements, &other.statements)
+    }
+}
@@ -19,11 +19,24 @@ use self::variant::Variant;
 pub struct Item {
     pub variant: Variant,
     pub location: Option<Location>,
+    /// Whether the item is accessible through the module path from outside the module.
+    pub is_public: bool,
 }
 
 impl Item {
-    pub fn new(variant: Variant, location: Option<Location>) -> Self {
-        Self { variant, location }
+    pub fn new(variant: Variant, location: Option<Location>, is_public: bool) -> Self {
+        Self {
+            variant,
+            location,
+            is_public,
+        }
+    }
+
+    ///
+    /// Creates a built-in item, which is public and does not have a location.
+    ///
+    pub fn new_built_in(variant: Variant) -> Self {
+        Self::new(variant, None, true)
     }
 }
 
@@ -0,0 +1,80 @@
+//!
+//! The semantic analyzer scope trait item variant.
+//!
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::fmt;
+use std::rc::Rc;
+
+use crate::semantic::element::r#type::Type;
+use crate::semantic::scope::Scope;
+use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
+
+///
+/// The trait item, declared using a `trait` statement.
+///
+/// The function types are resolved separately for each implementation, since they may refer
+/// to the implementing type as `Self`. Therefore, the function declarations are stored along
+/// with the declaration `scope`. The implementing types are tracked by their unique IDs.
+///
+#[derive(Debug, Clone)]
+pub struct Trait {
+    pub identifier: String,
+    pub functions: Vec<FnStatement>,
+    pub scope: Rc<RefCell<Scope>>,
+    implementations: Rc<RefCell<HashSet<usize>>>,
+}
+
+impl Trait {
+    pub fn new(identifier: String, functions: Vec<FnStatement>, scope: Rc<RefCell<Scope>>) -> Self {
+        Self {
+            identifier,
+            functions,
+            scope,
+            implementations: Rc::new(RefCell::new(HashSet::new())),
+        }
+    }
+
+    ///
+    /// Registers the `type` as an implementation of the trait.
+    ///
+    pub fn add_implementation(&self, r#type: &Type) {
+        if let Some(unique_id) = Self::unique_id(r#type) {
+            self.implementations.borrow_mut().insert(unique_id);
+        }
+    }
+
+    ///
+    /// Checks whether the trait is implemented for the `type`.
+    ///
+    pub fn is_implemented_by(&self, r#type: &Type) -> bool {
+        match Self::unique_id(r#type) {
+            Some(unique_id) => self.implementations.borrow().contains(&unique_id),
+            None => false,
+        }
+    }
+
+    ///
+    /// Only structures and enumerations can implement traits.
+    ///
+    fn unique_id(r#type: &Type) -> Option<usize> {
+        match r#type {
+            Type::Structure(structure) => Some(structure.unique_id),
+            Type::Enumeration(enumeration) => Some(enumeration.unique_id),
+            _ => None,
+        }
+    }
+}
+
+impl PartialEq<Self> for Trait {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.implementations, &other.implementations)
+    }
+}
+
+impl fmt::Display for Trait {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "trait {}", self.identifier)
+    }
+}
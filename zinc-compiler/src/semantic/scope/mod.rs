@@ -6,23 +6,33 @@ mod tests;
 
 pub mod builtin;
 pub mod error;
+pub mod flow;
+pub mod instances;
 pub mod item;
 pub mod stack;
 
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::rc::Rc;
 use std::str;
 
 use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::location::Location;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::path::Path;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
+use crate::semantic::symbol::Table as SymbolTable;
 use crate::syntax::tree::identifier::Identifier;
+use crate::warning::Warning;
 
 use self::builtin::BuiltInItems;
 use self::error::Error;
+use self::flow::Flow;
+use self::flow::Kind as FlowKind;
+use self::instances::Instances;
+use self::item::variant::r#trait::Trait as TraitItem;
 use self::item::variant::variable::Variable as VariableItem;
 use self::item::variant::Variant as ItemVariant;
 use self::item::Item;
@@ -36,6 +46,24 @@ use self::item::Item;
 pub struct Scope {
     parent: Option<Rc<RefCell<Self>>>,
     items: HashMap<String, Item>,
+    /// The number of `break`, `continue`, and `return` statements, which is only set for loop
+    /// and function bodies.
+    controls: Option<usize>,
+    /// The function identifier, return type, and its location, which are only set for
+    /// function bodies.
+    function: Option<(String, Type, Location)>,
+    /// The names of the items which have been referenced, which is used to report the unused
+    /// items when the scope is left.
+    used: HashSet<String>,
+    /// The names of the mutable variables which have been assigned to.
+    mutated: HashSet<String>,
+    /// The names of the items declared by `use` statements, with the statement locations.
+    imports: HashMap<String, Location>,
+    /// The generic function instances, which are only set for the global scope of a file and
+    /// for the instance scopes.
+    instances: Option<Instances>,
+    /// The flow of the variable values, which is only set for function bodies.
+    flow: Option<Flow>,
 }
 
 impl Scope {
@@ -46,6 +74,13 @@ impl Scope {
         Self {
             parent,
             items: HashMap::new(),
+            controls: None,
+            function: None,
+            used: HashSet::new(),
+            mutated: HashSet::new(),
+            imports: HashMap::new(),
+            instances: None,
+            flow: None,
         }
     }
 
@@ -56,16 +91,27 @@ impl Scope {
         Self {
             parent: None,
             items: BuiltInItems::new_map(),
+            controls: None,
+            function: None,
+            used: HashSet::new(),
+            mutated: HashSet::new(),
+            imports: HashMap::new(),
+            instances: Some(Instances::default()),
+            flow: None,
         }
     }
 
     ///
-    /// Declares a general item.
+    /// Declares a general item, which is normally a `use` import or an associated item.
+    ///
+    /// The item visibility is overridden by `is_public`, since an imported item is private
+    /// in the importing scope unless the import itself is public.
     ///
     pub fn declare_item(
         scope: Rc<RefCell<Scope>>,
         identifier: Identifier,
-        item: Item,
+        mut item: Item,
+        is_public: bool,
     ) -> Result<(), Error> {
         if let Ok(item) = Self::resolve_item(scope.clone(), &identifier.name) {
             return Err(Error::ItemRedeclared {
@@ -73,6 +119,7 @@ impl Scope {
                 reference: item.location,
             });
         }
+        item.is_public = is_public;
         scope.borrow_mut().items.insert(identifier.name, item);
         Ok(())
     }
@@ -80,21 +127,61 @@ impl Scope {
     ///
     /// Declares a variable, which is normally a `let` binding or a function actual parameter.
     ///
+    /// A variable may shadow another variable, which is reported as a warning. If the shadowed
+    /// variable is declared in the same scope, it is checked for being unused right away.
+    ///
     pub fn declare_variable(
         scope: Rc<RefCell<Scope>>,
         identifier: Identifier,
         variable: VariableItem,
     ) -> Result<(), Error> {
         if let Ok(item) = Self::resolve_item(scope.clone(), &identifier.name) {
-            return Err(Error::ItemRedeclared {
-                name: identifier.name,
-                reference: item.location,
-            });
+            match item.variant {
+                ItemVariant::Variable(_) => {
+                    if scope.borrow().items.contains_key(&identifier.name) {
+                        scope.borrow_mut().check_unused_variable(&identifier.name);
+                    }
+                    Warning::ShadowedBinding {
+                        location: identifier.location,
+                        name: identifier.name.clone(),
+                        reference: item.location,
+                    }
+                    .emit();
+                }
+                _ => {
+                    return Err(Error::ItemRedeclared {
+                        name: identifier.name,
+                        reference: item.location,
+                    })
+                }
+            }
         }
-        scope.borrow_mut().items.insert(
-            identifier.name,
-            Item::new(ItemVariant::Variable(variable), Some(identifier.location)),
+        let item = Item::new(
+            ItemVariant::Variable(variable),
+            Some(identifier.location),
+            false,
         );
+        SymbolTable::record_symbol(identifier.location, &identifier.name, &item);
+        Self::update_flow(scope.clone(), |flow| {
+            flow.declare(identifier.location, &identifier.name)
+        });
+        scope.borrow_mut().items.insert(identifier.name, item);
+        Ok(())
+    }
+
+    ///
+    /// Declares an item imported by a `use` statement, which is reported if it is not used.
+    ///
+    pub fn declare_import(
+        scope: Rc<RefCell<Scope>>,
+        identifier: Identifier,
+        item: Item,
+        is_public: bool,
+    ) -> Result<(), Error> {
+        let name = identifier.name.clone();
+        let location = identifier.location;
+        Self::declare_item(scope.clone(), identifier, item, is_public)?;
+        scope.borrow_mut().imports.insert(name, location);
         Ok(())
     }
 
@@ -105,6 +192,7 @@ impl Scope {
         scope: Rc<RefCell<Scope>>,
         identifier: Identifier,
         constant: Constant,
+        is_public: bool,
     ) -> Result<(), Error> {
         if let Ok(item) = Self::resolve_item(scope.clone(), &identifier.name) {
             return Err(Error::ItemRedeclared {
@@ -112,10 +200,13 @@ impl Scope {
                 reference: item.location,
             });
         }
-        scope.borrow_mut().items.insert(
-            identifier.name,
-            Item::new(ItemVariant::Constant(constant), Some(identifier.location)),
+        let item = Item::new(
+            ItemVariant::Constant(constant),
+            Some(identifier.location),
+            is_public,
         );
+        SymbolTable::record_symbol(identifier.location, &identifier.name, &item);
+        scope.borrow_mut().items.insert(identifier.name, item);
         Ok(())
     }
 
@@ -126,6 +217,7 @@ impl Scope {
         scope: Rc<RefCell<Scope>>,
         identifier: Identifier,
         r#type: Type,
+        is_public: bool,
     ) -> Result<(), Error> {
         if let Ok(item) = Self::resolve_item(scope.clone(), &identifier.name) {
             return Err(Error::ItemRedeclared {
@@ -133,10 +225,13 @@ impl Scope {
                 reference: item.location,
             });
         }
-        scope.borrow_mut().items.insert(
-            identifier.name,
-            Item::new(ItemVariant::Type(r#type), Some(identifier.location)),
+        let item = Item::new(
+            ItemVariant::Type(r#type),
+            Some(identifier.location),
+            is_public,
         );
+        SymbolTable::record_symbol(identifier.location, &identifier.name, &item);
+        scope.borrow_mut().items.insert(identifier.name, item);
         Ok(())
     }
 
@@ -147,6 +242,44 @@ impl Scope {
         scope: Rc<RefCell<Scope>>,
         identifier: Identifier,
         module: Rc<RefCell<Scope>>,
+        is_public: bool,
+    ) -> Result<(), Error> {
+        if let Ok(item) = Self::resolve_item(scope.clone(), &identifier.name) {
+            return Err(Error::ItemRedeclared {
+                name: identifier.name,
+                reference: item.location,
+            });
+        }
+        let item = Item::new(
+            ItemVariant::Module(module),
+            Some(identifier.location),
+            is_public,
+        );
+        SymbolTable::record_symbol(identifier.location, &identifier.name, &item);
+        scope.borrow_mut().items.insert(identifier.name, item);
+        Ok(())
+    }
+
+    ///
+    /// Declares a dependency package, which is accessible by its name like a module.
+    ///
+    /// Packages do not have a location and are never public, so they are not re-exported.
+    ///
+    pub fn declare_package(scope: Rc<RefCell<Scope>>, name: String, package: Rc<RefCell<Scope>>) {
+        scope
+            .borrow_mut()
+            .items
+            .insert(name, Item::new(ItemVariant::Module(package), None, false));
+    }
+
+    ///
+    /// Declares a trait, which is normally a `trait` binding.
+    ///
+    pub fn declare_trait(
+        scope: Rc<RefCell<Scope>>,
+        identifier: Identifier,
+        r#trait: TraitItem,
+        is_public: bool,
     ) -> Result<(), Error> {
         if let Ok(item) = Self::resolve_item(scope.clone(), &identifier.name) {
             return Err(Error::ItemRedeclared {
@@ -154,10 +287,13 @@ impl Scope {
                 reference: item.location,
             });
         }
-        scope.borrow_mut().items.insert(
-            identifier.name,
-            Item::new(ItemVariant::Module(module), Some(identifier.location)),
+        let item = Item::new(
+            ItemVariant::Trait(r#trait),
+            Some(identifier.location),
+            is_public,
         );
+        SymbolTable::record_symbol(identifier.location, &identifier.name, &item);
+        scope.borrow_mut().items.insert(identifier.name, item);
         Ok(())
     }
 
@@ -169,7 +305,7 @@ impl Scope {
     pub fn declare_self(&mut self, r#type: Type) {
         self.items.insert(
             Keyword::SelfUppercase.to_string(),
-            Item::new(ItemVariant::Type(r#type), None),
+            Item::new(ItemVariant::Type(r#type), None, false),
         );
     }
 
@@ -177,17 +313,39 @@ impl Scope {
     /// Gets an item at the specified path by looking through modules, implementations,
     /// and enumerations along the way.
     ///
+    /// The items accessed through a module must be public. The items of implementations and
+    /// enumerations are accessible wherever their type is.
+    ///
+    /// Each item along the path is marked as used.
+    ///
     pub fn resolve_path(scope: Rc<RefCell<Scope>>, path: &Path) -> Result<Item, SemanticError> {
         let mut current_scope = scope;
+        let mut is_module = false;
 
         for (index, identifier) in path.elements.iter().enumerate() {
             let item = Self::resolve_item(current_scope.clone(), &identifier.name)
                 .map_err(|error| SemanticError::Scope(identifier.location, error))?;
+            Self::mark_used(current_scope.clone(), &identifier.name);
+            SymbolTable::record_symbol(identifier.location, &identifier.name, &item);
+
+            if is_module && !item.is_public {
+                return Err(SemanticError::Scope(
+                    identifier.location,
+                    Error::ItemIsPrivate {
+                        name: identifier.name.to_owned(),
+                        reference: item.location,
+                    },
+                ));
+            }
 
             if index == path.elements.len() - 1 {
                 return Ok(item);
             }
 
+            is_module = match item.variant {
+                ItemVariant::Module(_) => true,
+                _ => false,
+            };
             current_scope = match item.variant {
                 ItemVariant::Module(ref scope) => scope.to_owned(),
                 ItemVariant::Type(Type::Enumeration(ref enumeration)) => {
@@ -228,6 +386,168 @@ impl Scope {
         }
     }
 
+    ///
+    /// Resolves the item within the current scope only, without looking through its parents.
+    ///
+    /// Is used to look for methods in the type scopes, which are children of the scopes where
+    /// the types are declared.
+    ///
+    pub fn resolve_item_local(scope: Rc<RefCell<Scope>>, identifier: &str) -> Result<Item, Error> {
+        match scope.borrow().items.get(identifier) {
+            Some(item) => Ok(item.to_owned()),
+            None => Err(Error::ItemUndeclared {
+                name: identifier.to_owned(),
+            }),
+        }
+    }
+
+    ///
+    /// Marks the item as used in the nearest scope it is declared in.
+    ///
+    /// The variable reference is also recorded into the value flow.
+    ///
+    pub fn mark_used(scope: Rc<RefCell<Scope>>, identifier: &str) {
+        if scope.borrow().items.contains_key(identifier) {
+            scope.borrow_mut().used.insert(identifier.to_owned());
+            let location = scope.borrow().variable_location(identifier);
+            if let Some(location) = location {
+                Self::update_flow(scope, |flow| flow.reference(location));
+            }
+            return;
+        }
+        let parent = scope.borrow().parent.clone();
+        if let Some(parent) = parent {
+            Self::mark_used(parent, identifier);
+        }
+    }
+
+    ///
+    /// Marks the mutable variable as assigned to in the nearest scope it is declared in.
+    ///
+    pub fn mark_mutated(scope: Rc<RefCell<Scope>>, identifier: &str) {
+        if scope.borrow().items.contains_key(identifier) {
+            scope.borrow_mut().mutated.insert(identifier.to_owned());
+            let location = scope.borrow().variable_location(identifier);
+            if let Some(location) = location {
+                Self::update_flow(scope, |flow| flow.mutate(location));
+            }
+            return;
+        }
+        let parent = scope.borrow().parent.clone();
+        if let Some(parent) = parent {
+            Self::mark_mutated(parent, identifier);
+        }
+    }
+
+    ///
+    /// Reports the variables declared in the scope, which have never been used or mutated.
+    ///
+    /// Is called when the scope is left.
+    ///
+    pub fn check_unused_variables(&mut self) {
+        let mut names: Vec<String> = self
+            .items
+            .iter()
+            .filter(|(_name, item)| match item.variant {
+                ItemVariant::Variable(_) => true,
+                _ => false,
+            })
+            .map(|(name, _item)| name.to_owned())
+            .collect();
+        names.sort();
+        for name in names.into_iter() {
+            self.check_unused_variable(&name);
+        }
+    }
+
+    ///
+    /// Reports the private functions and the imports of the module scope, which have never
+    /// been used.
+    ///
+    /// Is called when the module is analyzed.
+    ///
+    pub fn check_unused_items(&self) {
+        for (name, item) in self.items.iter() {
+            if item.is_public || self.used.contains(name) {
+                continue;
+            }
+
+            if let Some(location) = self.imports.get(name) {
+                Warning::UnusedImport {
+                    location: *location,
+                    name: name.to_owned(),
+                }
+                .emit();
+                continue;
+            }
+
+            match (&item.variant, item.location) {
+                (ItemVariant::Type(Type::Function(_)), Some(location))
+                    if name.as_str()
+                        != crate::semantic::element::r#type::function::user::FUNCTION_MAIN_IDENTIFIER =>
+                {
+                    Warning::UnusedFunction {
+                        location,
+                        name: name.to_owned(),
+                    }
+                    .emit()
+                }
+                _ => {}
+            }
+        }
+    }
+
+    ///
+    /// Reports the variable if it has never been used or mutated, and forgets its usage, since
+    /// the variable is either left or shadowed.
+    ///
+    /// The variables starting with an underscore and the `self` alias are not reported.
+    ///
+    fn check_unused_variable(&mut self, name: &str) {
+        let is_used = self.used.remove(name);
+        let is_mutated = self.mutated.remove(name);
+
+        let (is_mutable, location) = match self.items.get(name) {
+            Some(Item {
+                variant: ItemVariant::Variable(variable),
+                location: Some(location),
+                ..
+            }) => (variable.is_mutable, *location),
+            _ => return,
+        };
+        if name.starts_with('_') || name == Keyword::SelfLowercase.to_string() {
+            return;
+        }
+
+        if !is_used {
+            Warning::UnusedVariable {
+                location,
+                name: name.to_owned(),
+            }
+            .emit();
+        } else if is_mutable && !is_mutated {
+            Warning::UnusedMut {
+                location,
+                name: name.to_owned(),
+            }
+            .emit();
+        }
+    }
+
+    ///
+    /// Returns the items declared in the scope, without the items of its parents.
+    ///
+    pub fn items(&self) -> Vec<(String, Item)> {
+        self.items
+            .iter()
+            .map(|(name, item)| (name.to_owned(), item.to_owned()))
+            .collect()
+    }
+
+    pub fn parent(&self) -> Option<Rc<RefCell<Self>>> {
+        self.parent.clone()
+    }
+
     ///
     /// Checks whether the item is declared within the current scope hierarchy.
     ///
@@ -248,4 +568,195 @@ impl Scope {
     pub fn new_child(parent: Rc<RefCell<Scope>>) -> Rc<RefCell<Scope>> {
         Rc::new(RefCell::new(Scope::new(Some(parent))))
     }
+
+    ///
+    /// Creates a generic function instance scope with the function declaration scope as its
+    /// parent.
+    ///
+    /// The instances created during the instance body analysis are collected into `instances`
+    /// of the calling file instead of the declaring one.
+    ///
+    pub fn new_instance(parent: Rc<RefCell<Scope>>, instances: Instances) -> Rc<RefCell<Scope>> {
+        let mut scope = Scope::new(Some(parent));
+        scope.instances = Some(instances);
+        Rc::new(RefCell::new(scope))
+    }
+
+    ///
+    /// Creates a loop body scope with the current one as its parent.
+    ///
+    pub fn new_loop(parent: Rc<RefCell<Scope>>) -> Rc<RefCell<Scope>> {
+        let mut scope = Scope::new(Some(parent));
+        scope.controls = Some(0);
+        Rc::new(RefCell::new(scope))
+    }
+
+    ///
+    /// Creates a function body scope with the current one as its parent.
+    ///
+    /// The function data is used to check the `return` statements.
+    ///
+    pub fn new_function(
+        parent: Rc<RefCell<Scope>>,
+        identifier: String,
+        return_type: Type,
+        return_type_location: Location,
+    ) -> Rc<RefCell<Scope>> {
+        let mut scope = Scope::new(Some(parent));
+        scope.controls = Some(0);
+        scope.function = Some((identifier, return_type, return_type_location));
+        scope.flow = Some(Flow::default());
+        Rc::new(RefCell::new(scope))
+    }
+
+    ///
+    /// Registers a `break` or `continue` statement in the nearest loop body.
+    ///
+    /// Returns `false` if the scope is not located within a loop body.
+    ///
+    pub fn declare_loop_control(scope: Rc<RefCell<Scope>>) -> bool {
+        {
+            let mut scope = scope.borrow_mut();
+            if scope.function.is_some() {
+                return false;
+            }
+            if let Some(ref mut count) = scope.controls {
+                *count += 1;
+                return true;
+            }
+        }
+        match scope.borrow().parent {
+            Some(ref parent) => Self::declare_loop_control(parent.to_owned()),
+            None => false,
+        }
+    }
+
+    ///
+    /// Registers a `return` statement in the nearest function body and all the loop bodies
+    /// it is located within.
+    ///
+    /// Returns the function identifier, return type, and its location, or `None` if the scope
+    /// is not located within a function body.
+    ///
+    pub fn declare_return(scope: Rc<RefCell<Scope>>) -> Option<(String, Type, Location)> {
+        {
+            let mut scope = scope.borrow_mut();
+            if let Some(ref mut count) = scope.controls {
+                *count += 1;
+            }
+            if let Some(ref function) = scope.function {
+                return Some(function.to_owned());
+            }
+        }
+        match scope.borrow().parent {
+            Some(ref parent) => Self::declare_return(parent.to_owned()),
+            None => None,
+        }
+    }
+
+    ///
+    /// Returns the number of `break`, `continue`, and `return` statements registered in the
+    /// nearest loop or function body so far.
+    ///
+    pub fn controls(scope: Rc<RefCell<Scope>>) -> usize {
+        if let Some(count) = scope.borrow().controls {
+            return count;
+        }
+        match scope.borrow().parent {
+            Some(ref parent) => Self::controls(parent.to_owned()),
+            None => 0,
+        }
+    }
+
+    ///
+    /// Starts the value flow analysis of a statement or condition in the nearest function body.
+    ///
+    pub fn enter_flow(scope: Rc<RefCell<Scope>>) {
+        Self::update_flow(scope, Flow::enter);
+    }
+
+    ///
+    /// Finishes the value flow analysis of a statement or condition in the nearest function body.
+    ///
+    pub fn leave_flow(scope: Rc<RefCell<Scope>>, kind: FlowKind) {
+        Self::update_flow(scope, |flow| flow.leave(kind));
+    }
+
+    ///
+    /// Finishes the branches of the innermost condition in the nearest function body.
+    ///
+    pub fn leave_flow_condition(scope: Rc<RefCell<Scope>>) {
+        Self::update_flow(scope, Flow::leave_condition);
+    }
+
+    ///
+    /// Marks the statement being analyzed in the nearest function body as constrained.
+    ///
+    pub fn constrain_flow(scope: Rc<RefCell<Scope>>) {
+        Self::update_flow(scope, Flow::constrain);
+    }
+
+    ///
+    /// Constrains the enclosing conditions of a loop control statement in the nearest function
+    /// body.
+    ///
+    pub fn constrain_flow_conditions(scope: Rc<RefCell<Scope>>) {
+        Self::update_flow(scope, Flow::constrain_conditions);
+    }
+
+    ///
+    /// Reports the variables of the function body, whose values are never constrained.
+    ///
+    /// Is called when the function body is analyzed.
+    ///
+    pub fn check_unconstrained_values(&self) {
+        if let Some(ref flow) = self.flow {
+            flow.check();
+        }
+    }
+
+    ///
+    /// Returns the generic function instances of the nearest instance scope or of the file
+    /// global scope.
+    ///
+    pub fn instances(scope: Rc<RefCell<Scope>>) -> Instances {
+        if let Some(ref instances) = scope.borrow().instances {
+            return instances.to_owned();
+        }
+        match scope.borrow().parent {
+            Some(ref parent) => Self::instances(parent.to_owned()),
+            None => panic!(crate::PANIC_GLOBAL_SCOPE_INSTANCES),
+        }
+    }
+
+    ///
+    /// Updates the value flow of the nearest function body, if the scope is located within one.
+    ///
+    fn update_flow<F>(scope: Rc<RefCell<Scope>>, update: F)
+    where
+        F: FnOnce(&mut Flow),
+    {
+        if let Some(ref mut flow) = scope.borrow_mut().flow {
+            update(flow);
+            return;
+        }
+        let parent = scope.borrow().parent.clone();
+        if let Some(parent) = parent {
+            Self::update_flow(parent, update);
+        }
+    }
+
+    ///
+    /// Returns the declaration location of the variable declared in the scope itself.
+    ///
+    fn variable_location(&self, name: &str) -> Option<Location> {
+        match self.items.get(name) {
+            Some(Item {
+                variant: ItemVariant::Variable(_),
+                location,
+                ..
+            }) => *location,
+            _ => None,
+        }
+    }
 }
@@ -66,11 +66,22 @@ impl Stack {
     }
 
     ///
-    /// Removes the deepest scope from the current hierarchy.
+    /// Removes the deepest scope from the current hierarchy, reporting its unused variables.
     ///
     pub fn pop(&mut self) {
         self.elements
             .pop()
-            .expect(PANIC_THERE_MUST_ALWAYS_BE_A_SCOPE);
+            .expect(PANIC_THERE_MUST_ALWAYS_BE_A_SCOPE)
+            .borrow_mut()
+            .check_unused_variables();
+    }
+
+    ///
+    /// Removes all the scopes except the root one without reporting their unused variables.
+    ///
+    /// Is used to recover after an error, which has left the nested scopes on the stack.
+    ///
+    pub fn unwind(&mut self) {
+        self.elements.truncate(1);
     }
 }
@@ -4,10 +4,17 @@
 
 #![cfg(test)]
 
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
 use crate::error::Error;
 use crate::lexical::token::location::Location;
 use crate::semantic::error::Error as SemanticError;
 use crate::semantic::scope::error::Error as ScopeError;
+use crate::semantic::scope::Scope;
+
+static PANIC_COMPILE_DEPENDENCY: &str = "Dependencies must be successfully compiled";
 
 #[test]
 fn error_item_is_not_namespace() {
@@ -31,22 +38,100 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_item_is_private() {
+    let module_1 = r#"
+fn private() -> u8 {
+    42
+}
+"#;
+
+    let binary = r#"
+mod module_1;
+
+fn main() -> u8 {
+    module_1::private()
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        Location::new(5, 15),
+        ScopeError::ItemIsPrivate {
+            name: "private".to_owned(),
+            reference: Some(Location::new(2, 4)),
+        },
+    )));
+
+    let module_1 =
+        crate::semantic::tests::compile_module(module_1).expect(PANIC_COMPILE_DEPENDENCY);
+
+    let dependencies: HashMap<String, Rc<RefCell<Scope>>> = vec![("module_1".to_owned(), module_1)]
+        .into_iter()
+        .collect();
+
+    let result = crate::semantic::tests::compile_entry_with_dependencies(binary, dependencies);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_item_is_private_nested_module() {
+    let inner = r#"
+pub const VALUE: u8 = 42;
+"#;
+
+    let outer = r#"
+mod inner;
+"#;
+
+    let binary = r#"
+mod outer;
+
+use outer::inner::VALUE;
+
+fn main() -> u8 {
+    VALUE
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::Scope(
+        Location::new(4, 12),
+        ScopeError::ItemIsPrivate {
+            name: "inner".to_owned(),
+            reference: Some(Location::new(2, 5)),
+        },
+    )));
+
+    let inner = crate::semantic::tests::compile_module(inner).expect(PANIC_COMPILE_DEPENDENCY);
+
+    let dependencies: HashMap<String, Rc<RefCell<Scope>>> =
+        vec![("inner".to_owned(), inner)].into_iter().collect();
+    let outer = crate::semantic::tests::compile_module_with_dependencies(outer, dependencies)
+        .expect(PANIC_COMPILE_DEPENDENCY);
+
+    let dependencies: HashMap<String, Rc<RefCell<Scope>>> =
+        vec![("outer".to_owned(), outer)].into_iter().collect();
+
+    let result = crate::semantic::tests::compile_entry_with_dependencies(binary, dependencies);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_item_redeclared() {
     let input = r#"
+fn result() {}
+
 fn main() {
-    let result = 42;
-    {
-        let result = 69;
-    }
+    let result = 69;
 }
 "#;
 
     let expected = Err(Error::Semantic(SemanticError::Scope(
-        Location::new(5, 9),
+        Location::new(5, 5),
         ScopeError::ItemRedeclared {
             name: "result".to_owned(),
-            reference: Some(Location::new(3, 9)),
+            reference: Some(Location::new(2, 4)),
         },
     )));
 
@@ -98,3 +183,68 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_use_nested_module() {
+    let inner = r#"
+pub const VALUE: u8 = 42;
+"#;
+
+    let outer = r#"
+pub mod inner;
+
+pub use inner::VALUE;
+"#;
+
+    let binary = r#"
+mod outer;
+
+use outer::inner::VALUE;
+
+fn main() -> u8 {
+    VALUE + outer::VALUE
+}
+"#;
+
+    let expected = Ok(());
+
+    let inner = crate::semantic::tests::compile_module(inner).expect(PANIC_COMPILE_DEPENDENCY);
+
+    let dependencies: HashMap<String, Rc<RefCell<Scope>>> =
+        vec![("inner".to_owned(), inner)].into_iter().collect();
+    let outer = crate::semantic::tests::compile_module_with_dependencies(outer, dependencies)
+        .expect(PANIC_COMPILE_DEPENDENCY);
+
+    let dependencies: HashMap<String, Rc<RefCell<Scope>>> =
+        vec![("outer".to_owned(), outer)].into_iter().collect();
+
+    let result = crate::semantic::tests::compile_entry_with_dependencies(binary, dependencies);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_use_package() {
+    let package = r#"
+pub const VALUE: u8 = 42;
+"#;
+
+    let binary = r#"
+use merkle::VALUE;
+
+fn main() -> u8 {
+    VALUE + merkle::VALUE
+}
+"#;
+
+    let expected = Ok(());
+
+    let package = crate::semantic::tests::compile_module(package).expect(PANIC_COMPILE_DEPENDENCY);
+
+    let packages: HashMap<String, Rc<RefCell<Scope>>> =
+        vec![("merkle".to_owned(), package)].into_iter().collect();
+
+    let result = crate::semantic::tests::compile_entry_with_packages(binary, packages);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,346 @@
+//!
+//! The semantic analyzer symbol table.
+//!
+
+mod tests;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::lexical::token::location::Location;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::scope::builtin::BuiltInItems;
+use crate::semantic::scope::item::variant::Variant as ItemVariant;
+use crate::semantic::scope::item::Item;
+use crate::semantic::scope::Scope;
+
+thread_local! {
+    /// The symbol table being filled by the semantic analyzer, which is only set if it has been
+    /// requested with `Table::start`.
+    static TABLE: RefCell<Option<Table>> = RefCell::new(None);
+}
+
+///
+/// The symbol table of the analyzed source files, which is used by tools like the language
+/// server to navigate the source code.
+///
+/// The table is not filled unless requested, so the ordinary compilation does not pay for it.
+///
+#[derive(Debug, Default)]
+pub struct Table {
+    /// The item declarations and the resolved item references, in the order of analysis.
+    pub symbols: Vec<Symbol>,
+    /// The scopes at the statement locations, in the order of analysis.
+    scopes: Vec<(Location, Rc<RefCell<Scope>>)>,
+}
+
+///
+/// The item declaration or reference found in the source code.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symbol {
+    /// The location of the identifier.
+    pub location: Location,
+    /// The identifier name.
+    pub name: String,
+    /// The kind of the item the identifier refers to.
+    pub kind: Kind,
+    /// The item description, e.g. the variable type or the function signature.
+    pub description: String,
+    /// The item declaration location, which is not set for the built-in items and packages.
+    pub declaration: Option<Location>,
+}
+
+///
+/// The symbol item kind.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kind {
+    Variable,
+    Constant,
+    Function,
+    Type,
+    Module,
+    Trait,
+}
+
+///
+/// The item which may be written at some location.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Completion {
+    /// The item name.
+    pub name: String,
+    /// The item kind.
+    pub kind: Kind,
+    /// The item description, e.g. the variable type or the function signature.
+    pub description: String,
+}
+
+impl Table {
+    ///
+    /// Starts filling a new symbol table.
+    ///
+    pub fn start() {
+        TABLE.with(|table| table.replace(Some(Self::default())));
+    }
+
+    ///
+    /// Stops filling the symbol table and returns it.
+    ///
+    /// The generic function instances and the default trait methods are analyzed several
+    /// times, so the duplicate symbols are removed.
+    ///
+    pub fn take() -> Self {
+        let mut table = TABLE.with(|table| table.replace(None)).unwrap_or_default();
+
+        let mut symbols: Vec<Symbol> = Vec::with_capacity(table.symbols.len());
+        for symbol in table.symbols.into_iter() {
+            if !symbols.contains(&symbol) {
+                symbols.push(symbol);
+            }
+        }
+        table.symbols = symbols;
+        table
+    }
+
+    ///
+    /// Records the identifier at `location`, which refers to or declares the `item`.
+    ///
+    pub fn record_symbol(location: Location, name: &str, item: &Item) {
+        if !Self::is_started() {
+            return;
+        }
+
+        let (kind, description) = Self::describe(name, item);
+        let symbol = Symbol {
+            location,
+            name: name.to_owned(),
+            kind,
+            description,
+            declaration: item.location,
+        };
+        TABLE.with(|table| {
+            if let Some(ref mut table) = *table.borrow_mut() {
+                table.symbols.push(symbol);
+            }
+        });
+    }
+
+    ///
+    /// Records the scope, which is available at `location`.
+    ///
+    /// The block scopes are filled as the analysis goes, so the snapshot of the scope is stored
+    /// to leave out the items declared after `location`.
+    ///
+    pub fn record_scope(location: Location, scope: &Rc<RefCell<Scope>>, is_snapshot: bool) {
+        if !Self::is_started() {
+            return;
+        }
+
+        let scope = if is_snapshot {
+            Rc::new(RefCell::new(scope.borrow().clone()))
+        } else {
+            scope.to_owned()
+        };
+        TABLE.with(|table| {
+            if let Some(ref mut table) = *table.borrow_mut() {
+                table.scopes.push((location, scope));
+            }
+        });
+    }
+
+    ///
+    /// Returns the symbol, whose identifier contains `location`.
+    ///
+    pub fn symbol_at(&self, location: Location) -> Option<&Symbol> {
+        self.symbols.iter().find(|symbol| {
+            symbol.location.file_index == location.file_index
+                && symbol.location.line == location.line
+                && symbol.location.column <= location.column
+                && location.column < symbol.location.column + symbol.name.len()
+        })
+    }
+
+    ///
+    /// Returns the item declarations of the file, except for the variables, in the order of
+    /// their locations.
+    ///
+    pub fn declarations(&self, file_index: usize) -> Vec<&Symbol> {
+        let mut declarations: Vec<&Symbol> = self
+            .symbols
+            .iter()
+            .filter(|symbol| {
+                symbol.location.file_index == Some(file_index)
+                    && symbol.declaration == Some(symbol.location)
+                    && symbol.kind != Kind::Variable
+            })
+            .collect();
+        declarations.sort_by_key(|symbol| (symbol.location.line, symbol.location.column));
+        declarations
+    }
+
+    ///
+    /// Returns the items, which may be written at `location` after the `path`, e.g.
+    /// `std::crypto::`. If the path is empty, all the items visible at `location` are returned.
+    ///
+    pub fn completions(&self, location: Location, path: &[&str]) -> Vec<Completion> {
+        let scope = match self.scope_at(location) {
+            Some(scope) => scope,
+            None => return vec![],
+        };
+
+        let items = match path.split_first() {
+            None => Self::visible_items(scope),
+            Some((first, rest)) => {
+                let mut item = match Scope::resolve_item(scope, first) {
+                    Ok(item) => item,
+                    Err(_) => return vec![],
+                };
+                for name in rest.iter() {
+                    item = match Self::namespace(&item)
+                        .and_then(|namespace| Scope::resolve_item_local(namespace, name).ok())
+                    {
+                        Some(item) => item,
+                        None => return vec![],
+                    };
+                }
+                match Self::namespace(&item) {
+                    Some(namespace) => Self::namespace_items(namespace, &item),
+                    None => return vec![],
+                }
+            }
+        };
+
+        let mut completions: Vec<Completion> = items
+            .into_iter()
+            .map(|(name, item)| {
+                let (kind, description) = Self::describe(&name, &item);
+                Completion {
+                    name,
+                    kind,
+                    description,
+                }
+            })
+            .collect();
+        completions.sort_by(|a, b| a.name.cmp(&b.name));
+        completions
+    }
+
+    fn is_started() -> bool {
+        TABLE.with(|table| table.borrow().is_some())
+    }
+
+    ///
+    /// Returns the innermost scope recorded at or before `location` in the same file.
+    ///
+    fn scope_at(&self, location: Location) -> Option<Rc<RefCell<Scope>>> {
+        self.scopes
+            .iter()
+            .filter(|(scope_location, _scope)| {
+                scope_location.file_index == location.file_index
+                    && (scope_location.line, scope_location.column)
+                        <= (location.line, location.column)
+            })
+            .max_by_key(|(scope_location, _scope)| (scope_location.line, scope_location.column))
+            .map(|(_location, scope)| scope.to_owned())
+    }
+
+    ///
+    /// Collects the items of the scope and its parents, where the inner items shadow the outer
+    /// ones.
+    ///
+    fn visible_items(scope: Rc<RefCell<Scope>>) -> Vec<(String, Item)> {
+        let mut items: Vec<(String, Item)> = Vec::new();
+        let mut current = Some(scope);
+        while let Some(scope) = current {
+            for (name, item) in scope.borrow().items() {
+                if !items.iter().any(|(existing, _item)| existing == &name) {
+                    items.push((name, item));
+                }
+            }
+            current = scope.borrow().parent();
+        }
+        items
+    }
+
+    ///
+    /// Collects the items accessible through the namespace `item`.
+    ///
+    /// Only the public items of a module are accessible. The built-in items are declared in
+    /// every module scope, so they are left out unless the module is built-in itself.
+    ///
+    fn namespace_items(namespace: Rc<RefCell<Scope>>, item: &Item) -> Vec<(String, Item)> {
+        let is_module = match item.variant {
+            ItemVariant::Module(_) => true,
+            _ => false,
+        };
+        let built_in = BuiltInItems::new_map();
+
+        let items = namespace.borrow().items();
+        items
+            .into_iter()
+            .filter(|(name, namespace_item)| {
+                (!is_module || namespace_item.is_public)
+                    && (namespace_item.location.is_some() || !built_in.contains_key(name))
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the scope of the module, structure, or enumeration item.
+    ///
+    fn namespace(item: &Item) -> Option<Rc<RefCell<Scope>>> {
+        match item.variant {
+            ItemVariant::Module(ref scope) => Some(scope.to_owned()),
+            ItemVariant::Type(Type::Structure(ref structure)) => Some(structure.scope.to_owned()),
+            ItemVariant::Type(Type::Enumeration(ref enumeration)) => {
+                Some(enumeration.scope.to_owned())
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the item kind and its human-readable description.
+    ///
+    fn describe(name: &str, item: &Item) -> (Kind, String) {
+        match item.variant {
+            ItemVariant::Variable(ref variable) => (
+                Kind::Variable,
+                format!(
+                    "let {}{}: {}",
+                    if variable.is_mutable { "mut " } else { "" },
+                    name,
+                    variable.r#type
+                ),
+            ),
+            ItemVariant::Constant(ref constant) => (
+                Kind::Constant,
+                format!("const {}: {}", name, constant.r#type()),
+            ),
+            ItemVariant::Type(Type::Function(ref function)) => {
+                (Kind::Function, function.to_string())
+            }
+            ItemVariant::Type(Type::Structure(ref structure)) => (
+                Kind::Type,
+                format!(
+                    "struct {} {{ {} }}",
+                    structure.identifier,
+                    structure
+                        .fields
+                        .iter()
+                        .map(|(name, r#type)| format!("{}: {}", name, r#type))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+            ),
+            ItemVariant::Type(ref r#type @ Type::Enumeration(_)) => {
+                (Kind::Type, r#type.to_string())
+            }
+            ItemVariant::Type(ref r#type) => (Kind::Type, format!("type {} = {}", name, r#type)),
+            ItemVariant::Module(_) => (Kind::Module, format!("mod {}", name)),
+            ItemVariant::Trait(ref r#trait) => (Kind::Trait, r#trait.to_string()),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+//!
+//! The symbol table tests.
+//!
+
+#![cfg(test)]
+
+use crate::lexical::token::location::Location;
+use crate::semantic::symbol::Kind;
+
+static INPUT: &str = r#"
+const LIMIT: u8 = 100;
+
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn double(value: u8) -> u8 {
+    value * 2
+}
+
+fn main(witness: u8) -> u8 {
+    let first = double(witness);
+    let second = first + LIMIT;
+    second
+}
+"#;
+
+#[test]
+fn ok_symbol_variable_reference() {
+    let table = crate::semantic::tests::compile_entry_symbols(INPUT)
+        .expect(crate::semantic::tests::PANIC_TEST_DATA);
+
+    let symbol = table
+        .symbol_at(Location::new(15, 18))
+        .expect(crate::semantic::tests::PANIC_TEST_DATA);
+
+    assert_eq!(symbol.name, "first");
+    assert_eq!(symbol.kind, Kind::Variable);
+    assert_eq!(symbol.description, "let first: u8");
+    assert_eq!(symbol.declaration, Some(Location::new(14, 9)));
+}
+
+#[test]
+fn ok_symbol_function_reference() {
+    let table = crate::semantic::tests::compile_entry_symbols(INPUT)
+        .expect(crate::semantic::tests::PANIC_TEST_DATA);
+
+    let symbol = table
+        .symbol_at(Location::new(14, 17))
+        .expect(crate::semantic::tests::PANIC_TEST_DATA);
+
+    assert_eq!(symbol.name, "double");
+    assert_eq!(symbol.kind, Kind::Function);
+    assert_eq!(symbol.description, "fn double(value: u8) -> u8");
+    assert_eq!(symbol.declaration, Some(Location::new(9, 4)));
+}
+
+#[test]
+fn ok_declarations() {
+    let table = crate::semantic::tests::compile_entry_symbols(INPUT)
+        .expect(crate::semantic::tests::PANIC_TEST_DATA);
+
+    let declarations: Vec<(String, Kind)> = table
+        .symbols
+        .iter()
+        .filter(|symbol| {
+            symbol.declaration == Some(symbol.location) && symbol.kind != Kind::Variable
+        })
+        .map(|symbol| (symbol.name.to_owned(), symbol.kind))
+        .collect();
+
+    assert!(declarations.contains(&("LIMIT".to_owned(), Kind::Constant)));
+    assert!(declarations.contains(&("Point".to_owned(), Kind::Type)));
+    assert!(declarations.contains(&("double".to_owned(), Kind::Function)));
+    assert!(declarations.contains(&("main".to_owned(), Kind::Function)));
+}
+
+#[test]
+fn ok_completions_scope() {
+    let table = crate::semantic::tests::compile_entry_symbols(INPUT)
+        .expect(crate::semantic::tests::PANIC_TEST_DATA);
+
+    let names: Vec<String> = table
+        .completions(Location::new(14, 5), &[])
+        .into_iter()
+        .map(|completion| completion.name)
+        .collect();
+
+    assert!(names.contains(&"witness".to_owned()));
+    assert!(names.contains(&"first".to_owned()));
+    assert!(names.contains(&"double".to_owned()));
+    assert!(names.contains(&"std".to_owned()));
+    assert!(!names.contains(&"second".to_owned()));
+}
+
+#[test]
+fn ok_completions_path() {
+    let table = crate::semantic::tests::compile_entry_symbols(INPUT)
+        .expect(crate::semantic::tests::PANIC_TEST_DATA);
+
+    let completions = table.completions(Location::new(16, 5), &["std", "crypto"]);
+
+    let sha256 = completions
+        .iter()
+        .find(|completion| completion.name == "sha256")
+        .expect(crate::semantic::tests::PANIC_TEST_DATA);
+    assert_eq!(sha256.kind, Kind::Function);
+    assert!(!completions
+        .iter()
+        .any(|completion| completion.name == "std"));
+}
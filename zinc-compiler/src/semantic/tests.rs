@@ -9,10 +9,13 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use crate::error::Error;
+use crate::generator::Tree;
 use crate::Parser;
 use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 use crate::semantic::analyzer::module::Analyzer as ModuleAnalyzer;
 use crate::semantic::scope::Scope;
+use crate::semantic::symbol::Table as SymbolTable;
+use crate::warning::Warning;
 
 pub static PANIC_TEST_DATA: &str = "Test data is always valid";
 
@@ -26,23 +29,106 @@ pub(crate) fn compile_entry_with_dependencies(
     input: &str,
     dependencies: HashMap<String, Rc<RefCell<Scope>>>,
 ) -> Result<(), Error> {
+    let _intermediate = EntryAnalyzer::default()
+        .compile(
+            Parser::default()
+                .parse(input, None)
+                .expect(PANIC_SYNTAX_ERROR),
+            dependencies,
+        )
+        .map_err(self::first_error)?;
+
+    Ok(())
+}
+
+///
+/// Compiles the entry and returns its intermediate representation.
+///
+pub(crate) fn compile_entry_intermediate(input: &str) -> Result<Tree, Error> {
+    EntryAnalyzer::default()
+        .compile(
+            Parser::default()
+                .parse(input, None)
+                .expect(PANIC_SYNTAX_ERROR),
+            HashMap::new(),
+        )
+        .map_err(self::first_error)
+}
+
+///
+/// Compiles the entry and returns all the errors found by the analyzer.
+///
+pub(crate) fn compile_entry_errors(input: &str) -> Result<(), Vec<Error>> {
     let _intermediate = EntryAnalyzer::default().compile(
         Parser::default()
             .parse(input, None)
             .expect(PANIC_SYNTAX_ERROR),
-        dependencies,
+        HashMap::new(),
     )?;
 
     Ok(())
 }
 
+///
+/// Compiles the entry and returns the warnings emitted during the analysis.
+///
+pub(crate) fn compile_entry_warnings(input: &str) -> Result<Vec<Warning>, Error> {
+    compile_entry(input)?;
+
+    Ok(Warning::take_all())
+}
+
+///
+/// Compiles the entry and returns the symbol table filled during the analysis.
+///
+pub(crate) fn compile_entry_symbols(input: &str) -> Result<SymbolTable, Error> {
+    SymbolTable::start();
+    let result = compile_entry(input);
+    let table = SymbolTable::take();
+    result?;
+
+    Ok(table)
+}
+
+pub(crate) fn compile_entry_with_packages(
+    input: &str,
+    packages: HashMap<String, Rc<RefCell<Scope>>>,
+) -> Result<(), Error> {
+    let _intermediate = EntryAnalyzer::new_with_packages(packages)
+        .compile(
+            Parser::default()
+                .parse(input, None)
+                .expect(PANIC_SYNTAX_ERROR),
+            HashMap::new(),
+        )
+        .map_err(self::first_error)?;
+
+    Ok(())
+}
+
 pub(crate) fn compile_module(input: &str) -> Result<Rc<RefCell<Scope>>, Error> {
-    let (scope, _intermediate) = ModuleAnalyzer::new().compile(
-        Parser::default()
-            .parse(input, None)
-            .expect(PANIC_SYNTAX_ERROR),
-        HashMap::new(),
-    )?;
+    compile_module_with_dependencies(input, HashMap::new())
+}
+
+pub(crate) fn compile_module_with_dependencies(
+    input: &str,
+    dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+) -> Result<Rc<RefCell<Scope>>, Error> {
+    let (scope, _intermediate) = ModuleAnalyzer::new()
+        .compile(
+            Parser::default()
+                .parse(input, None)
+                .expect(PANIC_SYNTAX_ERROR),
+            dependencies,
+        )
+        .map_err(self::first_error)?;
 
     Ok(scope)
 }
+
+///
+/// The tests check the first error, since the next ones depend on the analyzer recovery.
+///
+fn first_error(errors: Vec<Error>) -> Error {
+    errors.into_iter().next().expect(PANIC_TEST_DATA)
+}
@@ -4,6 +4,7 @@
 
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::location::Location;
+use crate::lexical::token::Token;
 
 #[derive(Debug, PartialEq)]
 pub enum Error {
@@ -176,6 +177,44 @@ impl Error {
         Self::ExpectedMatchPattern { location, found }
     }
 
+    ///
+    /// Returns the unexpected token, which has been already taken from the token stream.
+    ///
+    pub fn token(&self) -> Token {
+        match self {
+            Self::ExpectedOneOf {
+                location, found, ..
+            }
+            | Self::ExpectedOneOfOrOperator {
+                location, found, ..
+            }
+            | Self::ExpectedIdentifier {
+                location, found, ..
+            }
+            | Self::ExpectedMutOrIdentifier {
+                location, found, ..
+            }
+            | Self::ExpectedFieldIdentifier {
+                location, found, ..
+            }
+            | Self::ExpectedType {
+                location, found, ..
+            }
+            | Self::ExpectedExpressionOrOperand { location, found }
+            | Self::ExpectedTypeOrValue {
+                location, found, ..
+            }
+            | Self::ExpectedValue {
+                location, found, ..
+            }
+            | Self::ExpectedIntegerLiteral { location, found }
+            | Self::ExpectedBindingPattern { location, found }
+            | Self::ExpectedMatchPattern { location, found } => {
+                Token::new(found.to_owned(), *location)
+            }
+        }
+    }
+
     pub fn format_one_of(lexemes: &[&'static str]) -> String {
         lexemes
             .iter()
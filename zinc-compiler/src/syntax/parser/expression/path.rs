@@ -9,16 +9,25 @@ use crate::error::Error;
 use crate::lexical::stream::TokenStream;
 use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::location::Location;
 use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
 use crate::syntax::parser::expression::terminal::Parser as TerminalOperandParser;
+use crate::syntax::parser::r#type::Parser as TypeParser;
+use crate::syntax::tree::expression::list::Expression as ListExpression;
 use crate::syntax::tree::expression::tree::builder::Builder as ExpressionTreeBuilder;
+use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
 use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 
 #[derive(Debug, Clone, Copy)]
 pub enum State {
     Terminal,
     DoubleColonOrEnd,
+    TerminalOrLesser,
+    GenericArgument,
+    CommaOrGreater,
 }
 
 impl Default for State {
@@ -32,9 +41,17 @@ pub struct Parser {
     state: State,
     next: Option<Token>,
     builder: ExpressionTreeBuilder,
+    location: Option<Location>,
+    generic_arguments: Vec<ExpressionTree>,
 }
 
 impl Parser {
+    ///
+    /// Parses a path expression, which may specify the generic arguments explicitly.
+    ///
+    /// 'std::crypto::sha256'
+    /// 'digest::<Sha256>'
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
@@ -59,14 +76,170 @@ impl Parser {
                             lexeme: Lexeme::Symbol(Symbol::DoubleColon),
                             location,
                         } => {
+                            self.location = Some(location);
+                            self.state = State::TerminalOrLesser;
+                        }
+                        token => return Ok((self.builder.finish(), Some(token))),
+                    }
+                }
+                State::TerminalOrLesser => {
+                    let location = self
+                        .location
+                        .take()
+                        .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Lesser),
+                            ..
+                        } => {
+                            self.builder
+                                .eat_operator(ExpressionOperator::GenericArguments, location);
+                            self.location = Some(location);
+                            self.state = State::GenericArgument;
+                        }
+                        token => {
                             self.builder
                                 .eat_operator(ExpressionOperator::Path, location);
+                            initial = Some(token);
                             self.state = State::Terminal;
                         }
-                        token => return Ok((self.builder.finish(), Some(token))),
+                    }
+                }
+                State::GenericArgument => {
+                    let (r#type, next) = TypeParser::default().parse(stream.clone(), None)?;
+                    self.next = next;
+                    self.generic_arguments.push(ExpressionTree::new(
+                        r#type.location,
+                        ExpressionTreeNode::operand(ExpressionOperand::Type(r#type)),
+                    ));
+                    self.state = State::CommaOrGreater;
+                }
+                State::CommaOrGreater => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::GenericArgument,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Greater),
+                            ..
+                        } => {
+                            let location = self
+                                .location
+                                .take()
+                                .expect(crate::PANIC_VALIDATED_DURING_SYNTAX_ANALYSIS);
+                            let arguments = ListExpression::new(
+                                location,
+                                self.generic_arguments.drain(..).collect(),
+                            );
+                            self.builder
+                                .eat_operand(ExpressionOperand::List(arguments), location);
+                            self.state = State::DoubleColonOrEnd;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ">"],
+                                lexeme,
+                                None,
+                            )));
+                        }
                     }
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Parser;
+    use crate::error::Error;
+    use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::symbol::Symbol;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::lexical::token::Token;
+    use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::expression::list::Expression as ListExpression;
+    use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+    use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+    use crate::syntax::tree::identifier::Identifier;
+    use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
+    use crate::syntax::tree::r#type::Type;
+
+    #[test]
+    fn ok_generic_arguments() {
+        let input = r#"digest::<Sha256, u8>"#;
+
+        let expected = Ok((
+            ExpressionTree::new_with_leaves(
+                Location::new(1, 7),
+                ExpressionTreeNode::operator(ExpressionOperator::GenericArguments),
+                Some(ExpressionTree::new(
+                    Location::new(1, 1),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::new(1, 1),
+                        "digest".to_owned(),
+                    ))),
+                )),
+                Some(ExpressionTree::new(
+                    Location::new(1, 7),
+                    ExpressionTreeNode::operand(ExpressionOperand::List(ListExpression::new(
+                        Location::new(1, 7),
+                        vec![
+                            ExpressionTree::new(
+                                Location::new(1, 10),
+                                ExpressionTreeNode::operand(ExpressionOperand::Type(Type::new(
+                                    Location::new(1, 10),
+                                    TypeVariant::alias(ExpressionTree::new(
+                                        Location::new(1, 10),
+                                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                            Identifier::new(
+                                                Location::new(1, 10),
+                                                "Sha256".to_owned(),
+                                            ),
+                                        )),
+                                    )),
+                                ))),
+                            ),
+                            ExpressionTree::new(
+                                Location::new(1, 18),
+                                ExpressionTreeNode::operand(ExpressionOperand::Type(Type::new(
+                                    Location::new(1, 18),
+                                    TypeVariant::integer_unsigned(8),
+                                ))),
+                            ),
+                        ],
+                    ))),
+                )),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::new(1, 21))),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_greater() {
+        let input = r#"digest::<Sha256; u8>"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 16),
+            vec![",", ">"],
+            Lexeme::Symbol(Symbol::Semicolon),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -11,6 +11,7 @@ use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::parser::recovery::Boundary;
 use crate::syntax::parser::statement::local_fn::Parser as FunctionLocalStatementParser;
 use crate::syntax::tree::expression::block::builder::Builder as BlockExpressionBuilder;
 use crate::syntax::tree::expression::block::Expression as BlockExpression;
@@ -40,6 +41,9 @@ impl Parser {
     ///
     /// Parses a block expression.
     ///
+    /// If a statement is invalid, its error is stored for the top-level parser, and the rest of
+    /// the statement is skipped up to the next one or the end of the block.
+    ///
     /// '
     /// {
     ///     let a = 42;
@@ -82,8 +86,27 @@ impl Parser {
                         } => return Ok((self.builder.finish(), self.next.take())),
                         token => {
                             let (statement, next, is_unterminated) =
-                                FunctionLocalStatementParser::default()
-                                    .parse(stream.clone(), Some(token))?;
+                                match FunctionLocalStatementParser::default()
+                                    .parse(stream.clone(), Some(token))
+                                {
+                                    Ok(result) => result,
+                                    Err(error) => {
+                                        match crate::syntax::parser::recovery::skip_statement(
+                                            stream.clone(),
+                                            &error,
+                                        ) {
+                                            Some(Boundary::Statement) => {
+                                                crate::syntax::parser::recovery::push(error);
+                                                continue;
+                                            }
+                                            Some(Boundary::Block) => {
+                                                crate::syntax::parser::recovery::push(error);
+                                                return Ok((self.builder.finish(), None));
+                                            }
+                                            None => return Err(error),
+                                        }
+                                    }
+                                };
                             self.next = next;
                             match statement {
                                 FunctionLocalStatement::Expression(expression) => {
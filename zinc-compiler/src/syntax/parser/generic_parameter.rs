@@ -0,0 +1,283 @@
+//!
+//! The generic parameter parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::lexeme::symbol::Symbol;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::parser::r#type::Parser as TypeParser;
+use crate::syntax::tree::generic_parameter::builder::Builder as GenericParameterBuilder;
+use crate::syntax::tree::generic_parameter::Parameter as GenericParameter;
+use crate::syntax::tree::identifier::Identifier;
+
+static HINT_EXPECTED_IDENTIFIER: &str =
+    "generic parameter must have an identifier, e.g. `fn f<T, const N: u8>(...) { ... }`";
+static HINT_EXPECTED_TYPE: &str =
+    "constant generic parameter must have a type, e.g. `fn f<const N: u8>(...) { ... }`";
+static HINT_EXPECTED_BOUND: &str =
+    "generic parameter bound must be a trait identifier, e.g. `fn f<H: Hasher>(...) { ... }`";
+
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    KeywordConstOrIdentifier,
+    Identifier,
+    ColonOrEnd,
+    Colon,
+    Type,
+    Bound,
+    PlusOrEnd,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::KeywordConstOrIdentifier
+    }
+}
+
+#[derive(Default)]
+pub struct Parser {
+    state: State,
+    builder: GenericParameterBuilder,
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses a generic parameter.
+    ///
+    /// 'T'
+    /// 'T: Hasher + Default'
+    /// 'const N: u8'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        mut initial: Option<Token>,
+    ) -> Result<(GenericParameter, Option<Token>), Error> {
+        loop {
+            match self.state {
+                State::KeywordConstOrIdentifier => {
+                    match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Const),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.builder.set_is_constant();
+                            self.state = State::Identifier;
+                        }
+                        token => {
+                            self.builder.set_location(token.location);
+                            self.next = Some(token);
+                            self.state = State::Identifier;
+                        }
+                    }
+                }
+                State::Identifier => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.builder
+                                .set_identifier(Identifier::new(location, identifier.inner));
+                            self.state = State::ColonOrEnd;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
+                State::ColonOrEnd => {
+                    if self.builder.is_constant() {
+                        self.state = State::Colon;
+                        continue;
+                    }
+
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Colon),
+                            ..
+                        } => self.state = State::Bound,
+                        token => return Ok((self.builder.finish(), Some(token))),
+                    }
+                }
+                State::Colon => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Colon),
+                            ..
+                        } => self.state = State::Type,
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_type(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_TYPE),
+                            )));
+                        }
+                    }
+                }
+                State::Type => {
+                    let (r#type, next) = TypeParser::default().parse(stream, self.next.take())?;
+                    self.builder.set_type(r#type);
+                    return Ok((self.builder.finish(), next));
+                }
+                State::Bound => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.builder
+                                .push_bound(Identifier::new(location, identifier.inner));
+                            self.state = State::PlusOrEnd;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_BOUND),
+                            )));
+                        }
+                    }
+                }
+                State::PlusOrEnd => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Plus),
+                            ..
+                        } => self.state = State::Bound,
+                        token => return Ok((self.builder.finish(), Some(token))),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Parser;
+    use super::HINT_EXPECTED_BOUND;
+    use super::HINT_EXPECTED_TYPE;
+    use crate::error::Error;
+    use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
+    use crate::lexical::token::lexeme::literal::Literal as LexicalLiteral;
+    use crate::lexical::token::lexeme::symbol::Symbol;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::lexical::token::Token;
+    use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::generic_parameter::variant::Variant as GenericParameterVariant;
+    use crate::syntax::tree::generic_parameter::Parameter as GenericParameter;
+    use crate::syntax::tree::identifier::Identifier;
+    use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
+    use crate::syntax::tree::r#type::Type;
+
+    #[test]
+    fn ok_type() {
+        let input = r#"T"#;
+
+        let expected = Ok((
+            GenericParameter::new(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 1), "T".to_owned()),
+                GenericParameterVariant::new_type(vec![]),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::new(1, 2))),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_type_bounds() {
+        let input = r#"H: Hasher + Default"#;
+
+        let expected = Ok((
+            GenericParameter::new(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 1), "H".to_owned()),
+                GenericParameterVariant::new_type(vec![
+                    Identifier::new(Location::new(1, 4), "Hasher".to_owned()),
+                    Identifier::new(Location::new(1, 13), "Default".to_owned()),
+                ]),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::new(1, 20))),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_constant() {
+        let input = r#"const N: u64"#;
+
+        let expected = Ok((
+            GenericParameter::new(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 7), "N".to_owned()),
+                GenericParameterVariant::new_constant(Type::new(
+                    Location::new(1, 10),
+                    TypeVariant::integer_unsigned(64),
+                )),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_type() {
+        let input = r#"const N>"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_type(
+            Location::new(1, 8),
+            Lexeme::Symbol(Symbol::Greater),
+            Some(HINT_EXPECTED_TYPE),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_bound() {
+        let input = r#"H: 42"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_identifier(
+            Location::new(1, 4),
+            Lexeme::Literal(LexicalLiteral::Integer(LexicalIntegerLiteral::new_decimal(
+                "42".to_owned(),
+            ))),
+            Some(HINT_EXPECTED_BOUND),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+}
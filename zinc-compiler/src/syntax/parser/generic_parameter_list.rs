@@ -0,0 +1,155 @@
+//!
+//! The generic parameter list parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::lexeme::symbol::Symbol;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::Token;
+use crate::syntax::parser::generic_parameter::Parser as GenericParameterParser;
+use crate::syntax::tree::generic_parameter::Parameter as GenericParameter;
+
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    GenericParameter,
+    CommaOrEnd,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::GenericParameter
+    }
+}
+
+#[derive(Default)]
+pub struct Parser {
+    state: State,
+    parameters: Vec<GenericParameter>,
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses a generic parameter list.
+    ///
+    /// 'T, U, const N: u8'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        mut initial: Option<Token>,
+    ) -> Result<(Vec<GenericParameter>, Option<Token>), Error> {
+        loop {
+            match self.state {
+                State::GenericParameter => {
+                    match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+                        token
+                        @
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Const),
+                            ..
+                        } => {
+                            let (parameter, next) = GenericParameterParser::default()
+                                .parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.parameters.push(parameter);
+                        }
+                        token
+                        @
+                        Token {
+                            lexeme: Lexeme::Identifier(_),
+                            ..
+                        } => {
+                            let (parameter, next) = GenericParameterParser::default()
+                                .parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.parameters.push(parameter);
+                        }
+                        token => self.next = Some(token),
+                    }
+                    self.state = State::CommaOrEnd;
+                }
+                State::CommaOrEnd => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::GenericParameter,
+                        token => return Ok((self.parameters, Some(token))),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Parser;
+    use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::symbol::Symbol;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::lexical::token::Token;
+    use crate::syntax::tree::generic_parameter::variant::Variant as GenericParameterVariant;
+    use crate::syntax::tree::generic_parameter::Parameter as GenericParameter;
+    use crate::syntax::tree::identifier::Identifier;
+    use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
+    use crate::syntax::tree::r#type::Type;
+
+    #[test]
+    fn ok_empty() {
+        let input = r#">"#;
+
+        let expected = Ok((
+            Vec::<GenericParameter>::new(),
+            Some(Token::new(
+                Lexeme::Symbol(Symbol::Greater),
+                Location::new(1, 1),
+            )),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_multiple() {
+        let input = r#"T, const N: u8>"#;
+
+        let expected = Ok((
+            vec![
+                GenericParameter::new(
+                    Location::new(1, 1),
+                    Identifier::new(Location::new(1, 1), "T".to_owned()),
+                    GenericParameterVariant::new_type(vec![]),
+                ),
+                GenericParameter::new(
+                    Location::new(1, 4),
+                    Identifier::new(Location::new(1, 10), "N".to_owned()),
+                    GenericParameterVariant::new_constant(Type::new(
+                        Location::new(1, 13),
+                        TypeVariant::integer_unsigned(8),
+                    )),
+                ),
+            ],
+            Some(Token::new(
+                Lexeme::Symbol(Symbol::Greater),
+                Location::new(1, 15),
+            )),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -5,9 +5,13 @@
 pub mod expression;
 pub mod field;
 pub mod field_list;
+pub mod generic_parameter;
+pub mod generic_parameter_list;
 pub mod pattern_binding;
 pub mod pattern_binding_list;
+pub mod pattern_binding_variant;
 pub mod pattern_match;
+pub mod recovery;
 pub mod statement;
 pub mod r#type;
 pub mod variant;
@@ -32,30 +36,56 @@ impl Parser {
     ///
     /// The top-level parser. Parses a list of module level statements.
     ///
-    pub fn parse(mut self, input: &str, file: Option<usize>) -> Result<Tree, Error> {
+    /// If a statement is invalid, the parser skips it up to the next statement or item and goes
+    /// on, so all the syntax errors are returned at once.
+    ///
+    pub fn parse(mut self, input: &str, file: Option<usize>) -> Result<Tree, Vec<Error>> {
         let stream = match file {
             Some(file) => TokenStream::new_with_file(input, file),
             None => TokenStream::new(input),
         };
         let stream = Rc::new(RefCell::new(stream));
 
+        self::recovery::take_all();
         let mut statements = Vec::new();
         loop {
-            match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
-                Token {
+            let token = match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())
+            {
+                Ok(Token {
                     lexeme: Lexeme::Eof,
                     ..
-                } => break,
-                token => {
-                    let (statement, next) =
-                        ModuleLocalStatementParser::default().parse(stream.clone(), Some(token))?;
+                }) => break,
+                Ok(token) => token,
+                Err(error) => {
+                    self::recovery::push(error);
+                    break;
+                }
+            };
+
+            let column = token.location.column;
+            match ModuleLocalStatementParser::default().parse(stream.clone(), Some(token)) {
+                Ok((statement, next)) => {
                     self.next = next;
                     statements.push(statement);
                 }
+                Err(error) => {
+                    self.next = self::recovery::skip_item(stream.clone(), &error, column);
+                    self::recovery::push(error);
+                    if self.next.is_none() {
+                        break;
+                    }
+                }
             }
         }
 
-        Ok(Tree { statements })
+        let errors = self::recovery::take_all();
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        let doc = stream.borrow_mut().take_module_doc();
+
+        Ok(Tree { statements, doc })
     }
 }
 
@@ -68,3 +98,51 @@ pub fn take_or_next(
         None => Ok(stream.borrow_mut().next()?),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Parser;
+    use crate::error::Error;
+    use crate::lexical::token::lexeme::symbol::Symbol;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::syntax::error::Error as SyntaxError;
+
+    #[test]
+    fn error_recovered_statements_and_items() {
+        let input = r#"
+fn first() -> u8 {
+    let a = 5 + ;
+    let b = ;
+    b
+}
+
+fn second() -> u8 {
+    let c = 42
+}
+
+fn main() {}
+"#;
+
+        let expected = Err(vec![
+            Error::Syntax(SyntaxError::expected_expression_or_operand(
+                Location::new(3, 17),
+                Lexeme::Symbol(Symbol::Semicolon),
+            )),
+            Error::Syntax(SyntaxError::expected_expression_or_operand(
+                Location::new(4, 13),
+                Lexeme::Symbol(Symbol::Semicolon),
+            )),
+            Error::Syntax(SyntaxError::expected_one_of_or_operator(
+                Location::new(10, 1),
+                vec![";"],
+                Lexeme::Symbol(Symbol::BracketCurlyRight),
+                None,
+            )),
+        ]);
+
+        let result = Parser::default().parse(input, None);
+
+        assert_eq!(result, expected);
+    }
+}
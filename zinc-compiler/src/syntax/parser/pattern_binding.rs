@@ -12,6 +12,7 @@ use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::parser::pattern_binding_variant::Parser as BindingPatternVariantParser;
 use crate::syntax::parser::r#type::Parser as TypeParser;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::pattern_binding::builder::Builder as BindingPatternBuilder;
@@ -46,6 +47,8 @@ impl Parser {
     /// Parses a binding pattern.
     ///
     /// 'mut a: u8'
+    /// '(a, b): (u8, u8)'
+    /// 'mut self'
     ///
     pub fn parse(
         mut self,
@@ -64,6 +67,25 @@ impl Parser {
                             self.builder.set_is_binding_mutable();
                             self.state = State::IdentifierOrWildcard;
                         }
+                        token
+                        @
+                        Token {
+                            lexeme: Lexeme::Identifier(_),
+                            ..
+                        }
+                        | token
+                        @
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        } => {
+                            self.builder.set_location(token.location);
+                            let (variant, next) = BindingPatternVariantParser::default()
+                                .parse(stream.clone(), Some(token))?;
+                            self.builder.set_variant(variant);
+                            self.next = next;
+                            self.state = State::Colon;
+                        }
                         token => {
                             self.builder.set_location(token.location);
                             self.next = Some(token);
@@ -88,6 +110,13 @@ impl Parser {
                             self.builder.set_is_wildcard();
                             self.state = State::Colon;
                         }
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::SelfLowercase),
+                            location,
+                        } => {
+                            self.builder.set_self_location(location);
+                            return Ok((self.builder.finish(), None));
+                        }
                         Token { lexeme, location } => {
                             return Err(Error::Syntax(SyntaxError::expected_binding_pattern(
                                 location, lexeme,
@@ -132,6 +161,9 @@ mod tests {
     use crate::lexical::token::lexeme::Lexeme;
     use crate::lexical::token::location::Location;
     use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
     use crate::syntax::tree::identifier::Identifier;
     use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
     use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
@@ -198,6 +230,32 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_self_alias() {
+        let input = "mut self";
+
+        let expected = Ok((
+            BindingPattern::new(
+                Location::new(1, 1),
+                BindingPatternVariant::new_self_alias(Location::new(1, 5), true),
+                Type::new(
+                    Location::new(1, 5),
+                    TypeVariant::alias(ExpressionTree::new(
+                        Location::new(1, 5),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::new(1, 5), "Self".to_owned()),
+                        )),
+                    )),
+                ),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_binding_pattern() {
         let input = "mut bool: bool";
@@ -38,6 +38,7 @@ impl Parser {
     /// Parses a binding pattern list.
     ///
     /// 'mut a: u8, b: field, c: (bool, bool)'
+    /// 'self, a: u8'
     ///
     pub fn parse(
         mut self,
@@ -75,6 +76,18 @@ impl Parser {
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::Underscore),
                             ..
+                        }
+                        | token
+                        @
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::SelfLowercase),
+                            ..
+                        }
+                        | token
+                        @
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
                         } => {
                             let (pattern, next) = BindingPatternParser::default()
                                 .parse(stream.clone(), Some(token))?;
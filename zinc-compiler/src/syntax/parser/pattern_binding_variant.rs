@@ -0,0 +1,410 @@
+//!
+//! The binding pattern variant parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::lexeme::symbol::Symbol;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::tree::expression::tree::builder::Builder as ExpressionTreeBuilder;
+use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+
+static HINT_EXPECTED_IDENTIFIER: &str =
+    "binding pattern must have an identifier, e.g. `(a, mut b)` or `Point { x, y: mut z }`";
+
+#[derive(Default)]
+pub struct Parser {}
+
+impl Parser {
+    ///
+    /// Parses a binding pattern variant, which may destructure tuples and structures.
+    ///
+    /// 'mut a'
+    /// '_'
+    /// '(a, (b, _))'
+    /// 'Point { x, y: mut z }'
+    ///
+    pub fn parse(
+        self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(BindingPatternVariant, Option<Token>), Error> {
+        match crate::syntax::parser::take_or_next(initial, stream.clone())? {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Mut),
+                ..
+            } => match crate::syntax::parser::take_or_next(None, stream)? {
+                Token {
+                    lexeme: Lexeme::Identifier(identifier),
+                    location,
+                } => Ok((
+                    BindingPatternVariant::MutableBinding(Identifier::new(
+                        location,
+                        identifier.inner,
+                    )),
+                    None,
+                )),
+                Token { lexeme, location } => Err(Error::Syntax(SyntaxError::expected_identifier(
+                    location,
+                    lexeme,
+                    Some(HINT_EXPECTED_IDENTIFIER),
+                ))),
+            },
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Underscore),
+                ..
+            } => Ok((BindingPatternVariant::Wildcard, None)),
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                location,
+            } => {
+                let (mut elements, is_parenthesized) = Self::tuple(stream)?;
+                if is_parenthesized {
+                    return Ok((elements.remove(0), None));
+                }
+                Ok((BindingPatternVariant::new_tuple(location, elements), None))
+            }
+            Token {
+                lexeme: Lexeme::Identifier(identifier),
+                location,
+            } => Self::path(stream, Identifier::new(location, identifier.inner)),
+            Token { lexeme, location } => Err(Error::Syntax(
+                SyntaxError::expected_binding_pattern(location, lexeme),
+            )),
+        }
+    }
+
+    ///
+    /// Parses either a single identifier binding or the structure path followed by the field
+    /// patterns, e.g. `module::Point { x, y }`.
+    ///
+    fn path(
+        stream: Rc<RefCell<TokenStream>>,
+        identifier: Identifier,
+    ) -> Result<(BindingPatternVariant, Option<Token>), Error> {
+        let location = identifier.location;
+        let mut path = ExpressionTreeBuilder::default();
+        let mut is_path = false;
+        path.eat_operand(ExpressionOperand::Identifier(identifier.clone()), location);
+
+        loop {
+            match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::DoubleColon),
+                    location,
+                } => {
+                    path.eat_operator(ExpressionOperator::Path, location);
+                    match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => path.eat_operand(
+                            ExpressionOperand::Identifier(Identifier::new(
+                                location,
+                                identifier.inner,
+                            )),
+                            location,
+                        ),
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_identifier(
+                                location, lexeme, None,
+                            )));
+                        }
+                    }
+                    is_path = true;
+                }
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                    ..
+                } => {
+                    let fields = Self::structure(stream)?;
+                    return Ok((
+                        BindingPatternVariant::new_structure(location, path.finish(), fields),
+                        None,
+                    ));
+                }
+                Token { lexeme, location } if is_path => {
+                    return Err(Error::Syntax(SyntaxError::expected_one_of(
+                        location,
+                        vec!["{"],
+                        lexeme,
+                        None,
+                    )));
+                }
+                token => return Ok((BindingPatternVariant::Binding(identifier), Some(token))),
+            }
+        }
+    }
+
+    ///
+    /// Parses the comma-separated patterns after the opening parenthesis.
+    ///
+    /// Returns the patterns and whether they are a single parenthesized pattern, which is
+    /// written without the trailing comma, e.g. `(a)` but not `(a,)`.
+    ///
+    fn tuple(
+        stream: Rc<RefCell<TokenStream>>,
+    ) -> Result<(Vec<BindingPatternVariant>, bool), Error> {
+        let mut elements = Vec::new();
+        let mut has_comma = false;
+
+        loop {
+            let (element, next) = match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                    ..
+                } => break,
+                token => Self::default().parse(stream.clone(), Some(token))?,
+            };
+            elements.push(element);
+
+            match crate::syntax::parser::take_or_next(next, stream.clone())? {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Comma),
+                    ..
+                } => has_comma = true,
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                    ..
+                } => break,
+                Token { lexeme, location } => {
+                    return Err(Error::Syntax(SyntaxError::expected_one_of(
+                        location,
+                        vec![",", ")"],
+                        lexeme,
+                        None,
+                    )));
+                }
+            }
+        }
+
+        let is_parenthesized = elements.len() == 1 && !has_comma;
+        Ok((elements, is_parenthesized))
+    }
+
+    ///
+    /// Parses the structure field patterns after the opening curly bracket.
+    ///
+    /// The field identifier is also used as the binding if the pattern is omitted.
+    ///
+    fn structure(
+        stream: Rc<RefCell<TokenStream>>,
+    ) -> Result<Vec<(Identifier, BindingPatternVariant)>, Error> {
+        let mut fields = Vec::new();
+
+        loop {
+            let (field, is_mutable) =
+                match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                    Token {
+                        lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                        ..
+                    } => break,
+                    Token {
+                        lexeme: Lexeme::Keyword(Keyword::Mut),
+                        ..
+                    } => match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => (Identifier::new(location, identifier.inner), true),
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_IDENTIFIER),
+                            )));
+                        }
+                    },
+                    Token {
+                        lexeme: Lexeme::Identifier(identifier),
+                        location,
+                    } => (Identifier::new(location, identifier.inner), false),
+                    Token { lexeme, location } => {
+                        return Err(Error::Syntax(SyntaxError::expected_identifier(
+                            location,
+                            lexeme,
+                            Some(HINT_EXPECTED_IDENTIFIER),
+                        )));
+                    }
+                };
+
+            let next = match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Colon),
+                    ..
+                } if !is_mutable => {
+                    let (pattern, next) = Self::default().parse(stream.clone(), None)?;
+                    fields.push((field, pattern));
+                    crate::syntax::parser::take_or_next(next, stream.clone())?
+                }
+                token => {
+                    let pattern = if is_mutable {
+                        BindingPatternVariant::MutableBinding(field.clone())
+                    } else {
+                        BindingPatternVariant::Binding(field.clone())
+                    };
+                    fields.push((field, pattern));
+                    token
+                }
+            };
+
+            match next {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Comma),
+                    ..
+                } => continue,
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                    ..
+                } => break,
+                Token { lexeme, location } => {
+                    return Err(Error::Syntax(SyntaxError::expected_one_of(
+                        location,
+                        vec![",", "}"],
+                        lexeme,
+                        None,
+                    )));
+                }
+            }
+        }
+
+        Ok(fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Parser;
+    use crate::error::Error;
+    use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::identifier::Identifier as LexicalIdentifier;
+    use crate::lexical::token::lexeme::symbol::Symbol;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::lexical::token::Token;
+    use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+    use crate::syntax::tree::identifier::Identifier;
+    use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+
+    #[test]
+    fn ok_binding() {
+        let input = "value: u8";
+
+        let expected = Ok((
+            BindingPatternVariant::Binding(Identifier::new(
+                Location::new(1, 1),
+                "value".to_owned(),
+            )),
+            Some(Token::new(
+                Lexeme::Symbol(Symbol::Colon),
+                Location::new(1, 6),
+            )),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_tuple() {
+        let input = "(a, (mut b, _))";
+
+        let expected = Ok((
+            BindingPatternVariant::new_tuple(
+                Location::new(1, 1),
+                vec![
+                    BindingPatternVariant::Binding(Identifier::new(
+                        Location::new(1, 2),
+                        "a".to_owned(),
+                    )),
+                    BindingPatternVariant::new_tuple(
+                        Location::new(1, 5),
+                        vec![
+                            BindingPatternVariant::MutableBinding(Identifier::new(
+                                Location::new(1, 10),
+                                "b".to_owned(),
+                            )),
+                            BindingPatternVariant::Wildcard,
+                        ],
+                    ),
+                ],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_structure() {
+        let input = "Point { x, y: mut z }";
+
+        let expected = Ok((
+            BindingPatternVariant::new_structure(
+                Location::new(1, 1),
+                ExpressionTree::new(
+                    Location::new(1, 1),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::new(1, 1),
+                        "Point".to_owned(),
+                    ))),
+                ),
+                vec![
+                    (
+                        Identifier::new(Location::new(1, 9), "x".to_owned()),
+                        BindingPatternVariant::Binding(Identifier::new(
+                            Location::new(1, 9),
+                            "x".to_owned(),
+                        )),
+                    ),
+                    (
+                        Identifier::new(Location::new(1, 12), "y".to_owned()),
+                        BindingPatternVariant::MutableBinding(Identifier::new(
+                            Location::new(1, 19),
+                            "z".to_owned(),
+                        )),
+                    ),
+                ],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_parenthesis() {
+        let input = "(a b)";
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 4),
+            vec![",", ")"],
+            Lexeme::Identifier(LexicalIdentifier::new("b".to_owned())),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+}
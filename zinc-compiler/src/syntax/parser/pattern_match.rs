@@ -13,13 +13,20 @@ use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
 use crate::syntax::parser::expression::terminal::Parser as TerminalOperandParser;
+use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
 use crate::syntax::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::literal::boolean::Literal as BooleanLiteral;
 use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
 use crate::syntax::tree::pattern_match::builder::Builder as MatchPatternBuilder;
+use crate::syntax::tree::pattern_match::variant::Variant as MatchPatternVariant;
 use crate::syntax::tree::pattern_match::Pattern as MatchPattern;
 
+static HINT_EXPECTED_FIELD: &str =
+    "structure pattern must list its fields, e.g. `Point { x, y: 0 }` or `Op::Transfer { to, amount }`";
+
 #[derive(Debug, Clone, Copy)]
 pub enum State {
     Start,
@@ -48,6 +55,9 @@ impl Parser {
     /// '42'
     /// 'variable'
     /// 'Path::To::Item'
+    /// '(a, 42, _)'
+    /// 'Path::To::Variant(a, _)'
+    /// 'Path::To::Structure { a, b: (c, false) }'
     /// '_'
     ///
     pub fn parse(
@@ -86,6 +96,18 @@ impl Parser {
                                 .set_binding(Identifier::new(location, identifier.inner));
                             self.state = State::PathOperatorOrEnd;
                         }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            location,
+                        } => {
+                            let (mut elements, is_parenthesized) = Self::tuple(stream)?;
+                            if is_parenthesized {
+                                return Ok((elements.remove(0), None));
+                            }
+                            self.builder.set_location(location);
+                            self.builder.set_tuple_elements(elements);
+                            return Ok((self.builder.finish(), None));
+                        }
                         Token {
                             lexeme: Lexeme::Symbol(Symbol::Underscore),
                             location,
@@ -111,19 +133,163 @@ impl Parser {
                                 .push_path_operator(ExpressionOperator::Path, location);
                             self.state = State::PathOperand;
                         }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        } => {
+                            let (elements, _is_parenthesized) = Self::tuple(stream)?;
+                            self.builder.set_tuple_elements(elements);
+                            return Ok((self.builder.finish(), None));
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                            ..
+                        } => {
+                            let fields = Self::structure(stream)?;
+                            self.builder.set_structure_fields(fields);
+                            return Ok((self.builder.finish(), None));
+                        }
                         token => return Ok((self.builder.finish(), Some(token))),
                     }
                 }
                 State::PathOperand => {
-                    let (expression, next) =
-                        TerminalOperandParser::default().parse(stream.clone(), self.next.take())?;
-                    self.next = next;
-                    self.builder.push_path_element(expression);
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.builder.push_path_element(ExpressionTree::new(
+                                location,
+                                ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                    Identifier::new(location, identifier.inner),
+                                )),
+                            ));
+                        }
+                        token => {
+                            let (expression, next) = TerminalOperandParser::default()
+                                .parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.builder.push_path_element(expression);
+                        }
+                    }
                     self.state = State::PathOperatorOrEnd;
                 }
             }
         }
     }
+
+    ///
+    /// Parses the comma-separated patterns after the opening parenthesis.
+    ///
+    /// Returns the patterns and whether they are a single parenthesized pattern, which is
+    /// written without the trailing comma, e.g. `(a)` but not `(a,)`.
+    ///
+    fn tuple(stream: Rc<RefCell<TokenStream>>) -> Result<(Vec<MatchPattern>, bool), Error> {
+        let mut elements = Vec::new();
+        let mut has_comma = false;
+
+        loop {
+            let (element, next) = match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                    ..
+                } => break,
+                token => Self::default().parse(stream.clone(), Some(token))?,
+            };
+            elements.push(element);
+
+            match crate::syntax::parser::take_or_next(next, stream.clone())? {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Comma),
+                    ..
+                } => has_comma = true,
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                    ..
+                } => break,
+                Token { lexeme, location } => {
+                    return Err(Error::Syntax(SyntaxError::expected_one_of(
+                        location,
+                        vec![",", ")"],
+                        lexeme,
+                        None,
+                    )));
+                }
+            }
+        }
+
+        let is_parenthesized = elements.len() == 1 && !has_comma;
+        Ok((elements, is_parenthesized))
+    }
+
+    ///
+    /// Parses the structure field patterns after the opening curly bracket.
+    ///
+    /// The field identifier is also used as the binding if the pattern is omitted.
+    ///
+    fn structure(
+        stream: Rc<RefCell<TokenStream>>,
+    ) -> Result<Vec<(Identifier, MatchPattern)>, Error> {
+        let mut fields = Vec::new();
+
+        loop {
+            let field = match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                    ..
+                } if !fields.is_empty() => break,
+                Token {
+                    lexeme: Lexeme::Identifier(identifier),
+                    location,
+                } => Identifier::new(location, identifier.inner),
+                Token { lexeme, location } => {
+                    return Err(Error::Syntax(SyntaxError::expected_identifier(
+                        location,
+                        lexeme,
+                        Some(HINT_EXPECTED_FIELD),
+                    )));
+                }
+            };
+
+            let next = match crate::syntax::parser::take_or_next(None, stream.clone())? {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Colon),
+                    ..
+                } => {
+                    let (pattern, next) = Self::default().parse(stream.clone(), None)?;
+                    fields.push((field, pattern));
+                    crate::syntax::parser::take_or_next(next, stream.clone())?
+                }
+                token => {
+                    let pattern =
+                        MatchPattern::new(field.location, MatchPatternVariant::Binding(field.clone()));
+                    fields.push((field, pattern));
+                    token
+                }
+            };
+
+            match next {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Comma),
+                    ..
+                } => continue,
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                    ..
+                } => break,
+                Token { lexeme, location } => {
+                    return Err(Error::Syntax(SyntaxError::expected_one_of(
+                        location,
+                        vec![",", "}"],
+                        lexeme,
+                        None,
+                    )));
+                }
+            }
+        }
+
+        Ok(fields)
+    }
 }
 
 #[cfg(test)]
@@ -250,6 +416,144 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_enumeration_tuple() {
+        let input = "Op::Burn(amount, _)";
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::new(1, 1),
+                MatchPatternVariant::new_enumeration_tuple(
+                    ExpressionTree::new_with_leaves(
+                        Location::new(1, 3),
+                        ExpressionTreeNode::operator(ExpressionOperator::Path),
+                        Some(ExpressionTree::new(
+                            Location::new(1, 1),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::new(1, 1), "Op".to_owned()),
+                            )),
+                        )),
+                        Some(ExpressionTree::new(
+                            Location::new(1, 5),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::new(1, 5), "Burn".to_owned()),
+                            )),
+                        )),
+                    ),
+                    vec![
+                        MatchPattern::new(
+                            Location::new(1, 10),
+                            MatchPatternVariant::Binding(Identifier::new(
+                                Location::new(1, 10),
+                                "amount".to_owned(),
+                            )),
+                        ),
+                        MatchPattern::new(Location::new(1, 18), MatchPatternVariant::Wildcard),
+                    ],
+                ),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_enumeration_structure() {
+        let input = "Op::Transfer { to, amount: value }";
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::new(1, 1),
+                MatchPatternVariant::new_structure(
+                    ExpressionTree::new_with_leaves(
+                        Location::new(1, 3),
+                        ExpressionTreeNode::operator(ExpressionOperator::Path),
+                        Some(ExpressionTree::new(
+                            Location::new(1, 1),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::new(1, 1), "Op".to_owned()),
+                            )),
+                        )),
+                        Some(ExpressionTree::new(
+                            Location::new(1, 5),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::new(1, 5), "Transfer".to_owned()),
+                            )),
+                        )),
+                    ),
+                    vec![
+                        (
+                            Identifier::new(Location::new(1, 16), "to".to_owned()),
+                            MatchPattern::new(
+                                Location::new(1, 16),
+                                MatchPatternVariant::Binding(Identifier::new(
+                                    Location::new(1, 16),
+                                    "to".to_owned(),
+                                )),
+                            ),
+                        ),
+                        (
+                            Identifier::new(Location::new(1, 20), "amount".to_owned()),
+                            MatchPattern::new(
+                                Location::new(1, 28),
+                                MatchPatternVariant::Binding(Identifier::new(
+                                    Location::new(1, 28),
+                                    "value".to_owned(),
+                                )),
+                            ),
+                        ),
+                    ],
+                ),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_tuple() {
+        let input = "(true, (x, _))";
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::new(1, 1),
+                MatchPatternVariant::new_tuple(vec![
+                    MatchPattern::new(
+                        Location::new(1, 2),
+                        MatchPatternVariant::BooleanLiteral(BooleanLiteral::new(
+                            Location::new(1, 2),
+                            LexicalBooleanLiteral::True,
+                        )),
+                    ),
+                    MatchPattern::new(
+                        Location::new(1, 8),
+                        MatchPatternVariant::new_tuple(vec![
+                            MatchPattern::new(
+                                Location::new(1, 9),
+                                MatchPatternVariant::Binding(Identifier::new(
+                                    Location::new(1, 9),
+                                    "x".to_owned(),
+                                )),
+                            ),
+                            MatchPattern::new(Location::new(1, 12), MatchPatternVariant::Wildcard),
+                        ]),
+                    ),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn ok_wildcard() {
         let input = "_";
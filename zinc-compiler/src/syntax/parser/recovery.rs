@@ -0,0 +1,123 @@
+//!
+//! The syntax error recovery.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::lexeme::symbol::Symbol;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::Token;
+
+thread_local! {
+    /// The syntax errors the parser has recovered from, which have not been taken by the caller yet.
+    static ERRORS: RefCell<Vec<Error>> = RefCell::new(Vec::new());
+}
+
+///
+/// The synchronization point found after skipping the rest of an invalid statement.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Boundary {
+    /// The statement has been terminated with a semicolon, so the next statement follows.
+    Statement,
+    /// The block containing the statement has been closed.
+    Block,
+}
+
+///
+/// Stores the error the parser has recovered from.
+///
+pub fn push(error: Error) {
+    ERRORS.with(|errors| errors.borrow_mut().push(error));
+}
+
+///
+/// Takes the errors the parser has recovered from since the last call, in the order of their
+/// occurrence.
+///
+pub fn take_all() -> Vec<Error> {
+    ERRORS.with(|errors| errors.replace(Vec::new()))
+}
+
+///
+/// Skips the rest of the invalid block statement up to its terminating semicolon or the
+/// closing bracket of the block, stepping over the nested blocks.
+///
+/// The unexpected token of `error` has been already taken from the stream, so it is checked first.
+/// Returns `None` if the error is not a syntax one or the end of input has been reached, and
+/// the parser must not go on.
+///
+pub fn skip_statement(stream: Rc<RefCell<TokenStream>>, error: &Error) -> Option<Boundary> {
+    let mut token = match error {
+        Error::Syntax(inner) => inner.token(),
+        _ => return None,
+    };
+
+    let mut depth = 0;
+    loop {
+        match token.lexeme {
+            Lexeme::Symbol(Symbol::BracketCurlyLeft) => depth += 1,
+            Lexeme::Symbol(Symbol::BracketCurlyRight) if depth == 0 => {
+                return Some(Boundary::Block)
+            }
+            Lexeme::Symbol(Symbol::BracketCurlyRight) => depth -= 1,
+            Lexeme::Symbol(Symbol::Semicolon) if depth == 0 => return Some(Boundary::Statement),
+            Lexeme::Eof => return None,
+            _ => {}
+        }
+
+        token = stream.borrow_mut().next().ok()?;
+    }
+}
+
+///
+/// Skips the rest of the invalid module item up to the next item, which starts at a column not
+/// greater than `column`, that is, is not nested into the invalid one.
+///
+/// The unexpected token of `error` has been already taken from the stream, so it is checked first.
+/// Returns the first token of the next item, or `None` if the error is not a syntax one or
+/// the end of input has been reached.
+///
+pub fn skip_item(stream: Rc<RefCell<TokenStream>>, error: &Error, column: usize) -> Option<Token> {
+    let mut token = match error {
+        Error::Syntax(inner) => inner.token(),
+        _ => return None,
+    };
+
+    loop {
+        match token.lexeme {
+            Lexeme::Eof => return None,
+            Lexeme::Keyword(ref keyword)
+                if token.location.column <= column && self::is_item_start(keyword) =>
+            {
+                return Some(token)
+            }
+            _ => {}
+        }
+
+        token = stream.borrow_mut().next().ok()?;
+    }
+}
+
+///
+/// Checks if the keyword starts a module item.
+///
+fn is_item_start(keyword: &Keyword) -> bool {
+    match keyword {
+        Keyword::Pub
+        | Keyword::Const
+        | Keyword::Type
+        | Keyword::Struct
+        | Keyword::Enum
+        | Keyword::Fn
+        | Keyword::Mod
+        | Keyword::Use
+        | Keyword::Impl
+        | Keyword::Trait => true,
+        _ => false,
+    }
+}
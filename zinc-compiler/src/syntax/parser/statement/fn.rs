@@ -13,6 +13,7 @@ use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
 use crate::syntax::parser::expression::terminal::block::Parser as BlockExpressionParser;
+use crate::syntax::parser::generic_parameter_list::Parser as GenericParameterListParser;
 use crate::syntax::parser::pattern_binding_list::Parser as BindingPatternListParser;
 use crate::syntax::parser::r#type::Parser as TypeParser;
 use crate::syntax::tree::identifier::Identifier;
@@ -28,12 +29,15 @@ static HINT_EXPECTED_ARGUMENT_LIST: &str =
 pub enum State {
     KeywordFn,
     Identifier,
+    LesserOrParenthesisLeft,
+    GenericParameterList,
+    Greater,
     ParenthesisLeft,
     ArgumentBindingList,
     ParenthesisRight,
     ArrowOrBody,
     ReturnType,
-    Body,
+    BodyOrSemicolon,
 }
 
 impl Default for State {
@@ -59,6 +63,16 @@ impl Parser {
     /// }
     /// '
     ///
+    /// '
+    /// fn first<T, const N: u8>(array: [T; N]) -> T {
+    ///     array[0]
+    /// }
+    /// '
+    ///
+    /// '
+    /// fn hash(preimage: [bool; 512]) -> [bool; 256];
+    /// '
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
@@ -93,7 +107,7 @@ impl Parser {
                         } => {
                             let identifier = Identifier::new(location, identifier.inner);
                             self.builder.set_identifier(identifier);
-                            self.state = State::ParenthesisLeft;
+                            self.state = State::LesserOrParenthesisLeft;
                         }
                         Token { lexeme, location } => {
                             return Err(Error::Syntax(SyntaxError::expected_identifier(
@@ -104,6 +118,41 @@ impl Parser {
                         }
                     }
                 }
+                State::LesserOrParenthesisLeft => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Lesser),
+                            ..
+                        } => self.state = State::GenericParameterList,
+                        token => {
+                            self.next = Some(token);
+                            self.state = State::ParenthesisLeft;
+                        }
+                    }
+                }
+                State::GenericParameterList => {
+                    let (generic_parameters, next) =
+                        GenericParameterListParser::default().parse(stream.clone(), None)?;
+                    self.builder.set_generic_parameters(generic_parameters);
+                    self.next = next;
+                    self.state = State::Greater;
+                }
+                State::Greater => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Greater),
+                            ..
+                        } => self.state = State::ParenthesisLeft,
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ">"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
                 State::ParenthesisLeft => {
                     match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -151,7 +200,7 @@ impl Parser {
                         } => self.state = State::ReturnType,
                         token => {
                             self.next = Some(token);
-                            self.state = State::Body;
+                            self.state = State::BodyOrSemicolon;
                         }
                     }
                 }
@@ -159,14 +208,22 @@ impl Parser {
                     let (r#type, next) = TypeParser::default().parse(stream.clone(), None)?;
                     self.next = next;
                     self.builder.set_return_type(r#type);
-                    self.state = State::Body;
+                    self.state = State::BodyOrSemicolon;
                 }
-                State::Body => {
-                    let (expression, next) =
-                        BlockExpressionParser::default().parse(stream, self.next.take())?;
+                State::BodyOrSemicolon => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        token => {
+                            let (expression, next) =
+                                BlockExpressionParser::default().parse(stream, Some(token))?;
 
-                    self.builder.set_body(expression);
-                    return Ok((self.builder.finish(), next));
+                            self.builder.set_body(expression);
+                            return Ok((self.builder.finish(), next));
+                        }
+                    }
                 }
             }
         }
@@ -186,6 +243,11 @@ mod tests {
     use crate::lexical::token::location::Location;
     use crate::syntax::error::Error as SyntaxError;
     use crate::syntax::tree::expression::block::Expression as BlockExpression;
+    use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+    use crate::syntax::tree::generic_parameter::variant::Variant as GenericParameterVariant;
+    use crate::syntax::tree::generic_parameter::Parameter as GenericParameter;
     use crate::syntax::tree::identifier::Identifier;
     use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
     use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
@@ -201,6 +263,7 @@ mod tests {
             FnStatement::new(
                 Location::new(1, 1),
                 Identifier::new(Location::new(1, 4), "f".to_owned()),
+                vec![],
                 vec![BindingPattern::new(
                     Location::new(1, 6),
                     BindingPatternVariant::Binding(Identifier::new(
@@ -210,7 +273,7 @@ mod tests {
                     Type::new(Location::new(1, 9), TypeVariant::field()),
                 )],
                 None,
-                BlockExpression::new(Location::new(1, 16), vec![], None),
+                Some(BlockExpression::new(Location::new(1, 16), vec![], None)),
             ),
             None,
         ));
@@ -228,6 +291,7 @@ mod tests {
             FnStatement::new(
                 Location::new(1, 1),
                 Identifier::new(Location::new(1, 4), "f".to_owned()),
+                vec![],
                 vec![BindingPattern::new(
                     Location::new(1, 6),
                     BindingPatternVariant::Binding(Identifier::new(
@@ -237,7 +301,7 @@ mod tests {
                     Type::new(Location::new(1, 9), TypeVariant::field()),
                 )],
                 Some(Type::new(Location::new(1, 19), TypeVariant::field())),
-                BlockExpression::new(Location::new(1, 25), vec![], None),
+                Some(BlockExpression::new(Location::new(1, 25), vec![], None)),
             ),
             None,
         ));
@@ -247,6 +311,100 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_declaration() {
+        let input = r#"fn f(a: field) -> field;"#;
+
+        let expected = Ok((
+            FnStatement::new(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 4), "f".to_owned()),
+                vec![],
+                vec![BindingPattern::new(
+                    Location::new(1, 6),
+                    BindingPatternVariant::Binding(Identifier::new(
+                        Location::new(1, 6),
+                        "a".to_owned(),
+                    )),
+                    Type::new(Location::new(1, 9), TypeVariant::field()),
+                )],
+                Some(Type::new(Location::new(1, 19), TypeVariant::field())),
+                None,
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_generic() {
+        let input = r#"fn f<T, const N: u8>(a: T) {}"#;
+
+        let expected = Ok((
+            FnStatement::new(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 4), "f".to_owned()),
+                vec![
+                    GenericParameter::new(
+                        Location::new(1, 6),
+                        Identifier::new(Location::new(1, 6), "T".to_owned()),
+                        GenericParameterVariant::new_type(vec![]),
+                    ),
+                    GenericParameter::new(
+                        Location::new(1, 9),
+                        Identifier::new(Location::new(1, 15), "N".to_owned()),
+                        GenericParameterVariant::new_constant(Type::new(
+                            Location::new(1, 18),
+                            TypeVariant::integer_unsigned(8),
+                        )),
+                    ),
+                ],
+                vec![BindingPattern::new(
+                    Location::new(1, 22),
+                    BindingPatternVariant::Binding(Identifier::new(
+                        Location::new(1, 22),
+                        "a".to_owned(),
+                    )),
+                    Type::new(
+                        Location::new(1, 25),
+                        TypeVariant::alias(ExpressionTree::new(
+                            Location::new(1, 25),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::new(1, 25), "T".to_owned()),
+                            )),
+                        )),
+                    ),
+                )],
+                None,
+                Some(BlockExpression::new(Location::new(1, 28), vec![], None)),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_greater() {
+        let input = r#"fn f<T; N>() {}"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 7),
+            vec![",", ">"],
+            Lexeme::Symbol(Symbol::Semicolon),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_identifier() {
         let input = r#"fn (a: u8) -> field {}"#;
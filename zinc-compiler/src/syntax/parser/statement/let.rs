@@ -13,6 +13,7 @@ use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
 use crate::syntax::parser::expression::Parser as ExpressionParser;
+use crate::syntax::parser::pattern_binding_variant::Parser as BindingPatternVariantParser;
 use crate::syntax::parser::r#type::Parser as TypeParser;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::statement::r#let::builder::Builder as LetStatementBuilder;
@@ -52,6 +53,7 @@ impl Parser {
     /// Parses a 'let' statement.
     ///
     /// 'let mut value: field = 42;'
+    /// 'let (a, Point { x, y }) = pair;'
     ///
     pub fn parse(
         mut self,
@@ -88,12 +90,28 @@ impl Parser {
                             self.builder.set_mutable();
                             self.state = State::Identifier;
                         }
+                        token
+                        @
                         Token {
-                            lexeme: Lexeme::Identifier(identifier),
-                            location,
+                            lexeme: Lexeme::Identifier(_),
+                            ..
+                        }
+                        | token
+                        @
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        }
+                        | token
+                        @
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Underscore),
+                            ..
                         } => {
-                            let identifier = Identifier::new(location, identifier.inner);
-                            self.builder.set_identifier(identifier);
+                            let (binding, next) = BindingPatternVariantParser::default()
+                                .parse(stream.clone(), Some(token))?;
+                            self.builder.set_binding(binding);
+                            self.next = next;
                             self.state = State::ColonOrEquals;
                         }
                         Token { lexeme, location } => {
@@ -210,6 +228,7 @@ mod tests {
     use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
     use crate::syntax::tree::identifier::Identifier;
     use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
     use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
     use crate::syntax::tree::r#type::Type;
     use crate::syntax::tree::statement::r#let::Statement as LetStatement;
@@ -221,8 +240,10 @@ mod tests {
         let expected = Ok((
             LetStatement::new(
                 Location::new(1, 1),
-                Identifier::new(Location::new(1, 5), "a".to_owned()),
-                false,
+                BindingPatternVariant::Binding(Identifier::new(
+                    Location::new(1, 5),
+                    "a".to_owned(),
+                )),
                 None,
                 ExpressionTree::new(
                     Location::new(1, 9),
@@ -249,8 +270,10 @@ mod tests {
         let expected = Ok((
             LetStatement::new(
                 Location::new(1, 1),
-                Identifier::new(Location::new(1, 9), "a".to_owned()),
-                true,
+                BindingPatternVariant::MutableBinding(Identifier::new(
+                    Location::new(1, 9),
+                    "a".to_owned(),
+                )),
                 Some(Type::new(
                     Location::new(1, 12),
                     TypeVariant::integer_unsigned(232),
@@ -273,6 +296,43 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_tuple() {
+        let input = r#"let (a, mut b) = pair;"#;
+
+        let expected = Ok((
+            LetStatement::new(
+                Location::new(1, 1),
+                BindingPatternVariant::new_tuple(
+                    Location::new(1, 5),
+                    vec![
+                        BindingPatternVariant::Binding(Identifier::new(
+                            Location::new(1, 6),
+                            "a".to_owned(),
+                        )),
+                        BindingPatternVariant::MutableBinding(Identifier::new(
+                            Location::new(1, 13),
+                            "b".to_owned(),
+                        )),
+                    ],
+                ),
+                None,
+                ExpressionTree::new(
+                    Location::new(1, 18),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::new(1, 18),
+                        "pair".to_owned(),
+                    ))),
+                ),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_mut_or_identifier() {
         let input = r#"let = 42;"#;
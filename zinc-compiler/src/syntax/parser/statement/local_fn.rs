@@ -11,10 +11,12 @@ use crate::lexical::token::lexeme::keyword::Keyword;
 use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
 use crate::syntax::parser::expression::Parser as ExpressionParser;
 use crate::syntax::parser::statement::r#const::Parser as ConstStatementParser;
 use crate::syntax::parser::statement::r#for::Parser as ForStatementParser;
 use crate::syntax::parser::statement::r#let::Parser as LetStatementParser;
+use crate::syntax::parser::statement::r#return::Parser as ReturnStatementParser;
 use crate::syntax::tree::statement::local_fn::Statement as FunctionLocalStatement;
 
 #[derive(Default)]
@@ -65,6 +67,24 @@ impl Parser {
                 self.next = next;
                 FunctionLocalStatement::For(statement)
             }
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Return),
+                ..
+            } => {
+                let (statement, next) =
+                    ReturnStatementParser::default().parse(stream.clone(), Some(token))?;
+                return Ok((FunctionLocalStatement::Return(statement), next, false));
+            }
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Break),
+                location,
+            } => return Self::loop_control(stream, FunctionLocalStatement::Break(location)),
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Continue),
+                location,
+            } => return Self::loop_control(stream, FunctionLocalStatement::Continue(location)),
             Token {
                 lexeme: Lexeme::Symbol(Symbol::Semicolon),
                 location,
@@ -89,6 +109,35 @@ impl Parser {
             statement => Ok((statement, None, false)),
         }
     }
+
+    ///
+    /// Parses the end of a loop control statement, e.g. `break` or `continue`.
+    ///
+    /// The semicolon may be omitted if the statement is the last one in its block.
+    ///
+    fn loop_control(
+        stream: Rc<RefCell<TokenStream>>,
+        statement: FunctionLocalStatement,
+    ) -> Result<(FunctionLocalStatement, Option<Token>, bool), Error> {
+        match crate::syntax::parser::take_or_next(None, stream)? {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                ..
+            } => Ok((statement, None, false)),
+            token
+            @
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                ..
+            } => Ok((statement, Some(token), false)),
+            Token { lexeme, location } => Err(Error::Syntax(SyntaxError::expected_one_of(
+                location,
+                vec![";", "}"],
+                lexeme,
+                None,
+            ))),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -97,17 +146,22 @@ mod tests {
     use std::rc::Rc;
 
     use super::Parser;
+    use crate::error::Error;
     use crate::lexical::stream::TokenStream;
     use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
+    use crate::lexical::token::lexeme::literal::Literal as LexicalLiteral;
+    use crate::lexical::token::lexeme::symbol::Symbol;
     use crate::lexical::token::lexeme::Lexeme;
     use crate::lexical::token::location::Location;
     use crate::lexical::token::Token;
+    use crate::syntax::error::Error as SyntaxError;
     use crate::syntax::tree::expression::block::Expression as BlockExpression;
     use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
     use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
     use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
     use crate::syntax::tree::identifier::Identifier;
     use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
     use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
     use crate::syntax::tree::r#type::Type;
     use crate::syntax::tree::statement::local_fn::Statement as FunctionLocalStatement;
@@ -120,8 +174,10 @@ mod tests {
         let expected = Ok((
             FunctionLocalStatement::Let(LetStatement::new(
                 Location::new(1, 1),
-                Identifier::new(Location::new(1, 9), "a".to_owned()),
-                true,
+                BindingPatternVariant::MutableBinding(Identifier::new(
+                    Location::new(1, 9),
+                    "a".to_owned(),
+                )),
                 Some(Type::new(
                     Location::new(1, 12),
                     TypeVariant::integer_unsigned(232),
@@ -174,4 +230,55 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn ok_break() {
+        let input = r#"break;"#;
+
+        let expected = Ok((
+            FunctionLocalStatement::Break(Location::new(1, 1)),
+            None,
+            false,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_continue_last_in_block() {
+        let input = r#"continue }"#;
+
+        let expected = Ok((
+            FunctionLocalStatement::Continue(Location::new(1, 1)),
+            Some(Token::new(
+                Lexeme::Symbol(Symbol::BracketCurlyRight),
+                Location::new(1, 10),
+            )),
+            false,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_loop_control_expected_semicolon() {
+        let input = r#"break 42;"#;
+
+        let expected: Result<_, Error> = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 7),
+            vec![";", "}"],
+            Lexeme::Literal(LexicalLiteral::Integer(LexicalIntegerLiteral::new_decimal(
+                "42".to_owned(),
+            ))),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
 }
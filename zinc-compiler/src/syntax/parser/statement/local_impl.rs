@@ -31,7 +31,10 @@ impl Parser {
         stream: Rc<RefCell<TokenStream>>,
         mut initial: Option<Token>,
     ) -> Result<(ImplementationLocalStatement, Option<Token>), Error> {
-        match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+        let token = crate::syntax::parser::take_or_next(initial.take(), stream.clone())?;
+        let doc = stream.borrow_mut().take_doc(token.location);
+
+        let (mut statement, next) = match token {
             token
             @
             Token {
@@ -58,6 +61,12 @@ impl Parser {
                 lexeme,
                 Some(HINT_ONLY_SOME_STATEMENTS),
             ))),
+        }?;
+
+        if let Some(doc) = doc {
+            statement.set_doc(doc);
         }
+
+        Ok((statement, next))
     }
 }
@@ -18,12 +18,15 @@ use crate::syntax::parser::statement::r#enum::Parser as EnumStatementParser;
 use crate::syntax::parser::statement::r#fn::Parser as FnStatementParser;
 use crate::syntax::parser::statement::r#impl::Parser as ImplStatementParser;
 use crate::syntax::parser::statement::r#struct::Parser as StructStatementParser;
+use crate::syntax::parser::statement::r#trait::Parser as TraitStatementParser;
 use crate::syntax::parser::statement::r#type::Parser as TypeStatementParser;
 use crate::syntax::parser::statement::r#use::Parser as UseStatementParser;
 use crate::syntax::tree::statement::local_mod::Statement as ModuleLocalStatement;
 
 static HINT_ONLY_SOME_STATEMENTS: &str =
-    "only constants, types, functions, and type implementations may be declared at the module root";
+    "only constants, types, functions, traits, and type implementations may be declared at the module root";
+static HINT_ONLY_SOME_PUBLIC_STATEMENTS: &str =
+    "only constants, types, functions, traits, modules, and imports may be declared as `pub`";
 
 #[derive(Default)]
 pub struct Parser {}
@@ -32,12 +35,28 @@ impl Parser {
     ///
     /// Parses a top-level statement allowed in modules.
     ///
+    /// 'pub fn sum(a: u8, b: u8) -> u8 { a + b }'
+    ///
     pub fn parse(
         self,
         stream: Rc<RefCell<TokenStream>>,
         mut initial: Option<Token>,
     ) -> Result<(ModuleLocalStatement, Option<Token>), Error> {
-        match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+        let mut is_public = false;
+        let token = crate::syntax::parser::take_or_next(initial.take(), stream.clone())?;
+        let doc = stream.borrow_mut().take_doc(token.location);
+        let token = match token {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Pub),
+                ..
+            } => {
+                is_public = true;
+                crate::syntax::parser::take_or_next(None, stream.clone())?
+            }
+            token => token,
+        };
+
+        let (mut statement, next) = match token {
             token
             @
             Token {
@@ -99,21 +118,116 @@ impl Parser {
             Token {
                 lexeme: Lexeme::Keyword(Keyword::Impl),
                 ..
-            } => ImplStatementParser::default()
+            } if !is_public => ImplStatementParser::default()
                 .parse(stream, Some(token))
                 .map(|(statement, next)| (ModuleLocalStatement::Impl(statement), next)),
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Trait),
+                ..
+            } => TraitStatementParser::default()
+                .parse(stream, Some(token))
+                .map(|(statement, next)| (ModuleLocalStatement::Trait(statement), next)),
             Token {
                 lexeme: Lexeme::Symbol(Symbol::Semicolon),
                 location,
-            } => Ok((ModuleLocalStatement::Empty(location), None)),
+            } if !is_public => Ok((ModuleLocalStatement::Empty(location), None)),
+            Token { lexeme, location } if is_public => {
+                Err(Error::Syntax(SyntaxError::expected_one_of(
+                    location,
+                    vec![
+                        "type", "struct", "enum", "fn", "mod", "use", "trait", "const",
+                    ],
+                    lexeme,
+                    Some(HINT_ONLY_SOME_PUBLIC_STATEMENTS),
+                )))
+            }
             Token { lexeme, location } => Err(Error::Syntax(SyntaxError::expected_one_of(
                 location,
                 vec![
-                    "type", "struct", "enum", "fn", "mod", "use", "impl", "const",
+                    "type", "struct", "enum", "fn", "mod", "use", "impl", "trait", "const",
                 ],
                 lexeme,
                 Some(HINT_ONLY_SOME_STATEMENTS),
             ))),
+        }?;
+
+        if is_public {
+            statement.set_public();
         }
+        if let Some(doc) = doc {
+            statement.set_doc(doc);
+        }
+
+        Ok((statement, next))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Parser;
+    use crate::error::Error;
+    use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::keyword::Keyword;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::identifier::Identifier;
+    use crate::syntax::tree::statement::local_mod::Statement as ModuleLocalStatement;
+    use crate::syntax::tree::statement::module::Statement as ModStatement;
+
+    #[test]
+    fn ok_private() {
+        let input = r#"mod jabberwocky;"#;
+
+        let expected = Ok((
+            ModuleLocalStatement::Mod(ModStatement::new(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 5), "jabberwocky".to_owned()),
+            )),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_public() {
+        let input = r#"pub mod jabberwocky;"#;
+
+        let mut statement = ModStatement::new(
+            Location::new(1, 5),
+            Identifier::new(Location::new(1, 9), "jabberwocky".to_owned()),
+        );
+        statement.is_public = true;
+        let expected = Ok((ModuleLocalStatement::Mod(statement), None));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_public_impl() {
+        let input = r#"pub impl Data {}"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 5),
+            vec![
+                "type", "struct", "enum", "fn", "mod", "use", "trait", "const",
+            ],
+            Lexeme::Keyword(Keyword::Impl),
+            Some(super::HINT_ONLY_SOME_PUBLIC_STATEMENTS),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
     }
 }
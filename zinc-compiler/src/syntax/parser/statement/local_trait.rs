@@ -0,0 +1,62 @@
+//!
+//! The trait-local statement parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::lexeme::symbol::Symbol;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::parser::statement::r#fn::Parser as FnStatementParser;
+use crate::syntax::tree::statement::local_trait::Statement as TraitLocalStatement;
+
+static HINT_ONLY_SOME_STATEMENTS: &str = "only functions may be declared within a trait";
+
+#[derive(Default)]
+pub struct Parser {}
+
+impl Parser {
+    ///
+    /// Parses a statement allowed in traits.
+    ///
+    pub fn parse(
+        self,
+        stream: Rc<RefCell<TokenStream>>,
+        mut initial: Option<Token>,
+    ) -> Result<(TraitLocalStatement, Option<Token>), Error> {
+        let token = crate::syntax::parser::take_or_next(initial.take(), stream.clone())?;
+        let doc = stream.borrow_mut().take_doc(token.location);
+
+        let (mut statement, next) = match token {
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Fn),
+                ..
+            } => FnStatementParser::default()
+                .parse(stream, Some(token))
+                .map(|(statement, next)| (TraitLocalStatement::Fn(statement), next)),
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                location,
+            } => Ok((TraitLocalStatement::Empty(location), None)),
+            Token { lexeme, location } => Err(Error::Syntax(SyntaxError::expected_one_of(
+                location,
+                vec!["fn"],
+                lexeme,
+                Some(HINT_ONLY_SOME_STATEMENTS),
+            ))),
+        }?;
+
+        if let Some(doc) = doc {
+            statement.set_doc(doc);
+        }
+
+        Ok((statement, next))
+    }
+}
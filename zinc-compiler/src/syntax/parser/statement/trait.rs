@@ -0,0 +1,243 @@
+//!
+//! The trait statement parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::error::Error;
+use crate::lexical::stream::TokenStream;
+use crate::lexical::token::lexeme::keyword::Keyword;
+use crate::lexical::token::lexeme::symbol::Symbol;
+use crate::lexical::token::lexeme::Lexeme;
+use crate::lexical::token::Token;
+use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::parser::statement::local_trait::Parser as TraitLocalStatementParser;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::statement::r#trait::builder::Builder as TraitStatementBuilder;
+use crate::syntax::tree::statement::r#trait::Statement as TraitStatement;
+
+static HINT_EXPECTED_IDENTIFIER: &str =
+    "trait must have an identifier, e.g. `trait Hasher { ... }`";
+
+#[derive(Debug, Clone, Copy)]
+pub enum State {
+    KeywordTrait,
+    Identifier,
+    BracketCurlyLeft,
+    StatementOrBracketCurlyRight,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        State::KeywordTrait
+    }
+}
+
+#[derive(Default)]
+pub struct Parser {
+    state: State,
+    builder: TraitStatementBuilder,
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses a 'trait' statement.
+    ///
+    /// '
+    /// trait Hasher {
+    ///     fn hash(preimage: [bool; 512]) -> [bool; 256];
+    /// }
+    /// '
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        mut initial: Option<Token>,
+    ) -> Result<(TraitStatement, Option<Token>), Error> {
+        loop {
+            match self.state {
+                State::KeywordTrait => {
+                    match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Trait),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::Identifier;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["trait"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::Identifier => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.builder.set_identifier(identifier);
+                            self.state = State::BracketCurlyLeft;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
+                State::BracketCurlyLeft => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                            ..
+                        } => {
+                            self.state = State::StatementOrBracketCurlyRight;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(Error::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["{"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::StatementOrBracketCurlyRight => {
+                    match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        token => {
+                            let (statement, next) = TraitLocalStatementParser::default()
+                                .parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.builder.push_statement(statement);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::Parser;
+    use crate::error::Error;
+    use crate::lexical::stream::TokenStream;
+    use crate::lexical::token::lexeme::symbol::Symbol;
+    use crate::lexical::token::lexeme::Lexeme;
+    use crate::lexical::token::location::Location;
+    use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::identifier::Identifier;
+    use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+    use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
+    use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
+    use crate::syntax::tree::r#type::Type;
+    use crate::syntax::tree::statement::local_trait::Statement as TraitLocalStatement;
+    use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
+    use crate::syntax::tree::statement::r#trait::Statement as TraitStatement;
+
+    #[test]
+    fn ok_empty() {
+        let input = r#"
+    trait Test {}
+"#;
+
+        let expected = Ok((
+            TraitStatement::new(
+                Location::new(2, 5),
+                Identifier::new(Location::new(2, 11), "Test".to_owned()),
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_single() {
+        let input = r#"
+    trait Test {
+        fn f(a: field) -> field;
+    }
+"#;
+
+        let expected = Ok((
+            TraitStatement::new(
+                Location::new(2, 5),
+                Identifier::new(Location::new(2, 11), "Test".to_owned()),
+                vec![TraitLocalStatement::Fn(FnStatement::new(
+                    Location::new(3, 9),
+                    Identifier::new(Location::new(3, 12), "f".to_owned()),
+                    vec![],
+                    vec![BindingPattern::new(
+                        Location::new(3, 14),
+                        BindingPatternVariant::Binding(Identifier::new(
+                            Location::new(3, 14),
+                            "a".to_owned(),
+                        )),
+                        Type::new(Location::new(3, 17), TypeVariant::field()),
+                    )],
+                    Some(Type::new(Location::new(3, 27), TypeVariant::field())),
+                    None,
+                ))],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_identifier() {
+        let input = r#"trait { fn f(); }"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_identifier(
+            Location::new(1, 7),
+            Lexeme::Symbol(Symbol::BracketCurlyLeft),
+            Some(super::HINT_EXPECTED_IDENTIFIER),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_bracket_curly_left() {
+        let input = r#"trait Test;"#;
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_one_of(
+            Location::new(1, 11),
+            vec!["{"],
+            Lexeme::Symbol(Symbol::Semicolon),
+            None,
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -43,6 +43,24 @@ impl Parser {
         mut initial: Option<Token>,
     ) -> Result<(Type, Option<Token>), Error> {
         match crate::syntax::parser::take_or_next(initial.take(), stream.clone())? {
+            token
+            @
+            Token {
+                lexeme: Lexeme::Identifier(_),
+                ..
+            }
+            | token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::SelfUppercase),
+                ..
+            } => {
+                let location = token.location;
+                let (expression, next) = PathParser::default().parse(stream, Some(token))?;
+                self.builder.set_location(location);
+                self.builder.set_path_expression(expression);
+                Ok((self.builder.finish(), next))
+            }
             Token {
                 lexeme: Lexeme::Keyword(keyword),
                 location,
@@ -63,18 +81,6 @@ impl Parser {
             },
             token
             @
-            Token {
-                lexeme: Lexeme::Identifier(_),
-                ..
-            } => {
-                let location = token.location;
-                let (expression, next) = PathParser::default().parse(stream, Some(token))?;
-                self.builder.set_location(location);
-                self.builder.set_path_expression(expression);
-                Ok((self.builder.finish(), next))
-            }
-            token
-            @
             Token {
                 lexeme: Lexeme::Symbol(Symbol::BracketSquareLeft),
                 ..
@@ -105,7 +111,12 @@ mod tests {
     use crate::lexical::token::lexeme::literal::Literal as LexicalLiteral;
     use crate::lexical::token::lexeme::Lexeme;
     use crate::lexical::token::location::Location;
+    use crate::lexical::token::Token;
     use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+    use crate::syntax::tree::identifier::Identifier;
     use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
     use crate::syntax::tree::r#type::Type;
 
@@ -145,6 +156,29 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_alias_self() {
+        let input = "Self";
+
+        let expected = Ok((
+            Type::new(
+                Location::new(1, 1),
+                TypeVariant::alias(ExpressionTree::new(
+                    Location::new(1, 1),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::new(1, 1),
+                        "Self".to_owned(),
+                    ))),
+                )),
+            ),
+            Some(Token::new(Lexeme::Eof, Location::new(1, 5))),
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_type_keyword() {
         let input = "while";
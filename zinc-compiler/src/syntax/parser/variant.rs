@@ -12,14 +12,19 @@ use crate::lexical::token::lexeme::symbol::Symbol;
 use crate::lexical::token::lexeme::Lexeme;
 use crate::lexical::token::Token;
 use crate::syntax::error::Error as SyntaxError;
+use crate::syntax::parser::field_list::Parser as FieldListParser;
+use crate::syntax::parser::r#type::Parser as TypeParser;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
 use crate::syntax::tree::variant::builder::Builder as VariantBuilder;
+use crate::syntax::tree::variant::payload::Payload;
 use crate::syntax::tree::variant::Variant;
 
 static HINT_EXPECTED_IDENTIFIER: &str =
     "enumeration variant must have an identifier, e.g. `Value = 42`";
 static HINT_EXPECTED_VALUE: &str = "enumeration variant must be initialized, e.g. `Value = 42`";
+static HINT_EXPECTED_FIELD: &str =
+    "enumeration variant payload must have at least one field, e.g. `Value { a: u8 }`";
 
 #[derive(Default)]
 pub struct Parser {
@@ -32,6 +37,8 @@ impl Parser {
     /// Parses an enum variant.
     ///
     /// 'A = 1'
+    /// 'A(u8, field)'
+    /// 'A { a: u8, b: field }'
     ///
     pub fn parse(
         mut self,
@@ -61,6 +68,14 @@ impl Parser {
                 lexeme: Lexeme::Symbol(Symbol::Equals),
                 ..
             } => {}
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                ..
+            } => return self.tuple(stream),
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                ..
+            } => return self.structure(stream),
             Token { lexeme, location } => {
                 return Err(Error::Syntax(SyntaxError::expected_value(
                     location,
@@ -84,6 +99,87 @@ impl Parser {
             )),
         }
     }
+
+    ///
+    /// Parses the tuple-like variant payload after the opening parenthesis.
+    ///
+    fn tuple(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+    ) -> Result<(Variant, Option<Token>), Error> {
+        let mut types = Vec::new();
+
+        loop {
+            match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                    ..
+                } if !types.is_empty() => break,
+                token => {
+                    let (r#type, next) =
+                        TypeParser::default().parse(stream.clone(), Some(token))?;
+                    self.next = next;
+                    types.push(r#type);
+                }
+            }
+
+            match crate::syntax::parser::take_or_next(self.next.take(), stream.clone())? {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Comma),
+                    ..
+                } => continue,
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                    ..
+                } => break,
+                Token { lexeme, location } => {
+                    return Err(Error::Syntax(SyntaxError::expected_one_of(
+                        location,
+                        vec![",", ")"],
+                        lexeme,
+                        None,
+                    )));
+                }
+            }
+        }
+
+        self.builder.set_payload(Payload::Tuple(types));
+        Ok((self.builder.finish(), None))
+    }
+
+    ///
+    /// Parses the structure-like variant payload after the opening curly bracket.
+    ///
+    fn structure(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+    ) -> Result<(Variant, Option<Token>), Error> {
+        let (fields, next) = FieldListParser::default().parse(stream.clone(), None)?;
+
+        match crate::syntax::parser::take_or_next(next, stream)? {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                location,
+            } if fields.is_empty() => Err(Error::Syntax(SyntaxError::expected_identifier(
+                location,
+                Lexeme::Symbol(Symbol::BracketCurlyRight),
+                Some(HINT_EXPECTED_FIELD),
+            ))),
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                ..
+            } => {
+                self.builder.set_payload(Payload::Structure(fields));
+                Ok((self.builder.finish(), None))
+            }
+            Token { lexeme, location } => Err(Error::Syntax(SyntaxError::expected_one_of(
+                location,
+                vec!["}"],
+                lexeme,
+                None,
+            ))),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -96,11 +192,16 @@ mod tests {
     use crate::lexical::stream::TokenStream;
     use crate::lexical::token::lexeme::identifier::Identifier as LexicalIdentifier;
     use crate::lexical::token::lexeme::literal::integer::Integer as LexicalIntegerLiteral;
+    use crate::lexical::token::lexeme::symbol::Symbol;
     use crate::lexical::token::lexeme::Lexeme;
     use crate::lexical::token::location::Location;
     use crate::syntax::error::Error as SyntaxError;
+    use crate::syntax::tree::field::Field;
     use crate::syntax::tree::identifier::Identifier;
     use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
+    use crate::syntax::tree::r#type::Type;
+    use crate::syntax::tree::variant::payload::Payload;
     use crate::syntax::tree::variant::Variant;
 
     #[test]
@@ -124,6 +225,56 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_tuple() {
+        let input = "A(u8, field)";
+
+        let expected = Ok((
+            Variant::new_with_payload(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 1), "A".to_owned()),
+                Payload::Tuple(vec![
+                    Type::new(Location::new(1, 3), TypeVariant::integer_unsigned(8)),
+                    Type::new(Location::new(1, 7), TypeVariant::field()),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_structure() {
+        let input = "A { a: u8, b: field }";
+
+        let expected = Ok((
+            Variant::new_with_payload(
+                Location::new(1, 1),
+                Identifier::new(Location::new(1, 1), "A".to_owned()),
+                Payload::Structure(vec![
+                    Field::new(
+                        Location::new(1, 5),
+                        Identifier::new(Location::new(1, 5), "a".to_owned()),
+                        Type::new(Location::new(1, 8), TypeVariant::integer_unsigned(8)),
+                    ),
+                    Field::new(
+                        Location::new(1, 12),
+                        Identifier::new(Location::new(1, 12), "b".to_owned()),
+                        Type::new(Location::new(1, 15), TypeVariant::field()),
+                    ),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_value() {
         let input = "A";
@@ -152,4 +303,19 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn error_expected_field() {
+        let input = "A {}";
+
+        let expected = Err(Error::Syntax(SyntaxError::expected_identifier(
+            Location::new(1, 4),
+            Lexeme::Symbol(Symbol::BracketCurlyRight),
+            Some(super::HINT_EXPECTED_FIELD),
+        )));
+
+        let result = Parser::default().parse(Rc::new(RefCell::new(TokenStream::new(input))), None);
+
+        assert_eq!(result, expected);
+    }
 }
@@ -70,4 +70,5 @@ pub enum Operator {
 
     // compile-time access
     Path,
+    GenericArguments,
 }
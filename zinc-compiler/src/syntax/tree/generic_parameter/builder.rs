@@ -0,0 +1,68 @@
+//!
+//! The generic parameter builder.
+//!
+
+use crate::lexical::token::location::Location;
+use crate::syntax::tree::generic_parameter::variant::Variant as GenericParameterVariant;
+use crate::syntax::tree::generic_parameter::Parameter as GenericParameter;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::r#type::Type;
+
+#[derive(Default)]
+pub struct Builder {
+    location: Option<Location>,
+    identifier: Option<Identifier>,
+    is_constant: bool,
+    r#type: Option<Type>,
+    bounds: Vec<Identifier>,
+}
+
+impl Builder {
+    pub fn set_location(&mut self, value: Location) {
+        self.location = Some(value);
+    }
+
+    pub fn set_identifier(&mut self, value: Identifier) {
+        self.identifier = Some(value);
+    }
+
+    pub fn set_is_constant(&mut self) {
+        self.is_constant = true;
+    }
+
+    pub fn set_type(&mut self, value: Type) {
+        self.r#type = Some(value);
+    }
+
+    pub fn push_bound(&mut self, value: Identifier) {
+        self.bounds.push(value);
+    }
+
+    pub fn is_constant(&self) -> bool {
+        self.is_constant
+    }
+
+    pub fn finish(mut self) -> GenericParameter {
+        let location = self
+            .location
+            .take()
+            .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location"));
+
+        let identifier = self
+            .identifier
+            .take()
+            .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "identifier"));
+
+        let variant = if self.is_constant {
+            GenericParameterVariant::new_constant(
+                self.r#type
+                    .take()
+                    .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "type")),
+            )
+        } else {
+            GenericParameterVariant::new_type(self.bounds)
+        };
+
+        GenericParameter::new(location, identifier, variant)
+    }
+}
@@ -0,0 +1,28 @@
+//!
+//! The generic parameter.
+//!
+
+pub mod builder;
+pub mod variant;
+
+use crate::lexical::token::location::Location;
+use crate::syntax::tree::identifier::Identifier;
+
+use self::variant::Variant;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Parameter {
+    pub location: Location,
+    pub identifier: Identifier,
+    pub variant: Variant,
+}
+
+impl Parameter {
+    pub fn new(location: Location, identifier: Identifier, variant: Variant) -> Self {
+        Self {
+            location,
+            identifier,
+            variant,
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//!
+//! The generic parameter variant.
+//!
+
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::r#type::Type;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Variant {
+    /// The type parameter with optional trait bounds, e.g. `T` or `T: Hasher`
+    Type { bounds: Vec<Identifier> },
+    /// The constant parameter, e.g. `const N: u64`
+    Constant { r#type: Type },
+}
+
+impl Variant {
+    pub fn new_type(bounds: Vec<Identifier>) -> Self {
+        Self::Type { bounds }
+    }
+
+    pub fn new_constant(r#type: Type) -> Self {
+        Self::Constant { r#type }
+    }
+}
@@ -4,6 +4,7 @@
 
 pub mod expression;
 pub mod field;
+pub mod generic_parameter;
 pub mod identifier;
 pub mod literal;
 pub mod pattern_binding;
@@ -18,4 +19,5 @@ use self::statement::local_mod::Statement as ModuleLocalStatement;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Tree {
     pub statements: Vec<ModuleLocalStatement>,
+    pub doc: Option<String>,
 }
@@ -2,18 +2,25 @@
 //! The binding pattern builder.
 //!
 
+use crate::lexical::token::lexeme::keyword::Keyword;
 use crate::lexical::token::location::Location;
+use crate::syntax::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::syntax::tree::expression::tree::node::Node as ExpressionTreeNode;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
 use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
+use crate::syntax::tree::r#type::variant::Variant as TypeVariant;
 use crate::syntax::tree::r#type::Type;
 
 #[derive(Default)]
 pub struct Builder {
     location: Option<Location>,
     binding: Option<Identifier>,
+    variant: Option<BindingPatternVariant>,
     is_binding_mutable: bool,
     wildcard: bool,
+    self_location: Option<Location>,
     r#type: Option<Type>,
 }
 
@@ -26,6 +33,10 @@ impl Builder {
         self.binding = Some(value);
     }
 
+    pub fn set_variant(&mut self, value: BindingPatternVariant) {
+        self.variant = Some(value);
+    }
+
     pub fn set_is_binding_mutable(&mut self) {
         self.is_binding_mutable = true;
     }
@@ -34,6 +45,10 @@ impl Builder {
         self.wildcard = true;
     }
 
+    pub fn set_self_location(&mut self, value: Location) {
+        self.self_location = Some(value);
+    }
+
     pub fn set_type(&mut self, value: Type) {
         self.r#type = Some(value);
     }
@@ -44,7 +59,27 @@ impl Builder {
             .take()
             .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location"));
 
-        let variant = if self.wildcard {
+        if let Some(self_location) = self.self_location.take() {
+            let r#type = Type::new(
+                self_location,
+                TypeVariant::alias(ExpressionTree::new(
+                    self_location,
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        self_location,
+                        Keyword::SelfUppercase.to_string(),
+                    ))),
+                )),
+            );
+            return BindingPattern::new(
+                location,
+                BindingPatternVariant::new_self_alias(self_location, self.is_binding_mutable),
+                r#type,
+            );
+        }
+
+        let variant = if let Some(variant) = self.variant.take() {
+            variant
+        } else if self.wildcard {
             BindingPatternVariant::Wildcard
         } else if let Some(identifier) = self.binding.take() {
             if self.is_binding_mutable {
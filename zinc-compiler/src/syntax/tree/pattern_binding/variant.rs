@@ -2,6 +2,8 @@
 //! The binding pattern variant.
 //!
 
+use crate::lexical::token::location::Location;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::identifier::Identifier;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -9,6 +11,19 @@ pub enum Variant {
     Binding(Identifier),
     MutableBinding(Identifier),
     Wildcard,
+    Tuple {
+        location: Location,
+        elements: Vec<Self>,
+    },
+    Structure {
+        location: Location,
+        path: ExpressionTree,
+        fields: Vec<(Identifier, Self)>,
+    },
+    SelfAlias {
+        location: Location,
+        is_mutable: bool,
+    },
 }
 
 impl Variant {
@@ -23,4 +38,38 @@ impl Variant {
     pub fn new_wildcard() -> Self {
         Self::Wildcard
     }
+
+    pub fn new_tuple(location: Location, elements: Vec<Self>) -> Self {
+        Self::Tuple { location, elements }
+    }
+
+    pub fn new_structure(
+        location: Location,
+        path: ExpressionTree,
+        fields: Vec<(Identifier, Self)>,
+    ) -> Self {
+        Self::Structure {
+            location,
+            path,
+            fields,
+        }
+    }
+
+    pub fn new_self_alias(location: Location, is_mutable: bool) -> Self {
+        Self::SelfAlias {
+            location,
+            is_mutable,
+        }
+    }
+
+    ///
+    /// Checks whether the pattern destructures the value into several bindings.
+    ///
+    pub fn is_destructuring(&self) -> bool {
+        match self {
+            Self::Tuple { .. } => true,
+            Self::Structure { .. } => true,
+            _ => false,
+        }
+    }
 }
@@ -20,6 +20,8 @@ pub struct Builder {
     integer_literal: Option<IntegerLiteral>,
     binding: Option<Identifier>,
     path_builder: ExpressionTreeBuilder,
+    tuple_elements: Option<Vec<MatchPattern>>,
+    structure_fields: Option<Vec<(Identifier, MatchPattern)>>,
     is_wildcard: bool,
 }
 
@@ -50,6 +52,16 @@ impl Builder {
         self.path_builder.eat(tree);
     }
 
+    pub fn set_tuple_elements(&mut self, value: Vec<MatchPattern>) {
+        self.move_binding_to_path();
+        self.tuple_elements = Some(value);
+    }
+
+    pub fn set_structure_fields(&mut self, value: Vec<(Identifier, MatchPattern)>) {
+        self.move_binding_to_path();
+        self.structure_fields = Some(value);
+    }
+
     pub fn set_is_wildcard(&mut self) {
         self.is_wildcard = true;
     }
@@ -68,13 +80,27 @@ impl Builder {
             MatchPatternVariant::IntegerLiteral(integer_literal)
         } else if let Some(identifier) = self.binding.take() {
             MatchPatternVariant::Binding(identifier)
+        } else if let Some(elements) = self.tuple_elements.take() {
+            if self.path_builder.is_empty() {
+                MatchPatternVariant::Tuple(elements)
+            } else {
+                MatchPatternVariant::EnumerationTuple {
+                    path: self.path_builder.finish(),
+                    elements,
+                }
+            }
+        } else if let Some(fields) = self.structure_fields.take() {
+            MatchPatternVariant::Structure {
+                path: self.path_builder.finish(),
+                fields,
+            }
         } else if !self.path_builder.is_empty() {
             MatchPatternVariant::Path(self.path_builder.finish())
         } else {
             panic!(
                 "{}{}",
                 crate::PANIC_BUILDER_REQUIRES_VALUE,
-                "boolean | integer | binding | path | wildcard"
+                "boolean | integer | binding | path | tuple | structure | wildcard"
             );
         };
 
@@ -6,6 +6,7 @@ use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::literal::boolean::Literal as BooleanLiteral;
 use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
+use crate::syntax::tree::pattern_match::Pattern;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Variant {
@@ -13,6 +14,15 @@ pub enum Variant {
     IntegerLiteral(IntegerLiteral),
     Binding(Identifier),
     Path(ExpressionTree),
+    Tuple(Vec<Pattern>),
+    EnumerationTuple {
+        path: ExpressionTree,
+        elements: Vec<Pattern>,
+    },
+    Structure {
+        path: ExpressionTree,
+        fields: Vec<(Identifier, Pattern)>,
+    },
     Wildcard,
 }
 
@@ -33,6 +43,18 @@ impl Variant {
         Self::Path(expression)
     }
 
+    pub fn new_tuple(elements: Vec<Pattern>) -> Self {
+        Self::Tuple(elements)
+    }
+
+    pub fn new_enumeration_tuple(path: ExpressionTree, elements: Vec<Pattern>) -> Self {
+        Self::EnumerationTuple { path, elements }
+    }
+
+    pub fn new_structure(path: ExpressionTree, fields: Vec<(Identifier, Pattern)>) -> Self {
+        Self::Structure { path, fields }
+    }
+
     pub fn new_wildcard() -> Self {
         Self::Wildcard
     }
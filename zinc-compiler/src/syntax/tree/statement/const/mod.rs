@@ -12,6 +12,8 @@ use crate::syntax::tree::r#type::Type;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Statement {
     pub location: Location,
+    pub is_public: bool,
+    pub doc: Option<String>,
     pub identifier: Identifier,
     pub r#type: Type,
     pub expression: ExpressionTree,
@@ -26,6 +28,8 @@ impl Statement {
     ) -> Self {
         Self {
             location,
+            is_public: false,
+            doc: None,
             identifier,
             r#type,
             expression,
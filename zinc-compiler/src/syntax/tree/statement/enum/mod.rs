@@ -11,6 +11,8 @@ use crate::syntax::tree::variant::Variant;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Statement {
     pub location: Location,
+    pub is_public: bool,
+    pub doc: Option<String>,
     pub identifier: Identifier,
     pub variants: Vec<Variant>,
 }
@@ -19,6 +21,8 @@ impl Statement {
     pub fn new(location: Location, identifier: Identifier, variants: Vec<Variant>) -> Self {
         Self {
             location,
+            is_public: false,
+            doc: None,
             identifier,
             variants,
         }
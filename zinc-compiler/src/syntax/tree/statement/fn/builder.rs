@@ -4,6 +4,7 @@
 
 use crate::lexical::token::location::Location;
 use crate::syntax::tree::expression::block::Expression as BlockExpression;
+use crate::syntax::tree::generic_parameter::Parameter as GenericParameter;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
 use crate::syntax::tree::r#type::Type;
@@ -13,6 +14,7 @@ use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
 pub struct Builder {
     location: Option<Location>,
     identifier: Option<Identifier>,
+    generic_parameters: Vec<GenericParameter>,
     argument_bindings: Vec<BindingPattern>,
     return_type: Option<Type>,
     body: Option<BlockExpression>,
@@ -27,6 +29,10 @@ impl Builder {
         self.identifier = Some(value);
     }
 
+    pub fn set_generic_parameters(&mut self, value: Vec<GenericParameter>) {
+        self.generic_parameters = value;
+    }
+
     pub fn set_argument_bindings(&mut self, value: Vec<BindingPattern>) {
         self.argument_bindings = value;
     }
@@ -49,11 +55,10 @@ impl Builder {
             self.identifier.take().unwrap_or_else(|| {
                 panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "identifier")
             }),
+            self.generic_parameters,
             self.argument_bindings,
             self.return_type.take(),
-            self.body
-                .take()
-                .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "body")),
+            self.body.take(),
         )
     }
 }
@@ -6,6 +6,7 @@ pub mod builder;
 
 use crate::lexical::token::location::Location;
 use crate::syntax::tree::expression::block::Expression as BlockExpression;
+use crate::syntax::tree::generic_parameter::Parameter as GenericParameter;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::pattern_binding::Pattern as BindingPattern;
 use crate::syntax::tree::r#type::Type;
@@ -13,26 +14,37 @@ use crate::syntax::tree::r#type::Type;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Statement {
     pub location: Location,
+    pub is_public: bool,
+    pub doc: Option<String>,
     pub identifier: Identifier,
+    pub generic_parameters: Vec<GenericParameter>,
     pub argument_bindings: Vec<BindingPattern>,
     pub return_type: Option<Type>,
-    pub body: BlockExpression,
+    pub body: Option<BlockExpression>,
 }
 
 impl Statement {
     pub fn new(
         location: Location,
         identifier: Identifier,
+        generic_parameters: Vec<GenericParameter>,
         argument_bindings: Vec<BindingPattern>,
         return_type: Option<Type>,
-        body: BlockExpression,
+        body: Option<BlockExpression>,
     ) -> Self {
         Self {
             location,
+            is_public: false,
+            doc: None,
             identifier,
+            generic_parameters,
             argument_bindings,
             return_type,
             body,
         }
     }
+
+    pub fn is_generic(&self) -> bool {
+        !self.generic_parameters.is_empty()
+    }
 }
@@ -11,6 +11,7 @@ use crate::syntax::tree::statement::r#impl::Statement as ImplStatement;
 pub struct Builder {
     location: Option<Location>,
     identifier: Option<Identifier>,
+    trait_identifier: Option<Identifier>,
     statements: Vec<ImplementationLocalStatement>,
 }
 
@@ -23,6 +24,14 @@ impl Builder {
         self.identifier = Some(value);
     }
 
+    ///
+    /// Marks the already set identifier as the trait one, since the type identifier follows
+    /// the `for` keyword in trait implementations.
+    ///
+    pub fn move_identifier_to_trait(&mut self) {
+        self.trait_identifier = self.identifier.take();
+    }
+
     pub fn push_statement(&mut self, statement: ImplementationLocalStatement) {
         self.statements.push(statement);
     }
@@ -35,6 +44,7 @@ impl Builder {
             self.identifier.take().unwrap_or_else(|| {
                 panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "identifier")
             }),
+            self.trait_identifier.take(),
             self.statements,
         )
     }
@@ -12,6 +12,7 @@ use crate::syntax::tree::statement::local_impl::Statement as ImplementationLocal
 pub struct Statement {
     pub location: Location,
     pub identifier: Identifier,
+    pub trait_identifier: Option<Identifier>,
     pub statements: Vec<ImplementationLocalStatement>,
 }
 
@@ -19,11 +20,13 @@ impl Statement {
     pub fn new(
         location: Location,
         identifier: Identifier,
+        trait_identifier: Option<Identifier>,
         statements: Vec<ImplementationLocalStatement>,
     ) -> Self {
         Self {
             location,
             identifier,
+            trait_identifier,
             statements,
         }
     }
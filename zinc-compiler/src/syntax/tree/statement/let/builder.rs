@@ -5,6 +5,7 @@
 use crate::lexical::token::location::Location;
 use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::pattern_binding::variant::Variant as BindingPatternVariant;
 use crate::syntax::tree::r#type::Type;
 use crate::syntax::tree::statement::r#let::Statement as LetStatement;
 
@@ -13,6 +14,7 @@ pub struct Builder {
     location: Option<Location>,
     identifier: Option<Identifier>,
     is_mutable: bool,
+    binding: Option<BindingPatternVariant>,
     r#type: Option<Type>,
     expression: Option<ExpressionTree>,
 }
@@ -30,6 +32,10 @@ impl Builder {
         self.is_mutable = true;
     }
 
+    pub fn set_binding(&mut self, value: BindingPatternVariant) {
+        self.binding = Some(value);
+    }
+
     pub fn set_type(&mut self, value: Type) {
         self.r#type = Some(value);
     }
@@ -39,14 +45,29 @@ impl Builder {
     }
 
     pub fn finish(mut self) -> LetStatement {
+        let binding = match self.binding.take() {
+            Some(binding) => binding,
+            None => {
+                let identifier = self.identifier.take().unwrap_or_else(|| {
+                    panic!(
+                        "{}{}",
+                        crate::PANIC_BUILDER_REQUIRES_VALUE,
+                        "identifier | binding"
+                    )
+                });
+                if self.is_mutable {
+                    BindingPatternVariant::MutableBinding(identifier)
+                } else {
+                    BindingPatternVariant::Binding(identifier)
+                }
+            }
+        };
+
         LetStatement::new(
             self.location
                 .take()
                 .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location")),
-            self.identifier.take().unwrap_or_else(|| {
-                panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "identifier")
-            }),
-            self.is_mutable,
+            binding,
             self.r#type.take(),
             self.expression.take().unwrap_or_else(|| {
                 panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "expression")
@@ -7,12 +7,16 @@ use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
 use crate::syntax::tree::statement::r#const::Statement as ConstStatement;
 use crate::syntax::tree::statement::r#for::Statement as ForStatement;
 use crate::syntax::tree::statement::r#let::Statement as LetStatement;
+use crate::syntax::tree::statement::r#return::Statement as ReturnStatement;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Statement {
     Let(LetStatement),
     Const(ConstStatement),
     For(ForStatement),
+    Break(Location),
+    Continue(Location),
+    Return(ReturnStatement),
     Empty(Location),
     Expression(ExpressionTree),
 }
@@ -23,6 +27,9 @@ impl Statement {
             Self::Let(inner) => inner.location,
             Self::Const(inner) => inner.location,
             Self::For(inner) => inner.location,
+            Self::Break(location) => *location,
+            Self::Continue(location) => *location,
+            Self::Return(inner) => inner.location,
             Self::Empty(location) => *location,
             Self::Expression(inner) => inner.location,
         }
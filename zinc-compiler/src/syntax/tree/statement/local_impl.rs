@@ -21,4 +21,15 @@ impl Statement {
             Self::Empty(location) => *location,
         }
     }
+
+    ///
+    /// Sets the doc comment written before the item.
+    ///
+    pub fn set_doc(&mut self, doc: String) {
+        match self {
+            Self::Const(inner) => inner.doc = Some(doc),
+            Self::Fn(inner) => inner.doc = Some(doc),
+            Self::Empty(_) => {}
+        }
+    }
 }
@@ -9,6 +9,7 @@ use crate::syntax::tree::statement::r#enum::Statement as EnumStatement;
 use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
 use crate::syntax::tree::statement::r#impl::Statement as ImplStatement;
 use crate::syntax::tree::statement::r#struct::Statement as StructStatement;
+use crate::syntax::tree::statement::r#trait::Statement as TraitStatement;
 use crate::syntax::tree::statement::r#type::Statement as TypeStatement;
 use crate::syntax::tree::statement::r#use::Statement as UseStatement;
 
@@ -22,6 +23,7 @@ pub enum Statement {
     Mod(ModStatement),
     Use(UseStatement),
     Impl(ImplStatement),
+    Trait(TraitStatement),
     Empty(Location),
 }
 
@@ -36,7 +38,48 @@ impl Statement {
             Self::Mod(inner) => inner.location,
             Self::Use(inner) => inner.location,
             Self::Impl(inner) => inner.location,
+            Self::Trait(inner) => inner.location,
             Self::Empty(location) => *location,
         }
     }
+    ///
+    /// Marks the item as declared with the `pub` keyword.
+    ///
+    /// Type implementations and empty statements cannot be public, so they are left as is.
+    ///
+    pub fn set_public(&mut self) {
+        match self {
+            Self::Const(inner) => inner.is_public = true,
+            Self::Type(inner) => inner.is_public = true,
+            Self::Struct(inner) => inner.is_public = true,
+            Self::Enum(inner) => inner.is_public = true,
+            Self::Fn(inner) => inner.is_public = true,
+            Self::Mod(inner) => inner.is_public = true,
+            Self::Use(inner) => inner.is_public = true,
+            Self::Trait(inner) => inner.is_public = true,
+            Self::Impl(_) => {}
+            Self::Empty(_) => {}
+        }
+    }
+
+    ///
+    /// Sets the doc comment written before the item.
+    ///
+    /// Imports, type implementations, and empty statements are not documented, so they are
+    /// left as is.
+    ///
+    pub fn set_doc(&mut self, doc: String) {
+        match self {
+            Self::Const(inner) => inner.doc = Some(doc),
+            Self::Type(inner) => inner.doc = Some(doc),
+            Self::Struct(inner) => inner.doc = Some(doc),
+            Self::Enum(inner) => inner.doc = Some(doc),
+            Self::Fn(inner) => inner.doc = Some(doc),
+            Self::Mod(inner) => inner.doc = Some(doc),
+            Self::Trait(inner) => inner.doc = Some(doc),
+            Self::Use(_) => {}
+            Self::Impl(_) => {}
+            Self::Empty(_) => {}
+        }
+    }
 }
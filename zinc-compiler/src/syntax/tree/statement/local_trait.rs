@@ -0,0 +1,31 @@
+//!
+//! The trait-local statement.
+//!
+
+use crate::lexical::token::location::Location;
+use crate::syntax::tree::statement::r#fn::Statement as FnStatement;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    Fn(FnStatement),
+    Empty(Location),
+}
+
+impl Statement {
+    pub fn location(&self) -> Location {
+        match self {
+            Self::Fn(inner) => inner.location,
+            Self::Empty(location) => *location,
+        }
+    }
+
+    ///
+    /// Sets the doc comment written before the item.
+    ///
+    pub fn set_doc(&mut self, doc: String) {
+        match self {
+            Self::Fn(inner) => inner.doc = Some(doc),
+            Self::Empty(_) => {}
+        }
+    }
+}
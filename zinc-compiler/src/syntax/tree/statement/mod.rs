@@ -11,7 +11,10 @@ pub mod r#let;
 pub mod local_fn;
 pub mod local_impl;
 pub mod local_mod;
+pub mod local_trait;
 pub mod module;
+pub mod r#return;
 pub mod r#struct;
+pub mod r#trait;
 pub mod r#type;
 pub mod r#use;
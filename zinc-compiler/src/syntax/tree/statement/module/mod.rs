@@ -10,6 +10,8 @@ use crate::syntax::tree::identifier::Identifier;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Statement {
     pub location: Location,
+    pub is_public: bool,
+    pub doc: Option<String>,
     pub identifier: Identifier,
 }
 
@@ -17,6 +19,8 @@ impl Statement {
     pub fn new(location: Location, identifier: Identifier) -> Self {
         Self {
             location,
+            is_public: false,
+            doc: None,
             identifier,
         }
     }
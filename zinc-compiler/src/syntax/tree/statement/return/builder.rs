@@ -0,0 +1,32 @@
+//!
+//! The return statement builder.
+//!
+
+use crate::lexical::token::location::Location;
+use crate::syntax::tree::expression::tree::Tree as ExpressionTree;
+use crate::syntax::tree::statement::r#return::Statement as ReturnStatement;
+
+#[derive(Default)]
+pub struct Builder {
+    location: Option<Location>,
+    expression: Option<ExpressionTree>,
+}
+
+impl Builder {
+    pub fn set_location(&mut self, value: Location) {
+        self.location = Some(value);
+    }
+
+    pub fn set_expression(&mut self, value: ExpressionTree) {
+        self.expression = Some(value);
+    }
+
+    pub fn finish(mut self) -> ReturnStatement {
+        ReturnStatement::new(
+            self.location
+                .take()
+                .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location")),
+            self.expression.take(),
+        )
+    }
+}
@@ -11,6 +11,8 @@ use crate::syntax::tree::identifier::Identifier;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Statement {
     pub location: Location,
+    pub is_public: bool,
+    pub doc: Option<String>,
     pub identifier: Identifier,
     pub fields: Vec<Field>,
 }
@@ -19,6 +21,8 @@ impl Statement {
     pub fn new(location: Location, identifier: Identifier, fields: Vec<Field>) -> Self {
         Self {
             location,
+            is_public: false,
+            doc: None,
             identifier,
             fields,
         }
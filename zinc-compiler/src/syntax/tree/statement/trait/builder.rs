@@ -0,0 +1,41 @@
+//!
+//! The trait statement builder.
+//!
+
+use crate::lexical::token::location::Location;
+use crate::syntax::tree::identifier::Identifier;
+use crate::syntax::tree::statement::local_trait::Statement as TraitLocalStatement;
+use crate::syntax::tree::statement::r#trait::Statement as TraitStatement;
+
+#[derive(Default)]
+pub struct Builder {
+    location: Option<Location>,
+    identifier: Option<Identifier>,
+    statements: Vec<TraitLocalStatement>,
+}
+
+impl Builder {
+    pub fn set_location(&mut self, value: Location) {
+        self.location = Some(value);
+    }
+
+    pub fn set_identifier(&mut self, value: Identifier) {
+        self.identifier = Some(value);
+    }
+
+    pub fn push_statement(&mut self, statement: TraitLocalStatement) {
+        self.statements.push(statement);
+    }
+
+    pub fn finish(mut self) -> TraitStatement {
+        TraitStatement::new(
+            self.location
+                .take()
+                .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location")),
+            self.identifier.take().unwrap_or_else(|| {
+                panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "identifier")
+            }),
+            self.statements,
+        )
+    }
+}
@@ -11,6 +11,8 @@ use crate::syntax::tree::r#type::Type;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Statement {
     pub location: Location,
+    pub is_public: bool,
+    pub doc: Option<String>,
     pub identifier: Identifier,
     pub r#type: Type,
 }
@@ -19,6 +21,8 @@ impl Statement {
     pub fn new(location: Location, identifier: Identifier, r#type: Type) -> Self {
         Self {
             location,
+            is_public: false,
+            doc: None,
             identifier,
             r#type,
         }
@@ -5,6 +5,7 @@
 use crate::lexical::token::location::Location;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
+use crate::syntax::tree::variant::payload::Payload;
 use crate::syntax::tree::variant::Variant;
 
 #[derive(Default)]
@@ -12,6 +13,7 @@ pub struct Builder {
     location: Option<Location>,
     identifier: Option<Identifier>,
     literal: Option<IntegerLiteral>,
+    payload: Option<Payload>,
 }
 
 impl Builder {
@@ -27,17 +29,33 @@ impl Builder {
         self.literal = Some(value);
     }
 
+    pub fn set_payload(&mut self, value: Payload) {
+        self.payload = Some(value);
+    }
+
     pub fn finish(&mut self) -> Variant {
-        Variant::new(
-            self.location
-                .take()
-                .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location")),
-            self.identifier.take().unwrap_or_else(|| {
-                panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "identifier")
-            }),
-            self.literal
-                .take()
-                .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "literal")),
-        )
+        let location = self
+            .location
+            .take()
+            .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "location"));
+        let identifier = self
+            .identifier
+            .take()
+            .unwrap_or_else(|| panic!("{}{}", crate::PANIC_BUILDER_REQUIRES_VALUE, "identifier"));
+
+        match self.payload.take() {
+            Some(payload) => Variant::new_with_payload(location, identifier, payload),
+            None => Variant::new(
+                location,
+                identifier,
+                self.literal.take().unwrap_or_else(|| {
+                    panic!(
+                        "{}{}",
+                        crate::PANIC_BUILDER_REQUIRES_VALUE,
+                        "literal | payload"
+                    )
+                }),
+            ),
+        }
     }
 }
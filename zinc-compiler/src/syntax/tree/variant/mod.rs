@@ -3,16 +3,20 @@
 //!
 
 pub mod builder;
+pub mod payload;
 
 use crate::lexical::token::location::Location;
 use crate::syntax::tree::identifier::Identifier;
 use crate::syntax::tree::literal::integer::Literal as IntegerLiteral;
 
+use self::payload::Payload;
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Variant {
     pub location: Location,
     pub identifier: Identifier,
-    pub literal: IntegerLiteral,
+    pub literal: Option<IntegerLiteral>,
+    pub payload: Option<Payload>,
 }
 
 impl Variant {
@@ -20,7 +24,17 @@ impl Variant {
         Self {
             location,
             identifier,
-            literal,
+            literal: Some(literal),
+            payload: None,
+        }
+    }
+
+    pub fn new_with_payload(location: Location, identifier: Identifier, payload: Payload) -> Self {
+        Self {
+            location,
+            identifier,
+            literal: None,
+            payload: Some(payload),
         }
     }
 }
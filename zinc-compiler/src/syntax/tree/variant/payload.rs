@@ -0,0 +1,17 @@
+//!
+//! The variant payload.
+//!
+
+use crate::syntax::tree::field::Field;
+use crate::syntax::tree::r#type::Type;
+
+///
+/// The data carried by an algebraic enumeration variant.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Payload {
+    /// the tuple-like payload, e.g. `Burn(u64)`
+    Tuple(Vec<Type>),
+    /// the structure-like payload, e.g. `Transfer { to: field, amount: u64 }`
+    Structure(Vec<Field>),
+}
@@ -0,0 +1,144 @@
+//!
+//! The compiler lint.
+//!
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+///
+/// The lint group name, which sets the level of all the lints at once.
+///
+pub static GROUP_WARNINGS: &str = "warnings";
+
+///
+/// The kind of the warning, whose level can be configured.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lint {
+    UnusedVariables,
+    UnusedFunctions,
+    UnusedImports,
+    UnusedMut,
+    UnreachablePatterns,
+    ShadowedBindings,
+    UnusedExpressionResults,
+    UnconstrainedValues,
+}
+
+///
+/// The lint level, which defines whether the warning is ignored, reported, or turned into
+/// a compile error.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Allow,
+    Warn,
+    Deny,
+}
+
+///
+/// The lint levels configured for the compiled files.
+///
+#[derive(Debug, Clone)]
+pub struct Lints {
+    levels: HashMap<Lint, Level>,
+}
+
+impl Lint {
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::UnusedVariables,
+            Self::UnusedFunctions,
+            Self::UnusedImports,
+            Self::UnusedMut,
+            Self::UnreachablePatterns,
+            Self::ShadowedBindings,
+            Self::UnusedExpressionResults,
+            Self::UnconstrainedValues,
+        ]
+    }
+}
+
+impl Default for Lints {
+    fn default() -> Self {
+        Self {
+            levels: Lint::all()
+                .into_iter()
+                .map(|lint| (lint, Level::Warn))
+                .collect(),
+        }
+    }
+}
+
+impl Lints {
+    ///
+    /// Creates the lint levels from the `(name, level)` settings, where the `warnings` group
+    /// settings are applied first, so they can be overridden for the specific lints.
+    ///
+    pub fn new(mut settings: Vec<(String, Level)>) -> Result<Self, String> {
+        settings.sort_by_key(|(name, _level)| name != GROUP_WARNINGS);
+
+        let mut lints = Self::default();
+        for (name, level) in settings.into_iter() {
+            lints.set(name.as_str(), level)?;
+        }
+        Ok(lints)
+    }
+
+    ///
+    /// Sets the level of the lint called `name`, or of all the lints if `name` is `warnings`.
+    ///
+    pub fn set(&mut self, name: &str, level: Level) -> Result<(), String> {
+        if name == GROUP_WARNINGS {
+            for lint in Lint::all().into_iter() {
+                self.levels.insert(lint, level);
+            }
+            return Ok(());
+        }
+
+        let lint = Lint::from_str(name)?;
+        self.levels.insert(lint, level);
+        Ok(())
+    }
+
+    pub fn level(&self, lint: Lint) -> Level {
+        self.levels.get(&lint).copied().unwrap_or(Level::Warn)
+    }
+}
+
+impl FromStr for Lint {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::all()
+            .into_iter()
+            .find(|lint| lint.to_string() == name)
+            .ok_or_else(|| format!("unknown lint `{}`", name))
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnusedVariables => write!(f, "unused_variables"),
+            Self::UnusedFunctions => write!(f, "unused_functions"),
+            Self::UnusedImports => write!(f, "unused_imports"),
+            Self::UnusedMut => write!(f, "unused_mut"),
+            Self::UnreachablePatterns => write!(f, "unreachable_patterns"),
+            Self::ShadowedBindings => write!(f, "shadowed_bindings"),
+            Self::UnusedExpressionResults => write!(f, "unused_expression_results"),
+            Self::UnconstrainedValues => write!(f, "unconstrained_values"),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Allow => write!(f, "allow"),
+            Self::Warn => write!(f, "warn"),
+            Self::Deny => write!(f, "deny"),
+        }
+    }
+}
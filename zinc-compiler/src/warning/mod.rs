@@ -0,0 +1,224 @@
+//!
+//! The Zinc compiler warning.
+//!
+
+mod tests;
+
+pub mod lint;
+
+use std::cell::RefCell;
+
+use crate::diagnostic::Diagnostic;
+use crate::diagnostic::Severity;
+use crate::lexical::token::location::Location;
+
+use self::lint::Level;
+use self::lint::Lint;
+use self::lint::Lints;
+
+thread_local! {
+    /// The warnings emitted by the semantic analyzer, which have not been taken by the caller yet.
+    static WARNINGS: RefCell<Vec<Warning>> = RefCell::new(Vec::new());
+}
+
+///
+/// The warning, which does not prevent the compilation unless its lint is denied.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Warning {
+    UnusedVariable {
+        location: Location,
+        name: String,
+    },
+    UnusedFunction {
+        location: Location,
+        name: String,
+    },
+    UnusedImport {
+        location: Location,
+        name: String,
+    },
+    UnusedMut {
+        location: Location,
+        name: String,
+    },
+    UnreachablePattern {
+        location: Location,
+    },
+    ShadowedBinding {
+        location: Location,
+        name: String,
+        reference: Option<Location>,
+    },
+    UnusedExpressionResult {
+        location: Location,
+        r#type: String,
+    },
+    UnconstrainedValue {
+        location: Location,
+        name: String,
+    },
+}
+
+impl Warning {
+    ///
+    /// Stores the warning until the analyzed file warnings are taken.
+    ///
+    pub fn emit(self) {
+        WARNINGS.with(|warnings| warnings.borrow_mut().push(self));
+    }
+
+    ///
+    /// Takes the warnings emitted since the last call, in the order of their locations.
+    ///
+    /// The generic function instances and the default trait methods are analyzed several
+    /// times, so the duplicate warnings are removed.
+    ///
+    pub fn take_all() -> Vec<Self> {
+        let emitted = WARNINGS.with(|warnings| warnings.replace(Vec::new()));
+
+        let mut warnings: Vec<Self> = Vec::with_capacity(emitted.len());
+        for warning in emitted.into_iter() {
+            if !warnings.contains(&warning) {
+                warnings.push(warning);
+            }
+        }
+        warnings.sort_by_key(|warning| {
+            let location = warning.location();
+            (location.line, location.column)
+        });
+        warnings
+    }
+
+    ///
+    /// Converts the warnings, which are not allowed by `lints`, into diagnostics. The denied
+    /// warnings become errors.
+    ///
+    /// Returns an error with all the diagnostics if any of the warnings is denied.
+    ///
+    pub fn diagnostics(
+        warnings: Vec<Self>,
+        lints: &Lints,
+    ) -> Result<Vec<Diagnostic>, Vec<Diagnostic>> {
+        let mut is_denied = false;
+        let mut diagnostics = Vec::with_capacity(warnings.len());
+        for warning in warnings.into_iter() {
+            let level = lints.level(warning.lint());
+            match level {
+                Level::Allow => continue,
+                Level::Warn => {}
+                Level::Deny => is_denied = true,
+            }
+            diagnostics.push(warning.diagnostic(level));
+        }
+
+        if is_denied {
+            Err(diagnostics)
+        } else {
+            Ok(diagnostics)
+        }
+    }
+
+    pub fn lint(&self) -> Lint {
+        match self {
+            Self::UnusedVariable { .. } => Lint::UnusedVariables,
+            Self::UnusedFunction { .. } => Lint::UnusedFunctions,
+            Self::UnusedImport { .. } => Lint::UnusedImports,
+            Self::UnusedMut { .. } => Lint::UnusedMut,
+            Self::UnreachablePattern { .. } => Lint::UnreachablePatterns,
+            Self::ShadowedBinding { .. } => Lint::ShadowedBindings,
+            Self::UnusedExpressionResult { .. } => Lint::UnusedExpressionResults,
+            Self::UnconstrainedValue { .. } => Lint::UnconstrainedValues,
+        }
+    }
+
+    pub fn location(&self) -> Location {
+        match self {
+            Self::UnusedVariable { location, .. } => *location,
+            Self::UnusedFunction { location, .. } => *location,
+            Self::UnusedImport { location, .. } => *location,
+            Self::UnusedMut { location, .. } => *location,
+            Self::UnreachablePattern { location } => *location,
+            Self::ShadowedBinding { location, .. } => *location,
+            Self::UnusedExpressionResult { location, .. } => *location,
+            Self::UnconstrainedValue { location, .. } => *location,
+        }
+    }
+
+    ///
+    /// Converts the warning into a diagnostic, whose code is the lint name.
+    ///
+    pub fn diagnostic(self, level: Level) -> Diagnostic {
+        let lint = self.lint();
+
+        let diagnostic = match self {
+            Self::UnusedVariable { location, name } => Diagnostic::warning_line(
+                format!("unused variable `{}`", name).as_str(),
+                location,
+                None,
+                Some(
+                    format!(
+                        "if this is intentional, prefix it with an underscore: `_{}`",
+                        name
+                    )
+                    .as_str(),
+                ),
+            ),
+            Self::UnusedFunction { location, name } => Diagnostic::warning_line(
+                format!("function `{}` is never used", name).as_str(),
+                location,
+                None,
+                Some("consider removing the function or declaring it with the `pub` keyword"),
+            ),
+            Self::UnusedImport { location, name } => Diagnostic::warning_line(
+                format!("unused import `{}`", name).as_str(),
+                location,
+                None,
+                Some("consider removing the import"),
+            ),
+            Self::UnusedMut { location, name } => Diagnostic::warning_line(
+                format!("variable `{}` does not need to be mutable", name).as_str(),
+                location,
+                None,
+                Some("consider removing the `mut` keyword"),
+            ),
+            Self::UnreachablePattern { location } => Diagnostic::warning_line(
+                "match expression branch is unreachable",
+                location,
+                None,
+                Some("consider removing the branch or moving it above the branch with a wildcard or irrefutable binding"),
+            ),
+            Self::ShadowedBinding {
+                location,
+                name,
+                reference,
+            } => Diagnostic::warning_line(
+                format!("variable `{}` shadows the previous binding", name).as_str(),
+                location,
+                reference,
+                Some("consider giving the variable another name"),
+            ),
+            Self::UnusedExpressionResult { location, r#type } => Diagnostic::warning_line(
+                format!("unused expression result of type `{}`", r#type).as_str(),
+                location,
+                None,
+                Some("consider binding the result with `let` or removing the expression statement"),
+            ),
+            Self::UnconstrainedValue { location, name } => Diagnostic::warning_line(
+                format!("value of `{}` is never constrained by the circuit", name).as_str(),
+                location,
+                None,
+                Some("the value reaches neither the function result nor an `assert!` call, so it is not checked by the verifier"),
+            ),
+        };
+
+        let severity = match level {
+            Level::Deny => Severity::Error,
+            _ => Severity::Warning,
+        };
+        diagnostic
+            .with_code(lint.to_string())
+            .with_severity(severity)
+            .with_note(format!("lint `{}` is set to `{}`", lint, level))
+    }
+}
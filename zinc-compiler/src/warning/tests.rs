@@ -0,0 +1,287 @@
+//!
+//! The warning tests.
+//!
+
+#![cfg(test)]
+
+use crate::lexical::token::location::Location;
+use crate::warning::lint::Level;
+use crate::warning::lint::Lint;
+use crate::warning::lint::Lints;
+use crate::warning::Warning;
+
+#[test]
+fn ok_no_warnings() {
+    let input = r#"
+fn double(value: u8) -> u8 {
+    value * 2
+}
+
+fn main(witness: u8) -> u8 {
+    let mut result = witness;
+    result = double(result);
+    let _ignored = 42;
+    result
+}
+"#;
+
+    let expected = Ok(vec![]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_variable() {
+    let input = r#"
+fn main(witness: u8) -> u8 {
+    let value = 42;
+    witness
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedVariable {
+        location: Location::new(3, 9),
+        name: "value".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_variable_argument() {
+    let input = r#"
+fn main(witness: u8, unused: u8) -> u8 {
+    witness
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedVariable {
+        location: Location::new(2, 22),
+        name: "unused".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_function() {
+    let input = r#"
+fn unused() -> u8 {
+    42
+}
+
+fn main(witness: u8) -> u8 {
+    witness
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedFunction {
+        location: Location::new(2, 4),
+        name: "unused".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_import() {
+    let input = r#"
+use std::crypto::sha256;
+
+fn main(witness: u8) -> u8 {
+    witness
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedImport {
+        location: Location::new(2, 18),
+        name: "sha256".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_mut() {
+    let input = r#"
+fn main(witness: u8) -> u8 {
+    let mut value = witness;
+    value
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedMut {
+        location: Location::new(3, 13),
+        name: "value".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_shadowed_binding() {
+    let input = r#"
+fn main(witness: u8) -> u8 {
+    let value = witness;
+    let value = value + 1;
+    value
+}
+"#;
+
+    let expected = Ok(vec![Warning::ShadowedBinding {
+        location: Location::new(4, 9),
+        name: "value".to_owned(),
+        reference: Some(Location::new(3, 9)),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_expression_result() {
+    let input = r#"
+fn main(witness: u8) -> u8 {
+    witness * 2;
+    witness
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedExpressionResult {
+        location: Location::new(3, 13),
+        r#type: "u8".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unconstrained_value() {
+    let input = r#"
+fn main(witness: u8) -> u8 {
+    let product = witness * witness;
+    dbg!("{}", product);
+    witness
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnconstrainedValue {
+        location: Location::new(3, 9),
+        name: "product".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unconstrained_value_transitive() {
+    let input = r#"
+fn main(witness: u8) -> u8 {
+    let product = witness * witness;
+    let sum = product + 1;
+    dbg!("{}", sum);
+    witness
+}
+"#;
+
+    let expected = Ok(vec![
+        Warning::UnconstrainedValue {
+            location: Location::new(3, 9),
+            name: "product".to_owned(),
+        },
+        Warning::UnconstrainedValue {
+            location: Location::new(4, 9),
+            name: "sum".to_owned(),
+        },
+    ]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_unconstrained_value_assert() {
+    let input = r#"
+fn main(witness: u8) -> u8 {
+    let product = witness * witness;
+    assert!(product > 4);
+    witness
+}
+"#;
+
+    let expected = Ok(vec![]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_unconstrained_value_condition() {
+    let input = r#"
+fn main(witness: u8) -> u8 {
+    let product = witness * witness;
+    let sum = product + 1;
+    let mut result = 0;
+    if sum > 10 {
+        result = 1;
+    };
+    result
+}
+"#;
+
+    let expected = Ok(vec![]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_lints_set() {
+    let mut lints = Lints::default();
+
+    lints
+        .set("warnings", Level::Allow)
+        .expect(crate::semantic::tests::PANIC_TEST_DATA);
+    lints
+        .set("unused_mut", Level::Deny)
+        .expect(crate::semantic::tests::PANIC_TEST_DATA);
+
+    assert_eq!(lints.level(Lint::UnusedVariables), Level::Allow);
+    assert_eq!(lints.level(Lint::UnusedMut), Level::Deny);
+    assert_eq!(
+        lints.set("unused_everything", Level::Warn),
+        Err("unknown lint `unused_everything`".to_owned())
+    );
+}
+
+#[test]
+fn ok_lints_new_group_first() {
+    let lints = Lints::new(vec![
+        ("unused_mut".to_owned(), Level::Allow),
+        ("warnings".to_owned(), Level::Deny),
+    ])
+    .expect(crate::semantic::tests::PANIC_TEST_DATA);
+
+    assert_eq!(lints.level(Lint::UnusedMut), Level::Allow);
+    assert_eq!(lints.level(Lint::UnusedImports), Level::Deny);
+}
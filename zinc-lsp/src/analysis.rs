@@ -0,0 +1,183 @@
+//!
+//! The Zinc language server project analysis.
+//!
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::convert::TryFrom;
+use std::panic;
+use std::path::Path;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use zinc_compiler::Bytecode;
+use zinc_compiler::Diagnostic;
+use zinc_compiler::File;
+use zinc_compiler::Graph;
+use zinc_compiler::Lints;
+use zinc_compiler::MessageFormat;
+use zinc_compiler::Scope;
+use zinc_compiler::SymbolTable;
+
+static MANIFEST_FILE_NAME: &str = "Zargo.toml";
+static SOURCE_DIRECTORY: &str = "src";
+static ENTRY_FILE_NAME: &str = "main.zn";
+static LIBRARY_FILE_NAME: &str = "lib.zn";
+static ENTRY_FILE_STEM: &str = "main";
+
+///
+/// The result of compiling the project a document belongs to.
+///
+#[derive(Debug, Default)]
+pub struct Analysis {
+    /// The source code of each compiled file, which is either the open document or the file
+    /// contents on disk.
+    pub sources: HashMap<PathBuf, String>,
+    /// The diagnostics of each compiled file, which are empty for the valid files.
+    pub diagnostics: HashMap<PathBuf, Vec<Diagnostic>>,
+    /// The symbol table of all the compiled files.
+    pub symbols: SymbolTable,
+    /// The submodules of each source file, which are the `mod` statement names mapped to
+    /// the resolved file paths.
+    pub modules: HashMap<PathBuf, Vec<(String, PathBuf)>>,
+}
+
+impl Analysis {
+    ///
+    /// Compiles the project the `document` belongs to, where the open `documents` take
+    /// precedence over the files on disk.
+    ///
+    /// The project is the `Zargo.toml` directory the document is located in, whose `src/main.zn`
+    /// or `src/lib.zn` file is the module graph root. If the module graph cannot be built,
+    /// the document is compiled alone.
+    ///
+    /// The compiler panics are caught, so an unexpected input does not stop the server.
+    ///
+    pub fn new(document: &Path, documents: &HashMap<PathBuf, String>) -> Self {
+        let root = Self::root_file(document).unwrap_or_else(|| document.to_owned());
+
+        let mut analysis = Self::default();
+        SymbolTable::start();
+        let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+            match Graph::new(vec![root], MessageFormat::Human) {
+                Ok(graph) => analysis.compile_graph(graph, documents),
+                Err(_) => {
+                    analysis.compile_file(
+                        document.to_owned(),
+                        Rc::new(RefCell::new(Bytecode::new())),
+                        HashMap::new(),
+                        documents,
+                        &mut HashMap::new(),
+                    );
+                }
+            }
+        }));
+        analysis.symbols = SymbolTable::take();
+
+        if result.is_err() {
+            log::error!("The compiler has panicked on {:?}", document);
+        }
+        analysis
+    }
+
+    ///
+    /// Compiles the module graph files in order into the shared bytecode, skipping the files
+    /// whose submodules are invalid.
+    ///
+    fn compile_graph(&mut self, graph: Graph, documents: &HashMap<PathBuf, String>) {
+        let bytecode = Rc::new(RefCell::new(Bytecode::new()));
+        let mut modules = HashMap::<PathBuf, Rc<RefCell<Scope>>>::new();
+        let mut failed = HashSet::<PathBuf>::new();
+
+        for path in graph.files.iter().cloned() {
+            if graph.has_failed_dependency(&path, &failed) {
+                failed.insert(path);
+                continue;
+            }
+
+            let dependencies = graph.dependencies(&path, &modules);
+            if !self.compile_file(
+                path.clone(),
+                bytecode.clone(),
+                dependencies,
+                documents,
+                &mut modules,
+            ) {
+                failed.insert(path);
+            }
+        }
+
+        self.modules = graph.modules;
+    }
+
+    ///
+    /// Compiles the file, storing its diagnostics and, if it is a valid module, its scope
+    /// to `modules`.
+    ///
+    /// Returns `false` if the file is invalid.
+    ///
+    fn compile_file(
+        &mut self,
+        path: PathBuf,
+        bytecode: Rc<RefCell<Bytecode>>,
+        dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+        documents: &HashMap<PathBuf, String>,
+        modules: &mut HashMap<PathBuf, Rc<RefCell<Scope>>>,
+    ) -> bool {
+        let file = match documents.get(&path) {
+            Some(code) => File {
+                path: path.clone(),
+                code: code.to_owned(),
+            },
+            None => match File::try_from(path.clone()) {
+                Ok(file) => file,
+                Err(error) => {
+                    self.diagnostics
+                        .insert(path, vec![Diagnostic::error_message(error.as_str(), None)]);
+                    return false;
+                }
+            },
+        };
+
+        let lints = Lints::default();
+        let is_entry = path.file_stem().and_then(|stem| stem.to_str()) == Some(ENTRY_FILE_STEM);
+
+        let result = if is_entry {
+            file.compile_entry(bytecode, dependencies, HashMap::new(), &lints)
+        } else {
+            file.compile_module(bytecode, dependencies, HashMap::new(), &lints)
+                .map(|(scope, warnings)| {
+                    modules.insert(path.clone(), scope);
+                    warnings
+                })
+        };
+
+        self.sources.insert(path.clone(), file.code);
+        match result {
+            Ok(warnings) => {
+                self.diagnostics.insert(path, warnings);
+                true
+            }
+            Err(errors) => {
+                self.diagnostics.insert(path, errors);
+                false
+            }
+        }
+    }
+
+    ///
+    /// Finds the module graph root of the project the document belongs to.
+    ///
+    fn root_file(document: &Path) -> Option<PathBuf> {
+        let project = document
+            .ancestors()
+            .find(|directory| directory.join(MANIFEST_FILE_NAME).is_file())?;
+        let source = project.join(SOURCE_DIRECTORY);
+
+        vec![ENTRY_FILE_NAME, LIBRARY_FILE_NAME]
+            .into_iter()
+            .map(|name| source.join(name))
+            .find(|path| path.is_file())
+    }
+}
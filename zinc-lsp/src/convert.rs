@@ -0,0 +1,132 @@
+//!
+//! The Zinc language server protocol type conversions.
+//!
+
+use lsp_types::CompletionItemKind;
+use lsp_types::DiagnosticRelatedInformation;
+use lsp_types::DiagnosticSeverity;
+use lsp_types::Location as LspLocation;
+use lsp_types::NumberOrString;
+use lsp_types::Position;
+use lsp_types::Range;
+use lsp_types::SymbolKind as LspSymbolKind;
+use lsp_types::Url;
+
+use zinc_compiler::Diagnostic;
+use zinc_compiler::Location;
+use zinc_compiler::Severity;
+use zinc_compiler::SymbolKind;
+
+static DIAGNOSTIC_SOURCE: &str = "zinc";
+
+///
+/// Converts the compiler diagnostic, where `context` is the source code lines of its file.
+///
+/// The diagnostics without a location, e.g. the file reading errors, are put at the file
+/// beginning.
+///
+pub fn diagnostic(diagnostic: Diagnostic, context: &[&str]) -> lsp_types::Diagnostic {
+    let range = diagnostic
+        .span(context)
+        .map(|(start, end)| Range::new(position(start), position(end)))
+        .unwrap_or_default();
+
+    let severity = match diagnostic.severity {
+        Severity::Error => DiagnosticSeverity::ERROR,
+        Severity::Warning => DiagnosticSeverity::WARNING,
+    };
+
+    let code = if diagnostic.code.is_empty() {
+        None
+    } else {
+        Some(NumberOrString::String(diagnostic.code.clone()))
+    };
+
+    let mut message = diagnostic.message.clone();
+    for help in diagnostic.help.iter() {
+        message.push_str(&format!("\nhelp: {}", help));
+    }
+    for note in diagnostic.notes.iter() {
+        message.push_str(&format!("\nnote: {}", note));
+    }
+
+    let related_information = diagnostic.reference.and_then(|reference| {
+        let location = location(reference, 1)?;
+        Some(vec![DiagnosticRelatedInformation {
+            location,
+            message: "see here".to_owned(),
+        }])
+    });
+
+    lsp_types::Diagnostic {
+        range,
+        severity: Some(severity),
+        code,
+        source: Some(DIAGNOSTIC_SOURCE.to_owned()),
+        message,
+        related_information,
+        ..Default::default()
+    }
+}
+
+///
+/// Converts the compiler location, which is 1-based, to the protocol position, which is 0-based.
+///
+pub fn position(location: Location) -> Position {
+    Position::new(
+        location.line.saturating_sub(1) as u32,
+        location.column.saturating_sub(1) as u32,
+    )
+}
+
+///
+/// Converts the protocol position of the file with `file_index` to the compiler location.
+///
+pub fn location_from(file_index: usize, position: Position) -> Location {
+    Location {
+        file_index: Some(file_index),
+        line: position.line as usize + 1,
+        column: position.character as usize + 1,
+    }
+}
+
+///
+/// Converts the compiler location of an identifier with `length` characters to the protocol
+/// location.
+///
+/// Returns `None` if the location does not belong to a registered file.
+///
+pub fn location(location: Location, length: usize) -> Option<LspLocation> {
+    let uri = Url::from_file_path(location.file_path()?).ok()?;
+    let start = position(location);
+    let end = Position::new(start.line, start.character + length as u32);
+    Some(LspLocation::new(uri, Range::new(start, end)))
+}
+
+///
+/// Converts the symbol kind for the document outline.
+///
+pub fn symbol_kind(kind: SymbolKind) -> LspSymbolKind {
+    match kind {
+        SymbolKind::Variable => LspSymbolKind::VARIABLE,
+        SymbolKind::Constant => LspSymbolKind::CONSTANT,
+        SymbolKind::Function => LspSymbolKind::FUNCTION,
+        SymbolKind::Type => LspSymbolKind::STRUCT,
+        SymbolKind::Module => LspSymbolKind::MODULE,
+        SymbolKind::Trait => LspSymbolKind::INTERFACE,
+    }
+}
+
+///
+/// Converts the symbol kind for the completion list.
+///
+pub fn completion_kind(kind: SymbolKind) -> CompletionItemKind {
+    match kind {
+        SymbolKind::Variable => CompletionItemKind::VARIABLE,
+        SymbolKind::Constant => CompletionItemKind::CONSTANT,
+        SymbolKind::Function => CompletionItemKind::FUNCTION,
+        SymbolKind::Type => CompletionItemKind::STRUCT,
+        SymbolKind::Module => CompletionItemKind::MODULE,
+        SymbolKind::Trait => CompletionItemKind::INTERFACE,
+    }
+}
@@ -0,0 +1,68 @@
+//!
+//! The Zinc language server binary.
+//!
+
+mod analysis;
+mod convert;
+mod server;
+
+use std::process;
+
+use failure::Fail;
+use structopt::StructOpt;
+
+use self::server::Server;
+
+const EXIT_CODE_SUCCESS: i32 = 0;
+const EXIT_CODE_FAILURE: i32 = 1;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "zinc-lsp",
+    about = "The Zinc language server, which communicates over the standard input and output"
+)]
+struct Arguments {
+    #[structopt(
+        short = "v",
+        parse(from_occurrences),
+        help = "Shows verbose logs in the standard error, use multiple times for more verbosity"
+    )]
+    verbosity: usize,
+    #[structopt(
+        long = "stdio",
+        help = "Uses the standard input and output, which is the only supported transport"
+    )]
+    stdio: bool,
+}
+
+#[derive(Debug, Fail)]
+pub enum Error {
+    #[fail(display = "protocol: {}", _0)]
+    Protocol(String),
+    #[fail(display = "connection: {}", _0)]
+    Connection(String),
+}
+
+fn main() {
+    let args: Arguments = Arguments::from_args();
+
+    process::exit(match main_inner(args) {
+        Ok(()) => EXIT_CODE_SUCCESS,
+        Err(error) => {
+            log::error!("{}", error);
+            EXIT_CODE_FAILURE
+        }
+    })
+}
+
+fn main_inner(args: Arguments) -> Result<(), Error> {
+    zinc_bytecode::logger::init_logger("zinc-lsp", args.verbosity);
+
+    let (connection, io_threads) = lsp_server::Connection::stdio();
+    Server::initialize(connection)?.run()?;
+    io_threads
+        .join()
+        .map_err(|error| Error::Connection(error.to_string()))?;
+
+    Ok(())
+}
@@ -0,0 +1,447 @@
+//!
+//! The Zinc language server.
+//!
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::Path;
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use lsp_server::Connection;
+use lsp_server::Message;
+use lsp_server::Notification;
+use lsp_server::Request;
+use lsp_server::RequestId;
+use lsp_server::Response;
+use lsp_types::notification::DidChangeTextDocument;
+use lsp_types::notification::DidCloseTextDocument;
+use lsp_types::notification::DidOpenTextDocument;
+use lsp_types::notification::DidSaveTextDocument;
+use lsp_types::notification::Notification as _;
+use lsp_types::notification::PublishDiagnostics;
+use lsp_types::request::Completion;
+use lsp_types::request::DocumentSymbolRequest;
+use lsp_types::request::GotoDefinition;
+use lsp_types::request::HoverRequest;
+use lsp_types::request::Request as _;
+use lsp_types::CompletionItem;
+use lsp_types::CompletionOptions;
+use lsp_types::CompletionParams;
+use lsp_types::CompletionResponse;
+use lsp_types::DidChangeTextDocumentParams;
+use lsp_types::DidCloseTextDocumentParams;
+use lsp_types::DidOpenTextDocumentParams;
+use lsp_types::DidSaveTextDocumentParams;
+use lsp_types::DocumentSymbolParams;
+use lsp_types::DocumentSymbolResponse;
+use lsp_types::GotoDefinitionParams;
+use lsp_types::GotoDefinitionResponse;
+use lsp_types::Hover;
+use lsp_types::HoverContents;
+use lsp_types::HoverParams;
+use lsp_types::HoverProviderCapability;
+use lsp_types::Location as LspLocation;
+use lsp_types::MarkupContent;
+use lsp_types::MarkupKind;
+use lsp_types::OneOf;
+use lsp_types::Position;
+use lsp_types::PublishDiagnosticsParams;
+use lsp_types::Range;
+use lsp_types::ServerCapabilities;
+use lsp_types::SymbolInformation;
+use lsp_types::TextDocumentSyncCapability;
+use lsp_types::TextDocumentSyncKind;
+use lsp_types::Url;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use zinc_compiler::File;
+use zinc_compiler::Severity;
+use zinc_compiler::SymbolKind;
+
+use crate::analysis::Analysis;
+use crate::convert;
+use crate::Error;
+
+static COMPLETION_TRIGGER: &str = ":";
+static PATH_SEPARATOR: &str = "::";
+
+///
+/// The language server state.
+///
+pub struct Server {
+    /// The client connection.
+    connection: Connection,
+    /// The open documents, whose text takes precedence over the files on disk.
+    documents: HashMap<PathBuf, String>,
+    /// The last analysis, which is repeated on every document change.
+    analysis: Rc<Analysis>,
+    /// The last analysis without errors, which is used to complete the partially written code.
+    valid: Rc<Analysis>,
+    /// The files with the published diagnostics, which must be cleared by the next analysis.
+    published: HashSet<PathBuf>,
+}
+
+impl Server {
+    ///
+    /// Performs the protocol handshake, declaring the server capabilities.
+    ///
+    pub fn initialize(connection: Connection) -> Result<Self, Error> {
+        let capabilities = ServerCapabilities {
+            text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            definition_provider: Some(OneOf::Left(true)),
+            document_symbol_provider: Some(OneOf::Left(true)),
+            completion_provider: Some(CompletionOptions {
+                trigger_characters: Some(vec![COMPLETION_TRIGGER.to_owned()]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let capabilities = serde_json::to_value(capabilities)
+            .map_err(|error| Error::Protocol(error.to_string()))?;
+        connection
+            .initialize(capabilities)
+            .map_err(|error| Error::Protocol(error.to_string()))?;
+
+        Ok(Self {
+            connection,
+            documents: HashMap::new(),
+            analysis: Rc::new(Analysis::default()),
+            valid: Rc::new(Analysis::default()),
+            published: HashSet::new(),
+        })
+    }
+
+    ///
+    /// Handles the client messages until the shutdown request.
+    ///
+    pub fn run(mut self) -> Result<(), Error> {
+        while let Ok(message) = self.connection.receiver.recv() {
+            match message {
+                Message::Request(request) => {
+                    if self
+                        .connection
+                        .handle_shutdown(&request)
+                        .map_err(|error| Error::Protocol(error.to_string()))?
+                    {
+                        return Ok(());
+                    }
+                    self.handle_request(request)?;
+                }
+                Message::Notification(notification) => self.handle_notification(notification)?,
+                Message::Response(_response) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn handle_request(&mut self, request: Request) -> Result<(), Error> {
+        log::debug!("Request {}", request.method);
+
+        match request.method.as_str() {
+            HoverRequest::METHOD => {
+                let (id, params) = Self::extract::<HoverParams>(request)?;
+                let result = self.hover(params);
+                self.respond(id, result)
+            }
+            GotoDefinition::METHOD => {
+                let (id, params) = Self::extract::<GotoDefinitionParams>(request)?;
+                let result = self.definition(params);
+                self.respond(id, result)
+            }
+            Completion::METHOD => {
+                let (id, params) = Self::extract::<CompletionParams>(request)?;
+                let result = self.completion(params);
+                self.respond(id, result)
+            }
+            DocumentSymbolRequest::METHOD => {
+                let (id, params) = Self::extract::<DocumentSymbolParams>(request)?;
+                let result = self.document_symbols(params);
+                self.respond(id, result)
+            }
+            _ => self.send(Response::new_err(
+                request.id,
+                lsp_server::ErrorCode::MethodNotFound as i32,
+                format!("method `{}` is not supported", request.method),
+            )),
+        }
+    }
+
+    fn handle_notification(&mut self, notification: Notification) -> Result<(), Error> {
+        log::debug!("Notification {}", notification.method);
+
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params = Self::extract_notification::<DidOpenTextDocumentParams>(notification)?;
+                if let Some(path) = Self::path(&params.text_document.uri) {
+                    self.documents
+                        .insert(path.clone(), params.text_document.text);
+                    self.analyze(&path)?;
+                }
+            }
+            DidChangeTextDocument::METHOD => {
+                let params =
+                    Self::extract_notification::<DidChangeTextDocumentParams>(notification)?;
+                if let (Some(path), Some(change)) = (
+                    Self::path(&params.text_document.uri),
+                    params.content_changes.into_iter().last(),
+                ) {
+                    self.documents.insert(path.clone(), change.text);
+                    self.analyze(&path)?;
+                }
+            }
+            DidSaveTextDocument::METHOD => {
+                let params = Self::extract_notification::<DidSaveTextDocumentParams>(notification)?;
+                if let Some(path) = Self::path(&params.text_document.uri) {
+                    self.analyze(&path)?;
+                }
+            }
+            DidCloseTextDocument::METHOD => {
+                let params =
+                    Self::extract_notification::<DidCloseTextDocumentParams>(notification)?;
+                if let Some(path) = Self::path(&params.text_document.uri) {
+                    self.documents.remove(&path);
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Analyzes the project the document belongs to and publishes the diagnostics of all
+    /// its files.
+    ///
+    fn analyze(&mut self, document: &Path) -> Result<(), Error> {
+        let mut analysis = Analysis::new(document, &self.documents);
+
+        let is_valid = analysis.diagnostics.values().all(|diagnostics| {
+            diagnostics
+                .iter()
+                .all(|diagnostic| diagnostic.severity != Severity::Error)
+        });
+
+        let mut published = HashSet::with_capacity(analysis.diagnostics.len());
+        for (path, diagnostics) in analysis.diagnostics.drain() {
+            let code = analysis.sources.get(&path).cloned().unwrap_or_default();
+            let lines = code.lines().collect::<Vec<&str>>();
+            let diagnostics = diagnostics
+                .into_iter()
+                .map(|diagnostic| convert::diagnostic(diagnostic, &lines))
+                .collect();
+            self.publish(&path, diagnostics)?;
+            published.insert(path);
+        }
+        for path in self.published.difference(&published) {
+            self.publish(path, vec![])?;
+        }
+        self.published = published;
+
+        self.analysis = Rc::new(analysis);
+        if is_valid {
+            self.valid = self.analysis.clone();
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Describes the item under the cursor.
+    ///
+    fn hover(&self, params: HoverParams) -> Option<Hover> {
+        let position = params.text_document_position_params;
+        let symbol = self.analysis.symbols.symbol_at(Self::location(
+            &position.text_document.uri,
+            position.position,
+        )?)?;
+
+        let start = convert::position(symbol.location);
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!("```zinc\n{}\n```", symbol.description),
+            }),
+            range: Some(Range::new(
+                start,
+                Position::new(start.line, start.character + symbol.name.len() as u32),
+            )),
+        })
+    }
+
+    ///
+    /// Finds the declaration of the item under the cursor.
+    ///
+    /// The modules are declared with the `mod` statements, so the module file is returned
+    /// instead of the statement.
+    ///
+    fn definition(&self, params: GotoDefinitionParams) -> Option<GotoDefinitionResponse> {
+        let position = params.text_document_position_params;
+        let symbol = self.analysis.symbols.symbol_at(Self::location(
+            &position.text_document.uri,
+            position.position,
+        )?)?;
+        let declaration = symbol.declaration?;
+
+        if let SymbolKind::Module = symbol.kind {
+            let module = declaration.file_path().and_then(|path| {
+                self.analysis
+                    .modules
+                    .get(&path)?
+                    .iter()
+                    .find(|(name, _path)| name == &symbol.name)
+                    .map(|(_name, path)| path.to_owned())
+            });
+            if let Some(module) = module {
+                let uri = Url::from_file_path(module).ok()?;
+                return Some(GotoDefinitionResponse::Scalar(LspLocation::new(
+                    uri,
+                    Range::default(),
+                )));
+            }
+        }
+
+        convert::location(declaration, symbol.name.len()).map(GotoDefinitionResponse::Scalar)
+    }
+
+    ///
+    /// Lists the items, which may be written at the cursor.
+    ///
+    /// The document being edited is usually invalid, so the last valid analysis is used if
+    /// the current one has nothing to suggest.
+    ///
+    fn completion(&self, params: CompletionParams) -> Option<CompletionResponse> {
+        let position = params.text_document_position;
+        let uri = &position.text_document.uri;
+        let location = Self::location(uri, position.position)?;
+
+        let line = self
+            .documents
+            .get(&Self::path(uri)?)?
+            .lines()
+            .nth(position.position.line as usize)
+            .unwrap_or_default()
+            .chars()
+            .take(position.position.character as usize)
+            .collect::<String>();
+        let path = Self::completion_path(&line);
+        let path = path.iter().map(String::as_str).collect::<Vec<&str>>();
+
+        let mut completions = self.analysis.symbols.completions(location, &path);
+        if completions.is_empty() {
+            completions = self.valid.symbols.completions(location, &path);
+        }
+
+        Some(CompletionResponse::Array(
+            completions
+                .into_iter()
+                .map(|completion| CompletionItem {
+                    label: completion.name,
+                    kind: Some(convert::completion_kind(completion.kind)),
+                    detail: Some(completion.description),
+                    ..Default::default()
+                })
+                .collect(),
+        ))
+    }
+
+    ///
+    /// Lists the items declared in the document.
+    ///
+    #[allow(deprecated)]
+    fn document_symbols(&self, params: DocumentSymbolParams) -> Option<DocumentSymbolResponse> {
+        let path = Self::path(&params.text_document.uri)?;
+        let file_index = File::index(&path)?;
+
+        Some(DocumentSymbolResponse::Flat(
+            self.analysis
+                .symbols
+                .declarations(file_index)
+                .into_iter()
+                .filter_map(|symbol| {
+                    Some(SymbolInformation {
+                        name: symbol.name.to_owned(),
+                        kind: convert::symbol_kind(symbol.kind),
+                        tags: None,
+                        deprecated: None,
+                        location: convert::location(symbol.location, symbol.name.len())?,
+                        container_name: None,
+                    })
+                })
+                .collect(),
+        ))
+    }
+
+    ///
+    /// Returns the path written before the cursor, e.g. `["std", "crypto"]` for
+    /// `let hash = std::crypto::sha`.
+    ///
+    fn completion_path(line: &str) -> Vec<String> {
+        let is_identifier = |character: char| character.is_alphanumeric() || character == '_';
+
+        let mut rest = line.trim_end_matches(is_identifier);
+        let mut path = Vec::new();
+        while rest.ends_with(PATH_SEPARATOR) {
+            rest = &rest[..rest.len() - PATH_SEPARATOR.len()];
+            let name_start = rest
+                .rfind(|character: char| !is_identifier(character))
+                .map(|index| index + 1)
+                .unwrap_or(0);
+            if name_start == rest.len() {
+                break;
+            }
+            path.push(rest[name_start..].to_owned());
+            rest = &rest[..name_start];
+        }
+        path.reverse();
+        path
+    }
+
+    fn publish(&self, path: &Path, diagnostics: Vec<lsp_types::Diagnostic>) -> Result<(), Error> {
+        let uri = match Url::from_file_path(path) {
+            Ok(uri) => uri,
+            Err(()) => return Ok(()),
+        };
+        self.send(Notification::new(
+            PublishDiagnostics::METHOD.to_owned(),
+            PublishDiagnosticsParams::new(uri, diagnostics, None),
+        ))
+    }
+
+    fn respond<R: Serialize>(&self, id: RequestId, result: R) -> Result<(), Error> {
+        self.send(Response::new_ok(id, result))
+    }
+
+    fn send<M: Into<Message>>(&self, message: M) -> Result<(), Error> {
+        self.connection
+            .sender
+            .send(message.into())
+            .map_err(|error| Error::Connection(error.to_string()))
+    }
+
+    fn extract<P: DeserializeOwned>(request: Request) -> Result<(RequestId, P), Error> {
+        let method = request.method.clone();
+        request
+            .extract(method.as_str())
+            .map_err(|error| Error::Protocol(format!("{:?}", error)))
+    }
+
+    fn extract_notification<P: DeserializeOwned>(notification: Notification) -> Result<P, Error> {
+        let method = notification.method.clone();
+        notification
+            .extract(method.as_str())
+            .map_err(|error| Error::Protocol(format!("{:?}", error)))
+    }
+
+    fn path(uri: &Url) -> Option<PathBuf> {
+        uri.to_file_path().ok()
+    }
+
+    fn location(uri: &Url, position: Position) -> Option<zinc_compiler::Location> {
+        let file_index = File::index(&Self::path(uri)?)?;
+        Some(convert::location_from(file_index, position))
+    }
+}
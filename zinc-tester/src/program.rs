@@ -14,6 +14,8 @@ use zinc_bytecode::data::values::Value;
 use zinc_bytecode::program::Program;
 use zinc_compiler::Bytecode;
 use zinc_compiler::EntryAnalyzer;
+use zinc_compiler::Error as CompilerError;
+use zinc_compiler::MessageFormat;
 use zinc_compiler::Parser;
 
 pub struct ProgramData {
@@ -45,12 +47,12 @@ impl ProgramData {
 
         let syntax_tree = Parser::default()
             .parse(code, None)
-            .map_err(|error| error.format(lines.as_slice()))
+            .map_err(|errors| CompilerError::format_all(errors, lines.as_slice(), MessageFormat::Human))
             .map_err(Error::Compiler)?;
 
         let intermediate = EntryAnalyzer::new()
             .compile(syntax_tree, HashMap::new())
-            .map_err(|error| error.format(lines.as_slice()))
+            .map_err(|errors| CompilerError::format_all(errors, lines.as_slice(), MessageFormat::Human))
             .map_err(Error::Compiler)?;
 
         let bytecode = Rc::new(RefCell::new(Bytecode::new()));
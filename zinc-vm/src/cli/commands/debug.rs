@@ -1,6 +1,7 @@
 use crate::{Error, IoToError};
 use pairing::bn256::Bn256;
 use std::fs;
+use std::io;
 use std::path::PathBuf;
 use structopt::StructOpt;
 use zinc_bytecode::data::values::Value;
@@ -17,6 +18,12 @@ pub struct DebugCommand {
 
     #[structopt(short = "o", long = "output", help = "Program's output file")]
     pub output_path: PathBuf,
+
+    #[structopt(
+        long = "interactive",
+        help = "Stops at the first source line and reads the debugger commands from stdin"
+    )]
+    pub interactive: bool,
 }
 
 impl DebugCommand {
@@ -30,7 +37,20 @@ impl DebugCommand {
         let json = serde_json::from_str(&input_text)?;
         let input = Value::from_typed_json(&json, &program.input)?;
 
-        let output = zinc_vm::debug::<Bn256>(&program, &input)?;
+        let output = if self.interactive {
+            let stdin = io::stdin();
+            match zinc_vm::debug_interactive::<Bn256, _, _>(
+                &program,
+                &input,
+                stdin.lock(),
+                io::stdout(),
+            )? {
+                Some(output) => output,
+                None => return Ok(()),
+            }
+        } else {
+            zinc_vm::debug::<Bn256>(&program, &input)?
+        };
 
         let output_json = serde_json::to_string_pretty(&output.to_json())? + "\n";
         fs::write(&self.output_path, &output_json)
@@ -0,0 +1,53 @@
+use crate::{Error, IoToError};
+use pairing::bn256::Bn256;
+use std::fs;
+use std::path::PathBuf;
+use structopt::StructOpt;
+use zinc_bytecode::program::Program;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "export-r1cs",
+    about = "Writes the circuit's rank-1 constraint system"
+)]
+pub struct ExportR1csCommand {
+    #[structopt(short = "c", long = "circuit", help = "Circuit's bytecode file")]
+    pub circuit_path: PathBuf,
+
+    #[structopt(short = "o", long = "output", help = "Constraint system file")]
+    pub output_path: PathBuf,
+
+    #[structopt(
+        long = "format",
+        default_value = "r1cs",
+        possible_values = &["r1cs", "json"],
+        help = "The circom binary format or JSON"
+    )]
+    pub format: String,
+}
+
+impl ExportR1csCommand {
+    pub fn execute(&self) -> Result<(), Error> {
+        let bytes =
+            fs::read(&self.circuit_path).error_with_path(|| self.circuit_path.to_string_lossy())?;
+        let program = Program::from_bytes(bytes.as_slice()).map_err(Error::ProgramDecoding)?;
+
+        let r1cs = zinc_vm::export_r1cs::<Bn256>(&program)?;
+
+        let output = match self.format.as_str() {
+            "json" => (serde_json::to_string(&r1cs.to_json())? + "\n").into_bytes(),
+            _ => r1cs.to_circom(),
+        };
+        fs::write(&self.output_path, output)
+            .error_with_path(|| self.output_path.to_string_lossy())?;
+
+        println!(
+            "{} constraints, {} variables, {} public outputs",
+            r1cs.constraints.len(),
+            r1cs.labels.len(),
+            r1cs.public - 1
+        );
+
+        Ok(())
+    }
+}
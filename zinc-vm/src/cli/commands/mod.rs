@@ -1,14 +1,20 @@
 mod debug;
+mod export_r1cs;
+mod profile;
 mod prove;
 mod run;
 mod setup;
 mod verify;
+mod witness;
 
 use self::debug::DebugCommand;
+use self::export_r1cs::ExportR1csCommand;
+use self::profile::ProfileCommand;
 use self::prove::ProveCommand;
 use self::run::RunCommand;
 use self::setup::SetupCommand;
 use self::verify::VerifyCommand;
+use self::witness::WitnessCommand;
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt)]
@@ -29,7 +35,10 @@ pub struct Arguments {
 pub enum Command {
     Run(RunCommand),
     Debug(DebugCommand),
+    Profile(ProfileCommand),
+    Witness(WitnessCommand),
     Setup(SetupCommand),
+    ExportR1cs(ExportR1csCommand),
     Prove(ProveCommand),
     Verify(VerifyCommand),
 }
@@ -6,6 +6,7 @@ use std::path::PathBuf;
 use structopt::StructOpt;
 use zinc_bytecode::data::values::Value;
 use zinc_bytecode::program::Program;
+use zinc_vm::witness::Witness;
 
 #[derive(Debug, StructOpt)]
 #[structopt(name = "prove", about = "Executes circuit and prints program's output")]
@@ -16,8 +17,20 @@ pub struct ProveCommand {
     #[structopt(short = "k", long = "proving-key", help = "Proving key file")]
     pub proving_key_path: PathBuf,
 
-    #[structopt(short = "w", long = "witness", help = "File with witness values")]
-    pub witness_path: PathBuf,
+    #[structopt(
+        short = "w",
+        long = "witness",
+        required_unless = "from_witness_path",
+        help = "File with witness values"
+    )]
+    pub witness_path: Option<PathBuf>,
+
+    #[structopt(
+        long = "from-witness",
+        conflicts_with = "witness_path",
+        help = "Full witness file written by the `witness` command"
+    )]
+    pub from_witness_path: Option<PathBuf>,
 
     #[structopt(short = "p", long = "public-data", help = "File with witness values")]
     pub pubdata_path: PathBuf,
@@ -36,13 +49,29 @@ impl ProveCommand {
         let params = Parameters::<Bn256>::read(file, true)
             .error_with_path(|| self.proving_key_path.to_string_lossy())?;
 
-        // Read witness
-        let witness_json = fs::read_to_string(&self.witness_path)
-            .error_with_path(|| self.witness_path.to_string_lossy())?;
-        let witness_value = serde_json::from_str(&witness_json)?;
-        let witness_struct = Value::from_typed_json(&witness_value, &program.input)?;
+        let (pubdata, proof) = match (&self.witness_path, &self.from_witness_path) {
+            (_, Some(from_witness_path)) => {
+                // Read full witness
+                let witness_json = fs::read_to_string(from_witness_path)
+                    .error_with_path(|| from_witness_path.to_string_lossy())?;
+                let witness_value = serde_json::from_str(&witness_json)?;
+                let witness = Witness::<Bn256>::from_json(&witness_value, &program.output)
+                    .map_err(Error::Witness)?;
+
+                let proof = zinc_vm::prove_from_witness::<Bn256>(&program, &params, &witness)?;
+                (witness.output, proof)
+            }
+            (Some(witness_path), None) => {
+                // Read witness
+                let witness_json = fs::read_to_string(witness_path)
+                    .error_with_path(|| witness_path.to_string_lossy())?;
+                let witness_value = serde_json::from_str(&witness_json)?;
+                let witness_struct = Value::from_typed_json(&witness_value, &program.input)?;
 
-        let (pubdata, proof) = zinc_vm::prove::<Bn256>(&program, &params, &witness_struct)?;
+                zinc_vm::prove::<Bn256>(&program, &params, &witness_struct)?
+            }
+            (None, None) => unreachable!("the witness is required by the arguments parser"),
+        };
 
         // Write pubdata
         let pubdata_json = serde_json::to_string_pretty(&pubdata.to_json())? + "\n";
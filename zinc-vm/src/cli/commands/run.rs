@@ -5,6 +5,7 @@ use std::path::PathBuf;
 use structopt::StructOpt;
 use zinc_bytecode::data::values::Value;
 use zinc_bytecode::program::Program;
+use zinc_vm::trace::Tracer;
 
 #[derive(Debug, StructOpt)]
 #[structopt(name = "run", about = "Executes circuit and prints program's output")]
@@ -17,6 +18,12 @@ pub struct RunCommand {
 
     #[structopt(short = "o", long = "output", help = "Program's output file")]
     pub output_path: PathBuf,
+
+    #[structopt(
+        long = "trace",
+        help = "Writes the executed instructions to the JSON file, also if the circuit fails"
+    )]
+    pub trace_path: Option<PathBuf>,
 }
 
 impl RunCommand {
@@ -30,7 +37,16 @@ impl RunCommand {
         let json = serde_json::from_str(&input_text)?;
         let input = Value::from_typed_json(&json, &program.input)?;
 
-        let output = zinc_vm::run::<Bn256>(&program, &input)?;
+        let output = match self.trace_path {
+            Some(ref trace_path) => {
+                let mut tracer = Tracer::default();
+                let output = zinc_vm::run_traced::<Bn256>(&program, &input, &mut tracer);
+                fs::write(trace_path, tracer.to_json())
+                    .error_with_path(|| trace_path.to_string_lossy())?;
+                output?
+            }
+            None => zinc_vm::run::<Bn256>(&program, &input)?,
+        };
 
         let output_json = serde_json::to_string_pretty(&output.to_json())? + "\n";
         fs::write(&self.output_path, &output_json)
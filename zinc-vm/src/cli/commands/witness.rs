@@ -0,0 +1,51 @@
+use crate::{Error, IoToError};
+use pairing::bn256::Bn256;
+use std::fs;
+use std::path::PathBuf;
+use structopt::StructOpt;
+use zinc_bytecode::data::values::Value;
+use zinc_bytecode::program::Program;
+
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "witness",
+    about = "Executes circuit and writes the values of all its variables"
+)]
+pub struct WitnessCommand {
+    #[structopt(short = "c", long = "circuit", help = "Circuit's bytecode file")]
+    pub circuit_path: PathBuf,
+
+    #[structopt(short = "i", long = "input", help = "Program's input file")]
+    pub input_path: PathBuf,
+
+    #[structopt(
+        short = "o",
+        long = "output",
+        help = "Full witness file, which can be proved with `prove --from-witness`"
+    )]
+    pub output_path: PathBuf,
+}
+
+impl WitnessCommand {
+    pub fn execute(&self) -> Result<(), Error> {
+        let bytes =
+            fs::read(&self.circuit_path).error_with_path(|| self.circuit_path.to_string_lossy())?;
+        let program = Program::from_bytes(bytes.as_slice()).map_err(Error::ProgramDecoding)?;
+
+        let input_text = fs::read_to_string(&self.input_path)
+            .error_with_path(|| self.input_path.to_string_lossy())?;
+        let json = serde_json::from_str(&input_text)?;
+        let input = Value::from_typed_json(&json, &program.input)?;
+
+        let witness = zinc_vm::witness::<Bn256>(&program, &input)?;
+
+        let witness_json = serde_json::to_string(&witness.to_json())? + "\n";
+        fs::write(&self.output_path, &witness_json)
+            .error_with_path(|| self.output_path.to_string_lossy())?;
+
+        let output_json = serde_json::to_string_pretty(&witness.output.to_json())? + "\n";
+        print!("{}", output_json);
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use failure::Fail;
 use std::io;
 use zinc_bytecode::data::values::JsonValueError;
+use zinc_vm::witness::WitnessError;
 use zinc_vm::{RuntimeError, VerificationError};
 
 use hex::FromHexError;
@@ -25,6 +26,9 @@ pub enum Error {
     )]
     JsonValue(JsonValueError),
 
+    #[fail(display = "invalid full witness: {}", _0)]
+    Witness(WitnessError),
+
     #[fail(display = "failed to decode program: {}", _0)]
     ProgramDecoding(String),
 
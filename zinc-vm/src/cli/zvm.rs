@@ -15,7 +15,10 @@ fn main() {
     let result = match args.command {
         Command::Run(command) => command.execute(),
         Command::Debug(command) => command.execute(),
+        Command::Profile(command) => command.execute(),
+        Command::Witness(command) => command.execute(),
         Command::Setup(command) => command.execute(),
+        Command::ExportR1cs(command) => command.execute(),
         Command::Prove(command) => command.execute(),
         Command::Verify(command) => command.execute(),
     };
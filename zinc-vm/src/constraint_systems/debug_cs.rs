@@ -35,6 +35,11 @@ impl<E: Engine> DebugConstraintSystem<E> {
     pub fn num_constraints(&self) -> usize {
         self.constraints_num
     }
+
+    /// The number of allocated variables, not counting the constant `ONE` input.
+    pub fn num_variables(&self) -> usize {
+        self.inputs.len() - 1 + self.witness.len()
+    }
 }
 
 impl<E: Engine> ConstraintSystem<E> for DebugConstraintSystem<E> {
@@ -2,8 +2,14 @@ mod debug_cs;
 mod duplicate_removing_cs;
 mod logging_cs;
 mod noop_cs;
+mod r1cs_cs;
+mod replay_cs;
+mod witness_cs;
 
 pub use debug_cs::*;
 pub use duplicate_removing_cs::*;
 pub use logging_cs::*;
 pub use noop_cs::*;
+pub use r1cs_cs::*;
+pub use replay_cs::*;
+pub use witness_cs::*;
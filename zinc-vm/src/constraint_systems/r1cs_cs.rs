@@ -0,0 +1,141 @@
+use ff::Field;
+use franklin_crypto::bellman::{
+    ConstraintSystem, Index, LinearCombination, SynthesisError, Variable,
+};
+
+use crate::r1cs::{Constraint, R1cs};
+use crate::Engine;
+
+/// Records the constraints and the variable labels built from the namespaces.
+///
+/// The input variables start with `one`, which is allocated by the prover before the circuit.
+pub struct R1csCS<E: Engine> {
+    inputs: Vec<String>,
+    auxiliary: Vec<String>,
+    constraints: Vec<[Vec<(Index, E::Fr)>; 3]>,
+    namespace: Vec<String>,
+}
+
+impl<E: Engine> R1csCS<E> {
+    pub fn new() -> Self {
+        Self {
+            inputs: vec!["one".into()],
+            auxiliary: vec![],
+            constraints: vec![],
+            namespace: vec![],
+        }
+    }
+
+    /// Numbers the variables, so the private ones follow the public ones.
+    pub fn into_r1cs(self) -> R1cs<E> {
+        let public = self.inputs.len();
+        let wire = |index: &Index| match *index {
+            Index::Input(index) => index,
+            Index::Aux(index) => public + index,
+        };
+        let combination = |terms: &[(Index, E::Fr)]| {
+            terms
+                .iter()
+                .map(|(index, coefficient)| (wire(index), *coefficient))
+                .collect::<Vec<(usize, E::Fr)>>()
+        };
+
+        let constraints = self
+            .constraints
+            .iter()
+            .map(|[a, b, c]| Constraint {
+                a: combination(a),
+                b: combination(b),
+                c: combination(c),
+            })
+            .collect();
+
+        let mut labels = self.inputs;
+        labels.extend(self.auxiliary);
+
+        R1cs {
+            labels,
+            public,
+            constraints,
+        }
+    }
+
+    fn label(&self, annotation: String) -> String {
+        let mut path = self.namespace.clone();
+        path.push(annotation);
+        path.join("/")
+    }
+
+    fn terms(lc: LinearCombination<E>) -> Vec<(Index, E::Fr)> {
+        lc.as_ref()
+            .iter()
+            .filter(|(_, coefficient)| !coefficient.is_zero())
+            .map(|(variable, coefficient)| (variable.get_unchecked(), *coefficient))
+            .collect()
+    }
+}
+
+impl<E: Engine> Default for R1csCS<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Engine> ConstraintSystem<E> for R1csCS<E> {
+    type Root = Self;
+
+    fn alloc<F, A, AR>(&mut self, annotation: A, _f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let label = self.label(annotation().into());
+        self.auxiliary.push(label);
+        Ok(Variable::new_unchecked(Index::Aux(
+            self.auxiliary.len() - 1,
+        )))
+    }
+
+    fn alloc_input<F, A, AR>(&mut self, annotation: A, _f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let label = self.label(annotation().into());
+        self.inputs.push(label);
+        Ok(Variable::new_unchecked(Index::Input(self.inputs.len() - 1)))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, _annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LB: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LC: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+    {
+        self.constraints.push([
+            Self::terms(a(LinearCombination::zero())),
+            Self::terms(b(LinearCombination::zero())),
+            Self::terms(c(LinearCombination::zero())),
+        ]);
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        self.namespace.push(name_fn().into());
+    }
+
+    fn pop_namespace(&mut self) {
+        self.namespace.pop();
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+}
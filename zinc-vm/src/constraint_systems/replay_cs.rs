@@ -0,0 +1,104 @@
+use franklin_crypto::bellman::{ConstraintSystem, LinearCombination, SynthesisError, Variable};
+use std::marker::PhantomData;
+
+use crate::witness::Witness;
+use crate::Engine;
+
+/// Allocates the variables with the values of a recorded witness instead of the computed ones,
+/// so the circuit can be synthesized without its inputs.
+///
+/// The first input of the witness is `ONE`, which is allocated by the prover itself.
+pub struct ReplayCS<'a, E, CS>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    cs: CS,
+    witness: &'a Witness<E>,
+    next_input: usize,
+    next_auxiliary: usize,
+    _pd: PhantomData<E>,
+}
+
+impl<'a, E, CS> ReplayCS<'a, E, CS>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    pub fn new(cs: CS, witness: &'a Witness<E>) -> Self {
+        Self {
+            cs,
+            witness,
+            next_input: 1,
+            next_auxiliary: 0,
+            _pd: PhantomData,
+        }
+    }
+
+    /// Checks whether all the witness values have been allocated.
+    pub fn is_exhausted(&self) -> bool {
+        self.next_input == self.witness.inputs.len()
+            && self.next_auxiliary == self.witness.auxiliary.len()
+    }
+}
+
+impl<'a, E, CS> ConstraintSystem<E> for ReplayCS<'a, E, CS>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    type Root = Self;
+
+    fn alloc<F, A, AR>(&mut self, annotation: A, _f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let value = self.witness.auxiliary.get(self.next_auxiliary).cloned();
+        self.next_auxiliary += 1;
+        self.cs.alloc(annotation, || {
+            value.ok_or(SynthesisError::AssignmentMissing)
+        })
+    }
+
+    fn alloc_input<F, A, AR>(&mut self, annotation: A, _f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        let value = self.witness.inputs.get(self.next_input).cloned();
+        self.next_input += 1;
+        self.cs.alloc_input(annotation, || {
+            value.ok_or(SynthesisError::AssignmentMissing)
+        })
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LB: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LC: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+    {
+        self.cs.enforce(annotation, a, b, c)
+    }
+
+    fn push_namespace<NR, N>(&mut self, name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        self.cs.get_root().push_namespace(name_fn);
+    }
+
+    fn pop_namespace(&mut self) {
+        self.cs.get_root().pop_namespace();
+    }
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+}
@@ -0,0 +1,103 @@
+use ff::Field;
+use franklin_crypto::bellman::{
+    ConstraintSystem, Index, LinearCombination, SynthesisError, Variable,
+};
+
+use crate::Engine;
+
+/// Records the values of all the allocated variables and checks the constraints against them.
+///
+/// The input variables start with `ONE`, which is allocated by the prover before the circuit.
+pub struct WitnessCS<E: Engine> {
+    pub inputs: Vec<E::Fr>,
+    pub auxiliary: Vec<E::Fr>,
+    /// The annotation of the first unsatisfied constraint.
+    pub unsatisfied: Option<String>,
+}
+
+impl<E: Engine> WitnessCS<E> {
+    pub fn new() -> Self {
+        Self {
+            inputs: vec![E::Fr::one()],
+            auxiliary: vec![],
+            unsatisfied: None,
+        }
+    }
+
+    fn evaluate(&self, lc: &LinearCombination<E>) -> E::Fr {
+        let mut result = E::Fr::zero();
+        for (variable, coefficient) in lc.as_ref() {
+            let mut value = match variable.get_unchecked() {
+                Index::Input(index) => self.inputs[index],
+                Index::Aux(index) => self.auxiliary[index],
+            };
+            value.mul_assign(coefficient);
+            result.add_assign(&value);
+        }
+        result
+    }
+}
+
+impl<E: Engine> Default for WitnessCS<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E: Engine> ConstraintSystem<E> for WitnessCS<E> {
+    type Root = Self;
+
+    fn alloc<F, A, AR>(&mut self, _annotation: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.auxiliary.push(f()?);
+        Ok(Variable::new_unchecked(Index::Aux(
+            self.auxiliary.len() - 1,
+        )))
+    }
+
+    fn alloc_input<F, A, AR>(&mut self, _annotation: A, f: F) -> Result<Variable, SynthesisError>
+    where
+        F: FnOnce() -> Result<E::Fr, SynthesisError>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.inputs.push(f()?);
+        Ok(Variable::new_unchecked(Index::Input(self.inputs.len() - 1)))
+    }
+
+    fn enforce<A, AR, LA, LB, LC>(&mut self, annotation: A, a: LA, b: LB, c: LC)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+        LA: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LB: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+        LC: FnOnce(LinearCombination<E>) -> LinearCombination<E>,
+    {
+        if self.unsatisfied.is_some() {
+            return;
+        }
+
+        let mut left = self.evaluate(&a(LinearCombination::zero()));
+        left.mul_assign(&self.evaluate(&b(LinearCombination::zero())));
+        if left != self.evaluate(&c(LinearCombination::zero())) {
+            self.unsatisfied = Some(annotation().into());
+        }
+    }
+
+    fn push_namespace<NR, N>(&mut self, _name_fn: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+    }
+
+    fn pop_namespace(&mut self) {}
+
+    fn get_root(&mut self) -> &mut Self::Root {
+        self
+    }
+}
@@ -103,6 +103,7 @@ where
         self.state
             .frames_stack
             .push(FunctionFrame::new(offset, self.state.instruction_counter));
+        self.callers.push(self.location.clone());
 
         for i in 0..inputs_count {
             let arg = self.pop()?;
@@ -127,6 +128,9 @@ where
             .ok_or(MalformedBytecode::StackUnderflow)?;
 
         self.state.instruction_counter = frame.return_address;
+        if let Some(location) = self.callers.pop() {
+            self.location = location;
+        }
 
         for p in outputs.into_iter().rev() {
             self.push(p)?;
@@ -8,7 +8,8 @@ pub use state::*;
 
 use crate::core::location::CodeLocation;
 use crate::errors::MalformedBytecode;
-use crate::gadgets::{Gadgets, Scalar, ScalarType};
+use crate::gadgets::{Gadgets, IntegerType, Scalar, ScalarType};
+use crate::trace::{StepTrace, TraceValue};
 use crate::Engine;
 use colored::Colorize;
 use franklin_crypto::bellman::ConstraintSystem;
@@ -48,12 +49,26 @@ impl<E: Engine, CS: ConstraintSystem<E>> CounterNamespace<E, CS> {
     }
 }
 
+/// The instruction being executed, which is passed to the instruction callback.
+pub struct Step<'a> {
+    pub instruction: &'a Instruction,
+    /// The location set by the markers executed so far.
+    pub location: &'a CodeLocation,
+    /// The locations of the calls of the functions being executed, the outermost first.
+    pub callers: &'a [CodeLocation],
+    /// The evaluation stack and condition data, which is only collected if tracing is enabled.
+    pub trace: Option<&'a StepTrace>,
+}
+
 pub struct VirtualMachine<E: Engine, CS: ConstraintSystem<E>> {
     pub(crate) debugging: bool,
     state: State<E>,
     cs: CounterNamespace<E, CS>,
     outputs: Vec<Scalar<E>>,
     pub(crate) location: CodeLocation,
+    pub(crate) callers: Vec<CodeLocation>,
+    pub(crate) tracing: bool,
+    steps: usize,
 }
 
 impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
@@ -70,6 +85,9 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
             cs: CounterNamespace::new(cs),
             outputs: vec![],
             location: CodeLocation::new(),
+            callers: vec![],
+            tracing: false,
+            steps: 0,
         }
     }
 
@@ -85,9 +103,24 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
         mut check_cs: F,
     ) -> Result<Vec<Option<BigInt>>, RuntimeError>
     where
-        CB: FnMut(&CS) -> (),
+        CB: FnMut(&CS, &Step) -> (),
         F: FnMut(&CS) -> Result<(), RuntimeError>,
     {
+        self.start(program, inputs)?;
+
+        while !self.is_finished(program) {
+            self.step(program, &mut instruction_callback, &mut check_cs)?;
+        }
+
+        self.get_outputs()
+    }
+
+    /// Allocates the inputs and prepares the VM to execute the first instruction.
+    pub(crate) fn start(
+        &mut self,
+        program: &Program,
+        inputs: Option<&[BigInt]>,
+    ) -> Result<(), RuntimeError> {
         self.cs.cs.enforce(
             || "ONE * ONE = ONE (do this to avoid `unconstrained` error)",
             |zero| zero + CS::one(),
@@ -99,33 +132,89 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
             .constant_bigint(&1.into(), ScalarType::Boolean)?;
         self.condition_push(one)?;
 
-        self.init_root_frame(&program.input, inputs)?;
-
-        let mut step = 0;
-        while self.state.instruction_counter < program.bytecode.len() {
-            let namespace = format!("step={}, addr={}", step, self.state.instruction_counter);
-            self.cs.cs.push_namespace(|| namespace);
-            let instruction = &program.bytecode[self.state.instruction_counter];
-            log::info!(
-                "{}:{} > {}",
-                step,
-                self.state.instruction_counter,
-                dispatch_instruction!(instruction => instruction.to_assembly())
+        self.init_root_frame(&program.input, inputs)
+    }
+
+    pub(crate) fn is_finished(&self, program: &Program) -> bool {
+        self.state.instruction_counter >= program.bytecode.len()
+    }
+
+    /// Executes the instruction at the instruction counter.
+    pub(crate) fn step<CB, F>(
+        &mut self,
+        program: &Program,
+        instruction_callback: &mut CB,
+        check_cs: &mut F,
+    ) -> Result<(), RuntimeError>
+    where
+        CB: FnMut(&CS, &Step) -> (),
+        F: FnMut(&CS) -> Result<(), RuntimeError>,
+    {
+        let namespace = format!(
+            "step={}, addr={}",
+            self.steps, self.state.instruction_counter
+        );
+        self.cs.cs.push_namespace(|| namespace);
+        let address = self.state.instruction_counter;
+        let instruction = &program.bytecode[address];
+        log::info!(
+            "{}:{} > {}",
+            self.steps,
+            address,
+            dispatch_instruction!(instruction => instruction.to_assembly())
+        );
+        let condition = if self.tracing {
+            self.state.evaluation_stack.start_journal();
+            self.state.conditions_stack.last().map(TraceValue::from)
+        } else {
+            None
+        };
+        self.state.instruction_counter += 1;
+        let result = dispatch_instruction!(instruction => instruction.execute(self));
+
+        log::trace!("{}", self.state);
+        if result.is_ok() {
+            // The callback is called before the constraint system check, so the instruction
+            // which has made it unsatisfied is also reported.
+            let trace = if self.tracing {
+                let (popped, pushed) = self.state.evaluation_stack.take_journal();
+                Some(StepTrace {
+                    address,
+                    popped: popped.iter().map(Self::trace_value).collect(),
+                    pushed: pushed.iter().map(Self::trace_value).collect(),
+                    condition,
+                })
+            } else {
+                None
+            };
+            instruction_callback(
+                &self.cs.cs,
+                &Step {
+                    instruction,
+                    location: &self.location,
+                    callers: self.callers.as_slice(),
+                    trace: trace.as_ref(),
+                },
             );
-            self.state.instruction_counter += 1;
-            let result = dispatch_instruction!(instruction => instruction.execute(self));
-            if let Err(err) = result.and(check_cs(&self.cs.cs)) {
-                log::error!("{}\nat {}", err, self.location.to_string().blue());
-                return Err(err);
-            }
+        }
 
-            log::trace!("{}", self.state);
-            instruction_callback(&self.cs.cs);
-            self.cs.cs.pop_namespace();
-            step += 1;
+        if let Err(err) = result.and(check_cs(&self.cs.cs)) {
+            log::error!("{}\nat {}", err, self.location.to_string().blue());
+            return Err(err);
         }
+        self.cs.cs.pop_namespace();
+        self.steps += 1;
 
-        self.get_outputs()
+        Ok(())
+    }
+
+    pub(crate) fn state(&self) -> &State<E> {
+        &self.state
+    }
+
+    fn trace_value(cell: &Cell<E>) -> TraceValue {
+        let Cell::Value(value) = cell;
+        TraceValue::from(value)
     }
 
     fn init_root_frame(
@@ -145,15 +234,19 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
             None => std::iter::repeat(None).zip(types).collect(),
         };
 
-        for (value, dtype) in value_type_pairs {
+        for (value, (dtype, tags)) in value_type_pairs {
             let variable = self.operations().allocate_witness(value, dtype)?;
+            if let Some(tags) = tags {
+                self.operations()
+                    .enforce_one_of(&variable, tags.as_slice())?;
+            }
             self.push(Cell::Value(variable))?;
         }
 
         Ok(())
     }
 
-    fn get_outputs(&mut self) -> Result<Vec<Option<BigInt>>, RuntimeError> {
+    pub(crate) fn get_outputs(&mut self) -> Result<Vec<Option<BigInt>>, RuntimeError> {
         let outputs_fr: Vec<_> = self.outputs.iter().map(|f| (*f).clone()).collect();
 
         let mut outputs_bigint = Vec::with_capacity(outputs_fr.len());
@@ -197,15 +290,39 @@ impl<E: Engine, CS: ConstraintSystem<E>> VirtualMachine<E, CS> {
     }
 }
 
-fn data_type_into_scalar_types(dtype: &object_types::DataType) -> Vec<ScalarType> {
-    fn internal(types: &mut Vec<ScalarType>, dtype: &object_types::DataType) {
+/// Flattens the input type into the scalar types of its slots, where the enum tags are given
+/// the values of their variants, which the tags are constrained to.
+fn data_type_into_scalar_types(
+    dtype: &object_types::DataType,
+) -> Vec<(ScalarType, Option<Vec<BigInt>>)> {
+    fn internal(
+        types: &mut Vec<(ScalarType, Option<Vec<BigInt>>)>,
+        dtype: &object_types::DataType,
+    ) {
         match dtype {
             object_types::DataType::Unit => {}
             object_types::DataType::Scalar(scalar_type) => {
-                types.push(*scalar_type);
+                types.push((*scalar_type, None));
             }
-            object_types::DataType::Enum => {
-                types.push(ScalarType::Field);
+            object_types::DataType::Enum {
+                bitlength,
+                variants,
+            } => {
+                let tags = variants
+                    .iter()
+                    .map(|variant| variant.value.clone())
+                    .collect();
+                types.push((
+                    ScalarType::Integer(IntegerType {
+                        is_signed: false,
+                        bitlength: *bitlength,
+                    }),
+                    Some(tags),
+                ));
+                // The union slots are cast to the variant field types when destructured.
+                for _ in 1..dtype.size() {
+                    types.push((ScalarType::Field, None));
+                }
             }
             object_types::DataType::Struct(fields) => {
                 for (_, t) in fields {
@@ -68,6 +68,11 @@ impl<E: Engine> DataStack<E> {
         }
     }
 
+    /// Reads the cell without the uninitialized memory check, so it can be inspected.
+    pub fn peek(&self, address: usize) -> Option<&Cell<E>> {
+        self.memory.get(address).and_then(Option::as_ref)
+    }
+
     pub fn set(&mut self, address: usize, value: Cell<E>) -> Result<(), RuntimeError> {
         if self.memory.len() <= address {
             let mut extra = vec![None; address + 1 - self.memory.len()];
@@ -10,6 +10,8 @@ use std::fmt;
 #[derive(Debug)]
 pub struct EvaluationStack<E: Engine> {
     stack: Vec<Vec<Cell<E>>>,
+    /// The cells popped and pushed since the journal has been started.
+    journal: Option<(Vec<Cell<E>>, Vec<Cell<E>>)>,
 }
 
 impl<E: Engine> EvaluationStack<E> {
@@ -17,10 +19,15 @@ impl<E: Engine> EvaluationStack<E> {
     pub fn new() -> Self {
         Self {
             stack: vec![vec![]],
+            journal: None,
         }
     }
 
     pub fn push(&mut self, value: Cell<E>) -> Result<(), RuntimeError> {
+        if let Some((_, pushed)) = self.journal.as_mut() {
+            pushed.push(value.clone());
+        }
+
         self.stack
             .last_mut()
             .ok_or_else(|| {
@@ -31,13 +38,39 @@ impl<E: Engine> EvaluationStack<E> {
     }
 
     pub fn pop(&mut self) -> Result<Cell<E>, RuntimeError> {
-        self.stack
+        let value = self
+            .stack
             .last_mut()
             .ok_or_else(|| {
                 RuntimeError::InternalError("Evaluation stack root frame missing".into())
             })?
             .pop()
-            .ok_or_else(|| MalformedBytecode::StackUnderflow.into())
+            .ok_or(MalformedBytecode::StackUnderflow)?;
+
+        if let Some((popped, _)) = self.journal.as_mut() {
+            popped.push(value.clone());
+        }
+
+        Ok(value)
+    }
+
+    /// Starts recording the popped and pushed cells.
+    pub fn start_journal(&mut self) {
+        self.journal = Some((Vec::new(), Vec::new()));
+    }
+
+    /// Stops recording and returns the popped and pushed cells.
+    pub fn take_journal(&mut self) -> (Vec<Cell<E>>, Vec<Cell<E>>) {
+        self.journal.take().unwrap_or_default()
+    }
+
+    /// The cells of all the frames, the top one first.
+    pub fn cells(&self) -> Vec<&Cell<E>> {
+        self.stack
+            .iter()
+            .rev()
+            .flat_map(|frame| frame.iter().rev())
+            .collect()
     }
 
     pub fn fork(&mut self) {
@@ -59,6 +92,11 @@ impl<E: Engine> EvaluationStack<E> {
             return Err(MalformedBytecode::BranchStacksDoNotMatch.into());
         }
 
+        if let Some((popped, _)) = self.journal.as_mut() {
+            popped.extend(then_case.iter().cloned());
+            popped.extend(else_case.iter().cloned());
+        }
+
         for (i, (t, e)) in then_case.into_iter().zip(else_case.into_iter()).enumerate() {
             match (t, e) {
                 (Cell::Value(tv), Cell::Value(ev)) => {
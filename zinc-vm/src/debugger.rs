@@ -0,0 +1,457 @@
+//!
+//! The interactive circuit debugger.
+//!
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::io::BufRead;
+use std::io::Write;
+use std::path::Path;
+
+use franklin_crypto::circuit::test::TestConstraintSystem;
+use num_bigint::BigInt;
+
+use zinc_bytecode::program::Program;
+use zinc_bytecode::{dispatch_instruction, Instruction, InstructionInfo};
+
+use crate::core::{Cell, VirtualMachine};
+use crate::{Engine, RuntimeError};
+
+static HELP: &str = "\
+break <line> | break <file>:<line>   sets a breakpoint at the source line (b)
+delete <number>                      removes the breakpoint (d)
+breakpoints                          lists the breakpoints
+continue                             runs until a breakpoint or the end (c)
+step                                 runs until the next source line, entering the calls (s)
+next                                 runs until the next source line, stepping over the calls (n)
+finish                               runs until the current function returns (f)
+stepi                                executes a single instruction (si)
+list                                 shows the source code around the current line (l)
+backtrace                            shows the function calls (bt)
+stack                                shows the evaluation stack, the top first (st)
+frame                                shows the data stack frame of the current function (fr)
+conditions                           shows the condition stack, the innermost first (cond)
+constraints                          shows the number of constraints generated so far (cs)
+help                                 shows this message (h)
+quit                                 stops the circuit (q)
+An empty line repeats the previous command.";
+
+const LIST_LINES_AROUND: usize = 5;
+
+///
+/// The condition the execution is resumed until.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    /// Until a breakpoint or the end of the circuit.
+    Continue,
+    /// Until the next source line, including the ones of the called functions.
+    Step,
+    /// Until the next source line of the function at the call depth, or of its callers.
+    Next(usize),
+    /// Until the function at the call depth returns.
+    Finish(usize),
+    /// Until the next instruction.
+    Instruction,
+}
+
+///
+/// The result of a debugger command.
+///
+enum Action {
+    /// Resume the execution.
+    Resume(Mode),
+    /// Read the next command.
+    Prompt,
+    /// Stop the circuit.
+    Quit,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Breakpoint {
+    number: usize,
+    file: String,
+    line: usize,
+}
+
+///
+/// The debugger, which executes the circuit instruction by instruction and stops at the
+/// breakpoints and after the stepping commands read from `input`.
+///
+pub struct Debugger<'a, E: Engine, R: BufRead, W: Write> {
+    vm: &'a mut VirtualMachine<E, TestConstraintSystem<E>>,
+    program: &'a Program,
+    input: R,
+    output: W,
+    mode: Mode,
+    breakpoints: Vec<Breakpoint>,
+    breakpoints_created: usize,
+    last_command: String,
+    sources: HashMap<String, Option<Vec<String>>>,
+}
+
+impl<'a, E: Engine, R: BufRead, W: Write> Debugger<'a, E, R, W> {
+    pub fn new(
+        vm: &'a mut VirtualMachine<E, TestConstraintSystem<E>>,
+        program: &'a Program,
+        input: R,
+        output: W,
+    ) -> Self {
+        Self {
+            vm,
+            program,
+            input,
+            output,
+            mode: Mode::Step,
+            breakpoints: Vec::new(),
+            breakpoints_created: 0,
+            last_command: String::new(),
+            sources: HashMap::new(),
+        }
+    }
+
+    ///
+    /// Runs the circuit, stopping at its first source line.
+    ///
+    /// Returns the circuit outputs, or `None` if it has been stopped with `quit` or the end
+    /// of the input.
+    ///
+    pub fn run(
+        &mut self,
+        inputs: Option<&[BigInt]>,
+    ) -> Result<Option<Vec<Option<BigInt>>>, RuntimeError> {
+        let program = self.program;
+
+        self.vm.start(program, inputs)?;
+        writeln!(
+            self.output,
+            "The Zinc debugger, type `help` for the list of commands"
+        )
+        .map_err(Self::io)?;
+
+        while !self.vm.is_finished(program) {
+            let instruction = &program.bytecode[self.vm.state().instruction_counter];
+            self.vm.step(program, &mut |_, _| {}, &mut |cs| {
+                if !cs.is_satisfied() {
+                    return Err(RuntimeError::UnsatisfiedConstraint);
+                }
+
+                Ok(())
+            })?;
+
+            if !self.is_stopped(instruction)? {
+                continue;
+            }
+
+            self.print_location()?;
+            loop {
+                match self.prompt()? {
+                    Action::Resume(mode) => {
+                        self.mode = mode;
+                        break;
+                    }
+                    Action::Prompt => continue,
+                    Action::Quit => return Ok(None),
+                }
+            }
+        }
+
+        writeln!(self.output, "The circuit has finished").map_err(Self::io)?;
+        self.vm.get_outputs().map(Some)
+    }
+
+    ///
+    /// Checks whether the execution must be stopped after the `instruction`.
+    ///
+    fn is_stopped(&mut self, instruction: &Instruction) -> Result<bool, RuntimeError> {
+        let depth = self.vm.callers.len();
+
+        match instruction {
+            Instruction::LineMarker(_) => {
+                let location = &self.vm.location;
+                let breakpoint = self.breakpoints.iter().find(|breakpoint| {
+                    location.line == Some(breakpoint.line)
+                        && location
+                            .file
+                            .as_ref()
+                            .map(|file| Path::new(file).ends_with(&breakpoint.file))
+                            .unwrap_or_default()
+                });
+                if let Some(breakpoint) = breakpoint {
+                    writeln!(self.output, "Breakpoint {} hit", breakpoint.number)
+                        .map_err(Self::io)?;
+                    return Ok(true);
+                }
+
+                Ok(match self.mode {
+                    Mode::Step | Mode::Instruction => true,
+                    Mode::Next(next_depth) => depth <= next_depth,
+                    Mode::Finish(_) | Mode::Continue => false,
+                })
+            }
+            Instruction::Return(_) => Ok(match self.mode {
+                Mode::Finish(finish_depth) => depth < finish_depth,
+                Mode::Instruction => true,
+                _ => false,
+            }),
+            _ => Ok(self.mode == Mode::Instruction),
+        }
+    }
+
+    ///
+    /// Reads and executes a command.
+    ///
+    fn prompt(&mut self) -> Result<Action, RuntimeError> {
+        write!(self.output, "(zdb) ").map_err(Self::io)?;
+        self.output.flush().map_err(Self::io)?;
+
+        let mut line = String::new();
+        if self.input.read_line(&mut line).map_err(Self::io)? == 0 {
+            writeln!(self.output).map_err(Self::io)?;
+            return Ok(Action::Quit);
+        }
+        let line = match line.trim() {
+            "" => self.last_command.to_owned(),
+            line => line.to_owned(),
+        };
+        self.last_command = line.clone();
+
+        let mut words = line.split_whitespace();
+        let command = words.next().unwrap_or_default();
+        let argument = words.next();
+        let depth = self.vm.callers.len();
+
+        match command {
+            "" => Ok(Action::Prompt),
+            "continue" | "c" => Ok(Action::Resume(Mode::Continue)),
+            "step" | "s" => Ok(Action::Resume(Mode::Step)),
+            "next" | "n" => Ok(Action::Resume(Mode::Next(depth))),
+            "finish" | "f" => Ok(Action::Resume(Mode::Finish(depth))),
+            "stepi" | "si" => Ok(Action::Resume(Mode::Instruction)),
+            "quit" | "q" => Ok(Action::Quit),
+            command => {
+                let result = match command {
+                    "break" | "b" => self.add_breakpoint(argument),
+                    "delete" | "d" => self.delete_breakpoint(argument),
+                    "breakpoints" => self.print_breakpoints(),
+                    "list" | "l" => self.print_source(),
+                    "backtrace" | "bt" => self.print_backtrace(),
+                    "stack" | "st" => self.print_stack(),
+                    "frame" | "fr" => self.print_frame(),
+                    "conditions" | "cond" => self.print_conditions(),
+                    "constraints" | "cs" => self.print_constraints(),
+                    "help" | "h" => writeln!(self.output, "{}", HELP),
+                    command => writeln!(
+                        self.output,
+                        "Unknown command `{}`, type `help` for the list of commands",
+                        command
+                    ),
+                };
+                result.map_err(Self::io)?;
+                Ok(Action::Prompt)
+            }
+        }
+    }
+
+    fn add_breakpoint(&mut self, argument: Option<&str>) -> io::Result<()> {
+        let argument = argument.unwrap_or_default();
+        let (file, line) = match argument.rfind(':') {
+            Some(index) => (Some(argument[..index].to_owned()), &argument[index + 1..]),
+            None => (self.vm.location.file.to_owned(), argument),
+        };
+        let (file, line) = match (file, line.parse::<usize>()) {
+            (Some(file), Ok(line)) if !file.is_empty() => (file, line),
+            _ => {
+                return writeln!(
+                    self.output,
+                    "Expected the breakpoint location as `<line>` or `<file>:<line>`"
+                )
+            }
+        };
+
+        self.breakpoints_created += 1;
+        let breakpoint = Breakpoint {
+            number: self.breakpoints_created,
+            file,
+            line,
+        };
+        writeln!(
+            self.output,
+            "Breakpoint {} at {}:{}",
+            breakpoint.number, breakpoint.file, breakpoint.line
+        )?;
+        self.breakpoints.push(breakpoint);
+
+        Ok(())
+    }
+
+    fn delete_breakpoint(&mut self, argument: Option<&str>) -> io::Result<()> {
+        let number = argument.and_then(|argument| argument.parse::<usize>().ok());
+        match self
+            .breakpoints
+            .iter()
+            .position(|breakpoint| Some(breakpoint.number) == number)
+        {
+            Some(index) => {
+                let breakpoint = self.breakpoints.remove(index);
+                writeln!(self.output, "Deleted breakpoint {}", breakpoint.number)
+            }
+            None => writeln!(self.output, "Expected the number of an existing breakpoint"),
+        }
+    }
+
+    fn print_breakpoints(&mut self) -> io::Result<()> {
+        if self.breakpoints.is_empty() {
+            return writeln!(self.output, "No breakpoints");
+        }
+
+        for breakpoint in self.breakpoints.iter() {
+            writeln!(
+                self.output,
+                "{:>4}  {}:{}",
+                breakpoint.number, breakpoint.file, breakpoint.line
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn print_location(&mut self) -> Result<(), RuntimeError> {
+        writeln!(self.output, "{}", self.vm.location).map_err(Self::io)?;
+
+        let location = self.vm.location.clone();
+        if let (Some(file), Some(line)) = (location.file, location.line) {
+            if let Some(code) = self.source_line(file.as_str(), line) {
+                writeln!(self.output, "{:>5} | {}", line, code).map_err(Self::io)?;
+            }
+        }
+
+        if self.mode == Mode::Instruction && !self.vm.is_finished(self.program) {
+            let address = self.vm.state().instruction_counter;
+            let instruction = &self.program.bytecode[address];
+            writeln!(
+                self.output,
+                "next: {:05} {}",
+                address,
+                dispatch_instruction!(instruction => instruction.to_assembly())
+            )
+            .map_err(Self::io)?;
+        }
+
+        Ok(())
+    }
+
+    fn print_source(&mut self) -> io::Result<()> {
+        let location = self.vm.location.clone();
+        let (file, current) = match (location.file, location.line) {
+            (Some(file), Some(line)) => (file, line),
+            _ => return writeln!(self.output, "The source line is unknown"),
+        };
+
+        let first = current.saturating_sub(LIST_LINES_AROUND).max(1);
+        for line in first..=current + LIST_LINES_AROUND {
+            let code = match self.source_line(file.as_str(), line) {
+                Some(code) => code,
+                None => break,
+            };
+            let marker = if line == current { "=>" } else { "  " };
+            writeln!(self.output, "{} {:>5} | {}", marker, line, code)?;
+        }
+
+        Ok(())
+    }
+
+    fn print_backtrace(&mut self) -> io::Result<()> {
+        let mut locations = vec![&self.vm.location];
+        locations.extend(
+            self.vm
+                .callers
+                .iter()
+                .rev()
+                .filter(|caller| caller.function.is_some()),
+        );
+
+        for (index, location) in locations.into_iter().enumerate() {
+            writeln!(self.output, "#{} {}", index, location)?;
+        }
+
+        Ok(())
+    }
+
+    fn print_stack(&mut self) -> io::Result<()> {
+        let cells = self.vm.state().evaluation_stack.cells();
+        if cells.is_empty() {
+            return writeln!(self.output, "The evaluation stack is empty");
+        }
+
+        for (index, cell) in cells.into_iter().enumerate() {
+            let Cell::Value(value) = cell;
+            writeln!(self.output, "{:>4}: {}", index, value)?;
+        }
+
+        Ok(())
+    }
+
+    fn print_frame(&mut self) -> io::Result<()> {
+        let state = self.vm.state();
+        let frame = match state.frames_stack.last() {
+            Some(frame) => frame,
+            None => return writeln!(self.output, "There is no function frame"),
+        };
+        if frame.stack_frame_begin == frame.stack_frame_end {
+            return writeln!(self.output, "The function frame is empty");
+        }
+
+        for address in frame.stack_frame_begin..frame.stack_frame_end {
+            let offset = address - frame.stack_frame_begin;
+            match state.data_stack.peek(address) {
+                Some(Cell::Value(value)) => writeln!(self.output, "{:>4}: {}", offset, value)?,
+                None => writeln!(self.output, "{:>4}: <empty>", offset)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    fn print_conditions(&mut self) -> io::Result<()> {
+        for (index, condition) in self.vm.state().conditions_stack.iter().rev().enumerate() {
+            writeln!(self.output, "{:>4}: {}", index, condition)?;
+        }
+
+        Ok(())
+    }
+
+    fn print_constraints(&mut self) -> io::Result<()> {
+        let cs = self.vm.constraint_system();
+        let (constraints, is_satisfied) = (cs.num_constraints(), cs.is_satisfied());
+
+        writeln!(
+            self.output,
+            "{} constraints, {}",
+            constraints,
+            if is_satisfied {
+                "satisfied"
+            } else {
+                "not satisfied"
+            }
+        )
+    }
+
+    fn source_line(&mut self, file: &str, line: usize) -> Option<String> {
+        self.sources
+            .entry(file.to_owned())
+            .or_insert_with(|| {
+                fs::read_to_string(file)
+                    .ok()
+                    .map(|code| code.lines().map(str::to_owned).collect())
+            })
+            .as_ref()
+            .and_then(|lines| lines.get(line.wrapping_sub(1)))
+            .cloned()
+    }
+
+    fn io(error: io::Error) -> RuntimeError {
+        RuntimeError::InternalError(format!("debugger input or output: {}", error))
+    }
+}
@@ -95,8 +95,8 @@ pub enum RuntimeError {
         scalar_type: ScalarType,
     },
 
-    #[fail(display = "using witness as array index is not yet supported")]
-    WitnessArrayIndex,
+    #[fail(display = "enum value {} does not belong to any variant", _0)]
+    InvalidEnumValue(BigInt),
 }
 
 impl From<SynthesisError> for RuntimeError {
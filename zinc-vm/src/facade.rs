@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::io::{BufRead, Write};
 
 use bellman::groth16;
 use bellman::pairing::bn256::Bn256;
@@ -9,10 +10,17 @@ use rand::ThreadRng;
 
 use zinc_bytecode::program::Program;
 
-use crate::constraint_systems::{DebugConstraintSystem, DuplicateRemovingCS};
+use crate::constraint_systems::{
+    DebugConstraintSystem, DuplicateRemovingCS, R1csCS, ReplayCS, WitnessCS,
+};
 use crate::core::VirtualMachine;
+use crate::debugger::Debugger;
 pub use crate::errors::{MalformedBytecode, Result, RuntimeError, TypeSizeError};
 use crate::gadgets::utils::bigint_to_fr;
+use crate::profiler::{Cost, Profiler};
+use crate::r1cs::R1cs;
+use crate::trace::Tracer;
+use crate::witness::Witness;
 use crate::Engine;
 use failure::Fail;
 use franklin_crypto::circuit::test::TestConstraintSystem;
@@ -32,7 +40,34 @@ impl<E: Engine> Circuit<E> for VMCircuit<'_> {
         // let cs = LoggingConstraintSystem::new(cs.namespace(|| "logging"));
         let cs = DuplicateRemovingCS::new(cs.namespace(|| "duplicates removing"));
         let mut vm = VirtualMachine::new(cs, false);
-        *self.result = Some(vm.run(self.program, self.inputs, |_| {}, |_| Ok(())));
+        *self.result = Some(vm.run(self.program, self.inputs, |_, _| {}, |_| Ok(())));
+        Ok(())
+    }
+}
+
+struct WitnessCircuit<'a, E: Engine> {
+    program: &'a Program,
+    witness: &'a Witness<E>,
+    result: &'a mut Option<Result<()>>,
+}
+
+impl<E: Engine> Circuit<E> for WitnessCircuit<'_, E> {
+    fn synthesize<CS: ConstraintSystem<E>>(
+        self,
+        cs: &mut CS,
+    ) -> std::result::Result<(), SynthesisError> {
+        let cs = ReplayCS::new(cs.namespace(|| "duplicates removing"), self.witness);
+        let mut vm = VirtualMachine::new(DuplicateRemovingCS::new(cs), false);
+        let result = vm.run(self.program, None, |_, _| {}, |_| Ok(()));
+        *self.result = Some(result.and_then(|_| {
+            if vm.constraint_system().inner().is_exhausted() {
+                Ok(())
+            } else {
+                Err(RuntimeError::InternalError(
+                    "the witness has more variables than the circuit".into(),
+                ))
+            }
+        }));
         Ok(())
     }
 }
@@ -47,7 +82,7 @@ pub fn run<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
     let result = vm.run(
         program,
         Some(&inputs_flat),
-        |cs| {
+        |cs, _| {
             let num = cs.num_constraints() - num_constraints;
             num_constraints += num;
             log::debug!("Constraints: {}", num);
@@ -81,9 +116,17 @@ pub fn run<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
     Ok(value)
 }
 
-pub fn debug<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
-    let cs = TestConstraintSystem::<Bn256>::new();
+/// Runs the circuit like `run`, recording each executed instruction into `tracer`.
+///
+/// If the circuit fails, the trace ends with the failing instruction.
+pub fn run_traced<E: Engine>(
+    program: &Program,
+    inputs: &Value,
+    tracer: &mut Tracer,
+) -> Result<Value> {
+    let cs = DebugConstraintSystem::<Bn256>::default();
     let mut vm = VirtualMachine::new(cs, true);
+    vm.tracing = true;
 
     let inputs_flat = inputs.to_flat_values();
 
@@ -91,10 +134,10 @@ pub fn debug<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
     let result = vm.run(
         program,
         Some(&inputs_flat),
-        |cs| {
+        |cs, step| {
             let num = cs.num_constraints() - num_constraints;
             num_constraints += num;
-            log::debug!("Constraints: {}", num);
+            tracer.record(step, num);
         },
         |cs| {
             if !cs.is_satisfied() {
@@ -106,21 +149,95 @@ pub fn debug<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
     )?;
 
     let cs = vm.constraint_system();
+    if !cs.is_satisfied() {
+        return Err(RuntimeError::UnsatisfiedConstraint);
+    }
 
-    log::trace!("{}", cs.pretty_print());
+    let output_flat = result
+        .into_iter()
+        .map(|v| v.expect("`run` always computes witness"))
+        .collect::<Vec<_>>();
+
+    let value = Value::from_flat_values(&program.output, &output_flat).ok_or_else(|| {
+        TypeSizeError::Output {
+            expected: 0,
+            actual: 0,
+        }
+    })?;
+
+    Ok(value)
+}
 
+pub fn profile<E: Engine>(program: &Program, inputs: &Value) -> Result<(Value, Profiler)> {
+    let cs = DebugConstraintSystem::<Bn256>::default();
+    let mut vm = VirtualMachine::new(cs, true);
+
+    let inputs_flat = inputs.to_flat_values();
+
+    let mut profiler = Profiler::default();
+    let mut cost = Cost::default();
+    let result = vm.run(
+        program,
+        Some(&inputs_flat),
+        |cs, step| {
+            let total = Cost::new(cs.num_constraints(), cs.num_variables());
+            profiler.record(step, total - cost);
+            cost = total;
+        },
+        |cs| {
+            if !cs.is_satisfied() {
+                return Err(RuntimeError::UnsatisfiedConstraint);
+            }
+
+            Ok(())
+        },
+    )?;
+
+    let cs = vm.constraint_system();
     if !cs.is_satisfied() {
-        log::error!("unsatisfied: {}", cs.which_is_unsatisfied().unwrap());
         return Err(RuntimeError::UnsatisfiedConstraint);
     }
 
-    let unconstrained = cs.find_unconstrained();
-    if !unconstrained.is_empty() {
-        log::error!("Unconstrained: {}", unconstrained);
-        return Err(RuntimeError::InternalError(
-            "Generated unconstrained variables".into(),
-        ));
-    }
+    let output_flat = result
+        .into_iter()
+        .map(|v| v.expect("`run` always computes witness"))
+        .collect::<Vec<_>>();
+
+    let value = Value::from_flat_values(&program.output, &output_flat).ok_or_else(|| {
+        TypeSizeError::Output {
+            expected: 0,
+            actual: 0,
+        }
+    })?;
+
+    Ok((value, profiler))
+}
+
+pub fn debug<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
+    let cs = TestConstraintSystem::<Bn256>::new();
+    let mut vm = VirtualMachine::new(cs, true);
+
+    let inputs_flat = inputs.to_flat_values();
+
+    let mut num_constraints = 0;
+    let result = vm.run(
+        program,
+        Some(&inputs_flat),
+        |cs, _| {
+            let num = cs.num_constraints() - num_constraints;
+            num_constraints += num;
+            log::debug!("Constraints: {}", num);
+        },
+        |cs| {
+            if !cs.is_satisfied() {
+                return Err(RuntimeError::UnsatisfiedConstraint);
+            }
+
+            Ok(())
+        },
+    )?;
+
+    check_test_cs(vm.constraint_system())?;
 
     let output_flat = result
         .into_iter()
@@ -137,6 +254,60 @@ pub fn debug<E: Engine>(program: &Program, inputs: &Value) -> Result<Value> {
     Ok(value)
 }
 
+/// Runs the circuit in the interactive debugger, which reads the commands from `input` and
+/// writes to `output`. Returns `None` if the circuit has been stopped before finishing.
+pub fn debug_interactive<E: Engine, R: BufRead, W: Write>(
+    program: &Program,
+    inputs: &Value,
+    input: R,
+    output: W,
+) -> Result<Option<Value>> {
+    let cs = TestConstraintSystem::<Bn256>::new();
+    let mut vm = VirtualMachine::new(cs, true);
+
+    let inputs_flat = inputs.to_flat_values();
+
+    let result = match Debugger::new(&mut vm, program, input, output).run(Some(&inputs_flat))? {
+        Some(result) => result,
+        None => return Ok(None),
+    };
+
+    check_test_cs(vm.constraint_system())?;
+
+    let output_flat = result
+        .into_iter()
+        .map(|v| v.expect("`run` always computes witness"))
+        .collect::<Vec<_>>();
+
+    let value = Value::from_flat_values(&program.output, &output_flat).ok_or_else(|| {
+        TypeSizeError::Output {
+            expected: 0,
+            actual: 0,
+        }
+    })?;
+
+    Ok(Some(value))
+}
+
+fn check_test_cs(cs: &TestConstraintSystem<Bn256>) -> Result<()> {
+    log::trace!("{}", cs.pretty_print());
+
+    if !cs.is_satisfied() {
+        log::error!("unsatisfied: {}", cs.which_is_unsatisfied().unwrap());
+        return Err(RuntimeError::UnsatisfiedConstraint);
+    }
+
+    let unconstrained = cs.find_unconstrained();
+    if !unconstrained.is_empty() {
+        log::error!("Unconstrained: {}", unconstrained);
+        return Err(RuntimeError::InternalError(
+            "Generated unconstrained variables".into(),
+        ));
+    }
+
+    Ok(())
+}
+
 pub fn setup<E: Engine>(program: &Program) -> Result<Parameters<E>> {
     let rng = &mut rand::thread_rng();
     let mut result = None;
@@ -203,6 +374,102 @@ pub fn prove<E: Engine>(
     }
 }
 
+/// Computes the values of all the variables allocated by the circuit, which can be used to
+/// prove it later with `prove_from_witness`.
+pub fn witness<E: Engine>(program: &Program, inputs: &Value) -> Result<Witness<E>> {
+    let inputs_flat = inputs.to_flat_values();
+
+    let mut cs = WitnessCS::<E>::new();
+    let mut result = None;
+    let circuit = VMCircuit {
+        program,
+        inputs: Some(&inputs_flat),
+        result: &mut result,
+    };
+    Circuit::<E>::synthesize(circuit, &mut cs).map_err(RuntimeError::SynthesisError)?;
+
+    let values = match result {
+        None => {
+            return Err(RuntimeError::InternalError(
+                "circuit hasn't generate outputs".into(),
+            ))
+        }
+        Some(result) => result?,
+    };
+
+    if let Some(constraint) = cs.unsatisfied {
+        log::error!("unsatisfied: {}", constraint);
+        return Err(RuntimeError::UnsatisfiedConstraint);
+    }
+
+    let output_flat: Vec<BigInt> = values
+        .into_iter()
+        .map(|v| v.expect("`witness` always computes witness"))
+        .collect();
+
+    let output = Value::from_flat_values(&program.output, &output_flat).ok_or_else(|| {
+        TypeSizeError::Output {
+            expected: 0,
+            actual: 0,
+        }
+    })?;
+
+    Ok(Witness {
+        inputs: cs.inputs,
+        auxiliary: cs.auxiliary,
+        output,
+    })
+}
+
+/// Proves the circuit with the variable values computed by `witness`, without executing it
+/// with the circuit input.
+pub fn prove_from_witness<E: Engine>(
+    program: &Program,
+    params: &Parameters<E>,
+    witness: &Witness<E>,
+) -> Result<Proof<E>> {
+    let rng = &mut rand::thread_rng();
+
+    let mut result = None;
+    let circuit = WitnessCircuit {
+        program,
+        witness,
+        result: &mut result,
+    };
+
+    let proof =
+        groth16::create_random_proof(circuit, params, rng).map_err(|error| match error {
+            SynthesisError::AssignmentMissing => RuntimeError::InternalError(
+                "the witness has fewer variables than the circuit".into(),
+            ),
+            error => RuntimeError::SynthesisError(error),
+        })?;
+
+    match result {
+        None => Err(RuntimeError::InternalError(
+            "circuit hasn't been synthesized".into(),
+        )),
+        Some(result) => result.map(|_| proof),
+    }
+}
+
+/// Synthesizes the circuit without its input and records its constraints and variables.
+pub fn export_r1cs<E: Engine>(program: &Program) -> Result<R1cs<E>> {
+    let mut cs = R1csCS::<E>::new();
+    let mut result = None;
+    let circuit = VMCircuit {
+        program,
+        inputs: None,
+        result: &mut result,
+    };
+    Circuit::<E>::synthesize(circuit, &mut cs).map_err(RuntimeError::SynthesisError)?;
+
+    match result.expect("vm should return either output or error") {
+        Ok(_) => Ok(cs.into_r1cs()),
+        Err(error) => Err(error),
+    }
+}
+
 #[derive(Debug, Fail)]
 pub enum VerificationError {
     #[fail(display = "value overflow: value {} is not in the field", _0)]
@@ -0,0 +1,118 @@
+mod and;
+mod not;
+mod or;
+mod shift_left;
+mod shift_right;
+mod xor;
+
+pub use self::and::*;
+pub use self::not::*;
+pub use self::or::*;
+pub use self::shift_left::*;
+pub use self::shift_right::*;
+pub use self::xor::*;
+
+use crate::gadgets::{utils, IntegerType, Scalar, ScalarType};
+use crate::{Engine, Result, RuntimeError};
+use franklin_crypto::bellman::{ConstraintSystem, SynthesisError};
+use franklin_crypto::circuit::boolean::Boolean;
+use franklin_crypto::circuit::expression::Expression;
+use franklin_crypto::circuit::num::AllocatedNum;
+use num_bigint::BigInt;
+
+fn integer_type(scalar_type: ScalarType) -> Result<IntegerType> {
+    match scalar_type {
+        ScalarType::Integer(int_type) => Ok(int_type),
+        scalar_type => Err(RuntimeError::TypeError {
+            expected: "integer type".into(),
+            actual: scalar_type.to_string(),
+        }),
+    }
+}
+
+/// Decomposes the integer into little-endian bits.
+///
+/// Signed integers are decomposed into their two's complement representation.
+fn into_bits_le<E, CS>(mut cs: CS, scalar: &Scalar<E>) -> Result<Vec<Boolean>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    let int_type = integer_type(scalar.get_type())?;
+    let expr = scalar.to_expression::<CS>();
+
+    if !int_type.is_signed {
+        let bits = expr.into_bits_le_fixed(cs.namespace(|| "into_bits_le"), int_type.bitlength)?;
+        return Ok(bits);
+    }
+
+    let base_value = BigInt::from(1) << int_type.bitlength;
+    let base_fr = utils::bigint_to_fr::<E>(&base_value).expect("length is too big");
+    let complement = expr + Expression::<E>::constant::<CS>(base_fr);
+
+    let bits = complement.into_bits_le_fixed(
+        cs.namespace(|| "into_bits_le_complement"),
+        int_type.bitlength + 1,
+    )?;
+
+    Ok(Vec::from(&bits[..int_type.bitlength]))
+}
+
+/// Packs little-endian bits into an integer of the given type.
+///
+/// The bits of signed integers are treated as the two's complement representation.
+fn from_bits_le<E, CS>(mut cs: CS, bits: &[Boolean], int_type: IntegerType) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    if !int_type.is_signed {
+        let num =
+            AllocatedNum::pack_bits_to_element(cs.namespace(|| "pack_bits_to_element"), bits)?;
+        return Ok(Scalar::new_unchecked_variable(
+            num.get_value(),
+            num.get_variable(),
+            int_type.into(),
+        ));
+    }
+
+    let mut bits = Vec::from(bits);
+    let sign_bit = bits[int_type.bitlength - 1].clone();
+    bits.push(sign_bit.not());
+
+    let num = AllocatedNum::pack_bits_to_element(cs.namespace(|| "pack_bits_to_element"), &bits)?;
+
+    let base_value = BigInt::from(1) << int_type.bitlength;
+    let base_expr = Expression::<E>::constant::<CS>(
+        utils::bigint_to_fr::<E>(&base_value).expect("length is too big"),
+    );
+    let num = (Expression::from(&num) - base_expr).into_number(cs.namespace(|| "result"))?;
+
+    Ok(Scalar::new_unchecked_variable(
+        num.get_value(),
+        num.get_variable(),
+        int_type.into(),
+    ))
+}
+
+/// Selects `if_true` bits if `condition` is set, and `if_false` bits otherwise.
+fn conditional_select_bits<E, CS>(
+    mut cs: CS,
+    condition: &Boolean,
+    if_true: &[Boolean],
+    if_false: &[Boolean],
+) -> std::result::Result<Vec<Boolean>, SynthesisError>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    if_true
+        .iter()
+        .zip(if_false.iter())
+        .enumerate()
+        .map(|(i, (t, f))| {
+            // `ch(c, t, f)` is `(c AND t) XOR (NOT c AND f)`, that is, a single bit multiplexer.
+            Boolean::sha256_ch(cs.namespace(|| format!("select {}", i)), condition, t, f)
+        })
+        .collect()
+}
@@ -0,0 +1,30 @@
+use crate::auto_const;
+use crate::gadgets::auto_const::prelude::*;
+use crate::gadgets::bitwise::{from_bits_le, integer_type, into_bits_le};
+use crate::gadgets::Scalar;
+use crate::{Engine, Result};
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::boolean::Boolean;
+
+pub fn bit_not<E, CS>(cs: CS, scalar: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    fn inner<E, CS>(mut cs: CS, scalar: &Scalar<E>) -> Result<Scalar<E>>
+    where
+        E: Engine,
+        CS: ConstraintSystem<E>,
+    {
+        let int_type = integer_type(scalar.get_type())?;
+
+        let bits = into_bits_le(cs.namespace(|| "bits"), scalar)?
+            .iter()
+            .map(Boolean::not)
+            .collect::<Vec<Boolean>>();
+
+        from_bits_le(cs.namespace(|| "result"), &bits, int_type)
+    }
+
+    auto_const!(inner, cs, scalar)
+}
@@ -0,0 +1,39 @@
+use crate::auto_const;
+use crate::gadgets::auto_const::prelude::*;
+use crate::gadgets::bitwise::{from_bits_le, integer_type, into_bits_le};
+use crate::gadgets::{Scalar, ScalarType, ScalarTypeExpectation};
+use crate::{Engine, Result};
+use franklin_crypto::bellman::{ConstraintSystem, SynthesisError};
+use franklin_crypto::circuit::boolean::Boolean;
+
+pub fn bit_or<E, CS>(cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    fn inner<E, CS>(mut cs: CS, left: &Scalar<E>, right: &Scalar<E>) -> Result<Scalar<E>>
+    where
+        E: Engine,
+        CS: ConstraintSystem<E>,
+    {
+        let scalar_type = ScalarType::expect_same(left.get_type(), right.get_type())?;
+        let int_type = integer_type(scalar_type)?;
+
+        let left_bits = into_bits_le(cs.namespace(|| "left bits"), left)?;
+        let right_bits = into_bits_le(cs.namespace(|| "right bits"), right)?;
+
+        let bits = left_bits
+            .iter()
+            .zip(right_bits.iter())
+            .enumerate()
+            .map(|(i, (l, r))| {
+                Boolean::and(cs.namespace(|| format!("bit {}", i)), &l.not(), &r.not())
+                    .map(|nor| nor.not())
+            })
+            .collect::<std::result::Result<Vec<Boolean>, SynthesisError>>()?;
+
+        from_bits_le(cs.namespace(|| "result"), &bits, int_type)
+    }
+
+    auto_const!(inner, cs, left, right)
+}
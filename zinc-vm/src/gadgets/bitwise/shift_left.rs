@@ -0,0 +1,58 @@
+use crate::auto_const;
+use crate::gadgets::auto_const::prelude::*;
+use crate::gadgets::bitwise::{conditional_select_bits, from_bits_le, integer_type, into_bits_le};
+use crate::gadgets::Scalar;
+use crate::{Engine, Result};
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::boolean::Boolean;
+
+pub fn shift_left<E, CS>(cs: CS, num: &Scalar<E>, shift: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    fn inner<E, CS>(mut cs: CS, num: &Scalar<E>, shift: &Scalar<E>) -> Result<Scalar<E>>
+    where
+        E: Engine,
+        CS: ConstraintSystem<E>,
+    {
+        let int_type = integer_type(num.get_type())?;
+        integer_type(shift.get_type())?;
+
+        let mut bits = into_bits_le(cs.namespace(|| "bits"), num)?;
+
+        if shift.is_constant() {
+            let offset = shift.get_constant_usize()?;
+            bits = shift_bits_left(&bits, Some(offset));
+        } else {
+            let shift_bits = into_bits_le(cs.namespace(|| "shift bits"), shift)?;
+
+            // Barrel shifter: the `i`-th bit of the shift amount shifts by `2^i` positions.
+            for (i, shift_bit) in shift_bits.iter().enumerate() {
+                let shifted = shift_bits_left(&bits, 1usize.checked_shl(i as u32));
+                bits = conditional_select_bits(
+                    cs.namespace(|| format!("stage {}", i)),
+                    shift_bit,
+                    &shifted,
+                    &bits,
+                )?;
+            }
+        }
+
+        from_bits_le(cs.namespace(|| "result"), &bits, int_type)
+    }
+
+    auto_const!(inner, cs, num, shift)
+}
+
+/// Shifts little-endian bits towards the most significant end, filling with zeros.
+///
+/// `None` stands for an offset which does not fit into `usize`.
+fn shift_bits_left(bits: &[Boolean], offset: Option<usize>) -> Vec<Boolean> {
+    let length = bits.len();
+    let offset = offset.unwrap_or(length).min(length);
+
+    let mut result = vec![Boolean::constant(false); offset];
+    result.extend_from_slice(&bits[..length - offset]);
+    result
+}
@@ -0,0 +1,66 @@
+use crate::auto_const;
+use crate::gadgets::auto_const::prelude::*;
+use crate::gadgets::bitwise::{conditional_select_bits, from_bits_le, integer_type, into_bits_le};
+use crate::gadgets::Scalar;
+use crate::{Engine, Result};
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::boolean::Boolean;
+
+/// Shifts the integer to the right.
+///
+/// The shift is logical for unsigned integers and arithmetic for signed ones.
+pub fn shift_right<E, CS>(cs: CS, num: &Scalar<E>, shift: &Scalar<E>) -> Result<Scalar<E>>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    fn inner<E, CS>(mut cs: CS, num: &Scalar<E>, shift: &Scalar<E>) -> Result<Scalar<E>>
+    where
+        E: Engine,
+        CS: ConstraintSystem<E>,
+    {
+        let int_type = integer_type(num.get_type())?;
+        integer_type(shift.get_type())?;
+
+        let mut bits = into_bits_le(cs.namespace(|| "bits"), num)?;
+        let fill = if int_type.is_signed {
+            bits[int_type.bitlength - 1].clone()
+        } else {
+            Boolean::constant(false)
+        };
+
+        if shift.is_constant() {
+            let offset = shift.get_constant_usize()?;
+            bits = shift_bits_right(&bits, Some(offset), &fill);
+        } else {
+            let shift_bits = into_bits_le(cs.namespace(|| "shift bits"), shift)?;
+
+            // Barrel shifter: the `i`-th bit of the shift amount shifts by `2^i` positions.
+            for (i, shift_bit) in shift_bits.iter().enumerate() {
+                let shifted = shift_bits_right(&bits, 1usize.checked_shl(i as u32), &fill);
+                bits = conditional_select_bits(
+                    cs.namespace(|| format!("stage {}", i)),
+                    shift_bit,
+                    &shifted,
+                    &bits,
+                )?;
+            }
+        }
+
+        from_bits_le(cs.namespace(|| "result"), &bits, int_type)
+    }
+
+    auto_const!(inner, cs, num, shift)
+}
+
+/// Shifts little-endian bits towards the least significant end, filling with `fill`.
+///
+/// `None` stands for an offset which does not fit into `usize`.
+fn shift_bits_right(bits: &[Boolean], offset: Option<usize>, fill: &Boolean) -> Vec<Boolean> {
+    let length = bits.len();
+    let offset = offset.unwrap_or(length).min(length);
+
+    let mut result = Vec::from(&bits[offset..]);
+    result.extend(std::iter::repeat(fill.clone()).take(offset));
+    result
+}
@@ -1,11 +1,12 @@
 use std::marker::PhantomData;
 use std::mem;
 
-use bellman::{ConstraintSystem, Namespace};
+use bellman::{ConstraintSystem, LinearCombination, Namespace};
 use ff::Field;
 use num_bigint::BigInt;
 
 use crate::core::RuntimeError;
+use crate::gadgets::utils::math;
 use crate::gadgets::{utils, Gadget, Scalar, ScalarType, ScalarTypeExpectation, ScalarVariant};
 use crate::{gadgets, Engine};
 use franklin_crypto::circuit::expression::Expression;
@@ -99,6 +100,80 @@ where
         self.witness_fr(fr, scalar_type)
     }
 
+    ///
+    /// Enforces `element` to be equal to one of `values`, which is done by constraining the
+    /// product of the differences `element - value` to zero.
+    ///
+    pub fn enforce_one_of(
+        &mut self,
+        element: &Scalar<E>,
+        values: &[BigInt],
+    ) -> Result<(), RuntimeError> {
+        if values.is_empty() {
+            return Ok(());
+        }
+
+        let mut differences = Vec::with_capacity(values.len());
+        for value in values.iter() {
+            let fr =
+                utils::bigint_to_fr::<E>(value).ok_or_else(|| RuntimeError::ValueOverflow {
+                    value: value.clone(),
+                    scalar_type: element.get_type(),
+                })?;
+            let difference_value = element.get_value().map(|mut difference| {
+                difference.sub_assign(&fr);
+                difference
+            });
+            let difference_lc = element.lc::<CS>() - (fr, CS::one());
+            differences.push((difference_value, difference_lc));
+        }
+
+        let mut cs = self.cs_namespace();
+
+        let mut differences = differences.into_iter();
+        let (mut product_value, mut product_lc) = differences.next().expect("Always exists");
+        for (index, (difference_value, difference_lc)) in differences.enumerate() {
+            let value = match (product_value, difference_value) {
+                (Some(mut product), Some(difference)) => {
+                    product.mul_assign(&difference);
+                    Some(product)
+                }
+                _ => None,
+            };
+            let variable = cs
+                .alloc(|| format!("product {}", index), || value.grab())
+                .map_err(RuntimeError::SynthesisError)?;
+
+            cs.enforce(
+                || format!("product {} constraint", index),
+                |lc| lc + &product_lc,
+                |lc| lc + &difference_lc,
+                |lc| lc + variable,
+            );
+
+            product_value = value;
+            product_lc = LinearCombination::zero() + variable;
+        }
+
+        if let Some(product) = product_value {
+            if !product.is_zero() {
+                let value = element.get_value().expect("Always exists");
+                return Err(RuntimeError::InvalidEnumValue(
+                    utils::fr_to_bigint_unsigned(&value),
+                ));
+            }
+        }
+
+        cs.enforce(
+            || "one of the values",
+            |lc| lc + &product_lc,
+            |lc| lc + CS::one(),
+            |lc| lc,
+        );
+
+        Ok(())
+    }
+
     pub fn constant_bigint(
         &self,
         value: &BigInt,
@@ -286,15 +361,17 @@ where
     /// This gadget only enforces 0 <= index < array.len() if condition is true
     pub fn conditional_array_get(
         &mut self,
-        _condition: &Scalar<E>,
+        condition: &Scalar<E>,
         array: &[Scalar<E>],
         index: &Scalar<E>,
     ) -> Result<Scalar<E>, RuntimeError> {
-        if !index.is_constant() {
-            return Err(RuntimeError::WitnessArrayIndex);
+        if index.is_constant() {
+            return self.enforcing_array_get(array, index);
         }
-        // let zero = Scalar::new_constant_int(0, index.get_type());
-        // let index = gadgets::conditional_select(self.cs_namespace(), condition, index, &zero)?;
+
+        // Inside a false branch the index may be out of bounds, so it is replaced with zero.
+        let zero = Scalar::new_constant_int(0, index.get_type());
+        let index = gadgets::conditional_select(self.cs_namespace(), condition, index, &zero)?;
         self.enforcing_array_get(array, &index)
     }
 
@@ -325,34 +402,34 @@ where
                 }
                 Ok(array[i].clone())
             }
-            _ => {
-                Err(RuntimeError::WitnessArrayIndex)
-                // let mut cs = self.cs_namespace();
-                // let num_bits = math::log2ceil(array.len());
-                // let bits_le = index.to_expression::<CS>().into_bits_le_fixed(
-                //     cs.namespace(|| "into_bits"),
-                //     num_bits
-                // )?;
-                // let bits_be = bits_le
-                //     .into_iter()
-                //     .rev()
-                //     .enumerate()
-                //     .map(|(i, bit)| {
-                //         Scalar::from_boolean(cs.namespace(|| format!("bit {}", i)), bit)
-                //     })
-                //     .collect::<Result<Vec<Scalar<E>>, RuntimeError>>()?;
-
-                // gadgets::recursive_select(
-                //     cs.namespace(|| "recursive_select"),
-                //     &bits_be,
-                //     array
-                // )
+            ScalarVariant::Variable(_) => {
+                if array.len() == 1 {
+                    return Ok(array[0].clone());
+                }
+
+                let mut cs = self.cs_namespace();
+                let num_bits = math::log2ceil(array.len());
+                let bits_le = index
+                    .to_expression::<CS>()
+                    .into_bits_le_fixed(cs.namespace(|| "into_bits"), num_bits)?;
+                let bits_be = bits_le
+                    .into_iter()
+                    .rev()
+                    .enumerate()
+                    .map(|(i, bit)| {
+                        Scalar::from_boolean(cs.namespace(|| format!("bit {}", i)), bit)
+                    })
+                    .collect::<Result<Vec<Scalar<E>>, RuntimeError>>()?;
+
+                gadgets::recursive_select(cs.namespace(|| "recursive_select"), &bits_be, array)
             }
         }
     }
 
-    pub fn array_set(
+    /// This gadget only enforces 0 <= index < array.len() if condition is true
+    pub fn conditional_array_set(
         &mut self,
+        condition: &Scalar<E>,
         array: &[Scalar<E>],
         index: Scalar<E>,
         value: Scalar<E>,
@@ -371,17 +448,34 @@ where
                 }
                 new_array[i] = value;
             }
-            _ => {
-                return Err(RuntimeError::WitnessArrayIndex);
-                // let mut new_array = Vec::new();
-
-                // for (i, p) in array.iter().enumerate() {
-                //     let curr_index = Scalar::new_constant_int(i, ScalarType::Field);
-                //     let is_current_index = self.eq(curr_index, index.clone())?;
-                //     let cs = self.cs_namespace();
-                //     let value = gadgets::conditional_select(cs, &is_current_index, &value, p)?;
-                //     new_array.push(value);
-                // }
+            ScalarVariant::Variable(_) => {
+                // Inside a false branch the index may be out of bounds, so it is replaced with zero.
+                // The write itself is discarded when the data stack branches are merged.
+                let zero = Scalar::new_constant_int(0, index.get_type());
+                let index =
+                    gadgets::conditional_select(self.cs_namespace(), condition, &index, &zero)?;
+
+                let mut cs = self.cs_namespace();
+                let length = Scalar::new_constant_bigint(&array.len().into(), index.get_type())?;
+                let lt = gadgets::comparison::lt(cs.namespace(|| "lt"), &index, &length)?;
+                mem::drop(cs);
+                self.assert(lt, Some("index out of bounds"))?;
+
+                let mut cs = self.cs_namespace();
+                for (i, old) in new_array.iter_mut().enumerate() {
+                    let current = Scalar::new_constant_int(i, index.get_type());
+                    let is_current = gadgets::comparison::eq(
+                        cs.namespace(|| format!("is current {}", i)),
+                        &current,
+                        &index,
+                    )?;
+                    *old = gadgets::conditional_select(
+                        cs.namespace(|| format!("select {}", i)),
+                        &is_current,
+                        &value,
+                        old,
+                    )?;
+                }
             }
         };
 
@@ -397,3 +491,47 @@ where
         gadget.synthesize_vec(cs, input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bellman::ConstraintSystem;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+    use num_bigint::BigInt;
+    use pairing::bn256::Bn256;
+
+    use zinc_bytecode::scalar::{IntegerType, ScalarType};
+
+    use crate::core::RuntimeError;
+    use crate::gadgets::Gadgets;
+
+    #[test]
+    fn test_enforce_one_of() {
+        let mut cs = TestConstraintSystem::<Bn256>::new();
+
+        let tag_type = ScalarType::Integer(IntegerType {
+            is_signed: false,
+            bitlength: 8,
+        });
+        let values = [BigInt::from(0), BigInt::from(1), BigInt::from(5)];
+
+        {
+            let mut gadgets = Gadgets::new(cs.namespace(|| "valid"));
+            let tag = gadgets
+                .allocate_witness(Some(&BigInt::from(5)), tag_type)
+                .unwrap();
+            gadgets.enforce_one_of(&tag, &values).unwrap();
+        }
+        assert!(cs.is_satisfied(), "valid");
+
+        {
+            let mut gadgets = Gadgets::new(cs.namespace(|| "invalid"));
+            let tag = gadgets
+                .allocate_witness(Some(&BigInt::from(3)), tag_type)
+                .unwrap();
+            match gadgets.enforce_one_of(&tag, &values) {
+                Err(RuntimeError::InvalidEnumValue(value)) => assert_eq!(value, BigInt::from(3)),
+                result => panic!("expected an invalid enum value error, got {:?}", result),
+            }
+        }
+    }
+}
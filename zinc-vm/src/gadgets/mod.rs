@@ -1,6 +1,7 @@
 pub mod arithmetic;
 pub mod arrays;
 pub mod auto_const;
+pub mod bitwise;
 pub mod boolean;
 pub mod comparison;
 mod conditional_select;
@@ -8,6 +9,7 @@ pub mod types;
 
 pub use arithmetic::*;
 pub use arrays::*;
+pub use bitwise::*;
 pub use boolean::*;
 pub use comparison::*;
 pub use conditional_select::*;
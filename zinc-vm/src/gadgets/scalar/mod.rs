@@ -6,6 +6,7 @@ use num_traits::ToPrimitive;
 use std::fmt;
 
 use crate::gadgets::utils;
+use crate::trace::TraceValue;
 use crate::{Engine, Result, RuntimeError};
 use ff::{Field, PrimeField};
 use franklin_crypto::bellman::{LinearCombination, SynthesisError};
@@ -284,3 +285,9 @@ impl<E: Engine> From<AllocatedNum<E>> for Scalar<E> {
         }
     }
 }
+
+impl<E: Engine> From<&Scalar<E>> for TraceValue {
+    fn from(scalar: &Scalar<E>) -> Self {
+        Self::new(scalar.to_bigint(), scalar.get_type(), scalar.is_constant())
+    }
+}
@@ -30,9 +30,13 @@ where
             let cs = vm.constraint_system();
             let offset = Scalar::new_constant_bigint(&i.into(), index.get_type())?;
             let address = gadgets::add(cs.namespace(|| format!("address {}", i)), &index, &offset)?;
-            array = vm
-                .operations()
-                .array_set(array.as_slice(), address, value)?;
+            let condition = vm.condition_top()?;
+            array = vm.operations().conditional_array_set(
+                &condition,
+                array.as_slice(),
+                address,
+                value,
+            )?;
         }
 
         for (i, value) in array.into_iter().enumerate() {
@@ -18,7 +18,10 @@ where
             array.push(vm.load(self.address + i)?.value()?);
         }
 
-        let new_array = vm.operations().array_set(array.as_slice(), index, value)?;
+        let condition = vm.condition_top()?;
+        let new_array =
+            vm.operations()
+                .conditional_array_set(&condition, array.as_slice(), index, value)?;
 
         for (i, value) in new_array.into_iter().enumerate() {
             vm.store(self.address + i, Cell::Value(value))?;
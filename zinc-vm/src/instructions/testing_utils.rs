@@ -87,7 +87,7 @@ impl VMTestRunner {
 
         let program = Program::new(DataType::Unit, DataType::Unit, self.instructions.clone());
 
-        vm.run(&program, Some(&[]), |_| {}, |_| Ok(()))
+        vm.run(&program, Some(&[]), |_, _| {}, |_| Ok(()))
             .map_err(TestingError::RuntimeError)?;
 
         let cs = vm.constraint_system();
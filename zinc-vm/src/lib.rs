@@ -1,9 +1,14 @@
 pub mod constraint_systems;
 mod core;
+pub mod debugger;
 mod errors;
 pub mod gadgets;
 mod instructions;
+pub mod profiler;
+pub mod r1cs;
 pub mod stdlib;
+pub mod trace;
+pub mod witness;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,321 @@
+//!
+//! The constraint profiler.
+//!
+
+use std::collections::HashMap;
+use std::ops::Add;
+use std::ops::Sub;
+
+use zinc_bytecode::Instruction;
+
+use crate::core::location::CodeLocation;
+use crate::core::Step;
+
+static UNKNOWN: &str = "<unknown>";
+
+///
+/// The constraints and variables allocated by the executed instructions.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Cost {
+    pub constraints: usize,
+    pub variables: usize,
+}
+
+///
+/// The constraint profiler, which attributes the cost of each executed instruction to its
+/// source line, function, and standard library call.
+///
+#[derive(Debug, Default)]
+pub struct Profiler {
+    total: Cost,
+    lines: HashMap<(String, usize), Cost>,
+    functions: HashMap<String, Cost>,
+    builtins: HashMap<String, (Cost, usize)>,
+    stacks: HashMap<String, Cost>,
+}
+
+impl Cost {
+    pub fn new(constraints: usize, variables: usize) -> Self {
+        Self {
+            constraints,
+            variables,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.constraints == 0 && self.variables == 0
+    }
+}
+
+impl Add for Cost {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self::new(
+            self.constraints + other.constraints,
+            self.variables + other.variables,
+        )
+    }
+}
+
+impl Sub for Cost {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self::new(
+            self.constraints.saturating_sub(other.constraints),
+            self.variables.saturating_sub(other.variables),
+        )
+    }
+}
+
+impl Profiler {
+    ///
+    /// Records the `cost` of the executed instruction `step`.
+    ///
+    /// The instructions executed before the first function marker, like the witness allocation,
+    /// are attributed to the `<unknown>` function.
+    ///
+    pub fn record(&mut self, step: &Step, cost: Cost) {
+        if cost.is_zero() {
+            return;
+        }
+
+        self.total = self.total + cost;
+
+        let function = Self::function(step.location);
+        Self::add(&mut self.functions, function.to_owned(), cost);
+
+        if let Some(line) = step.location.line {
+            let file = step.location.file.as_deref().unwrap_or(UNKNOWN);
+            Self::add(&mut self.lines, (file.to_owned(), line), cost);
+        }
+
+        let mut frames = step
+            .callers
+            .iter()
+            .filter(|caller| caller.function.is_some())
+            .map(Self::frame)
+            .collect::<Vec<String>>();
+        frames.push(Self::frame(step.location));
+
+        if let Instruction::CallBuiltin(call) = step.instruction {
+            let name = format!("{:?}", call.identifier);
+            let entry = self.builtins.entry(name.clone()).or_default();
+            entry.0 = entry.0 + cost;
+            entry.1 += 1;
+            frames.push(name);
+        }
+
+        Self::add(&mut self.stacks, frames.join(";"), cost);
+    }
+
+    ///
+    /// The total cost of the recorded instructions.
+    ///
+    pub fn total(&self) -> Cost {
+        self.total
+    }
+
+    ///
+    /// Writes the report with the functions, the source lines and the standard library calls
+    /// sorted by their constraints, where each line is followed by its code read with `source`
+    /// by the file name.
+    ///
+    pub fn report<S>(&self, mut source: S) -> String
+    where
+        S: FnMut(&str) -> Option<String>,
+    {
+        let mut report = format!(
+            "Total: {} constraints, {} variables\n",
+            self.total.constraints, self.total.variables
+        );
+
+        report.push_str(&format!(
+            "\nFunctions:\n{:>12} {:>12}  function\n",
+            "constraints", "variables"
+        ));
+        for (function, cost) in Self::sorted(&self.functions).into_iter() {
+            report.push_str(&format!(
+                "{:>12} {:>12}  {}\n",
+                cost.constraints, cost.variables, function
+            ));
+        }
+
+        let mut files = HashMap::<String, Option<Vec<String>>>::new();
+        report.push_str(&format!(
+            "\nSource lines:\n{:>12} {:>12}  location\n",
+            "constraints", "variables"
+        ));
+        for ((file, line), cost) in Self::sorted(&self.lines).into_iter() {
+            let code = files
+                .entry(file.to_owned())
+                .or_insert_with(|| {
+                    source(file.as_str()).map(|code| code.lines().map(str::to_owned).collect())
+                })
+                .as_ref()
+                .and_then(|lines| lines.get(line.wrapping_sub(1)))
+                .map(|code| code.trim().to_owned())
+                .unwrap_or_default();
+            report.push_str(&format!(
+                "{:>12} {:>12}  {}:{}  {}\n",
+                cost.constraints, cost.variables, file, line, code
+            ));
+        }
+
+        if !self.builtins.is_empty() {
+            report.push_str(&format!(
+                "\nStandard library calls:\n{:>12} {:>12} {:>8}  function\n",
+                "constraints", "variables", "calls"
+            ));
+            let mut builtins = self
+                .builtins
+                .iter()
+                .collect::<Vec<(&String, &(Cost, usize))>>();
+            builtins.sort_by(|(name_1, (cost_1, _)), (name_2, (cost_2, _))| {
+                Self::order(cost_1, cost_2).then_with(|| name_1.cmp(name_2))
+            });
+            for (name, (cost, calls)) in builtins.into_iter() {
+                report.push_str(&format!(
+                    "{:>12} {:>12} {:>8}  {}\n",
+                    cost.constraints, cost.variables, calls, name
+                ));
+            }
+        }
+
+        report
+    }
+
+    ///
+    /// Writes the folded stacks, which can be turned into a flamegraph by tools like
+    /// `flamegraph.pl` or `inferno-flamegraph`.
+    ///
+    /// Each line is a `;`-separated list of the `function:line` frames followed by the number
+    /// of constraints. The stacks without constraints are omitted.
+    ///
+    pub fn folded(&self) -> String {
+        let mut stacks = self
+            .stacks
+            .iter()
+            .filter(|(_, cost)| cost.constraints > 0)
+            .collect::<Vec<(&String, &Cost)>>();
+        stacks.sort_by_key(|(stack, _)| *stack);
+
+        stacks
+            .into_iter()
+            .map(|(stack, cost)| format!("{} {}\n", stack, cost.constraints))
+            .collect()
+    }
+
+    fn add<K>(entries: &mut HashMap<K, Cost>, key: K, cost: Cost)
+    where
+        K: std::hash::Hash + Eq,
+    {
+        let entry = entries.entry(key).or_default();
+        *entry = *entry + cost;
+    }
+
+    fn sorted<K>(entries: &HashMap<K, Cost>) -> Vec<(&K, &Cost)>
+    where
+        K: Ord,
+    {
+        let mut entries = entries.iter().collect::<Vec<(&K, &Cost)>>();
+        entries.sort_by(|(key_1, cost_1), (key_2, cost_2)| {
+            Self::order(cost_1, cost_2).then_with(|| key_1.cmp(key_2))
+        });
+        entries
+    }
+
+    fn order(cost_1: &Cost, cost_2: &Cost) -> std::cmp::Ordering {
+        cost_2
+            .constraints
+            .cmp(&cost_1.constraints)
+            .then_with(|| cost_2.variables.cmp(&cost_1.variables))
+    }
+
+    fn function(location: &CodeLocation) -> &str {
+        location.function.as_deref().unwrap_or(UNKNOWN)
+    }
+
+    fn frame(location: &CodeLocation) -> String {
+        match location.line {
+            Some(line) => format!("{}:{}", Self::function(location), line),
+            None => Self::function(location).to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_bytecode::builtins::BuiltinIdentifier;
+    use zinc_bytecode::instructions::*;
+    use zinc_bytecode::InstructionInfo;
+
+    use crate::core::location::CodeLocation;
+    use crate::core::Step;
+    use crate::profiler::Cost;
+    use crate::profiler::Profiler;
+
+    fn location(function: &str, line: usize) -> CodeLocation {
+        CodeLocation {
+            file: Some("main.zn".to_owned()),
+            function: Some(function.to_owned()),
+            line: Some(line),
+            column: Some(1),
+        }
+    }
+
+    #[test]
+    fn test_profile() {
+        let mut profiler = Profiler::default();
+
+        let main = location("main", 3);
+        let hash = location("hash", 7);
+        let add = Add.wrap();
+        let sha256 = CallBuiltin::new(BuiltinIdentifier::CryptoSha256, 8, 256).wrap();
+
+        profiler.record(
+            &Step {
+                instruction: &add,
+                location: &main,
+                callers: &[],
+                trace: None,
+            },
+            Cost::new(2, 1),
+        );
+        profiler.record(
+            &Step {
+                instruction: &sha256,
+                location: &hash,
+                callers: &[CodeLocation::new(), main.clone()],
+                trace: None,
+            },
+            Cost::new(100, 90),
+        );
+        profiler.record(
+            &Step {
+                instruction: &add,
+                location: &main,
+                callers: &[],
+                trace: None,
+            },
+            Cost::new(0, 0),
+        );
+
+        assert_eq!(profiler.total(), Cost::new(102, 91));
+        assert_eq!(
+            profiler.folded(),
+            "main:3 2\nmain:3;hash:7;CryptoSha256 100\n"
+        );
+
+        let report =
+            profiler.report(|_| Some("\n\nlet a = b + c;\n\n\n\nsha256(preimage)".to_owned()));
+        assert!(report.starts_with("Total: 102 constraints, 91 variables\n"));
+        assert!(
+            report.contains("         100           90  hash\n           2            1  main\n")
+        );
+        assert!(report.contains("         100           90  main.zn:7  sha256(preimage)\n"));
+        assert!(report.contains("         100           90        1  CryptoSha256\n"));
+    }
+}
@@ -0,0 +1,188 @@
+//!
+//! The rank-1 constraint system export.
+//!
+
+use ff::{PrimeField, PrimeFieldRepr};
+use num_bigint::{BigInt, Sign};
+use serde_json::json;
+
+use crate::gadgets::utils::fr_to_bigint_unsigned;
+use crate::Engine;
+
+const CIRCOM_MAGIC: &[u8; 4] = b"r1cs";
+const CIRCOM_VERSION: u32 = 1;
+const CIRCOM_SECTION_HEADER: u32 = 1;
+const CIRCOM_SECTION_CONSTRAINTS: u32 = 2;
+const CIRCOM_SECTION_WIRE_TO_LABEL: u32 = 3;
+
+///
+/// The constraint `a * b = c`, where each linear combination is a list of the variable indexes
+/// and their coefficients.
+///
+#[derive(Debug, Clone)]
+pub struct Constraint<E: Engine> {
+    pub a: Vec<(usize, E::Fr)>,
+    pub b: Vec<(usize, E::Fr)>,
+    pub c: Vec<(usize, E::Fr)>,
+}
+
+///
+/// The constraint system of a circuit.
+///
+/// The variables are numbered like the circom wires: the first one is the constant `one`, which
+/// is followed by the public outputs and then by the private variables. The circuit input is
+/// among the private variables and can be told by the labels.
+///
+#[derive(Debug, Clone)]
+pub struct R1cs<E: Engine> {
+    /// The labels of the variables, which are built from the namespaces of the constraint system.
+    pub labels: Vec<String>,
+    /// The number of the public variables, including `one`.
+    pub public: usize,
+    pub constraints: Vec<Constraint<E>>,
+}
+
+impl<E: Engine> R1cs<E> {
+    ///
+    /// Writes the constraint system in the circom `.r1cs` binary format, where the label of
+    /// each variable has the same index as the variable.
+    ///
+    pub fn to_circom(&self) -> Vec<u8> {
+        let prime = Self::repr_bytes(E::Fr::char());
+
+        let mut header = Vec::new();
+        header.extend_from_slice(&(prime.len() as u32).to_le_bytes());
+        header.extend_from_slice(prime.as_slice());
+        header.extend_from_slice(&(self.labels.len() as u32).to_le_bytes());
+        header.extend_from_slice(&((self.public - 1) as u32).to_le_bytes());
+        header.extend_from_slice(&0u32.to_le_bytes());
+        header.extend_from_slice(&0u32.to_le_bytes());
+        header.extend_from_slice(&(self.labels.len() as u64).to_le_bytes());
+        header.extend_from_slice(&(self.constraints.len() as u32).to_le_bytes());
+
+        let mut constraints = Vec::new();
+        for constraint in self.constraints.iter() {
+            for combination in [&constraint.a, &constraint.b, &constraint.c].iter() {
+                constraints.extend_from_slice(&(combination.len() as u32).to_le_bytes());
+                for (variable, coefficient) in combination.iter() {
+                    constraints.extend_from_slice(&(*variable as u32).to_le_bytes());
+                    constraints.extend(Self::repr_bytes(coefficient.into_repr()));
+                }
+            }
+        }
+
+        let mut wire_to_label = Vec::new();
+        for variable in 0..self.labels.len() {
+            wire_to_label.extend_from_slice(&(variable as u64).to_le_bytes());
+        }
+
+        let mut file = Vec::new();
+        file.extend_from_slice(CIRCOM_MAGIC);
+        file.extend_from_slice(&CIRCOM_VERSION.to_le_bytes());
+        file.extend_from_slice(&3u32.to_le_bytes());
+        for (section_type, section) in [
+            (CIRCOM_SECTION_HEADER, header),
+            (CIRCOM_SECTION_CONSTRAINTS, constraints),
+            (CIRCOM_SECTION_WIRE_TO_LABEL, wire_to_label),
+        ]
+        .iter()
+        {
+            file.extend_from_slice(&section_type.to_le_bytes());
+            file.extend_from_slice(&(section.len() as u64).to_le_bytes());
+            file.extend_from_slice(section.as_slice());
+        }
+        file
+    }
+
+    ///
+    /// Writes the constraint system in the JSON format of `snarkjs r1cs export json`, with the
+    /// additional `labels` array.
+    ///
+    pub fn to_json(&self) -> serde_json::Value {
+        let prime = Self::repr_bytes(E::Fr::char());
+        let constraints = self
+            .constraints
+            .iter()
+            .map(|constraint| {
+                json!([
+                    Self::combination_to_json(&constraint.a),
+                    Self::combination_to_json(&constraint.b),
+                    Self::combination_to_json(&constraint.c),
+                ])
+            })
+            .collect::<Vec<serde_json::Value>>();
+
+        json!({
+            "n8": prime.len(),
+            "prime": BigInt::from_bytes_le(Sign::Plus, prime.as_slice()).to_string(),
+            "nVars": self.labels.len(),
+            "nOutputs": self.public - 1,
+            "nPubInputs": 0,
+            "nPrvInputs": 0,
+            "nLabels": self.labels.len(),
+            "nConstraints": self.constraints.len(),
+            "constraints": constraints,
+            "map": (0..self.labels.len()).collect::<Vec<usize>>(),
+            "labels": self.labels,
+        })
+    }
+
+    fn combination_to_json(combination: &[(usize, E::Fr)]) -> serde_json::Value {
+        let mut object = serde_json::Map::new();
+        for (variable, coefficient) in combination.iter() {
+            object.insert(
+                variable.to_string(),
+                fr_to_bigint_unsigned(coefficient).to_string().into(),
+            );
+        }
+        serde_json::Value::Object(object)
+    }
+
+    fn repr_bytes(repr: <E::Fr as PrimeField>::Repr) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        repr.write_le(&mut bytes)
+            .expect("failed to write into Vec<u8>");
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ff::Field;
+    use franklin_crypto::bellman::ConstraintSystem;
+    use pairing::bn256::{Bn256, Fr};
+
+    use crate::constraint_systems::R1csCS;
+
+    #[test]
+    fn test_export() {
+        let mut cs = R1csCS::<Bn256>::new();
+        {
+            let mut cs = cs.namespace(|| "square");
+            let x = cs.alloc(|| "x", || Ok(Fr::one())).unwrap();
+            let y = cs.alloc_input(|| "y", || Ok(Fr::one())).unwrap();
+            cs.enforce(|| "x * x = y", |lc| lc + x, |lc| lc + x, |lc| lc + y);
+        }
+        let r1cs = cs.into_r1cs();
+
+        assert_eq!(r1cs.labels, vec!["one", "square/y", "square/x"]);
+        assert_eq!(r1cs.public, 2);
+
+        let json = r1cs.to_json();
+        assert_eq!(json["nVars"], 3);
+        assert_eq!(json["nOutputs"], 1);
+        assert_eq!(json["nConstraints"], 1);
+        assert_eq!(
+            json["constraints"].to_string(),
+            r#"[[{"2":"1"},{"2":"1"},{"1":"1"}]]"#
+        );
+
+        let circom = r1cs.to_circom();
+        assert_eq!(&circom[..12], b"r1cs\x01\x00\x00\x00\x03\x00\x00\x00");
+        assert_eq!(
+            &circom[12..24],
+            b"\x01\x00\x00\x00\x40\x00\x00\x00\x00\x00\x00\x00"
+        );
+        assert_eq!(circom.len(), 256);
+    }
+}
@@ -0,0 +1,200 @@
+//!
+//! The execution trace.
+//!
+
+use num_bigint::BigInt;
+use serde_json::json;
+
+use zinc_bytecode::scalar::ScalarType;
+use zinc_bytecode::{dispatch_instruction, Instruction, InstructionInfo};
+
+use crate::core::location::CodeLocation;
+use crate::core::Step;
+
+///
+/// The evaluation stack and condition data of an executed instruction, which the VM only
+/// collects if the tracing has been enabled.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepTrace {
+    /// The address of the instruction in the bytecode.
+    pub address: usize,
+    /// The cells popped from the evaluation stack, in the popping order.
+    pub popped: Vec<TraceValue>,
+    /// The cells pushed onto the evaluation stack, in the pushing order.
+    pub pushed: Vec<TraceValue>,
+    /// The top of the condition stack before the instruction was executed.
+    pub condition: Option<TraceValue>,
+}
+
+///
+/// A typed scalar value, which is unknown if there is no witness.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceValue {
+    pub value: Option<BigInt>,
+    pub scalar_type: ScalarType,
+    pub is_constant: bool,
+}
+
+///
+/// The execution trace writer, which records each executed instruction as a JSON object.
+///
+#[derive(Debug, Default)]
+pub struct Tracer {
+    entries: Vec<String>,
+}
+
+impl TraceValue {
+    pub fn new(value: Option<BigInt>, scalar_type: ScalarType, is_constant: bool) -> Self {
+        Self {
+            value,
+            scalar_type,
+            is_constant,
+        }
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        json!({
+            "value": self.value.as_ref().map(BigInt::to_string),
+            "type": self.scalar_type.to_string(),
+            "constant": self.is_constant,
+        })
+    }
+}
+
+impl Tracer {
+    ///
+    /// Records the executed instruction `step`, which has added `constraints` to the
+    /// constraint system.
+    ///
+    /// The steps without the trace data, which is only collected by a VM with the tracing
+    /// enabled, are ignored.
+    ///
+    pub fn record(&mut self, step: &Step, constraints: usize) {
+        let trace = match step.trace {
+            Some(trace) => trace,
+            None => return,
+        };
+
+        let instruction = step.instruction;
+        let assembly = dispatch_instruction!(instruction => instruction.to_assembly());
+        let entry = json!({
+            "step": self.entries.len(),
+            "address": trace.address,
+            "instruction": assembly,
+            "location": Self::location(step.location),
+            "popped": trace.popped.iter().map(TraceValue::to_json).collect::<Vec<serde_json::Value>>(),
+            "pushed": trace.pushed.iter().map(TraceValue::to_json).collect::<Vec<serde_json::Value>>(),
+            "condition": trace.condition.as_ref().map(TraceValue::to_json),
+            "constraints": constraints,
+        });
+        self.entries.push(entry.to_string());
+    }
+
+    ///
+    /// The number of the recorded instructions.
+    ///
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    ///
+    /// Writes the trace as a JSON array with an instruction per line, so the traces of two
+    /// runs can be compared with a line-based diff.
+    ///
+    pub fn to_json(&self) -> String {
+        if self.entries.is_empty() {
+            return "[]\n".to_owned();
+        }
+
+        format!("[\n{}\n]\n", self.entries.join(",\n"))
+    }
+
+    fn location(location: &CodeLocation) -> serde_json::Value {
+        json!({
+            "file": location.file,
+            "function": location.function,
+            "line": location.line,
+            "column": location.column,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+
+    use zinc_bytecode::instructions::*;
+    use zinc_bytecode::scalar::{IntegerType, ScalarType};
+    use zinc_bytecode::InstructionInfo;
+
+    use crate::core::location::CodeLocation;
+    use crate::core::Step;
+    use crate::trace::{StepTrace, TraceValue, Tracer};
+
+    #[test]
+    fn test_trace() {
+        let mut tracer = Tracer::default();
+
+        let add = Add.wrap();
+        let location = CodeLocation {
+            file: Some("main.zn".to_owned()),
+            function: Some("main".to_owned()),
+            line: Some(3),
+            column: Some(13),
+        };
+        let u8_value = |value: i32, is_constant| {
+            TraceValue::new(
+                Some(BigInt::from(value)),
+                ScalarType::Integer(IntegerType::U8),
+                is_constant,
+            )
+        };
+        let trace = StepTrace {
+            address: 7,
+            popped: vec![u8_value(2, true), u8_value(40, false)],
+            pushed: vec![u8_value(42, false)],
+            condition: Some(TraceValue::new(None, ScalarType::Boolean, false)),
+        };
+
+        tracer.record(
+            &Step {
+                instruction: &add,
+                location: &location,
+                callers: &[],
+                trace: None,
+            },
+            0,
+        );
+        assert!(tracer.is_empty());
+
+        tracer.record(
+            &Step {
+                instruction: &add,
+                location: &location,
+                callers: &[],
+                trace: Some(&trace),
+            },
+            1,
+        );
+
+        assert_eq!(
+            tracer.to_json(),
+            concat!(
+                "[\n",
+                r#"{"address":7,"condition":{"constant":false,"type":"bool","value":null},"#,
+                r#""constraints":1,"instruction":"add","#,
+                r#""location":{"column":13,"file":"main.zn","function":"main","line":3},"#,
+                r#""popped":[{"constant":true,"type":"u8","value":"2"},"#,
+                r#"{"constant":false,"type":"u8","value":"40"}],"#,
+                r#""pushed":[{"constant":false,"type":"u8","value":"42"}],"step":0}"#,
+                "\n]\n",
+            )
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//!
+//! The full witness assignment.
+//!
+
+use failure::Fail;
+use ff::PrimeField;
+use serde_json::json;
+
+use zinc_bytecode::data::types::DataType;
+use zinc_bytecode::data::values::{JsonValueError, Value};
+
+use crate::gadgets::utils::fr_to_bigint_unsigned;
+use crate::Engine;
+
+///
+/// The values of all the variables allocated by the circuit, in the allocation order.
+///
+/// It is written as a JSON object with the `inputs` and `auxiliary` arrays of the field elements
+/// as decimal strings, and the `output` of the circuit as the public data JSON.
+///
+#[derive(Debug, Clone)]
+pub struct Witness<E: Engine> {
+    /// The public input variables, starting with `ONE`, which are followed by the public outputs.
+    pub inputs: Vec<E::Fr>,
+    /// The private variables, which are the circuit input and the intermediate values.
+    pub auxiliary: Vec<E::Fr>,
+    /// The circuit output, which is written as the public data by the prover.
+    pub output: Value,
+}
+
+#[derive(Debug, Fail)]
+pub enum WitnessError {
+    #[fail(display = "missing the `{}` array", _0)]
+    MissingArray(&'static str),
+
+    #[fail(
+        display = "expected a field element as a decimal string, found `{}`",
+        _0
+    )]
+    InvalidElement(String),
+
+    #[fail(display = "invalid output: {}", _0)]
+    Output(JsonValueError),
+}
+
+impl<E: Engine> Witness<E> {
+    pub fn to_json(&self) -> serde_json::Value {
+        json!({
+            "inputs": Self::elements_to_json(&self.inputs),
+            "auxiliary": Self::elements_to_json(&self.auxiliary),
+            "output": self.output.to_json(),
+        })
+    }
+
+    ///
+    /// Reads the witness written by `to_json`, where the output has the `output_type` of
+    /// the circuit.
+    ///
+    pub fn from_json(
+        json: &serde_json::Value,
+        output_type: &DataType,
+    ) -> Result<Self, WitnessError> {
+        Ok(Self {
+            inputs: Self::elements_from_json(json, "inputs")?,
+            auxiliary: Self::elements_from_json(json, "auxiliary")?,
+            output: Value::from_typed_json(&json["output"], output_type)
+                .map_err(WitnessError::Output)?,
+        })
+    }
+
+    fn elements_to_json(elements: &[E::Fr]) -> serde_json::Value {
+        serde_json::Value::Array(
+            elements
+                .iter()
+                .map(|element| fr_to_bigint_unsigned(element).to_string().into())
+                .collect(),
+        )
+    }
+
+    fn elements_from_json(
+        json: &serde_json::Value,
+        name: &'static str,
+    ) -> Result<Vec<E::Fr>, WitnessError> {
+        json[name]
+            .as_array()
+            .ok_or(WitnessError::MissingArray(name))?
+            .iter()
+            .map(|element| {
+                element
+                    .as_str()
+                    .and_then(E::Fr::from_str)
+                    .ok_or_else(|| WitnessError::InvalidElement(element.to_string()))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ff::{Field, PrimeField};
+    use pairing::bn256::{Bn256, Fr};
+    use serde_json::json;
+
+    use zinc_bytecode::data::types::DataType;
+    use zinc_bytecode::data::values::{ScalarValue, Value};
+    use zinc_bytecode::scalar::ScalarType;
+
+    use crate::witness::Witness;
+
+    #[test]
+    fn test_json() {
+        let witness = Witness::<Bn256> {
+            inputs: vec![Fr::one(), Fr::from_str("42").unwrap()],
+            auxiliary: vec![Fr::from_str("6").unwrap(), Fr::zero()],
+            output: Value::Scalar(ScalarValue::Field(42.into())),
+        };
+
+        let json = witness.to_json();
+        assert_eq!(
+            json.to_string(),
+            r#"{"auxiliary":["6","0"],"inputs":["1","42"],"output":"42"}"#
+        );
+
+        let output_type = DataType::Scalar(ScalarType::Field);
+        let parsed = Witness::<Bn256>::from_json(&json, &output_type).expect("valid witness");
+        assert_eq!(parsed.inputs, witness.inputs);
+        assert_eq!(parsed.auxiliary, witness.auxiliary);
+        assert_eq!(parsed.output.to_json(), witness.output.to_json());
+        assert!(Witness::<Bn256>::from_json(&json!({ "inputs": [] }), &output_type).is_err());
+    }
+}